@@ -1,4 +1,5 @@
 from . import abstraction
+from . import lint
 from . import _autosar_data
 from ._autosar_data import *
 
@@ -7,6 +8,7 @@ __doc__ = _autosar_data.__doc__
 # explicitly set __all__ to avoid re-exports
 __all__ = [
     "abstraction",
+    "lint",
     "ElementType",
     "AutosarVersion",
     "AutosarModel",
@@ -21,6 +23,8 @@ __all__ = [
     "SubElementSpec",
     "ContentMode",
     "ValidSubElementInfo",
+    "BrokenReference",
+    "TextMatch",
     "CharacterDataTypeEnum",
     "CharacterDataTypeFloat",
     "CharacterDataTypeRestrictedString",
@@ -29,5 +33,6 @@ __all__ = [
     "check_file",
     "check_buffer",
     "AutosarDataError",
+    "ModelFrozenError",
     "__version__",
 ]
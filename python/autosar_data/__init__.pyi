@@ -18,9 +18,12 @@ Variables:
 """
 
 import autosar_data.abstraction
+import autosar_data.lint
 
 # from ._autosar_data import *
 from typing import (
+    Any,
+    Callable,
     Optional,
     final,
     Dict,
@@ -30,9 +33,13 @@ from typing import (
     List,
     Tuple,
     TypeAlias,
+    TypeVar,
     Union,
+    overload,
 )
 
+_T = TypeVar("_T")
+
 IncompatibleItemError: TypeAlias = Union[
     IncompatibleAttributeError,
     IncompatibleAttributeValueError,
@@ -177,18 +184,27 @@ class ArxmlFile:
         ...
     model: AutosarModel
     """the autosar data model which this file is part of"""
-    elements_dfs: Iterator[Tuple[int, Element]]
+    elements_dfs: ItemIterator[Tuple[int, Element]]
     """dfs iterator over all elements in this file"""
     def elements_dfs_with_max_depth(
         self, max_depth: int
-    ) -> Iterator[Tuple[int, Element]]:
+    ) -> ItemIterator[Tuple[int, Element]]:
         """dfs iterator over all elements in this file, with a maximum depth"""
         ...
 
     def serialize(self) -> str:
         """serialize the the file to a string. This string can be loaded as valid arxml if is written to disk."""
         ...
-    xml_standalone: bool
+    xml_standalone: Optional[bool]
+    """the standalone attribute in the xml declaration of the file"""
+    encoding: str
+    """the encoding in the xml declaration of the file (read-only). The file is always written as UTF-8, but the spelling from the loaded file is kept. Files which declare a different encoding are rejected when they are loaded""""
+    header_comments: List[str]
+    """comments that are placed before the root element of the file"""
+    schema_location: Optional[str]
+    """value of the xsi:schemaLocation attribute of the root element. Setting it to None restores the default for the version of the file"""
+    root_attributes: Dict[str, str]
+    """additional attributes of the root element, e.g. T, S or xml:space"""
     """contains the xml standalone attribute (if any) in the xml file header"""
 
 @final
@@ -202,10 +218,15 @@ class Attribute:
     content: CharacterData
     """content of the attribute - this data can be free-form text, a pre-defined enum value (str), or very rarely a float or int"""
 
-@final
 class AutosarDataError(Exception):
     pass
 
+@final
+class ModelFrozenError(AutosarDataError):
+    """raised when a frozen model is modified"""
+
+    pass
+
 @final
 class AutosarModel:
     """
@@ -233,9 +254,12 @@ class AutosarModel:
         ...
 
     def load_file(
-        self, filename: str, strict: bool = False
+        self, filename: str, strict: bool = False, read_only: bool = False
     ) -> Tuple[ArxmlFile, List[str]]:
-        """load a file as arxml"""
+        """load a file as arxml
+
+        If read_only is true, then the model is frozen after loading the file.
+        """
         ...
 
     def remove_file(self, arxmlfile: ArxmlFile) -> None:
@@ -256,12 +280,28 @@ class AutosarModel:
     def get_element_by_path(self, autosar_path: str) -> Element:
         """get an identifiable element in the model by its Autosar path"""
         ...
-    elements_dfs: Iterator[Tuple[int, Element]]
+
+    def get_element_by_xml_path(self, xml_path: str, /) -> Optional[Element]:
+        """get an element in the model by its xml path, as returned by Element.xml_path
+
+        This also works for elements that are not identifiable. The xml path is not necessarily unique,
+        since siblings with the same element name have the same path. A segment can be given a 1-based
+        index among the siblings that match it, e.g. `<FIBEX-ELEMENT-REF-CONDITIONAL>[2]`.
+        Returns None if no element matches, and raises an error if several elements match.
+        """
+        ...
+    elements_dfs: ItemIterator[Tuple[int, Element]]
     """depth first dearch iterator over all elements in the model, regardless of their association with a file"""
+    def elements_dfs_named(
+        self, element_names: List[ElementName], /
+    ) -> ItemIterator[Tuple[int, Element]]:
+        """depth first search iterator over the elements in the model which have one of the given element names"""
+        ...
+
     def sort(self) -> None:
         """sort the entire model in place. Takes all ordering constraints into account."""
         ...
-    identifiable_elements: Iterator[Tuple[str, Element]]
+    identifiable_elements: ItemIterator[Tuple[str, Element]]
     """iterator over all identifiable elements in the model"""
     def get_references_to(self, target_path: str) -> List[Element]:
         """get all reference elements which refer to the given Autosar path"""
@@ -275,6 +315,197 @@ class AutosarModel:
         """create a fully independent copy of the model"""
         ...
 
+    def freeze(self) -> None:
+        """make the model read-only
+
+        Any attempt to modify a frozen model, its files or its elements raises a ModelFrozenError.
+        """
+        ...
+
+    def unfreeze(self) -> None:
+        """make a frozen model modifiable again"""
+        ...
+
+    is_frozen: bool
+    """true if the model is frozen, i.e. read-only"""
+
+    def find_unreferenced(
+        self,
+        element_types: Optional[List[ElementName]] = None,
+        roots: Optional[List[Element]] = None,
+    ) -> List[Element]:
+        """find all elements in AR-PACKAGEs that are not referenced by any other element
+
+        A reference to a sub element also counts as a reference to the containing element.
+        If roots are given, then all elements that can't be reached from the roots by following references
+        are reported, including groups of elements that only reference each other. Elements that are not
+        among the element_types are kept, so they count as roots too. If a root is an AR-PACKAGE, then everything
+        inside of it is kept. The result is sorted by path.
+        """
+        ...
+
+    def remove_unreferenced(
+        self,
+        dry_run: bool = True,
+        element_types: Optional[List[ElementName]] = None,
+        roots: Optional[List[Element]] = None,
+    ) -> List[str]:
+        """remove all elements in AR-PACKAGEs that are not referenced by any other element
+
+        Without roots, removing an element can cause further elements to become unreferenced, so the search
+        is repeated until no more unreferenced elements are found. With roots, all elements that can't be
+        reached from the roots are removed, as in find_unreferenced.
+        Returns the paths of all removed elements. If dry_run is true, the model is not modified.
+        """
+        ...
+
+    def fingerprints(
+        self, algorithm: str = "sha256", ignore: Optional[List[AttributeName]] = None
+    ) -> Dict[str, str]:
+        """calculate a content hash for every identifiable element in the model
+
+        Returns a dict which maps each Autosar path to the content hash of the element, see Element.content_hash
+        """
+        ...
+
+    def get_element_by_uuid(self, uuid: str, /) -> Optional[Element]:
+        """get an element in the model by its UUID
+
+        The lookup uses an index. Each result is checked against the model, and the index is rebuilt if it is
+        outdated or if the UUID is not found, so a lookup of a missing UUID scans the whole model.
+        If several elements share the same UUID, then any one of them is returned.
+        """
+        ...
+
+    def find_duplicate_uuids(self) -> Dict[str, List[Element]]:
+        """find all UUIDs that are used by more than one element
+
+        Returns a dict which maps each duplicated UUID to the list of elements that use it
+        """
+        ...
+
+    def assign_missing_uuids(
+        self, generator: Optional[Callable[[], Any]] = None
+    ) -> List[Element]:
+        """set a UUID on every identifiable element that does not have one yet
+
+        The UUIDs are created by calling the generator, which defaults to uuid.uuid4.
+        Returns the list of elements that received a new UUID.
+        """
+        ...
+
+    def regenerate_uuids(
+        self, subtree: Element, /, generator: Optional[Callable[[], Any]] = None
+    ) -> None:
+        """replace the UUIDs of the given element and all of its sub elements with newly generated ones
+
+        Only elements that already have a UUID are modified. This is useful after copying a subtree,
+        since the copy has the same UUIDs as the original.
+        The UUIDs are created by calling the generator, which defaults to uuid.uuid4.
+        """
+        ...
+
+    def deduplicate(self, element_names: List[ElementName], /) -> Dict[str, str]:
+        """merge elements in AR-PACKAGEs that only differ in their SHORT-NAME and UUID
+
+        For each group of identical elements, the one with the lexicographically smallest path is kept.
+        All references to the other elements are redirected to it, and the other elements are removed.
+        Returns a dict which maps the paths of the removed elements to the paths of the kept elements.
+        """
+        ...
+
+    def extract(
+        self,
+        paths: List[str],
+        include_dependencies: bool = True,
+        into: Optional[AutosarModel] = None,
+    ) -> Tuple[AutosarModel, List[Tuple[str, str]]]:
+        """copy the elements with the given paths into a new or existing model, keeping their Autosar paths
+
+        If include_dependencies is true, all elements that are referenced by the copied elements are copied as well,
+        until all references can be resolved. Elements that already exist in the destination model are not copied.
+        Returns a tuple of the destination model and a list of (element path, reference target) for all
+        references in the copied elements that could not be resolved in the destination model.
+        """
+        ...
+
+    def move_package(self, old_path: str, new_path: str, /) -> Element:
+        """move an AR-PACKAGE to a new Autosar path
+
+        Missing parent packages of the new path are created. All references to the package and its content are updated.
+        """
+        ...
+
+    def rename_path_prefix(self, old_prefix: str, new_prefix: str, /) -> Dict[str, str]:
+        """move all AR-PACKAGEs and packaged elements whose Autosar path starts with old_prefix, so that their path starts with new_prefix
+
+        The prefix only matches complete path segments, so the prefix "/A" matches "/A" and "/A/B", but not "/AB".
+        Missing parent packages are created, and all references are updated.
+        Returns a dict which maps the old paths of the moved elements to their new paths.
+        """
+        ...
+
+    def find_missing_documentation(
+        self,
+        fields: Optional[List[Literal["LONG-NAME", "DESC", "INTRODUCTION"]]] = None,
+        languages: Optional[List[str]] = None,
+        element_types: Optional[List[ElementName]] = None,
+    ) -> List[Tuple[Element, List[str]]]:
+        """find identifiable elements whose documentation is missing
+
+        The fields LONG-NAME, DESC and INTRODUCTION can be checked; by default only DESC is checked.
+        If languages are given, then the documentation must exist in each of them, otherwise any language is sufficient.
+        Returns a list of (element, missing) sorted by path. The entries in missing are the field names, e.g. "DESC",
+        or the field names and languages, e.g. "DESC:DE", if languages are given."""
+        ...
+
+    def reference_report(self) -> List[BrokenReference]:
+        """find all references whose target does not exist, and search for candidates that could be the intended target
+
+        Candidates must have the element type given by the DEST attribute of the reference. Their score
+        is between 0 and 1: the similarity of the SHORT-NAME to the name in the missing path contributes 3/4,
+        and the fraction of matching trailing path elements contributes 1/4.
+        """
+        ...
+
+    def auto_repair(self, threshold: float = 0.9) -> List[Tuple[Element, str, str]]:
+        """repair broken references which have exactly one candidate with a score of at least threshold
+
+        See reference_report for details about the candidates.
+        Returns a list of (reference element, old target path, new target path) for all repaired references.
+        """
+        ...
+
+    def search_text(
+        self,
+        pattern: str,
+        element_names: Optional[List[ElementName]] = None,
+        include_attributes: bool = False,
+    ) -> List[TextMatch]:
+        """search for a regular expression in the character data of all elements
+
+        If element_names is given, then only the content of elements with these names is searched.
+        If include_attributes is true, then the attribute values are searched as well.
+        """
+        ...
+
+    def replace_text(
+        self,
+        pattern: str,
+        replacement: str,
+        dry_run: bool = True,
+        element_names: Optional[List[ElementName]] = None,
+        include_attributes: bool = False,
+    ) -> List[Tuple[Element, Optional[str], str, str]]:
+        """replace all matches of a regular expression in the character data of all elements
+
+        The replacement can refer to capture groups using $1 or ${name}. Only string values are modified.
+        Changing the text of a SHORT-NAME renames the parent element and updates all references to it.
+        Returns a list of (element, attribute name, old text, new text) for all changes.
+        If dry_run is true, the model is not modified.
+        """
+        ...
+
 @final
 class AutosarVersion:
     """
@@ -336,6 +567,41 @@ class ContentMode:
     Characters: ContentMode
     Mixed: ContentMode
 
+class ItemIterator(Iterator[_T]):
+    """
+    The iterator type returned by the iterator methods of this module
+
+    Items are produced lazily. Length, indexing and slicing refer to the items that have not been iterated over yet.
+    """
+
+    def __iter__(self) -> ItemIterator[_T]: ...
+    def __next__(self) -> _T: ...
+    def __len__(self) -> int:
+        """get the number of remaining items; all of them are buffered in order to count them
+
+        This is not the total number of items: items that were already returned by `next()` are not counted."""
+        ...
+
+    def __length_hint__(self) -> int:
+        """estimate the number of remaining items without buffering them"""
+        ...
+
+    @overload
+    def __getitem__(self, index: int) -> _T: ...
+    @overload
+    def __getitem__(self, index: slice) -> List[_T]: ...
+    def by_name(self, name: str, /) -> Optional[_T]:
+        """get the first remaining item with the given name
+
+        The items before it remain available for the iteration."""
+        ...
+
+    def filter(self, *, element_name: ElementName) -> ItemIterator[_T]:
+        """filter the remaining items by their element name
+
+        This consumes the iterator and returns a new iterator containing only the matching items."""
+        ...
+
 @final
 class Element:
     """
@@ -347,6 +613,17 @@ class Element:
     def serialize(self) -> str:
         """serialize this element and its sub elements into a string. This string is valid xml, but it is not a vaild arxml file"""
         ...
+
+    def content_hash(
+        self, algorithm: str = "sha256", ignore: Optional[List[AttributeName]] = None
+    ) -> str:
+        """calculate a hash of the content of this element and all of its sub elements
+
+        The hash is calculated over a canonical serialization of the element, so it is stable across runs and platforms.
+        Comments and the ignored attributes are not included. By default only the UUID attribute is ignored.
+        Supported algorithms are "sha256", "sha384" and "sha512".
+        """
+        ...
     parent: Element
     """reference to the parent of this element"""
     named_parent: Element
@@ -390,10 +667,67 @@ class Element:
         """create a copy of some other element (with all of its children) as a child of this element (optionally at a specific position)"""
         ...
 
+    def import_from(
+        self,
+        other: Element,
+        /,
+        rename_map: Optional[Dict[str, str]] = None,
+        on_conflict: Literal["error", "skip", "replace", "rename"] = "error",
+    ) -> Element:
+        """import a copy of an element from another model as a sub element of this element
+
+        References in the copy are rewritten using the rename_map, which maps path prefixes in the source
+        model to path prefixes in this model. The longest matching prefix is used. References to the
+        imported element itself or to its sub elements are always redirected to the copy.
+        If this element already has a sub element with the same name, on_conflict decides what happens:
+        "error" raises an exception, "skip" returns the existing element, "replace" removes the existing
+        element before importing, and "rename" imports the copy with a new unique name.
+        """
+        ...
+
     def move_element_here(self, move_element: Element, position: Optional[int] = None) -> Element:
         """move an element from somewhere else in this model or from another model to become a child element (optionally at a specific position)"""
         ...
 
+    def insert_before(self, sibling: Element, /) -> Element:
+        """move this element so that it is located directly before the given sibling
+
+        If the parent has the content mode Sequence, then the element can only be placed next to elements with the same name.
+        """
+        ...
+
+    def insert_after(self, sibling: Element, /) -> Element:
+        """move this element so that it is located directly after the given sibling
+
+        If the parent has the content mode Sequence, then the element can only be placed next to elements with the same name.
+        """
+        ...
+
+    def move_to(self, position: int, /) -> None:
+        """move this element to a new position within its parent
+
+        The position is the index of the element in the content of the parent after the move.
+        If the parent has the content mode Sequence, then the element can only be moved among elements with the same name.
+        """
+        ...
+
+    def swap_with(self, other: Element, /) -> None:
+        """swap the positions of this element and the other element
+
+        Both elements must be part of the same model, and neither may be a sub element of the other. If they have
+        the same parent and its content mode is Sequence, then both elements must have the same name.
+        If the swap fails, then neither element is moved.
+        """
+        ...
+
+    def sort_by(self, key: Callable[[Element], Any], /) -> None:
+        """sort the sub elements of this element using a key function, like the Python builtin sorted()
+
+        This is only possible if the content mode of this element is Bag, since the order of the sub elements is
+        not significant in this case. The sort is stable.
+        """
+        ...
+
     def remove_sub_element(self, element: Element) -> None:
         """remove a sub element and all of its content"""
         ...
@@ -428,15 +762,26 @@ class Element:
         ...
     position: int
     """the position of this element in the content of its parent"""
-    sub_elements: Iterator[Element]
+    sub_elements: ItemIterator[Element]
     """an iterator over all sub elements in the content of this element. It skips character data content items"""
-    elements_dfs: Iterator[Tuple[int, Element]]
+    def sub_elements_named(self, name: ElementName, /) -> ItemIterator[Element]:
+        """iterate over the sub elements with the given element name"""
+        ...
+
+    elements_dfs: ItemIterator[Tuple[int, Element]]
     """depth first search iterator for this element and all of its sub elements"""
     def elements_dfs_with_max_depth(
         self, max_depth: int
-    ) -> Iterator[Tuple[int, Element]]:
+    ) -> ItemIterator[Tuple[int, Element]]:
         """depth first search iterator for this element and all of its sub elements, with a maximum depth"""
         ...
+
+    def elements_dfs_named(
+        self, element_names: List[ElementName], /
+    ) -> ItemIterator[Tuple[int, Element]]:
+        """depth first search iterator for this element and all of its sub elements, limited to the given element names"""
+        ...
+
     character_data: CharacterData
     """character content of this element, if any. For elements with ContentType=Element, or empty elements this is None"""
     def remove_character_data(self) -> None:
@@ -452,9 +797,9 @@ class Element:
         ...
     content_item_count: int
     """number of content items (character data and/or sub elements)"""
-    content: Iterator[Union[Element, CharacterData]]
+    content: ItemIterator[Union[Element, CharacterData]]
     """iterator over all content of this element"""
-    attributes: Iterator[Attribute]
+    attributes: ItemIterator[Attribute]
     """iterator over all attributes of this element"""
     def attribute_value(self, attrname: AttributeName) -> CharacterData:
         """get the attribute value of a specific attribute. Returns None if that attribute is not set"""
@@ -488,6 +833,8 @@ class Element:
     """a path listing all xml elements from the root of the model to the element. This is intended for display. e.g. in error messages"""
     min_version: AutosarVersion
     """the autosar version of the file containing the element. If multiple files in a merged model contain the element, then this is the minimum of the file versions."""
+    source_location: Optional[Tuple[str, int, int]]
+    """the position (filename, line, column) where this element was loaded from. Elements that were created programmatically have no source location"""
 
 @final
 class ElementType:
@@ -574,6 +921,38 @@ class IncompatibleElementError:
     allowed_versions: List[AutosarVersion]
     """list of versions in which this element is compatible"""
 
+@final
+class BrokenReference:
+    """
+    A reference whose target does not exist, together with possible replacement targets
+    """
+
+    element: Element
+    """the reference element"""
+    target: str
+    """the path of the missing target"""
+    dest: Optional[str]
+    """the value of the DEST attribute of the reference"""
+    candidates: List[Tuple[Element, float]]
+    """candidate targets and their similarity scores, best candidates first"""
+
+@final
+class TextMatch:
+    """
+    A piece of text in the model that matches a search pattern
+    """
+
+    element: Element
+    """the element containing the text"""
+    attribute: Optional[str]
+    """the name of the attribute, if the text is an attribute value"""
+    content_index: Optional[int]
+    """the index of the content item, if the element has mixed content"""
+    text: str
+    """the complete text"""
+    spans: List[Tuple[int, int]]
+    """the (start, end) character offsets of all matches in the text"""
+
 @final
 class ValidSubElementInfo:
     """
@@ -14,8 +14,14 @@ __all__ = [
     "ByteOrder",
     "ArPackage",
     "EcuInstance",
+    "PredefinedVariant",
+    "RemovePreview",
+    "Sd",
+    "Sdg",
+    "SenderReceiverToSignalMapping",
     "SwcToEcuMapping",
     "System",
     "SystemCategory",
     "SystemMapping",
+    "wrap",
 ]
@@ -1,19 +1,82 @@
-from typing import final, Iterator, List, Optional, Tuple
+from typing import final, overload, Any, Dict, Iterator, List, Optional, Tuple, TypeAlias, Union
 from autosar_data.abstraction.communication import *
 from autosar_data.abstraction.datatype import *
 from autosar_data.abstraction.ecu_configuration import *
 from autosar_data.abstraction.software_component import *
 
-from autosar_data import ArxmlFile, AutosarModel, AutosarVersion, Element
+from autosar_data import ArxmlFile, AutosarModel, AutosarVersion, Element, ItemIterator
+
+SdgValue: TypeAlias = Union[str, List[Tuple[str, "SdgValue"]], Dict[str, "SdgValue"]]
+SdgValues: TypeAlias = Union[List[Tuple[str, SdgValue]], Dict[str, SdgValue]]
+
+class _IdentifiableElement:
+    """
+    The methods for SDGs and documentation that are shared by all wrappers of identifiable elements
+
+    This base class only exists in the type stubs.
+    """
+
+    def long_name(self, language: Optional[str] = None) -> Dict[str, str]:
+        """get the LONG-NAME of this element as a dict of language -> text, optionally only in one language"""
+        ...
+
+    def set_long_name(self, text: Optional[str], /, language: str = "EN") -> None:
+        """set the LONG-NAME of this element in one language. If the text is None, this language is removed"""
+        ...
+
+    def desc(self, language: Optional[str] = None) -> Dict[str, str]:
+        """get the DESC of this element as a dict of language -> text, optionally only in one language"""
+        ...
+
+    def set_desc(self, text: Optional[str], /, language: str = "EN") -> None:
+        """set the DESC of this element in one language. If the text is None, this language is removed"""
+        ...
+
+    def introduction(self, language: Optional[str] = None) -> Dict[str, str]:
+        """get the INTRODUCTION of this element as a dict of language -> text, optionally only in one language
+
+        Paragraphs are separated by newlines."""
+        ...
+
+    def set_introduction(self, text: Optional[str], /, language: str = "EN") -> None:
+        """set the INTRODUCTION of this element in one language. If the text is None, this language is removed
+
+        Each line becomes a separate paragraph."""
+        ...
+
+    def get_sdg(self, gid: str, /) -> Optional[Sdg]:
+        """get the SDG with the given GID from the ADMIN-DATA of this element"""
+        ...
+
+    def sdgs(self) -> List[Sdg]:
+        """get all SDGs in the ADMIN-DATA of this element"""
+        ...
+
+    @overload
+    def set_sdg(self, gid: str, values: SdgValues, /) -> Sdg: ...
+    @overload
+    def set_sdg(self, sdg: Sdg, /) -> Sdg: ...
+    def set_sdg(self, gid: Union[str, Sdg], values: Optional[SdgValues] = None, /) -> Sdg:
+        """create or replace an SDG in the ADMIN-DATA of this element
+
+        The SDG is given either as an `Sdg` object, or as a GID and a list of (gid, value) pairs.
+        String values become SDs and list values become nested SDGs; the order is kept."""
+        ...
 
 @final
-class ArPackage:
+class ArPackage(_IdentifiableElement):
     """
     An `ArPackage` is an Autosar package, which can contain other packages or elements
     """
 
     def __init__(self, element: Element) -> ArPackage: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_application_array_data_type(
         self,
         name: str,
@@ -43,34 +106,72 @@ class ArPackage:
         """create a new `ApplicationRecordDataType` in the package"""
         ...
 
+    def get_or_create_application_record_data_type(self, name: str, /) -> ApplicationRecordDataType:
+        """get the `ApplicationRecordDataType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an APPLICATION-RECORD-DATA-TYPE"""
+        ...
+
     def create_application_sw_component_type(
         self, name: str
     ) -> ApplicationSwComponentType:
         """create a new `ApplicationSwComponentType` in the package"""
         ...
 
+    def get_or_create_application_sw_component_type(self, name: str, /) -> ApplicationSwComponentType:
+        """get the `ApplicationSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an APPLICATION-SW-COMPONENT-TYPE"""
+        ...
+
     def create_client_server_interface(self, name: str) -> ClientServerInterface:
         """create a new `ClientServerInterface` in the package"""
         ...
 
+    def get_or_create_client_server_interface(self, name: str, /) -> ClientServerInterface:
+        """get the `ClientServerInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a CLIENT-SERVER-INTERFACE"""
+        ...
+
     def create_complex_device_driver_sw_component_type(
         self, name: str
     ) -> ComplexDeviceDriverSwComponentType:
         """create a new `ComplexDeviceDriverSwComponentType` in the package"""
         ...
 
+    def get_or_create_complex_device_driver_sw_component_type(self, name: str, /) -> ComplexDeviceDriverSwComponentType:
+        """get the `ComplexDeviceDriverSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a COMPLEX-DEVICE-DRIVER-SW-COMPONENT-TYPE"""
+        ...
+
     def create_composition_sw_component_type(
         self, name: str
     ) -> CompositionSwComponentType:
         """create a new `CompositionSwComponentType` in the package"""
         ...
 
+    def get_or_create_composition_sw_component_type(self, name: str, /) -> CompositionSwComponentType:
+        """get the `CompositionSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a COMPOSITION-SW-COMPONENT-TYPE"""
+        ...
+
     def create_compu_method(
         self, name: str, content: CompuMethodContent
     ) -> CompuMethod:
         """create a new `CompuMethod` in the package"""
         ...
 
+    def get_or_create_compu_method(
+        self, name: str, content: CompuMethodContent, /
+    ) -> CompuMethod:
+        """get the `CompuMethod` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a COMPU-METHOD, or if its content differs"""
+        ...
+
     def create_constant_specification(
         self, name: str, value: ValueSpecification
     ) -> ConstantSpecification:
@@ -81,30 +182,66 @@ class ArPackage:
         """create a new `DataConstr` in the package"""
         ...
 
+    def get_or_create_data_constr(self, name: str, /) -> DataConstr:
+        """get the `DataConstr` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a DATA-CONSTR"""
+        ...
+
     def create_data_transformation_set(self, name: str) -> DataTransformationSet:
         """create a new `DataTransformationSet` in the package"""
         ...
 
+    def get_or_create_data_transformation_set(self, name: str, /) -> DataTransformationSet:
+        """get the `DataTransformationSet` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a DATA-TRANSFORMATION-SET"""
+        ...
+
     def create_data_type_mapping_set(self, name: str) -> DataTypeMappingSet:
         """create a new `DataTypeMappingSet` in the package"""
         ...
 
+    def get_or_create_data_type_mapping_set(self, name: str, /) -> DataTypeMappingSet:
+        """get the `DataTypeMappingSet` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a DATA-TYPE-MAPPING-SET"""
+        ...
+
     def create_ecu_abstraction_sw_component_type(
         self, name: str
     ) -> EcuAbstractionSwComponentType:
         """create a new `EcuAbstractionSwComponentType` in the package"""
         ...
 
+    def get_or_create_ecu_abstraction_sw_component_type(self, name: str, /) -> EcuAbstractionSwComponentType:
+        """get the `EcuAbstractionSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECU-ABSTRACTION-SW-COMPONENT-TYPE"""
+        ...
+
     def create_ecuc_definition_collection(self, name: str) -> EcucDefinitionCollection:
         """create a new `EcucDefinitionCollection` in the package"""
         ...
 
+    def get_or_create_ecuc_definition_collection(self, name: str, /) -> EcucDefinitionCollection:
+        """get the `EcucDefinitionCollection` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECUC-DEFINITION-COLLECTION"""
+        ...
+
     def create_ecuc_destination_uri_def_set(
         self, name: str
     ) -> EcucDestinationUriDefSet:
         """create a new `EcucDestinationUriDefSet` in the package"""
         ...
 
+    def get_or_create_ecuc_destination_uri_def_set(self, name: str, /) -> EcucDestinationUriDefSet:
+        """get the `EcucDestinationUriDefSet` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECUC-DESTINATION-URI-DEF-SET"""
+        ...
+
     def create_ecuc_module_configuration_values(
         self, name: str, definition: EcucModuleDef
     ) -> EcucModuleConfigurationValues:
@@ -115,10 +252,22 @@ class ArPackage:
         """create a new `EcucModuleDef` in the package"""
         ...
 
+    def get_or_create_ecuc_module_def(self, name: str, /) -> EcucModuleDef:
+        """get the `EcucModuleDef` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECUC-MODULE-DEF"""
+        ...
+
     def create_ecuc_value_collection(self, name: str) -> EcucValueCollection:
         """create a new `EcucValueCollection` in the package"""
         ...
 
+    def get_or_create_ecuc_value_collection(self, name: str, /) -> EcucValueCollection:
+        """get the `EcucValueCollection` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECUC-VALUE-COLLECTION"""
+        ...
+
     def create_implementation_data_type(
         self, settings: ImplementationDataTypeSettings
     ) -> ImplementationDataType:
@@ -135,28 +284,64 @@ class ArPackage:
         """create a new `ModeSwitchInterface` in the package"""
         ...
 
+    def get_or_create_mode_switch_interface(self, name: str, /) -> ModeSwitchInterface:
+        """get the `ModeSwitchInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a MODE-SWITCH-INTERFACE"""
+        ...
+
     def create_nv_data_interface(self, name: str) -> NvDataInterface:
         """create a new `NvDataInterface` in the package"""
         ...
 
+    def get_or_create_nv_data_interface(self, name: str, /) -> NvDataInterface:
+        """get the `NvDataInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a NV-DATA-INTERFACE"""
+        ...
+
     def create_parameter_interface(self, name: str) -> ParameterInterface:
         """create a new `ParameterInterface` in the package"""
         ...
 
+    def get_or_create_parameter_interface(self, name: str, /) -> ParameterInterface:
+        """get the `ParameterInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a PARAMETER-INTERFACE"""
+        ...
+
     def create_sender_receiver_interface(self, name: str) -> SenderReceiverInterface:
         """create a new `SenderReceiverInterface` in the package"""
         ...
 
+    def get_or_create_sender_receiver_interface(self, name: str, /) -> SenderReceiverInterface:
+        """get the `SenderReceiverInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SENDER-RECEIVER-INTERFACE"""
+        ...
+
     def create_sensor_actuator_sw_component_type(
         self, name: str
     ) -> SensorActuatorSwComponentType:
         """create a new `SensorActuatorSwComponentType` in the package"""
         ...
 
+    def get_or_create_sensor_actuator_sw_component_type(self, name: str, /) -> SensorActuatorSwComponentType:
+        """get the `SensorActuatorSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SENSOR-ACTUATOR-SW-COMPONENT-TYPE"""
+        ...
+
     def create_service_sw_component_type(self, name: str) -> ServiceSwComponentType:
         """create a new `ServiceSwComponentType` in the package"""
         ...
 
+    def get_or_create_service_sw_component_type(self, name: str, /) -> ServiceSwComponentType:
+        """get the `ServiceSwComponentType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SERVICE-SW-COMPONENT-TYPE"""
+        ...
+
     def create_someip_sd_client_event_group_timing_config(
         self, name: str, time_to_live: int
     ) -> SomeipSdClientEventGroupTimingConfig:
@@ -169,6 +354,12 @@ class ArPackage:
         """create a new `SomeipSdClientServiceInstanceConfig` in the package"""
         ...
 
+    def get_or_create_someip_sd_client_service_instance_config(self, name: str, /) -> SomeipSdClientServiceInstanceConfig:
+        """get the `SomeipSdClientServiceInstanceConfig` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SOMEIP-SD-CLIENT-SERVICE-INSTANCE-CONFIG"""
+        ...
+
     def create_someip_sd_server_event_group_timing_config(
         self, name: str, request_response_delay: RequestResponseDelay
     ) -> SomeipSdServerEventGroupTimingConfig:
@@ -195,6 +386,24 @@ class ArPackage:
         """create a new `SwBaseType` in the package"""
         ...
 
+    def get_or_create_sw_base_type(
+        self,
+        name: str,
+        bit_length: int,
+        base_type_encoding: BaseTypeEncoding,
+        /,
+        *,
+        byte_order: Optional[ByteOrder] = None,
+        mem_alignment: Optional[int] = None,
+        native_declaration: Optional[str] = None,
+    ) -> SwBaseType:
+        """get the `SwBaseType` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SW-BASE-TYPE, or if
+        its bit length or encoding differ from the requested ones. The optional settings are only used
+        when the base type is created."""
+        ...
+
     def create_system(self, name: str, category: SystemCategory, /) -> System:
         """create a new System in the package
 
@@ -202,18 +411,42 @@ class ArPackage:
         """
         ...
 
+    def get_or_create_system(self, name: str, category: SystemCategory, /) -> System:
+        """get the System with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SYSTEM, or if its category differs"""
+        ...
+
     def create_system_signal(self, name: str) -> SystemSignal:
         """create a new `SystemSignal` in the package"""
         ...
 
+    def get_or_create_system_signal(self, name: str, /) -> SystemSignal:
+        """get the `SystemSignal` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SYSTEM-SIGNAL"""
+        ...
+
     def create_system_signal_group(self, name: str) -> SystemSignalGroup:
         """create a new `SystemSignalGroup` in the package"""
         ...
 
+    def get_or_create_system_signal_group(self, name: str, /) -> SystemSignalGroup:
+        """get the `SystemSignalGroup` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a SYSTEM-SIGNAL-GROUP"""
+        ...
+
     def create_trigger_interface(self, name: str) -> TriggerInterface:
         """create a new `TriggerInterface` in the package"""
         ...
 
+    def get_or_create_trigger_interface(self, name: str, /) -> TriggerInterface:
+        """get the `TriggerInterface` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a TRIGGER-INTERFACE"""
+        ...
+
     def create_unit(self, name: str, /, *, display_name: Optional[str] = None) -> Unit:
         """create a new `Unit` in the package"""
         ...
@@ -222,11 +455,17 @@ class ArPackage:
         """create a new sub-package in the package"""
         ...
     element: Element
-    def elements(self) -> Iterator[Element]:
+    def elements(self) -> ItemIterator[Element]:
         """iterate over all elements in the package"""
         ...
 
-    def sub_packages(self) -> Iterator[ArPackage]:
+    def typed_elements(self) -> ItemIterator[Any]:
+        """iterate over all elements in the package, wrapped in the matching abstraction classes
+
+        Elements without an abstraction class are returned as plain `Element`s."""
+        ...
+
+    def sub_packages(self) -> ItemIterator[ArPackage]:
         """iterate over all sub-packages in the package"""
         ...
     name: str
@@ -241,13 +480,43 @@ class AutosarModelAbstraction:
         """create a new `AutosarModelAbstraction` with an empty `AutosarModel`"""
         ...
 
+    def all_compu_methods(self, /) -> List[CompuMethod]:
+        """get all COMPU-METHODs in the model, sorted by path"""
+        ...
+
+    def all_ecu_instances(self, /) -> List[EcuInstance]:
+        """get all ECU-INSTANCEs in the model, sorted by path"""
+        ...
+
+    def all_frames(self, /) -> List[Union[Frame, Element]]:
+        """get all frames in the model, sorted by path
+
+        Frames without an abstraction class are returned as plain `Element`s."""
+        ...
+
+    def all_isignals(self, /) -> List[ISignal]:
+        """get all I-SIGNALs in the model, sorted by path"""
+        ...
+
+    def all_pdus(self, /) -> List[Union[Pdu, UserDefinedPdu]]:
+        """get all PDUs in the model, sorted by path"""
+        ...
+
+    def all_sw_component_types(self, /) -> List[SwComponentType]:
+        """get all software component types in the model, sorted by path"""
+        ...
+
+    def all_system_signals(self, /) -> List[SystemSignal]:
+        """get all SYSTEM-SIGNALs in the model, sorted by path"""
+        ...
+
     def create_file(
         self, filename: str, /, *, version: Optional[AutosarVersion] = None
     ) -> ArxmlFile:
         """Create a new file in the model"""
         ...
 
-    def files(self, /) -> Iterator[ArxmlFile]:
+    def files(self, /) -> List[ArxmlFile]:
         """iterate over all files in the model"""
         ...
 
@@ -260,14 +529,56 @@ class AutosarModelAbstraction:
         """create an `AutosarModelAbstraction` from a file on disk"""
         ...
 
+    def get_compu_method(self, path: str, /) -> Optional[CompuMethod]:
+        """get the COMPU-METHOD with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not a COMPU-METHOD"""
+        ...
+
+    def get_ecu_instance(self, path: str, /) -> Optional[EcuInstance]:
+        """get the ECU-INSTANCE with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not an ECU-INSTANCE"""
+        ...
+
     def get_element_by_path(self, path: str, /) -> Optional[Element]:
         """Get an element by its path"""
         ...
 
+    def get_isignal(self, path: str, /) -> Optional[ISignal]:
+        """get the I-SIGNAL with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not an I-SIGNAL"""
+        ...
+
     def get_or_create_package(self, path: str, /) -> ArPackage:
         """Get a package by its path or create it if it does not exist"""
         ...
 
+    def get_sw_component_type(self, path: str, /) -> Optional[SwComponentType]:
+        """get the software component type with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not a software component type"""
+        ...
+
+    def get_system(self, path: str, /) -> Optional[System]:
+        """get the SYSTEM with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not a SYSTEM"""
+        ...
+
+    def get_system_signal(self, path: str, /) -> Optional[SystemSignal]:
+        """get the SYSTEM-SIGNAL with the given path
+
+        Returns None if the path does not exist, and raises an error if the element is not a SYSTEM-SIGNAL"""
+        ...
+
+    def get_typed_element_by_path(self, path: str, /) -> Optional[Any]:
+        """Get an element by its path, wrapped in the matching abstraction class
+
+        If there is no abstraction class for the element, then the plain `Element` is returned."""
+        ...
+
     def load_file(
         self, filename: str, /, *, strict: bool = False
     ) -> Tuple[ArxmlFile, List[str]]:
@@ -275,11 +586,17 @@ class AutosarModelAbstraction:
         ...
     model: AutosarModel
     """Get the underlying `AutosarModel` from the abstraction model"""
-    def packages(self, /) -> Iterator[ArPackage]:
+    def packages(self, /) -> ItemIterator[ArPackage]:
         """iterate over all top-level packages"""
         ...
+    def predefined_variants(self, /) -> List[PredefinedVariant]:
+        """get all PREDEFINED-VARIANTs in the model"""
+        ...
     root_element: Element
     """Get the root element of the model"""
+    def systems(self, /, *, category: Optional[SystemCategory] = None) -> List[System]:
+        """find all SYSTEMs in the model, optionally only those with the given category"""
+        ...
     def write(self, /) -> None:
         """write the model to disk, creating or updating all files in the model"""
         ...
@@ -295,14 +612,20 @@ class ByteOrder:
     Opaque: ByteOrder
 
 @final
-class EcuInstance:
+class EcuInstance(_IdentifiableElement):
     """
     The `EcuInstance` represents one ECU in a `System`Union[
     """
 
     def __init__(self, element: Element) -> EcuInstance: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def communication_controllers(self, /) -> Iterator[CommunicationController]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def communication_controllers(self, /) -> ItemIterator[CommunicationController]:
         """return an interator over all communication controllers in this `EcuInstance`"""
         ...
 
@@ -340,20 +663,133 @@ class EcuInstance:
         """Add a reference to an associated COM IPdu group"""
         ...
 
-    def associated_com_ipdu_groups(self) -> Iterator[ISignalIPduGroup]:
+    def associated_com_ipdu_groups(self) -> ItemIterator[ISignalIPduGroup]:
         """Iterate over all associated COM IPdu groups"""
         ...
     element: Element
     name: str
 
 @final
-class SwcToEcuMapping:
+class PredefinedVariant:
+    """
+    A `PredefinedVariant` selects one variant of a model with variation points
+
+    It assigns values to the system constants (SW-SYSTEMCONST) through the referenced
+    SW-SYSTEMCONSTANT-VALUE-SETs. These values are used to evaluate the conditions of the VARIATION-POINTs.
+
+    Only the conditions based on system constants (SW-SYSCOND) are evaluated; post-build variant conditions are ignored.
+    """
+
+    def __init__(self, element: Element) -> PredefinedVariant: ...
+    element: Element
+    def evaluate(self, element: Element, /) -> bool:
+        """evaluate the condition of a VARIATION-POINT in this variant
+
+        The element can either be a VARIATION-POINT or an element that contains one.
+        Elements without a variation point or without a SW-SYSCOND are always active."""
+        ...
+    name: Optional[str]
+    def resolve(self) -> AutosarModelAbstraction:
+        """create a copy of the model that is bound to this variant
+
+        In the copy, all elements whose variation point is inactive are removed, and the
+        VARIATION-POINTs of the remaining elements are removed. The original model is not modified.
+
+        The header settings of the files are carried over to the copy, and the copy is frozen if the
+        original model is frozen."""
+        ...
+
+    def system_constant_values(self) -> Dict[str, float]:
+        """get the values of all system constants in this variant, keyed by the path of the SW-SYSTEMCONST
+
+        The values of included variants are part of the result, unless they are overridden by this variant."""
+        ...
+
+    def variation_points(self) -> List[Tuple[Element, bool]]:
+        """evaluate all VARIATION-POINTs in the model
+
+        Returns a list of (element, active), where element is the element containing the VARIATION-POINT"""
+        ...
+
+@final
+class RemovePreview:
+    """
+    The effect that `remove` would have on the model, as returned by `preview_remove`
+    """
+
+    dangling_references: List[Element]
+    """the reference elements that would point to missing elements after the removal"""
+    elements: List[Element]
+    """the elements that would be removed; elements inside of removed elements are not listed separately"""
+    files: List[ArxmlFile]
+    """the files that contain removed elements or dangling references"""
+
+@final
+class Sd:
+    """
+    A special data (SD) item inside of an SDG
+    """
+
+    gid: str
+    """the GID of the SD"""
+    value: str
+    """the value of the SD"""
+
+@final
+class Sdg:
+    """
+    A special data group (SDG) in the ADMIN-DATA of an element
+
+    SDGs store data that is not covered by the Autosar standard, e.g. vendor or OEM specific attributes.
+    """
+
+    def get_sd(self, gid: str, /) -> Optional[str]:
+        """get the value of the first SD with the given GID"""
+        ...
+
+    def get_sdg(self, gid: str, /) -> Optional[Sdg]:
+        """get the first nested SDG with the given GID"""
+        ...
+    gid: str
+    """the GID of the SDG"""
+    content: List[Union[Sd, Sdg]]
+    """the SDs and nested SDGs of the SDG in their original order"""
+    sds: List[Sd]
+    """the special data (SD) items of the SDG"""
+    sdgs: List[Sdg]
+    """the nested SDGs"""
+
+@final
+class SenderReceiverToSignalMapping:
+    """
+    A `SenderReceiverToSignalMapping` contains a mapping between a sender/receiver port and a system signal
+    """
+
+    def __init__(self, element: Element) -> SenderReceiverToSignalMapping: ...
+    def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    system_signal: Optional[SystemSignal]
+    data_element: Optional[VariableDataPrototype]
+
+@final
+class SwcToEcuMapping(_IdentifiableElement):
     """
     A `SwcToEcuMapping` contains a mapping between a `SwComponentPrototype` and an `EcuInstance`
     """
 
     def __init__(self, element: Element) -> SwcToEcuMapping: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     ecu_instance: Optional[EcuInstance]
     """get the ECU instance which is the target of this mapping"""
     element: Element
@@ -362,7 +798,7 @@ class SwcToEcuMapping:
     """get the component prototype that is mapped here"""
 
 @final
-class System:
+class System(_IdentifiableElement):
     """
     The System is the top level of a system template
 
@@ -372,9 +808,15 @@ class System:
 
     def __init__(self, element: Element) -> System: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     category: Optional[SystemCategory]
     """category of the system"""
-    def clusters(self, /) -> Iterator[Cluster]:
+    def clusters(self, /) -> ItemIterator[Cluster]:
         """Create an iterator over all clusters connected to the SYSTEM"""
         ...
 
@@ -401,6 +843,14 @@ class System:
         This new frame needs to be linked to a `CanPhysicalChannel`"""
         ...
 
+    def get_or_create_can_frame(
+        self, name: str, package: ArPackage, byte_length: int, /
+    ) -> CanFrame:
+        """get the `CanFrame` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a CAN-FRAME, or if its length differs"""
+        ...
+
     def create_can_tp_config(
         self, name: str, package: ArPackage, can_cluster: CanCluster, /
     ) -> CanTpConfig:
@@ -441,6 +891,12 @@ class System:
         """create an `EcuInstance` that is connected to this System"""
         ...
 
+    def get_or_create_ecu_instance(self, name: str, package: ArPackage, /) -> EcuInstance:
+        """get the `EcuInstance` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an ECU-INSTANCE"""
+        ...
+
     def create_ethernet_cluster(
         self, cluster_name: str, package: ArPackage, /
     ) -> EthernetCluster:
@@ -485,6 +941,14 @@ class System:
         This new frame needs to be linked to a `FlexrayPhysicalChannel`"""
         ...
 
+    def get_or_create_flexray_frame(
+        self, name: str, package: ArPackage, byte_length: int, /
+    ) -> FlexrayFrame:
+        """get the `FlexrayFrame` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not a FLEXRAY-FRAME, or if its length differs"""
+        ...
+
     def create_flexray_tp_config(
         self, name: str, package: ArPackage, flexray_cluster: FlexrayCluster, /
     ) -> FlexrayTpConfig:
@@ -529,6 +993,22 @@ class System:
         """create a new isignal in the [`System`]"""
         ...
 
+    def get_or_create_isignal(
+        self,
+        name: str,
+        package: ArPackage,
+        bit_length: int,
+        syssignal: SystemSignal,
+        /,
+        *,
+        datatype: Optional[SwBaseType] = None,
+    ) -> ISignal:
+        """get the isignal with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an I-SIGNAL, or if
+        its length, system signal or datatype differ from the requested ones"""
+        ...
+
     def create_isignal_group(
         self, name: str, package: ArPackage, system_signal_group: SystemSignalGroup, /
     ) -> ISignalGroup:
@@ -544,6 +1024,14 @@ class System:
         """create an [`ISignalIPdu`] in the [`System`]"""
         ...
 
+    def get_or_create_isignal_ipdu(
+        self, name: str, package: ArPackage, length: int, /
+    ) -> ISignalIPdu:
+        """get the `ISignalIPdu` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an I-SIGNAL-I-PDU, or if its length differs"""
+        ...
+
     def create_multiplexed_ipdu(
         self, name: str, package: ArPackage, length: int, /
     ) -> MultiplexedIPdu:
@@ -554,6 +1042,14 @@ class System:
         """create an [`NPdu`] in the [`System`]"""
         ...
 
+    def get_or_create_n_pdu(
+        self, name: str, package: ArPackage, length: int, /
+    ) -> NPdu:
+        """get the `NPdu` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an N-PDU, or if its length differs"""
+        ...
+
     def create_nm_config(self, name: str, package: ArPackage, /) -> NmConfig:
         """Create a new `NmConfig` in the SYSTEM
 
@@ -565,6 +1061,14 @@ class System:
         """create an [`NmPdu`] in the [`System`]"""
         ...
 
+    def get_or_create_nm_pdu(
+        self, name: str, package: ArPackage, length: int, /
+    ) -> NmPdu:
+        """get the `NmPdu` with the given name in the package, or create it if it does not exist
+
+        Raises an error if an element with this name exists, but it is not an NM-PDU, or if its length differs"""
+        ...
+
     def create_secured_ipdu(
         self,
         name: str,
@@ -634,11 +1138,11 @@ class System:
         """
         ...
 
-    def ecu_instances(self, /) -> Iterator[EcuInstance]:
+    def ecu_instances(self, /) -> ItemIterator[EcuInstance]:
         """get an iterator over all ECU-INSTANCEs in this SYSTEM"""
         ...
     element: Element
-    def frames(self, /) -> Iterator[Frame]:
+    def frames(self, /) -> ItemIterator[Frame]:
         """iterate over all Frames in the System"""
         ...
 
@@ -650,11 +1154,11 @@ class System:
         """
         ...
 
-    def isignal_groups(self, /) -> Iterator[ISignalGroup]:
+    def isignal_groups(self, /) -> ItemIterator[ISignalGroup]:
         """iterate over all ISignalGroups in the System"""
         ...
 
-    def isignals(self, /) -> Iterator[ISignal]:
+    def isignals(self, /) -> ItemIterator[ISignal]:
         """iterate over all ISignals in the System
 
         This iterator returns all ISignals that are connected to the System using a FibexElementRef.
@@ -667,7 +1171,7 @@ class System:
         The System may contain zero or one `NmConfig`s."""
         ...
 
-    def pdus(self, /) -> Iterator[Pdu]:
+    def pdus(self, /) -> ItemIterator[Pdu]:
         """iterate over all PDUs in the System
 
         This iterator returns all PDUs that are connected to the System using a FibexElementRef.
@@ -703,7 +1207,7 @@ class SystemCategory:
     SystemExtract: SystemCategory
 
 @final
-class SystemMapping:
+class SystemMapping(_IdentifiableElement):
     """
     A `SystemMapping` contains mappings in the `System`
 
@@ -712,6 +1216,12 @@ class SystemMapping:
 
     def __init__(self, element: Element) -> SystemMapping: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def map_sender_receiver_to_signal(
         self,
@@ -722,7 +1232,7 @@ class SystemMapping:
         /,
         *,
         root_composition_prototype: Optional[RootSwCompositionPrototype] = None,
-    ) -> None:
+    ) -> SenderReceiverToSignalMapping:
         """create a new mapping between a sender/receiver port and a signal
 
         `signal`: the system signal that the port is mapped to
@@ -748,3 +1258,10 @@ class SystemMapping:
     system: Optional[System]
     """get the system that contains this mapping"""
     ...
+
+def wrap(element: Element, /) -> Optional[Any]:
+    """Wrap an `Element` in the matching abstraction class
+
+    The class is selected based on the element name, e.g. an I-SIGNAL becomes an `ISignal` and
+    a CAN-FRAME becomes a `CanFrame`. Returns `None` if there is no abstraction class for the element."""
+    ...
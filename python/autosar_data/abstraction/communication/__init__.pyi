@@ -1,8 +1,8 @@
 # Stub file for autosar_data.abstraction.communication
 
-from typing import List, Optional, Tuple, TypeAlias, Union, Iterator, Type, final
-from autosar_data import Element
-from autosar_data.abstraction import ByteOrder, EcuInstance, System
+from typing import Dict, List, Optional, Tuple, TypeAlias, Union, Iterator, Type, final
+from autosar_data import Element, ItemIterator
+from autosar_data.abstraction import _IdentifiableElement, ByteOrder, EcuInstance, System, RemovePreview
 from autosar_data.abstraction.datatype import (
     CompuMethod,
     DataConstr,
@@ -69,7 +69,7 @@ class CanAddressingMode:
     Standard: CanAddressingMode
 
 @final
-class CanCluster:
+class CanCluster(_IdentifiableElement):
     """
     A `CanCluster` contains all configuration items associated with a CAN network.
     The cluster connects multiple ECUs.
@@ -77,6 +77,12 @@ class CanCluster:
 
     def __init__(self, element: Element) -> CanCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     baudrate: int
     """get or set the baudrate of the cluster"""
     can_fd_baudrate: Optional[int]
@@ -97,13 +103,19 @@ class CanCluster:
     """get the `System` that contains this `CanCluster`"""
 
 @final
-class CanCommunicationConnector:
+class CanCommunicationConnector(_IdentifiableElement):
     """
     A connector between a [`CanCommunicationController`] in an ECU and a [`CanPhysicalChannel`]
     """
 
     def __init__(self, element: Element) -> CanCommunicationConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     controller: CanCommunicationController
     """Get the controller of the `CommunicationConnector`"""
     ecu_instance: EcuInstance
@@ -112,13 +124,19 @@ class CanCommunicationConnector:
     name: str
 
 @final
-class CanCommunicationController:
+class CanCommunicationController(_IdentifiableElement):
     """
     An `EcuInstance` needs a `CanCommunicationController` in order to connect to a CAN cluster.
     """
 
     def __init__(self, element: Element) -> CanCommunicationController: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def connect_physical_channel(
         self, connection_name: str, can_channel: CanPhysicalChannel, /
     ) -> CanCommunicationConnector:
@@ -131,7 +149,7 @@ class CanCommunicationController:
          - [`CanCommunicationConnector`] -> [`CanCommunicationController`]"""
         ...
 
-    def connected_channels(self, /) -> Iterator[CanPhysicalChannel]:
+    def connected_channels(self, /) -> ItemIterator[CanPhysicalChannel]:
         """return an iterator over the [`CanPhysicalChannel`]s connected to this controller"""
         ...
     ecu_instance: EcuInstance
@@ -140,13 +158,19 @@ class CanCommunicationController:
     name: str
 
 @final
-class CanFrame:
+class CanFrame(_IdentifiableElement):
     """
     A frame on a CAN bus
     """
 
     def __init__(self, element: Element) -> CanFrame: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def frame_triggerings(self, /) -> List[CanFrameTriggering]:
         """List all [`FrameTriggering`]s using this frame"""
@@ -165,19 +189,25 @@ class CanFrame:
         """map a PDU to the frame"""
         ...
 
-    def mapped_pdus(self, /) -> Iterator[Pdu]:
+    def mapped_pdus(self, /) -> ItemIterator[Pdu]:
         """returns an iterator over all PDUs in the frame"""
         ...
     name: str
 
 @final
-class CanFrameTriggering:
+class CanFrameTriggering(_IdentifiableElement):
     """
     The frame triggering connects a frame to a physical channel
     """
 
     def __init__(self, element: Element) -> CanFrameTriggering: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     addressing_mode: Optional[CanAddressingMode]
     """set the addressing mode for this frame triggering"""
     def connect_to_ecu(
@@ -191,7 +221,7 @@ class CanFrameTriggering:
     element: Element
     frame: Optional[CanFrame]
     """get the frame associated with this frame triggering"""
-    def frame_ports(self, /) -> Iterator[FramePort]:
+    def frame_ports(self, /) -> ItemIterator[FramePort]:
         """iterate over all frame ports for this frame triggering"""
         ...
     frame_type: Optional[CanFrameType]
@@ -199,7 +229,7 @@ class CanFrameTriggering:
     identifier: Optional[int]
     """can id associated with this frame"""
     name: str
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """iterate over all PDU triggerings referenced by the frame triggering"""
         ...
     physical_channel: CanPhysicalChannel
@@ -216,13 +246,19 @@ class CanFrameType:
     CanFd: CanFrameType
 
 @final
-class CanNmCluster:
+class CanNmCluster(_IdentifiableElement):
     """
     Can specific `NmCluster` attributes
     """
 
     def __init__(self, element: Element) -> CanNmCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     channel_sleep_master: Optional[bool]
     """set or remove the nmChannelSleepMaster flag"""
     communication_cluster: Optional[CanCluster]
@@ -244,7 +280,7 @@ class CanNmCluster:
     """get or set the nmMsgCycleTime"""
     nm_network_timeout: Optional[float]
     """get or set the nmNetworkTimeout"""
-    def nm_nodes(self, /) -> Iterator[CanNmNode]:
+    def nm_nodes(self, /) -> ItemIterator[CanNmNode]:
         """iterate over all `NmNodes` in this cluster"""
         ...
     nm_remote_sleep_indication_time: Optional[float]
@@ -274,11 +310,17 @@ class CanNmClusterCoupling:
 
     def __init__(self, element: Element) -> CanNmClusterCoupling: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_coupled_cluster(self, cluster: CanNmCluster, /) -> None:
         """add a reference to a coupled `NmCluster`"""
         ...
 
-    def coupled_clusters(self, /) -> Iterator[CanNmCluster]:
+    def coupled_clusters(self, /) -> ItemIterator[CanNmCluster]:
         """iterate over all coupled `NmClusters`"""
         ...
     element: Element
@@ -327,7 +369,7 @@ class CanNmClusterSettings:
     """nmWaitBusSleepTime: Timeout for bus calm down phase in seconds."""
 
 @final
-class CanNmNode:
+class CanNmNode(_IdentifiableElement):
     """
     A `CanNmNode` represents a node in a `CanNmCluster`.
 
@@ -336,6 +378,12 @@ class CanNmNode:
 
     def __init__(self, element: Element) -> CanNmNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_rx_nm_pdu(self, nm_pdu: NmPdu, /) -> None:
         """add an Rx `NmPdu`
 
@@ -361,34 +409,40 @@ class CanNmNode:
     """get or set ot remove the nmPassiveModeEnabled flag
 
     This flag is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed."""
-    def rx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def rx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all RX `NmPdus`"""
         ...
 
-    def tx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def tx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all TX `NmPdus`"""
         ...
 
 @final
-class CanPhysicalChannel:
+class CanPhysicalChannel(_IdentifiableElement):
     """
     The `CanPhysicalChannel contains all of the communication on a CAN network
     """
 
     def __init__(self, element: Element) -> CanPhysicalChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: CanCluster
     """get the cluster containing this physical channel"""
     element: Element
-    def frame_triggerings(self, /) -> Iterator[CanFrameTriggering]:
+    def frame_triggerings(self, /) -> ItemIterator[CanFrameTriggering]:
         """iterate over all frame triggerings of this physical channel"""
         ...
 
-    def signal_triggerings(self, /) -> Iterator[ISignalTriggering]:
+    def signal_triggerings(self, /) -> ItemIterator[ISignalTriggering]:
         """iterate over all signal triggerings of this physical channel"""
         ...
 
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """iterate over all PDU triggerings of this physical channel"""
         ...
     name: str
@@ -404,13 +458,19 @@ class CanPhysicalChannel:
         ...
 
 @final
-class CanTpAddress:
+class CanTpAddress(_IdentifiableElement):
     """
     A `CanTpAddress` represents a logical address in the `CanTp` module
     """
 
     def __init__(self, element: Element) -> CanTpAddress: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     tp_address: Optional[int]
@@ -429,13 +489,19 @@ class CanTpAddressingFormat:
     Standard: CanTpAddressingFormat
 
 @final
-class CanTpChannel:
+class CanTpChannel(_IdentifiableElement):
     """
     A `CanTpChannel` represents a channel in the `CanTp` module
     """
 
     def __init__(self, element: Element) -> CanTpChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     channel_id: Optional[int]
     """get or set the channel id of the channel"""
     channel_mode: Optional[CanTpChannelMode]
@@ -453,7 +519,7 @@ class CanTpChannelMode:
     HalfDuplex: CanTpChannelMode
 
 @final
-class CanTpConfig:
+class CanTpConfig(_IdentifiableElement):
     """
     Container for `CanTp` configuration
 
@@ -462,23 +528,29 @@ class CanTpConfig:
 
     def __init__(self, element: Element) -> CanTpConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def can_tp_addresses(self, /) -> Iterator[CanTpAddress]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def can_tp_addresses(self, /) -> ItemIterator[CanTpAddress]:
         """get all of the Can Tp addresses in the configuration"""
         ...
 
-    def can_tp_channels(self, /) -> Iterator[CanTpChannel]:
+    def can_tp_channels(self, /) -> ItemIterator[CanTpChannel]:
         """iterate over all `CanTpChannel`s in the configuration"""
         ...
 
-    def can_tp_connections(self, /) -> Iterator[CanTpConnection]:
+    def can_tp_connections(self, /) -> ItemIterator[CanTpConnection]:
         """get all of the `CanTpConnections` in the configuration"""
         ...
 
-    def can_tp_ecus(self, /) -> Iterator[CanTpEcu]:
+    def can_tp_ecus(self, /) -> ItemIterator[CanTpEcu]:
         """get an iterator over all ECUs in the configuration"""
         ...
 
-    def can_tp_nodes(self, /) -> Iterator[CanTpNode]:
+    def can_tp_nodes(self, /) -> ItemIterator[CanTpNode]:
         """get all of the `CanTpNodes` in the configuration"""
         ...
     cluster: Optional[CanCluster]
@@ -523,7 +595,7 @@ class CanTpConfig:
     name: str
 
 @final
-class CanTpConnection:
+class CanTpConnection(_IdentifiableElement):
     """
     A connection identifies the sender and the receiver of this particular communication.
     The `CanTp` module routes a Pdu through this connection.
@@ -531,6 +603,12 @@ class CanTpConnection:
 
     def __init__(self, element: Element) -> CanTpConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_receiver(self, receiver: CanTpNode, /) -> None:
         """add a receiver to the connection
 
@@ -546,7 +624,7 @@ class CanTpConnection:
     name: str
     padding_activation: Optional[bool]
     """get or set the padding activation of the connection"""
-    def receivers(self, /) -> Iterator[CanTpNode]:
+    def receivers(self, /) -> ItemIterator[CanTpNode]:
         """get all of the receivers of the connection"""
         ...
     tp_sdu: Optional[IPdu]
@@ -564,6 +642,12 @@ class CanTpEcu:
 
     def __init__(self, element: Element) -> CanTpEcu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cycle_time_main_function: Optional[float]
     """get or set the cycle time of the `CanTp` main function of the ECU"""
     ecu_instance: Optional[EcuInstance]
@@ -571,13 +655,19 @@ class CanTpEcu:
     element: Element
 
 @final
-class CanTpNode:
+class CanTpNode(_IdentifiableElement):
     """
     A `CanTpNode` provides the TP address and the connection to the topology description in a `CanTpConfig`
     """
 
     def __init__(self, element: Element) -> CanTpNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     address: Optional[CanTpAddress]
     """get or set the `CanTpAddress` of this Node"""
     connector: Optional[CanCommunicationConnector]
@@ -598,6 +688,12 @@ class ComTransformationTechnologyConfig:
         self, *, isignal_ipdu_length: int
     ) -> ComTransformationTechnologyConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     isignal_ipdu_length: int
     """The length of the `ISignalIpdu` tha will be transformed by this Com transformer.
     The value is only used up to AUTOSAR R20-11 (`AUTOSAR_00049`), where it is needed to calculate the buffer size."""
@@ -644,13 +740,19 @@ class CommunicationDirection:
     Out: CommunicationDirection
 
 @final
-class ConsumedEventGroup:
+class ConsumedEventGroup(_IdentifiableElement):
     """
     A `ConsumedEventGroup` is a group of events in a `ConsumedServiceInstance` that are consumed by an ECU
     """
 
     def __init__(self, element: Element) -> ConsumedEventGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_event_multicast_address(self, address: SocketAddress, /) -> None:
         """add an event multicast address to this `ConsumedEventGroup`"""
         ...
@@ -663,18 +765,18 @@ class ConsumedEventGroup:
     element: Element
     event_group_identifier: Optional[int]
     """get or set the event group identifier of this `ConsumedEventGroup`"""
-    def event_multicast_addresses(self, /) -> Iterator[SocketAddress]:
+    def event_multicast_addresses(self, /) -> ItemIterator[SocketAddress]:
         """get the event multicast addresses"""
         ...
     name: str
-    def pdu_activation_routing_groups(self, /) -> Iterator[PduActivationRoutingGroup]:
+    def pdu_activation_routing_groups(self, /) -> ItemIterator[PduActivationRoutingGroup]:
         """iterate over the `PduActivationRoutingGroup`s in this `ConsumedEventGroup`"""
         ...
     sd_client_timer_config: Optional[SomeipSdClientEventGroupTimingConfig]
     """get or set the SD client timer configuration for this `ConsumedEventGroup`"""
 
 @final
-class ConsumedEventGroupV1:
+class ConsumedEventGroupV1(_IdentifiableElement):
     """
     A `ConsumedEventGroupV1` is a SD event group of a service instance that is consumed by this ECU.
 
@@ -683,6 +785,12 @@ class ConsumedEventGroupV1:
 
     def __init__(self, element: Element) -> ConsumedEventGroupV1: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_routing_group(self, routing_group: SoAdRoutingGroup, /) -> None:
         """add a reference to a `SoAdRoutingGroup` to this `ConsumedEventGroup`"""
         ...
@@ -696,21 +804,27 @@ class ConsumedEventGroupV1:
         """list all `EventHandlerV1`s that reference this `ConsumedEventGroupV1`"""
         ...
     name: str
-    def routing_groups(self, /) -> Iterator[SoAdRoutingGroup]:
+    def routing_groups(self, /) -> ItemIterator[SoAdRoutingGroup]:
         """get the routing groups referenced by this `ConsumedEventGroup`"""
         ...
     sd_client_config: Optional[SdEventConfig]
     """get or set the SD client configuration for this `ConsumedEventGroup`"""
 
 @final
-class ConsumedServiceInstance:
+class ConsumedServiceInstance(_IdentifiableElement):
     """
     A `ConsumedServiceInstance` is a service that is consumed by an ECU
     """
 
     def __init__(self, element: Element) -> ConsumedServiceInstance: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def consumed_event_groups(self, /) -> Iterator[ConsumedEventGroup]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def consumed_event_groups(self, /) -> ItemIterator[ConsumedEventGroup]:
         """get the `ConsumedEventGroup`s in this `ConsumedServiceInstance`"""
         ...
 
@@ -722,7 +836,7 @@ class ConsumedServiceInstance:
     element: Element
     instance_identifier: Optional[int]
     """get or set the instance identifier of this `ConsumedServiceInstance`"""
-    def local_unicast_addresses(self, /) -> Iterator[SocketAddress]:
+    def local_unicast_addresses(self, /) -> ItemIterator[SocketAddress]:
         """iterate over the local unicast addresses"""
         ...
     major_version: Optional[int]
@@ -746,7 +860,7 @@ class ConsumedServiceInstance:
         ...
 
 @final
-class ConsumedServiceInstanceV1:
+class ConsumedServiceInstanceV1(_IdentifiableElement):
     """
     A `ConsumedServiceInstanceV1` is a SD service instance that is consumed by this ECU.
 
@@ -755,7 +869,13 @@ class ConsumedServiceInstanceV1:
 
     def __init__(self, element: Element) -> ConsumedServiceInstanceV1: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def consumed_event_groups(self, /) -> Iterator[ConsumedEventGroupV1]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def consumed_event_groups(self, /) -> ItemIterator[ConsumedEventGroupV1]:
         """get the `ConsumedEventGroup`s in this `ConsumedServiceInstanceV1`"""
         ...
 
@@ -817,17 +937,23 @@ class ContainedIPduProps:
     """update indication bit position of the contained IPdu"""
 
 @final
-class ContainerIPdu:
+class ContainerIPdu(_IdentifiableElement):
     """
     Several `IPdus` can be collected in one `ContainerIPdu` based on the headerType
     """
 
     def __init__(self, element: Element) -> ContainerIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
-    def contained_ipdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def contained_ipdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """iterate over all contained IPdu triggerings"""
         ...
     container_timeout: Optional[float]
@@ -913,23 +1039,29 @@ class DataIdMode:
     Lower8Bit: DataIdMode
 
 @final
-class DataTransformation:
+class DataTransformation(_IdentifiableElement):
     """
     A `DataTransformation` is a chain of `TransformationTechnology`s that are used to transform data
     """
 
     def __init__(self, element: Element) -> DataTransformation: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     data_transformation_set: Optional[DataTransformationSet]
     """get the `DataTransformationSet` that contains this `DataTransformation`"""
     element: Element
     name: str
-    def transformation_technologies(self, /) -> Iterator[TransformationTechnology]:
+    def transformation_technologies(self, /) -> ItemIterator[TransformationTechnology]:
         """Create an iterator over the `TransformationTechnologies` in the `DataTransformation`"""
         ...
 
 @final
-class DataTransformationSet:
+class DataTransformationSet(_IdentifiableElement):
     """
     A [`DataTransformationSet`] contains `DataTransformation`s and `TransformationTechnology`s used in communication
 
@@ -938,6 +1070,12 @@ class DataTransformationSet:
 
     def __init__(self, element: Element) -> DataTransformationSet: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_data_transformation(
         self,
         name: str,
@@ -954,23 +1092,29 @@ class DataTransformationSet:
         """Create a new `TransformationTechnology` in the `DataTransformationSet`"""
         ...
 
-    def data_transformations(self, /) -> Iterator[DataTransformation]:
+    def data_transformations(self, /) -> ItemIterator[DataTransformation]:
         """Iterate over all `DataTransformation`s in the `DataTransformationSet`"""
         ...
     element: Element
     name: str
-    def transformation_technologies(self, /) -> Iterator[TransformationTechnology]:
+    def transformation_technologies(self, /) -> ItemIterator[TransformationTechnology]:
         """Iterate over all `TransformationTechnology`s in the `DataTransformationSet`"""
         ...
 
 @final
-class DcmIPdu:
+class DcmIPdu(_IdentifiableElement):
     """
     Represents the `IPdus` handled by Dcm
     """
 
     def __init__(self, element: Element) -> DcmIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
@@ -995,7 +1139,7 @@ class DiagPduType:
     DiagResponse: DiagPduType
 
 @final
-class DoIpLogicAddress:
+class DoIpLogicAddress(_IdentifiableElement):
     """
     This element defines the logical address of a `DoIp` connection
     """
@@ -1007,13 +1151,19 @@ class DoIpLogicAddress:
     name: str
 
 @final
-class DoIpTpConfig:
+class DoIpTpConfig(_IdentifiableElement):
     """
     Container for `DoIp` TP configuration
     """
 
     def __init__(self, element: Element) -> DoIpTpConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: Optional[EthernetCluster]
     """get or set the reference to the `EthernetCluster` for this `DoIpTpConfig`"""
     def create_doip_logic_address(self, name: str, address: int, /) -> DoIpLogicAddress:
@@ -1031,24 +1181,30 @@ class DoIpTpConfig:
         """create a new `DoIpTpConnection`"""
         ...
 
-    def doip_logic_addresses(self, /) -> Iterator[DoIpLogicAddress]:
+    def doip_logic_addresses(self, /) -> ItemIterator[DoIpLogicAddress]:
         """iterate over all `DoIpLogicAddresss`"""
         ...
 
-    def doip_tp_connections(self, /) -> Iterator[DoIpTpConnection]:
+    def doip_tp_connections(self, /) -> ItemIterator[DoIpTpConnection]:
         """iterate over all `DoIpTpConnections`"""
         ...
     element: Element
     name: str
 
 @final
-class DoIpTpConnection:
+class DoIpTpConnection(_IdentifiableElement):
     """
     The `DoIpTpConnection` defines a `DoIp` transport protocol connection
     """
 
     def __init__(self, element: Element) -> DoIpTpConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     source: Optional[DoIpLogicAddress]
@@ -1192,7 +1348,7 @@ class EndToEndTransformationISignalProps:
     """get or set the transformer reference of the E2E transformation properties"""
 
 @final
-class EthernetCluster:
+class EthernetCluster(_IdentifiableElement):
     """
     An `EthernetCluster` contains all configuration items associated with an ethernet network.
     The cluster connects multiple ECUs.
@@ -1200,6 +1356,12 @@ class EthernetCluster:
 
     def __init__(self, element: Element) -> EthernetCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_physical_channel(
         self, channel_name: str, /, *, vlan_info: Optional[EthernetVlanInfo] = None
     ) -> EthernetPhysicalChannel:
@@ -1211,20 +1373,26 @@ class EthernetCluster:
         ...
     element: Element
     name: str
-    def physical_channels(self, /) -> Iterator[EthernetPhysicalChannel]:
+    def physical_channels(self, /) -> ItemIterator[EthernetPhysicalChannel]:
         """returns an iterator over all [`EthernetPhysicalChannel`]s in the cluster"""
         ...
     system: Optional[System]
     """get the `System` that this `EthernetCluster` is part of"""
 
 @final
-class EthernetCommunicationConnector:
+class EthernetCommunicationConnector(_IdentifiableElement):
     """
     A connector between an [`EthernetCommunicationController`] in an ECU and an [`EthernetPhysicalChannel`]
     """
 
     def __init__(self, element: Element) -> EthernetCommunicationConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     controller: EthernetCommunicationController
     """Get the controller of the `CommunicationConnector`"""
     ecu_instance: EcuInstance
@@ -1233,13 +1401,19 @@ class EthernetCommunicationConnector:
     name: str
 
 @final
-class EthernetCommunicationController:
+class EthernetCommunicationController(_IdentifiableElement):
     """
     An `EcuInstance` needs an `EthernetCommunicationController` in order to connect to an ethernet cluster.
     """
 
     def __init__(self, element: Element) -> EthernetCommunicationController: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def connect_physical_channel(
         self, connection_name: str, eth_channel: EthernetPhysicalChannel, /
     ) -> EthernetCommunicationConnector:
@@ -1252,7 +1426,7 @@ class EthernetCommunicationController:
          - `EthernetCommunicationConnector` -> [`EthernetCommunicationController`]"""
         ...
 
-    def connected_channels(self, /) -> Iterator[EthernetPhysicalChannel]:
+    def connected_channels(self, /) -> ItemIterator[EthernetPhysicalChannel]:
         """return an iterator over the [`EthernetPhysicalChannel`]s connected to this controller"""
         ...
     ecu_instance: EcuInstance
@@ -1261,13 +1435,19 @@ class EthernetCommunicationController:
     name: str
 
 @final
-class EthernetPhysicalChannel:
+class EthernetPhysicalChannel(_IdentifiableElement):
     """
     The `EthernetPhysicalChannel` represents a VLAN or untagged traffic
     """
 
     def __init__(self, element: Element) -> EthernetPhysicalChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: EthernetCluster
     """get the cluster containing this physical channel"""
     def configure_service_discovery_for_ecu(
@@ -1372,23 +1552,23 @@ class EthernetPhysicalChannel:
         """check if the channel contains any `SocketConnectionBundles` (old) or `SocketConnections` (very old)"""
         ...
     name: str
-    def signal_triggerings(self, /) -> Iterator[ISignalTriggering]:
+    def signal_triggerings(self, /) -> ItemIterator[ISignalTriggering]:
         """iterate over all signal triggerings of this physical channel"""
         ...
 
-    def network_endpoints(self, /) -> Iterator[NetworkEndpoint]:
+    def network_endpoints(self, /) -> ItemIterator[NetworkEndpoint]:
         """create an iterator over all [`NetworkEndpoint`]s in this channel"""
         ...
 
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """iterate over all PDU triggerings of this physical channel"""
         ...
 
-    def socket_addresses(self, /) -> Iterator[SocketAddress]:
+    def socket_addresses(self, /) -> ItemIterator[SocketAddress]:
         """create an iterator over all [`SocketAddress`]es in this channel"""
         ...
 
-    def socket_connection_bundles(self, /) -> Iterator[SocketConnectionBundle]:
+    def socket_connection_bundles(self, /) -> ItemIterator[SocketConnectionBundle]:
         """iterate over all socket connection bundles in this channel
 
         The `SocketConnectionBundle` is the "old" way to establish a connection between two sockets.
@@ -1449,13 +1629,19 @@ class EventGroupControlType:
     TriggerUnicast: EventGroupControlType
 
 @final
-class EventHandler:
+class EventHandler(_IdentifiableElement):
     """
     An `EventHandler` describes the handling of a single event in a `ProvidedServiceInstance`
     """
 
     def __init__(self, element: Element) -> EventHandler: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_pdu_activation_routing_group(
         self, name: str, event_group_control_type: EventGroupControlType, /
     ) -> PduActivationRoutingGroup:
@@ -1465,14 +1651,14 @@ class EventHandler:
     event_group_identifier: Optional[int]
     """get or set the event group identifier of this `EventHandler`"""
     name: str
-    def pdu_activation_routing_groups(self, /) -> Iterator[PduActivationRoutingGroup]:
+    def pdu_activation_routing_groups(self, /) -> ItemIterator[PduActivationRoutingGroup]:
         """get the `PduActivationRoutingGroup`s in this `EventHandler`"""
         ...
     sd_server_event_group_timing_config: Optional[SomeipSdServerEventGroupTimingConfig]
     """get or set the SD server event group timing configuration for this `EventHandler`"""
 
 @final
-class EventHandlerV1:
+class EventHandlerV1(_IdentifiableElement):
     """
     An `EventHandlerV1` is a SD event handler that is used to receive events from other ECUs.
 
@@ -1481,6 +1667,12 @@ class EventHandlerV1:
 
     def __init__(self, element: Element) -> EventHandlerV1: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_consumed_event_group(
         self, consumed_event_group: ConsumedEventGroupV1, /
     ) -> None:
@@ -1491,12 +1683,12 @@ class EventHandlerV1:
         """add a reference to a `SoAdRoutingGroup` to this `EventHandler`"""
         ...
 
-    def consumed_event_groups(self, /) -> Iterator[ConsumedEventGroupV1]:
+    def consumed_event_groups(self, /) -> ItemIterator[ConsumedEventGroupV1]:
         """get the consumed event groups referenced by this `EventHandler`"""
         ...
     element: Element
     name: str
-    def routing_groups(self, /) -> Iterator[SoAdRoutingGroup]:
+    def routing_groups(self, /) -> ItemIterator[SoAdRoutingGroup]:
         """get the routing groups referenced by this `EventHandler`"""
         ...
 
@@ -1516,6 +1708,12 @@ class FlexrayArTpChannel:
 
     def __init__(self, element: Element) -> FlexrayArTpChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     ack_type: Optional[FrArTpAckType]
     """get or set the ack type of the channel"""
     def add_n_pdu(self, n_pdu: NPdu, /) -> None:
@@ -1537,7 +1735,7 @@ class FlexrayArTpChannel:
         """create a new `FlexrayArTpConnection` for this channel"""
         ...
 
-    def flexray_ar_tp_connections(self, /) -> Iterator[FlexrayArTpConnection]:
+    def flexray_ar_tp_connections(self, /) -> ItemIterator[FlexrayArTpConnection]:
         """get an iterator over the connections in the channel"""
         ...
     element: Element
@@ -1549,18 +1747,24 @@ class FlexrayArTpChannel:
     """get or set the minimum separation time"""
     multicast_segmentation: Optional[bool]
     """get or set the multicast segmentation"""
-    def n_pdus(self, /) -> Iterator[NPdu]:
+    def n_pdus(self, /) -> ItemIterator[NPdu]:
         """iterate over the `NPdus` of the channel"""
         ...
 
 @final
-class FlexrayArTpConfig:
+class FlexrayArTpConfig(_IdentifiableElement):
     """
     The `FlexrayArTpConfig` represents the configuration of the Flexray Autosar Transport Protocol
     """
 
     def __init__(self, element: Element) -> FlexrayArTpConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: Optional[FlexrayCluster]
     """get or set the Flexray cluster for the configuration"""
     def create_flexray_ar_tp_channel(
@@ -1583,20 +1787,20 @@ class FlexrayArTpConfig:
         """create a new `TpAddress`"""
         ...
     element: Element
-    def flexray_ar_tp_channels(self, /) -> Iterator[FlexrayArTpChannel]:
+    def flexray_ar_tp_channels(self, /) -> ItemIterator[FlexrayArTpChannel]:
         """get an iterator over the channels in the configuration"""
         ...
 
-    def flexray_ar_tp_nodes(self, /) -> Iterator[FlexrayArTpNode]:
+    def flexray_ar_tp_nodes(self, /) -> ItemIterator[FlexrayArTpNode]:
         """get an iterator over the nodes"""
         ...
     name: str
-    def tp_addresses(self, /) -> Iterator[TpAddress]:
+    def tp_addresses(self, /) -> ItemIterator[TpAddress]:
         """iterate over all `TpAddresses`"""
         ...
 
 @final
-class FlexrayArTpConnection:
+class FlexrayArTpConnection(_IdentifiableElement):
     """
     `FlexrayArTpConnection` represents a connection within a `FlexrayArTpChannel`
 
@@ -1606,6 +1810,12 @@ class FlexrayArTpConnection:
 
     def __init__(self, element: Element) -> FlexrayArTpConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_target(self, target: FlexrayArTpNode, /) -> None:
         """add a target to the connection
 
@@ -1623,12 +1833,12 @@ class FlexrayArTpConnection:
     if Some(value) is passed, the reversed TP SDU is set to the given value, otherwise it is removed."""
     source: Optional[FlexrayArTpNode]
     """get or set the source of the connection"""
-    def targets(self, /) -> Iterator[FlexrayArTpNode]:
+    def targets(self, /) -> ItemIterator[FlexrayArTpNode]:
         """get the targets"""
         ...
 
 @final
-class FlexrayArTpNode:
+class FlexrayArTpNode(_IdentifiableElement):
     """
     `FlexrayArTpNode` represents a node in the Flexray Autosar Transport Protocol
 
@@ -1637,6 +1847,12 @@ class FlexrayArTpNode:
 
     def __init__(self, element: Element) -> FlexrayArTpNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_communication_connector(
         self, connector: FlexrayCommunicationConnector, /
     ) -> None:
@@ -1647,7 +1863,7 @@ class FlexrayArTpNode:
         Up to 2 connectors can be added to a node."""
         ...
 
-    def communication_connectors(self, /) -> Iterator[FlexrayCommunicationConnector]:
+    def communication_connectors(self, /) -> ItemIterator[FlexrayCommunicationConnector]:
         """get the connectors"""
         ...
     element: Element
@@ -1669,7 +1885,7 @@ class FlexrayChannelName:
     B: FlexrayChannelName
 
 @final
-class FlexrayCluster:
+class FlexrayCluster(_IdentifiableElement):
     """
     A `FlexrayCluster` contains all configuration items associated with a Flexray network.
     The cluster connects multiple ECUs.
@@ -1677,6 +1893,12 @@ class FlexrayCluster:
 
     def __init__(self, element: Element) -> FlexrayCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_physical_channel(
         self, name: str, channel_name: FlexrayChannelName, /
     ) -> FlexrayPhysicalChannel:
@@ -1777,13 +1999,19 @@ class FlexrayClusterSettings:
     """get or set the wakeup tx idle of the cluster"""
 
 @final
-class FlexrayCommunicationConnector:
+class FlexrayCommunicationConnector(_IdentifiableElement):
     """
     A connector between a [`FlexrayCommunicationController`] in an ECU and a [`FlexrayPhysicalChannel`]
     """
 
     def __init__(self, element: Element) -> FlexrayCommunicationConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     controller: FlexrayCommunicationController
     """Get or set the controller of the `CommunicationConnector`"""
     ecu_instance: EcuInstance
@@ -1792,13 +2020,19 @@ class FlexrayCommunicationConnector:
     name: str
 
 @final
-class FlexrayCommunicationController:
+class FlexrayCommunicationController(_IdentifiableElement):
     """
     An `EcuInstance` needs a `FlexrayCommunicationController` in order to connect to a Flexray cluster.
     """
 
     def __init__(self, element: Element) -> FlexrayCommunicationController: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def connect_physical_channel(
         self, connection_name: str, flx_channel: FlexrayPhysicalChannel
     ) -> FlexrayCommunicationConnector:
@@ -1811,7 +2045,7 @@ class FlexrayCommunicationController:
          - `FlexrayCommunicationConnector` -> [`FlexrayCommunicationController`]"""
         ...
 
-    def connected_channels(self, /) -> Iterator[FlexrayPhysicalChannel]:
+    def connected_channels(self, /) -> ItemIterator[FlexrayPhysicalChannel]:
         """return an iterator over the [`FlexrayPhysicalChannel`]s connected to this controller"""
         ...
     element: Element
@@ -1842,13 +2076,19 @@ class FlexrayCommunicationCycle_Repetition(FlexrayCommunicationCycle):
     cycle_repetition: CycleRepetition
 
 @final
-class FlexrayFrame:
+class FlexrayFrame(_IdentifiableElement):
     """
     a Flexray frame
     """
 
     def __init__(self, element: Element) -> FlexrayFrame: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def frame_triggerings(self, /) -> List[FlexrayFrameTriggering]:
         """List all `FlexrayFrameTriggering`s using this frame"""
@@ -1867,13 +2107,13 @@ class FlexrayFrame:
         """map a PDU to the frame"""
         ...
 
-    def mapped_pdus(self, /) -> Iterator[Pdu]:
+    def mapped_pdus(self, /) -> ItemIterator[Pdu]:
         """returns an iterator over all PDUs in the frame"""
         ...
     name: str
 
 @final
-class FlexrayFrameTriggering:
+class FlexrayFrameTriggering(_IdentifiableElement):
     """
     Iterator over all [`FlexrayFrameTriggering`]s using this frame
     map a PDU to the frame
@@ -1882,6 +2122,12 @@ class FlexrayFrameTriggering:
 
     def __init__(self, element: Element) -> FlexrayFrameTriggering: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def connect_to_ecu(
         self, ecu: EcuInstance, direction: CommunicationDirection, /
     ) -> FramePort:
@@ -1892,11 +2138,11 @@ class FlexrayFrameTriggering:
     element: Element
     frame: Optional[FlexrayFrame]
     """get the frame triggered by the frame triggering"""
-    def frame_ports(self, /) -> Iterator[FramePort]:
+    def frame_ports(self, /) -> ItemIterator[FramePort]:
         """get the frame ports connected to this frame triggering"""
         ...
     name: str
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """get the PDU triggerings referenced by this frame triggering"""
         ...
     physical_channel: FlexrayPhysicalChannel
@@ -1914,13 +2160,19 @@ class FlexrayFrameTriggering:
         ...
 
 @final
-class FlexrayNmCluster:
+class FlexrayNmCluster(_IdentifiableElement):
     """
     Flexray specific `NmCluster`
     """
 
     def __init__(self, element: Element) -> FlexrayNmCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     channel_sleep_master: Optional[bool]
     """get or set or remove the nmChannelSleepMaster flag"""
     communication_cluster: Optional[FlexrayCluster]
@@ -1936,7 +2188,7 @@ class FlexrayNmCluster:
     """get or set the nmDataCycle
     
     Number of Flexray Communication Cycles needed to transmit the Nm Data PDUs of all Flexray Nm Ecus of this `FlexrayNmCluster`."""
-    def nm_nodes(self, /) -> Iterator[FlexrayNmNode]:
+    def nm_nodes(self, /) -> ItemIterator[FlexrayNmNode]:
         """iterate over all `NmNodes` in this cluster"""
         ...
     nm_remote_sleep_indication_time: Optional[float]
@@ -1977,11 +2229,17 @@ class FlexrayNmClusterCoupling:
 
     def __init__(self, element: Element) -> FlexrayNmClusterCoupling: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_coupled_cluster(self, cluster: FlexrayNmCluster, /) -> None:
         """add a reference to a coupled `NmCluster`"""
         ...
 
-    def coupled_clusters(self, /) -> Iterator[FlexrayNmCluster]:
+    def coupled_clusters(self, /) -> ItemIterator[FlexrayNmCluster]:
         """iterate over all coupled `NmClusters`"""
         ...
     element: Element
@@ -2019,7 +2277,7 @@ class FlexrayNmClusterSettings:
     """nmVotingCycle: The number of Flexray Communication Cycles used to transmit the Nm Vote PDUs of all Flexray Nm Ecus of this `FlexrayNmCluster`."""
 
 @final
-class FlexrayNmNode:
+class FlexrayNmNode(_IdentifiableElement):
     """
     A `FlexrayNmNode` represents a Flexray specific `NmNode`.
 
@@ -2028,6 +2286,12 @@ class FlexrayNmNode:
 
     def __init__(self, element: Element) -> FlexrayNmNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_rx_nm_pdu(self, nm_pdu: NmPdu, /) -> None:
         """add an Rx `NmPdu`
 
@@ -2053,11 +2317,11 @@ class FlexrayNmNode:
     """get or set ot remove the nmPassiveModeEnabled flag
     
     This flag is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed."""
-    def rx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def rx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all RX `NmPdus`"""
         ...
 
-    def tx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def tx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all TX `NmPdus`"""
         ...
 
@@ -2076,27 +2340,33 @@ class FlexrayNmScheduleVariant:
     ScheduleVariant7: FlexrayNmScheduleVariant
 
 @final
-class FlexrayPhysicalChannel:
+class FlexrayPhysicalChannel(_IdentifiableElement):
     """
     the `FlexrayPhysicalChannel` represents either channel A or B of Flexray cluster
     """
 
     def __init__(self, element: Element) -> FlexrayPhysicalChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     channel_name: Optional[FlexrayChannelName]
     """get the channel name of a `FlexrayPhysicalChannel`"""
     cluster: FlexrayCluster
     """get the cluster containing this physical channel"""
     element: Element
-    def frame_triggerings(self, /) -> Iterator[FlexrayFrameTriggering]:
+    def frame_triggerings(self, /) -> ItemIterator[FlexrayFrameTriggering]:
         """iterate over all frame triggerings of this physical channel"""
         ...
 
-    def signal_triggerings(self, /) -> Iterator[ISignalTriggering]:
+    def signal_triggerings(self, /) -> ItemIterator[ISignalTriggering]:
         """iterate over all signal triggerings of this physical channel"""
         ...
 
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """iterate over all PDU triggerings of this physical channel"""
         ...
     name: str
@@ -2118,13 +2388,19 @@ class FlexrayPhysicalChannelsInfo:
     """get the channel B of the cluster"""
 
 @final
-class FlexrayTpConfig:
+class FlexrayTpConfig(_IdentifiableElement):
     """
     `FlexrayTpConfig` defines exactly one Flexray ISO TP Configuration
     """
 
     def __init__(self, element: Element) -> FlexrayTpConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: Optional[FlexrayCluster]
     """get or set the `FlexrayCluster` of the `FlexrayTpConfig`"""
     def create_flexray_tp_connection(
@@ -2162,38 +2438,44 @@ class FlexrayTpConfig:
         """create a new `TpAddress`"""
         ...
     element: Element
-    def flexray_tp_connection_controls(self, /) -> Iterator[FlexrayTpConnectionControl]:
+    def flexray_tp_connection_controls(self, /) -> ItemIterator[FlexrayTpConnectionControl]:
         """iterate over all `FlexrayTpConnectionControls`"""
         ...
 
-    def flexray_tp_connections(self, /) -> Iterator[FlexrayTpConnection]:
+    def flexray_tp_connections(self, /) -> ItemIterator[FlexrayTpConnection]:
         """iterate over all `FlexrayTpConnections`"""
         ...
 
-    def flexray_tp_ecus(self, /) -> Iterator[FlexrayTpEcu]:
+    def flexray_tp_ecus(self, /) -> ItemIterator[FlexrayTpEcu]:
         """iterate over all `FlexrayTpEcus`"""
         ...
 
-    def flexray_tp_nodes(self, /) -> Iterator[FlexrayTpNode]:
+    def flexray_tp_nodes(self, /) -> ItemIterator[FlexrayTpNode]:
         """iterate over all `FlexrayTpNodes`"""
         ...
 
-    def flexray_tp_pdu_pools(self, /) -> Iterator[FlexrayTpPduPool]:
+    def flexray_tp_pdu_pools(self, /) -> ItemIterator[FlexrayTpPduPool]:
         """iterate over all `FlexrayTpPduPools`"""
         ...
     name: str
-    def tp_addresses(self, /) -> Iterator[TpAddress]:
+    def tp_addresses(self, /) -> ItemIterator[TpAddress]:
         """iterate over all `TpAddresses`"""
         ...
 
 @final
-class FlexrayTpConnection:
+class FlexrayTpConnection(_IdentifiableElement):
     """
     A `FlexrayTpConnection` defines a connection between `FlexrayTpNodes`
     """
 
     def __init__(self, element: Element) -> FlexrayTpConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_receiver(self, receiver: FlexrayTpNode, /) -> None:
         """add a receiver to the connection"""
         ...
@@ -2205,7 +2487,7 @@ class FlexrayTpConnection:
     multicast_address: Optional[TpAddress]
     """get or set the multicast `TpAddress` of the connection"""
     name: str
-    def receivers(self, /) -> Iterator[FlexrayTpNode]:
+    def receivers(self, /) -> ItemIterator[FlexrayTpNode]:
         """iterate over all receivers of the connection"""
         ...
     reversed_tp_sdu: Optional[IPdu]
@@ -2219,13 +2501,19 @@ class FlexrayTpConnection:
     """get or set the TX `FlexrayTpPduPool` of the connection"""
 
 @final
-class FlexrayTpConnectionControl:
+class FlexrayTpConnectionControl(_IdentifiableElement):
     """
     A `FlexrayTpConnectionControl` defines the connection control parameters for a `FlexrayTpConnection`
     """
 
     def __init__(self, element: Element) -> FlexrayTpConnectionControl: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     max_fc_wait: Optional[int]
     """get or set the maxFcWait value"""
@@ -2245,6 +2533,12 @@ class FlexrayTpEcu:
 
     def __init__(self, element: Element) -> FlexrayTpEcu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cancellation: Optional[bool]
     """get or set the cancellation status of the `FlexrayTpEcu`"""
     cycle_time_main_function: Optional[float]
@@ -2256,13 +2550,19 @@ class FlexrayTpEcu:
     """get or set the full duplex enabled flag of the `FlexrayTpEcu`"""
 
 @final
-class FlexrayTpNode:
+class FlexrayTpNode(_IdentifiableElement):
     """
     A `FlexrayTpNode` provides the TP address and the connection to the topology description in a `FlexrayTpConfig`
     """
 
     def __init__(self, element: Element) -> FlexrayTpNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_communication_connector(
         self, connector: FlexrayCommunicationConnector, /
     ) -> None:
@@ -2271,7 +2571,7 @@ class FlexrayTpNode:
         In a system description this reference is mandatory."""
         ...
 
-    def communication_connectors(self, /) -> Iterator[FlexrayCommunicationConnector]:
+    def communication_connectors(self, /) -> ItemIterator[FlexrayCommunicationConnector]:
         """iterate over all `FlexrayCommunicationConnectors` of the node"""
         ...
     element: Element
@@ -2282,19 +2582,25 @@ class FlexrayTpNode:
     Setting None will remove the element"""
 
 @final
-class FlexrayTpPduPool:
+class FlexrayTpPduPool(_IdentifiableElement):
     """
     A `FlexrayTpPduPool` contains a set of `NPdus` that can be used for sending and receiving
     """
 
     def __init__(self, element: Element) -> FlexrayTpPduPool: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_n_pdu(self, n_pdu: NPdu, /) -> None:
         """add an `NPdu` to the `PduPool`"""
         ...
     element: Element
     name: str
-    def n_pdus(self, /) -> Iterator[NPdu]:
+    def n_pdus(self, /) -> ItemIterator[NPdu]:
         """iterate over all referenced `NPdus`"""
         ...
 
@@ -2309,13 +2615,19 @@ class FrArTpAckType:
     NoAck: FrArTpAckType
 
 @final
-class FramePort:
+class FramePort(_IdentifiableElement):
     """
     The `FramePort` allows an ECU to send or receive a frame
     """
 
     def __init__(self, element: Element) -> FramePort: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     communication_direction: Optional[CommunicationDirection]
     """get or set the communication direction of the frame port"""
     ecu: EcuInstance
@@ -2324,13 +2636,19 @@ class FramePort:
     name: str
 
 @final
-class GeneralPurposeIPdu:
+class GeneralPurposeIPdu(_IdentifiableElement):
     """
     This element is used for AUTOSAR Pdus without attributes that are routed by the `PduR`
     """
 
     def __init__(self, element: Element) -> GeneralPurposeIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     category: Optional[GeneralPurposeIPduCategory]
     """get the category of this PDU"""
     contained_ipdu_props: Optional[ContainedIPduProps]
@@ -2360,13 +2678,19 @@ class GeneralPurposeIPduCategory:
     Xcp: GeneralPurposeIPduCategory
 
 @final
-class GeneralPurposePdu:
+class GeneralPurposePdu(_IdentifiableElement):
     """
     This element is used for AUTOSAR Pdus without additional attributes that are routed by a bus interface
     """
 
     def __init__(self, element: Element) -> GeneralPurposePdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     category: Optional[GeneralPurposePduCategory]
     """get or set the category of this PDU"""
     element: Element
@@ -2417,13 +2741,19 @@ class GenericTransformationTechnologyConfig:
     """The version of the custom protocol"""
 
 @final
-class IPduPort:
+class IPduPort(_IdentifiableElement):
     """
     The `IPduPort` allows an ECU to send or receive a PDU
     """
 
     def __init__(self, element: Element) -> IPduPort: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     communication_direction: Optional[CommunicationDirection]
     """get or set the communication direction of this `IPduPort`"""
     ecu: EcuInstance
@@ -2455,13 +2785,19 @@ class IPv6AddressSource:
     RouterAdvertisement: IPv6AddressSource
 
 @final
-class ISignal:
+class ISignal(_IdentifiableElement):
     """
     Signal of the Interaction Layer
     """
 
     def __init__(self, element: Element) -> ISignal: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_data_transformation(
         self, data_transformation: DataTransformation, /
     ) -> None:
@@ -2480,7 +2816,7 @@ class ISignal:
         """create SomeIp transformation properties for this signal"""
         ...
 
-    def data_transformations(self, /) -> Iterator[DataTransformation]:
+    def data_transformations(self, /) -> ItemIterator[DataTransformation]:
         """get all data transformations that are applied to this signal"""
         ...
     datatype: Optional[SwBaseType]
@@ -2505,7 +2841,7 @@ class ISignal:
     """get the system signal that corresponds to this isignal"""
     def transformation_isignal_props(
         self, /
-    ) -> Iterator[
+    ) -> ItemIterator[
         Union[EndToEndTransformationISignalProps, SomeIpTransformationISignalProps]
     ]:
         """get all transformation properties that are applied to this signal"""
@@ -2514,13 +2850,19 @@ class ISignal:
     """get or set the initial value of the signal"""
 
 @final
-class ISignalGroup:
+class ISignalGroup(_IdentifiableElement):
     """
     An `ISignalGroup` groups signals that should always be kept together
     """
 
     def __init__(self, element: Element) -> ISignalGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_data_transformation(
         self, data_transformation: DataTransformation, /
     ) -> None:
@@ -2543,12 +2885,12 @@ class ISignalGroup:
         """create SomeIp transformation properties for this signal group"""
         ...
 
-    def data_transformations(self, /) -> Iterator[DataTransformation]:
+    def data_transformations(self, /) -> ItemIterator[DataTransformation]:
         """iterate over all data transformations that are applied to this signal group"""
         ...
     element: Element
     name: str
-    def signals(self, /) -> Iterator[ISignal]:
+    def signals(self, /) -> ItemIterator[ISignal]:
         """Iterator over all [`ISignal`]s in this group
 
         # Example"""
@@ -2557,20 +2899,26 @@ class ISignalGroup:
     """get the system signal group that is associated with this signal group"""
     def transformation_isignal_props(
         self, /
-    ) -> Iterator[
+    ) -> ItemIterator[
         Union[EndToEndTransformationISignalProps, SomeIpTransformationISignalProps]
     ]:
         """get all transformation properties that are applied to this signal group"""
         ...
 
 @final
-class ISignalIPdu:
+class ISignalIPdu(_IdentifiableElement):
     """
     Represents the `IPdus` handled by Com
     """
 
     def __init__(self, element: Element) -> ISignalIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
@@ -2596,7 +2944,7 @@ class ISignalIPdu:
         """map a signal group to the PDU"""
         ...
 
-    def mapped_signals(self, /) -> Iterator[Union[ISignal, ISignalGroup]]:
+    def mapped_signals(self, /) -> ItemIterator[Union[ISignal, ISignalGroup]]:
         """returns an iterator over all signals and signal groups mapped to the PDU"""
         ...
     name: str
@@ -2614,9 +2962,15 @@ class ISignalIPdu:
         ...
 
 @final
-class ISignalIPduGroup:
+class ISignalIPduGroup(_IdentifiableElement):
     def __init__(self, element: Element) -> ISignalIPduGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     communication_direction: Optional[CommunicationDirection]
     def add_pdu(self, pdu: ISignalIPdu, /) -> None:
         """add an `ISignalIPdu` to the group"""
@@ -2624,18 +2978,24 @@ class ISignalIPduGroup:
 
     element: Element
     name: str
-    def pdus(self, /) -> Iterator[ISignalIPdu]:
+    def pdus(self, /) -> ItemIterator[ISignalIPdu]:
         """iterate over all `ISignalIPdu`s in this group"""
         ...
 
 @final
-class ISignalPort:
+class ISignalPort(_IdentifiableElement):
     """
     The `ISignalPort` allows an ECU to send or receive a Signal
     """
 
     def __init__(self, element: Element) -> ISignalPort: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     communication_direction: Optional[CommunicationDirection]
     """get or set the communication direction of this port"""
     ecu: EcuInstance
@@ -2644,13 +3004,19 @@ class ISignalPort:
     name: str
 
 @final
-class ISignalToIPduMapping:
+class ISignalToIPduMapping(_IdentifiableElement):
     """
     `ISignalToIPduMapping` connects an `ISignal` or `ISignalGroup` to an `ISignalToIPdu`
     """
 
     def __init__(self, element: Element) -> ISignalToIPduMapping: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     byte_order: Optional[ByteOrder]
     """get or set the byte order of the data in the mapped signal."""
     element: Element
@@ -2671,13 +3037,19 @@ class ISignalToIPduMapping:
     This is never used for signal groups"""
 
 @final
-class ISignalTriggering:
+class ISignalTriggering(_IdentifiableElement):
     """
     an `ISignalTriggering` triggers a signal in a PDU
     """
 
     def __init__(self, element: Element) -> ISignalTriggering: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def connect_to_ecu(
         self, ecu: EcuInstance, direction: CommunicationDirection, /
     ) -> ISignalPort:
@@ -2687,7 +3059,7 @@ class ISignalTriggering:
     name: str
     physical_channel: PhysicalChannel
     """get the physical channel that contains this signal triggering"""
-    def signal_ports(self, /) -> Iterator[ISignalPort]:
+    def signal_ports(self, /) -> ItemIterator[ISignalPort]:
         """create an iterator over all signal ports that are connected to this signal triggering"""
         ...
 
@@ -2735,50 +3107,80 @@ class IpduTiming:
     """timing specification if the COM transmission mode is true"""
 
 @final
-class LinCluster:
+class LinCluster(_IdentifiableElement):
     """
     A `LinCluster` represents a LIN cluster in a LIN network
     """
 
     def __init__(self, element: Element) -> LinCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_physical_channel(self, channel_name: str, /) -> LinPhysicalChannel: ...
     element: Element
     name: str
 
 @final
-class LinEventTriggeredFrame:
+class LinEventTriggeredFrame(_IdentifiableElement):
     def __init__(self, element: Element) -> LinEventTriggeredFrame: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class LinMaster:
+class LinMaster(_IdentifiableElement):
     """
     A `LinMaster` represents a LIN master node in a LIN cluster
     """
 
     def __init__(self, element: Element) -> LinMaster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class LinPhysicalChannel:
+class LinPhysicalChannel(_IdentifiableElement):
     def __init__(self, element: Element) -> LinPhysicalChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class LinSlave:
+class LinSlave(_IdentifiableElement):
     """
     A `LinSlave` represents a LIN slave node in a LIN cluster
     """
 
     def __init__(self, element: Element) -> LinSlave: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
@@ -2786,6 +3188,12 @@ class LinSlave:
 class LinSporadicFrame:
     def __init__(self, element: Element) -> LinSporadicFrame: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
@@ -2793,6 +3201,12 @@ class LinSporadicFrame:
 class LinUnconditionalFrame:
     def __init__(self, element: Element) -> LinUnconditionalFrame: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
@@ -2826,13 +3240,19 @@ class MaximumMessageLengthType:
     Iso6: MaximumMessageLengthType
 
 @final
-class MultiplexedIPdu:
+class MultiplexedIPdu(_IdentifiableElement):
     """
     The multiplexed pdu contains one of serveral signal pdus
     """
 
     def __init__(self, element: Element) -> MultiplexedIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
@@ -2845,13 +3265,19 @@ class MultiplexedIPdu:
         ...
 
 @final
-class NPdu:
+class NPdu(_IdentifiableElement):
     """
     This is a Pdu of the transport layer. The main purpose of the TP layer is to segment and reassemble `IPdus`.
     """
 
     def __init__(self, element: Element) -> NPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
@@ -2864,13 +3290,19 @@ class NPdu:
         ...
 
 @final
-class NetworkEndpoint:
+class NetworkEndpoint(_IdentifiableElement):
     """
     A network endpoint contains address information for a connection
     """
 
     def __init__(self, element: Element) -> NetworkEndpoint: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_network_endpoint_address(self, address: NetworkEndpointAddress, /) -> None:
         """add a network endpoint address to this `NetworkEndpoint`
 
@@ -2881,7 +3313,7 @@ class NetworkEndpoint:
         """
         ...
 
-    def addresses(self, /) -> Iterator[NetworkEndpointAddress]:
+    def addresses(self, /) -> ItemIterator[NetworkEndpointAddress]:
         """iterator over all addresses in the `NetworkEndpoint`"""
         ...
     element: Element
@@ -2925,7 +3357,7 @@ class NetworkEndpointAddress_IPv6(NetworkEndpointAddress):
     default_router: Optional[str]
 
 @final
-class NmConfig:
+class NmConfig(_IdentifiableElement):
     """
     The `NmConfig` is the root element for the network management configuration.
 
@@ -2936,6 +3368,12 @@ class NmConfig:
 
     def __init__(self, element: Element) -> NmConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_can_nm_cluster(
         self, name: str, settings: CanNmClusterSettings, can_cluster: CanCluster
     ) -> CanNmCluster:
@@ -2983,7 +3421,7 @@ class NmConfig:
     name: str
     def nm_cluster_couplings(
         self, /
-    ) -> Iterator[
+    ) -> ItemIterator[
         Union[CanNmClusterCoupling, FlexrayNmClusterCoupling, UdpNmClusterCoupling]
     ]:
         """iterate over all `NmClusterCouplings`"""
@@ -2991,22 +3429,28 @@ class NmConfig:
 
     def nm_clusters(
         self, /
-    ) -> Iterator[Union[CanNmCluster, FlexrayNmCluster, UdpNmCluster]]:
+    ) -> ItemIterator[Union[CanNmCluster, FlexrayNmCluster, UdpNmCluster]]:
         """get all `NmClusters`"""
         ...
 
-    def nm_ecus(self, /) -> Iterator[NmEcu]:
+    def nm_ecus(self, /) -> ItemIterator[NmEcu]:
         """iterate over all `NmEcus`"""
         ...
 
 @final
-class NmEcu:
+class NmEcu(_IdentifiableElement):
     """
     The `NmEcu` represents an `EcuInstance` wich participates in network management.
     """
 
     def __init__(self, element: Element) -> NmEcu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cycle_time_main_function: Optional[float]
     """get or set or remove the nmCycletimeMainFunction value"""
     ecu_instance: Optional[EcuInstance]
@@ -3019,13 +3463,19 @@ class NmEcu:
     """get or set the nmComControlEnabled flag"""
 
 @final
-class NmPdu:
+class NmPdu(_IdentifiableElement):
     """
     Network Management Pdu
     """
 
     def __init__(self, element: Element) -> NmPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     length: Optional[int]
     """get or set the length of this PDU"""
@@ -3035,7 +3485,7 @@ class NmPdu:
         ...
 
 @final
-class PduActivationRoutingGroup:
+class PduActivationRoutingGroup(_IdentifiableElement):
     """
     A group of Pdus that can be activated or deactivated for transmission over a socket connection.
     It is used by `EventHandler`s in `ProvidedServiceInstance`s and `ConsumedServiceInstance`s.
@@ -3043,6 +3493,12 @@ class PduActivationRoutingGroup:
 
     def __init__(self, element: Element) -> PduActivationRoutingGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_ipdu_identifier_tcp(self, ipdu_identifier: SoConIPduIdentifier, /) -> None:
         """add a reference to a `SoConIPduIdentifier` for TCP communication to this `PduActivationRoutingGroup`"""
         ...
@@ -3053,11 +3509,11 @@ class PduActivationRoutingGroup:
     element: Element
     event_group_control_type: Optional[EventGroupControlType]
     """get or set the event group control type of this `PduActivationRoutingGroup`"""
-    def ipdu_identifiers_tcp(self, /) -> Iterator[SoConIPduIdentifier]:
+    def ipdu_identifiers_tcp(self, /) -> ItemIterator[SoConIPduIdentifier]:
         """get all `SoConIPduIdentifier`s for TCP communication in this `PduActivationRoutingGroup`"""
         ...
 
-    def ipdu_identifiers_udp(self, /) -> Iterator[SoConIPduIdentifier]:
+    def ipdu_identifiers_udp(self, /) -> ItemIterator[SoConIPduIdentifier]:
         """get all `SoConIPduIdentifier`s for UDP communication in this `PduActivationRoutingGroup`"""
         ...
     name: str
@@ -3073,13 +3529,19 @@ class PduCollectionTrigger:
     Never: PduCollectionTrigger
 
 @final
-class PduToFrameMapping:
+class PduToFrameMapping(_IdentifiableElement):
     """
     `PduToFrameMapping` connects a PDU to a frame
     """
 
     def __init__(self, element: Element) -> PduToFrameMapping: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     byte_order: Optional[ByteOrder]
     """get or set the byte order of the data in the PDU.
     
@@ -3104,13 +3566,19 @@ class PduToFrameMapping:
     """set or clear the bit position of the update bit for the mapped PDU."""
 
 @final
-class PduTriggering:
+class PduTriggering(_IdentifiableElement):
     """
     a `PduTriggering` triggers a PDU in a frame or ethernet connection
     """
 
     def __init__(self, element: Element) -> PduTriggering: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_pdu_port(
         self, ecu: EcuInstance, direction: CommunicationDirection, /
     ) -> IPduPort:
@@ -3120,35 +3588,41 @@ class PduTriggering:
     name: str
     pdu: Optional[Pdu]
     """get the Pdu that is triggered by this pdu triggering"""
-    def pdu_ports(self, /) -> Iterator[IPduPort]:
+    def pdu_ports(self, /) -> ItemIterator[IPduPort]:
         """create an iterator over the `IPduPorts` that are connected to this `PduTriggering`"""
         ...
     physical_channel: PhysicalChannel
     """get the physical channel that contains this pdu triggering"""
-    def signal_triggerings(self, /) -> Iterator[ISignalTriggering]:
+    def signal_triggerings(self, /) -> ItemIterator[ISignalTriggering]:
         """create an iterator over the `ISignalTriggerings` that are triggered by this `PduTriggering`"""
         ...
 
 @final
-class ProvidedServiceInstance:
+class ProvidedServiceInstance(_IdentifiableElement):
     """
     A `ProvidedServiceInstance` is a service that is provided by an ECU
     """
 
     def __init__(self, element: Element) -> ProvidedServiceInstance: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_event_handler(
         self, name: str, event_group_identifier: int, /
     ) -> EventHandler:
         """create a new `EventHandler` in this `ProvidedServiceInstance`"""
         ...
     element: Element
-    def event_handlers(self, /) -> Iterator[EventHandler]:
+    def event_handlers(self, /) -> ItemIterator[EventHandler]:
         """get the `EventHandler`s in this `ProvidedServiceInstance`"""
         ...
     instance_identifier: Optional[int]
     """get or set the instance identifier of this `ProvidedServiceInstance`"""
-    def local_unicast_addresses(self, /) -> Iterator[LocalUnicastAddress]:
+    def local_unicast_addresses(self, /) -> ItemIterator[LocalUnicastAddress]:
         """iterate over the local unicast addresses"""
         ...
     major_version: Optional[int]
@@ -3169,7 +3643,7 @@ class ProvidedServiceInstance:
         ...
 
 @final
-class ProvidedServiceInstanceV1:
+class ProvidedServiceInstanceV1(_IdentifiableElement):
     """
     A `ProvidedServiceInstanceV1` is a SD service instance that is provided by this ECU.
 
@@ -3178,11 +3652,17 @@ class ProvidedServiceInstanceV1:
 
     def __init__(self, element: Element) -> ProvidedServiceInstanceV1: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_event_handler(self, name: str, /) -> EventHandlerV1:
         """create a new `EventHandlerV1` in this `ProvidedServiceInstance`"""
         ...
     element: Element
-    def event_handlers(self, /) -> Iterator[EventHandlerV1]:
+    def event_handlers(self, /) -> ItemIterator[EventHandlerV1]:
         """get the `EventHandlerV1`s in this `ProvidedServiceInstance`"""
         ...
     instance_identifier: Optional[int]
@@ -3327,13 +3807,19 @@ class SecureCommunicationProps:
     """start position in bytes of the secure area inside the payload pdu"""
 
 @final
-class SecuredIPdu:
+class SecuredIPdu(_IdentifiableElement):
     """
     Wraps an `IPdu` to protect it from unauthorized manipulation
     """
 
     def __init__(self, element: Element) -> SecuredIPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     contained_ipdu_props: Optional[ContainedIPduProps]
     """set the ContainedIPduProps for this `IPdu`
     This is only needed when the `IPdu` is contained in a `ContainerIPdu`"""
@@ -3361,13 +3847,19 @@ class SecuredIPdu:
     use_as_cryptographic_ipdu: Optional[bool]
 
 @final
-class ServiceInstanceCollectionSet:
+class ServiceInstanceCollectionSet(_IdentifiableElement):
     """
     A `ServiceInstanceCollectionSet` contains `ServiceInstance`s that are provided or consumed by an ECU
     """
 
     def __init__(self, element: Element) -> ServiceInstanceCollectionSet: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_consumed_service_instance(
         self,
         name: str,
@@ -3395,12 +3887,12 @@ class ServiceInstanceCollectionSet:
     name: str
     def service_instances(
         self, /
-    ) -> Iterator[Union[ConsumedServiceInstance, ProvidedServiceInstance]]:
+    ) -> ItemIterator[Union[ConsumedServiceInstance, ProvidedServiceInstance]]:
         """create an iterator over all `ServiceInstances` in this set"""
         ...
 
 @final
-class SoAdRoutingGroup:
+class SoAdRoutingGroup(_IdentifiableElement):
     """
     A `SoAdRoutingGroup` is used to link `SomeIp` settings in Consumed/ProvidedServiceInstances
     to the `SocketConnectionBundles` used for transmission.
@@ -3409,19 +3901,31 @@ class SoAdRoutingGroup:
 
     def __init__(self, element: Element) -> SoAdRoutingGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     control_type: Optional[EventGroupControlType]
     """get or set the `EventGroupControlType` of this `SoAdRoutingGroup`"""
     element: Element
     name: str
 
 @final
-class SoConIPduIdentifier:
+class SoConIPduIdentifier(_IdentifiableElement):
     """
     A `SoConIPduIdentifier` describes a PDU that is transported over a static socket connection.
     """
 
     def __init__(self, element: Element) -> SoConIPduIdentifier: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     collection_trigger: Optional[PduCollectionTrigger]
     """get or set the collection trigger for this `SoConIPduIdentifier`"""
     element: Element
@@ -3437,7 +3941,7 @@ class SoConIPduIdentifier:
     """set the timeout for this `SoConIPduIdentifier`"""
 
 @final
-class SocketAddress:
+class SocketAddress(_IdentifiableElement):
     """
     A socket address establishes the link between one or more ECUs and a `NetworkEndpoint`.
     It contains all settings that are relevant for this combination.
@@ -3445,11 +3949,17 @@ class SocketAddress:
 
     def __init__(self, element: Element) -> SocketAddress: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_multicast_ecu(self, ecu: EcuInstance, /) -> None:
         """add an `EcuInstance` to this multicast `SocketAddress`"""
         ...
 
-    def consumed_service_instances(self, /) -> Iterator[ConsumedServiceInstanceV1]:
+    def consumed_service_instances(self, /) -> ItemIterator[ConsumedServiceInstanceV1]:
         """get the `ConsumedServiceInstance`s in this `SocketAddress`"""
         ...
 
@@ -3494,7 +4004,7 @@ class SocketAddress:
     """get the network endpoint of this `SocketAddress`"""
     physical_channel: EthernetPhysicalChannel
     """get the `EthernetPhysicalChannel` containing this `SocketAddress`"""
-    def provided_service_instances(self, /) -> Iterator[ProvidedServiceInstanceV1]:
+    def provided_service_instances(self, /) -> ItemIterator[ProvidedServiceInstanceV1]:
         """get the `ProvidedServiceInstanceV1`s in this `SocketAddress`"""
         ...
 
@@ -3503,7 +4013,7 @@ class SocketAddress:
         ...
     socket_address_type: Optional[SocketAddressType]
     """get the socket address type: unicast / multicast, as well as the connected ecus"""
-    def static_socket_connections(self, /) -> Iterator[StaticSocketConnection]:
+    def static_socket_connections(self, /) -> ItemIterator[StaticSocketConnection]:
         """iterate over all `StaticSocketConnection`s in this `SocketAddress`"""
         ...
     tp_config: Optional[TpConfig]
@@ -3541,6 +4051,12 @@ class SocketConnection:
 
     def __init__(self, element: Element) -> SocketConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     client_ip_addr_from_connection_request: Optional[bool]
     """get or set the `client_ip_addr_from_connection_request` attribute for this socket connection
     
@@ -3567,7 +4083,7 @@ class SocketConnection:
         """add a PDU to the socket connection, returning a `PduTriggering`"""
         ...
     element: Element
-    def pdu_triggerings(self, /) -> Iterator[PduTriggering]:
+    def pdu_triggerings(self, /) -> ItemIterator[PduTriggering]:
         """create an iterator over all PDU triggerings in this socket connection"""
         ...
     runtime_ip_address_configuration: bool
@@ -3578,12 +4094,12 @@ class SocketConnection:
     """get the socket connection bundle containing this socket connection"""
     def socket_connection_ipdu_identifiers(
         self, /
-    ) -> Iterator[SocketConnectionIpduIdentifier]:
+    ) -> ItemIterator[SocketConnectionIpduIdentifier]:
         """create an iterator over all `SocketConnectionIpduIdentifiers` in this socket connection"""
         ...
 
 @final
-class SocketConnectionBundle:
+class SocketConnectionBundle(_IdentifiableElement):
     """
     A `SocketConnectionBundle` describes a connection between a server port and multiple client ports.
     It contains multiple bundled connections, each transporting one or more PDUs.
@@ -3591,7 +4107,13 @@ class SocketConnectionBundle:
 
     def __init__(self, element: Element) -> SocketConnectionBundle: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def bundled_connections(self, /) -> Iterator[SocketConnection]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def bundled_connections(self, /) -> ItemIterator[SocketConnection]:
         """create an iterator over all bundled connections in this socket connection bundle"""
         ...
 
@@ -3618,6 +4140,12 @@ class SocketConnectionIpduIdentifier:
 
     def __init__(self, element: Element) -> SocketConnectionIpduIdentifier: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_routing_group(self, routing_group: SoAdRoutingGroup, /) -> None:
         """add a reference to a `SoAdRoutingGroup` to this `SocketConnectionIpduIdentifier`"""
         ...
@@ -3628,7 +4156,7 @@ class SocketConnectionIpduIdentifier:
     """set the header id for this `SocketConnectionIpduIdentifier`"""
     pdu_triggering: Optional[PduTriggering]
     """get the `PduTriggering` associated with this `SocketConnectionIpduIdentifier`"""
-    def routing_groups(self, /) -> Iterator[SoAdRoutingGroup]:
+    def routing_groups(self, /) -> ItemIterator[SoAdRoutingGroup]:
         """create an iterator over all `SoAdRoutingGroups` referenced by this `SocketConnectionIpduIdentifier`"""
         ...
     socket_connection: SocketConnection
@@ -3640,13 +4168,19 @@ class SocketConnectionIpduIdentifier:
         ...
 
 @final
-class SocketConnectionIpduIdentifierSet:
+class SocketConnectionIpduIdentifierSet(_IdentifiableElement):
     """
     A `SocketConnectionIpduIdentifierSet` contains a set of `SoConIPduIdentifiers`, which are used in static socket connections and in `SomeIp` events.
     """
 
     def __init__(self, element: Element) -> SocketConnectionIpduIdentifierSet: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_socon_ipdu_identifier(
         self,
         name: str,
@@ -3662,7 +4196,7 @@ class SocketConnectionIpduIdentifierSet:
         ...
     element: Element
     name: str
-    def socon_ipdu_identifiers(self, /) -> Iterator[SoConIPduIdentifier]:
+    def socon_ipdu_identifiers(self, /) -> ItemIterator[SoConIPduIdentifier]:
         """create an iterator over all `SoConIPduIdentifiers` in this set"""
         ...
 
@@ -3721,7 +4255,7 @@ class SomeIpTransformationTechnologyConfig:
     """The interface version the SOME/IP transformer shall use."""
 
 @final
-class SomeipSdClientEventGroupTimingConfig:
+class SomeipSdClientEventGroupTimingConfig(_IdentifiableElement):
     """
     A `SomeipSdClientEventGroupTimingConfig` contains the configuration for the timing of a `ConsumedEventGroup`
 
@@ -3732,6 +4266,12 @@ class SomeipSdClientEventGroupTimingConfig:
 
     def __init__(self, element: Element) -> SomeipSdClientEventGroupTimingConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     def request_response_delay(self) -> Optional[RequestResponseDelay]:
@@ -3753,7 +4293,7 @@ class SomeipSdClientEventGroupTimingConfig:
     """get or set the time to live of this `SomeipSdClientEventGroupTimingConfig`"""
 
 @final
-class SomeipSdClientServiceInstanceConfig:
+class SomeipSdClientServiceInstanceConfig(_IdentifiableElement):
     """
     A `SomeipSdClientServiceInstanceConfig` is a configuration for a `ConsumedServiceInstance`
 
@@ -3764,6 +4304,12 @@ class SomeipSdClientServiceInstanceConfig:
 
     def __init__(self, element: Element) -> SomeipSdClientServiceInstanceConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def initial_find_behavior(self) -> Optional[InitialSdDelayConfig]:
         """get the initial find behavior of this `SomeipSdClientServiceInstanceConfig`"""
@@ -3781,7 +4327,7 @@ class SomeipSdClientServiceInstanceConfig:
     Available since R21-11 (`AUTOSAR_00050`)"""
 
 @final
-class SomeipSdServerEventGroupTimingConfig:
+class SomeipSdServerEventGroupTimingConfig(_IdentifiableElement):
     """
     A `SomeipSdServerEventGroupTimingConfig` contains the configuration for the timing of an `EventHandler`
 
@@ -3792,6 +4338,12 @@ class SomeipSdServerEventGroupTimingConfig:
 
     def __init__(self, element: Element) -> SomeipSdServerEventGroupTimingConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     def request_response_delay(self) -> Optional[RequestResponseDelay]:
@@ -3805,7 +4357,7 @@ class SomeipSdServerEventGroupTimingConfig:
         ...
 
 @final
-class SomeipSdServerServiceInstanceConfig:
+class SomeipSdServerServiceInstanceConfig(_IdentifiableElement):
     """
     A `SomeipSdServerServiceInstanceConfig` is a configuration for a `ProvidedServiceInstance`
 
@@ -3816,6 +4368,12 @@ class SomeipSdServerServiceInstanceConfig:
 
     def __init__(self, element: Element) -> SomeipSdServerServiceInstanceConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def initial_offer_behavior(self) -> Optional[InitialSdDelayConfig]:
         """get the initial offer behavior of this `SomeipSdServerServiceInstanceConfig`"""
@@ -3846,7 +4404,7 @@ class SomeipSdServerServiceInstanceConfig:
     """get or set the service offer time to live of this `SomeipSdServerServiceInstanceConfig`"""
 
 @final
-class SomeipTpChannel:
+class SomeipTpChannel(_IdentifiableElement):
     """
     General settings for a `SomeIp` TP channel
 
@@ -3855,6 +4413,12 @@ class SomeipTpChannel:
 
     def __init__(self, element: Element) -> SomeipTpChannel: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     rx_timeout_time: Optional[float]
@@ -3863,13 +4427,19 @@ class SomeipTpChannel:
     """set the separationTime for the `SomeIpTpChannel`"""
 
 @final
-class SomeipTpConfig:
+class SomeipTpConfig(_IdentifiableElement):
     """
     A `SomipTpConfig` contains the configuration of individual `SomeIp` TP connections
     """
 
     def __init__(self, element: Element) -> SomeipTpConfig: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     cluster: Optional[Union[CanCluster, FlexrayCluster, EthernetCluster]]
     """get the communication cluster of this `SomeipTpConfig`"""
     def create_someip_tp_channel(self, name: str, /) -> SomeipTpChannel:
@@ -3890,11 +4460,11 @@ class SomeipTpConfig:
         ...
     element: Element
     name: str
-    def someip_tp_channels(self, /) -> Iterator[SomeipTpChannel]:
+    def someip_tp_channels(self, /) -> ItemIterator[SomeipTpChannel]:
         """iterate over all `SomeipTpChannel`s in this `SomeipTpConfig`"""
         ...
 
-    def someip_tp_connections(self, /) -> Iterator[SomeipTpConnection]:
+    def someip_tp_connections(self, /) -> ItemIterator[SomeipTpConnection]:
         """get all `SomeipTpConnection`s in this `SomeipTpConfig`"""
         ...
 
@@ -3906,6 +4476,12 @@ class SomeipTpConnection:
 
     def __init__(self, element: Element) -> SomeipTpConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     someip_tp_config: SomeipTpConfig
     """get the `SomeipTpConfig` that contains this `SomeipTpConnection`"""
@@ -3917,7 +4493,7 @@ class SomeipTpConnection:
     """get or set the `PduTriggering` for the transport PDU of this `SomeipTpConnection`"""
 
 @final
-class StaticSocketConnection:
+class StaticSocketConnection(_IdentifiableElement):
     """
     A static socket connection is a connection between two sockets.
 
@@ -3926,11 +4502,17 @@ class StaticSocketConnection:
 
     def __init__(self, element: Element) -> StaticSocketConnection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_ipdu_identifier(self, identifier: SoConIPduIdentifier, /) -> None:
         """add a `SoConIPduIdentifier` to this static socket connection"""
         ...
     element: Element
-    def ipdu_identifiers(self, /) -> Iterator[SoConIPduIdentifier]:
+    def ipdu_identifiers(self, /) -> ItemIterator[SoConIPduIdentifier]:
         """create an iterator over all `SoConIPduIdentifiers` in this static socket connection"""
         ...
     name: str
@@ -3944,7 +4526,7 @@ class StaticSocketConnection:
     """get or set the TCP role of this static socket connection"""
 
 @final
-class SystemSignal:
+class SystemSignal(_IdentifiableElement):
     """
     The system signal represents the communication system's view of data exchanged between SW components which reside on different ECUs
 
@@ -3953,6 +4535,12 @@ class SystemSignal:
 
     def __init__(self, element: Element) -> SystemSignal: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     compu_method: Optional[CompuMethod]
     """get or set the compu method for this signal"""
     data_constr: Optional[DataConstr]
@@ -3965,7 +4553,7 @@ class SystemSignal:
     """get or set the unit for this signal"""
 
 @final
-class SystemSignalGroup:
+class SystemSignalGroup(_IdentifiableElement):
     """
     A signal group refers to a set of signals that shall always be kept together. A signal group is used to
     guarantee the atomic transfer of AUTOSAR composite data types.
@@ -3975,12 +4563,18 @@ class SystemSignalGroup:
 
     def __init__(self, element: Element) -> SystemSignalGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_signal(self, signal: SystemSignal, /) -> None:
         """Add a signal to the signal group"""
         ...
     element: Element
     name: str
-    def signals(self, /) -> Iterator[ISignal]:
+    def signals(self, /) -> ItemIterator[ISignal]:
         """Iterate over all signals in the signal group"""
         ...
 
@@ -3994,7 +4588,7 @@ class TcpRole:
     Listen: TcpRole
 
 @final
-class TpAddress:
+class TpAddress(_IdentifiableElement):
     """
     Represents an ECUs transport layer address on the referenced channel
 
@@ -4003,6 +4597,12 @@ class TpAddress:
 
     def __init__(self, element: Element) -> TpAddress: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     address: Optional[int]
     """get or set the value of the address"""
     element: Element
@@ -4050,13 +4650,19 @@ class TransferProperty:
     TriggeredWithoutRepetition: TransferProperty
 
 @final
-class TransformationTechnology:
+class TransformationTechnology(_IdentifiableElement):
     """
     A `TransformationTechnology` describes how to transform signal or PDU data
     """
 
     def __init__(self, element: Element) -> TransformationTechnology: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def config(self) -> Optional[TransformationTechnologyConfig]:
         """get the configuration of the `TransformationTechnology`"""
         ...
@@ -4091,13 +4697,19 @@ class TransmissionModeTiming:
     """event controlled timing parameters"""
 
 @final
-class UdpNmCluster:
+class UdpNmCluster(_IdentifiableElement):
     """
     Udp / Ethernet specific `NmCluster`
     """
 
     def __init__(self, element: Element) -> UdpNmCluster: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     channel_sleep_master: Optional[bool]
     """get or set the nmChannelSleepMaster flag"""
     communication_cluster: Optional[EthernetCluster]
@@ -4126,7 +4738,7 @@ class UdpNmCluster:
     """get or set the `NmNetworkTimeout`"""
     nm_nid_position: Optional[int]
     """get or set the value nmNidPosition"""
-    def nm_nodes(self, /) -> Iterator[UdpNmNode]:
+    def nm_nodes(self, /) -> ItemIterator[UdpNmNode]:
         """iterate over all `NmNodes` in this cluster"""
         ...
     nm_remote_sleep_indication_time: Optional[float]
@@ -4160,11 +4772,17 @@ class UdpNmClusterCoupling:
 
     def __init__(self, element: Element) -> UdpNmClusterCoupling: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_coupled_cluster(self, cluster: UdpNmCluster, /) -> None:
         """add a reference to a coupled `NmCluster`"""
         ...
 
-    def coupled_clusters(self, /) -> Iterator[UdpNmCluster]:
+    def coupled_clusters(self, /) -> ItemIterator[UdpNmCluster]:
         """iterate over all coupled `NmClusters`"""
         ...
     element: Element
@@ -4201,13 +4819,19 @@ class UdpNmClusterSettings:
     """Timeout for bus calm down phase in seconds"""
 
 @final
-class UdpNmNode:
+class UdpNmNode(_IdentifiableElement):
     """
     Udp / Ethernet specific `NmNode`
     """
 
     def __init__(self, element: Element) -> UdpNmNode: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_rx_nm_pdu(self, /, nm_pdu: NmPdu) -> None:
         """add an Rx `NmPdu`
 
@@ -4236,23 +4860,29 @@ class UdpNmNode:
     """set the nmNodeId"""
     passive_mode: Optional[bool]
     """set ot remove the nmPassiveModeEnabled flag"""
-    def rx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def rx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all RX `NmPdus`"""
         ...
 
-    def tx_nm_pdus(self, /) -> Iterator[NmPdu]:
+    def tx_nm_pdus(self, /) -> ItemIterator[NmPdu]:
         """iterate over all TX `NmPdus`"""
         ...
     ...
 
 @final
-class UserDefinedPdu:
+class UserDefinedPdu(_IdentifiableElement):
     """
     User defined PDU
     """
 
     def __init__(self, element: Element) -> UserDefinedPdu: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     length: Optional[int]
     """get or set the length of this PDU"""
@@ -1,8 +1,8 @@
 # Stub file for autosar_data.abastraction.datatype
 
-from typing import final, Iterator, List, Optional, Union, Tuple, Type, TypeAlias
-from autosar_data import Element
-from autosar_data.abstraction import ByteOrder
+from typing import final, Iterator, Dict, List, Optional, Union, Tuple, Type, TypeAlias
+from autosar_data import Element, ItemIterator
+from autosar_data.abstraction import _IdentifiableElement, ByteOrder, RemovePreview
 from autosar_data.abstraction.software_component import (
     ArgumentDataPrototype,
     ParameterDataPrototype,
@@ -47,7 +47,7 @@ DataPrototype: TypeAlias = Union[
 ]
 
 @final
-class ApplicationArrayDataType:
+class ApplicationArrayDataType(_IdentifiableElement):
     """
     An application array data type
 
@@ -56,6 +56,12 @@ class ApplicationArrayDataType:
 
     def __init__(self, element: Element, /) -> ApplicationArrayDataType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     array_element: ApplicationArrayElement
     """array element of the array data type"""
     element: Element
@@ -69,13 +75,19 @@ class ApplicationArrayDataType:
         ...
 
 @final
-class ApplicationArrayElement:
+class ApplicationArrayElement(_IdentifiableElement):
     """
     An element in an application array data type
     """
 
     def __init__(self, element: Element, /) -> ApplicationArrayElement: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     data_type: ApplicationDataType
     """data type of the array element"""
     element: Element
@@ -141,7 +153,7 @@ class ApplicationPrimitiveCategory:
     Value: ApplicationPrimitiveCategory
 
 @final
-class ApplicationPrimitiveDataType:
+class ApplicationPrimitiveDataType(_IdentifiableElement):
     """
     An application primitive data type
 
@@ -150,6 +162,12 @@ class ApplicationPrimitiveDataType:
 
     def __init__(self, element: Element, /) -> ApplicationPrimitiveDataType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     category: ApplicationPrimitiveCategory
     """category of the primitive data type"""
     compu_method: CompuMethod
@@ -162,7 +180,7 @@ class ApplicationPrimitiveDataType:
     """unit of the primitive data type"""
 
 @final
-class ApplicationRecordDataType:
+class ApplicationRecordDataType(_IdentifiableElement):
     """
     An application record data type
 
@@ -171,6 +189,12 @@ class ApplicationRecordDataType:
 
     def __init__(self, element: Element, /) -> ApplicationRecordDataType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_record_element(
         self, name: str, data_type: ApplicationDataType, /
     ) -> ApplicationRecordElement:
@@ -178,18 +202,24 @@ class ApplicationRecordDataType:
         ...
     element: Element
     name: str
-    def record_elements(self, /) -> Iterator[ApplicationRecordElement]:
+    def record_elements(self, /) -> ItemIterator[ApplicationRecordElement]:
         """get an iterator over the record elements of the record data type"""
         ...
 
 @final
-class ApplicationRecordElement:
+class ApplicationRecordElement(_IdentifiableElement):
     """
     An element in an application record data type
     """
 
     def __init__(self, element: Element, /) -> ApplicationRecordElement: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     data_type: ApplicationDataType
     """data type of the record element"""
     element: Element
@@ -322,7 +352,7 @@ class CompositeRuleBasedValueSpecification:
     """label of the composite rule based value specification, if any"""
 
 @final
-class CompuMethod:
+class CompuMethod(_IdentifiableElement):
     """
     A `CompuMethod` describes the conversion between physical and internal values
 
@@ -331,6 +361,12 @@ class CompuMethod:
 
     def __init__(self, element: Element, /) -> CompuMethod: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     category: CompuMethodCategory
     """category of the `CompuMethod`"""
     def content(self, /) -> Optional[CompuMethodContent]:
@@ -348,11 +384,11 @@ class CompuMethod:
         """create a `CompuScale` in the `CompuMethod`"""
         ...
     element: Element
-    def int_to_phys_compu_scales(self, /) -> Iterator[CompuScale]:
+    def int_to_phys_compu_scales(self, /) -> ItemIterator[CompuScale]:
         """Create an iterator over the internal-to-physical `CompuScales`"""
         ...
     name: str
-    def phys_to_int_compu_scales(self, /) -> Iterator[CompuScale]:
+    def phys_to_int_compu_scales(self, /) -> ItemIterator[CompuScale]:
         """Create an iterator over the physical-to-internal `CompuScales`"""
         ...
 
@@ -515,6 +551,12 @@ class CompuScale:
 
     def __init__(self, element: Element, /) -> CompuScale: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     content: Union[CompuScaleRationalCoefficients, str, int]
     """content of the `CompuScale`"""
     element: Element
@@ -567,7 +609,7 @@ class ConstantReference:
     """label of the `ConstantReference`, if any"""
 
 @final
-class ConstantSpecification:
+class ConstantSpecification(_IdentifiableElement):
     """
     A `ConstantSpecification` is a specification of a constant value.
 
@@ -576,18 +618,30 @@ class ConstantSpecification:
 
     def __init__(self, element: Element, /) -> ConstantSpecification: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     value_specification: ValueSpecification
 
 @final
-class DataConstr:
+class DataConstr(_IdentifiableElement):
     """
     `DataConstr` represents a data constraint.
     """
 
     def __init__(self, element: Element, /) -> DataConstr: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_data_constr_rule(
         self,
         rule_type: DataConstrType,
@@ -599,7 +653,7 @@ class DataConstr:
         """Create a data constraint rule"""
         ...
 
-    def data_constr_rules(self, /) -> Iterator[DataConstrRule]:
+    def data_constr_rules(self, /) -> ItemIterator[DataConstrRule]:
         """Get all data constraint rules"""
         ...
     element: Element
@@ -613,6 +667,12 @@ class DataConstrRule:
 
     def __init__(self, element: Element, /) -> DataConstrRule: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_limit: Optional[float]
     """get the lower limit"""
@@ -638,6 +698,12 @@ class DataTypeMap:
 
     def __init__(self, element: Element, /) -> DataTypeMap: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     application_data_type: ApplicationDataType
     """Get the `ApplicationDataType` of the `DataTypeMap`"""
     element: Element
@@ -645,7 +711,7 @@ class DataTypeMap:
     """Get the `ImplementationDataType` of the `DataTypeMap`"""
 
 @final
-class DataTypeMappingSet:
+class DataTypeMappingSet(_IdentifiableElement):
     """
     A [`DataTypeMappingSet`] contains `DataTypeMap`s
 
@@ -654,6 +720,12 @@ class DataTypeMappingSet:
 
     def __init__(self, element: Element, /) -> DataTypeMappingSet: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_data_type_map(
         self,
         implementation_data_type: ImplementationDataType,
@@ -663,7 +735,7 @@ class DataTypeMappingSet:
         """Create a new `DataTypeMap` in the `DataTypeMappingSet`"""
         ...
 
-    def data_type_maps(self, /) -> Iterator[DataTypeMap]:
+    def data_type_maps(self, /) -> ItemIterator[DataTypeMap]:
         """Get an iterator over the `DataTypeMap`s in the `DataTypeMappingSet`"""
         ...
     element: Element
@@ -684,7 +756,7 @@ class ImplementationDataCategory:
     Value: ImplementationDataCategory
 
 @final
-class ImplementationDataType:
+class ImplementationDataType(_IdentifiableElement):
     """
     An implementation data type; specifics are determined by the category
 
@@ -693,6 +765,12 @@ class ImplementationDataType:
 
     def __init__(self, element: Element, /) -> ImplementationDataType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def apply_settings(self, settings: ImplementationDataTypeSettings, /) -> None:
         """apply the settings to this implementation data type
 
@@ -720,18 +798,24 @@ class ImplementationDataType:
         """get the settings of this implementation data type"""
         ...
 
-    def sub_elements(self, /) -> Iterator[ImplementationDataTypeElement]:
+    def sub_elements(self, /) -> ItemIterator[ImplementationDataTypeElement]:
         """create an iterator over the sub-elements of this implementation data type"""
         ...
 
 @final
-class ImplementationDataTypeElement:
+class ImplementationDataTypeElement(_IdentifiableElement):
     """
     An element of an implementation data type
     """
 
     def __init__(self, element: Element, /) -> ImplementationDataTypeElement: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def apply_settings(self, settings: ImplementationDataTypeSettings, /) -> None:
         """apply the settings to this implementation data type
 
@@ -759,7 +843,7 @@ class ImplementationDataTypeElement:
         """get the settings of this implementation data type"""
         ...
 
-    def sub_elements(self, /) -> Iterator[ImplementationDataTypeElement]:
+    def sub_elements(self, /) -> ItemIterator[ImplementationDataTypeElement]:
         """create an iterator over the sub-elements of this implementation data type"""
         ...
 
@@ -1175,7 +1259,7 @@ class SwAxisContCategory:
     """rescale axis"""
 
 @final
-class SwBaseType:
+class SwBaseType(_IdentifiableElement):
     """
     `SwBaseType` is a basic data type.
 
@@ -1184,6 +1268,12 @@ class SwBaseType:
 
     def __init__(self, element: Element, /) -> SwBaseType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     base_type_encoding: BaseTypeEncoding
     """set the base type encoding of the `SwBaseType`"""
     bit_length: Optional[int]
@@ -1337,7 +1427,7 @@ class TextValueSpecification:
     """label of the text value specification, if any"""
 
 @final
-class Unit:
+class Unit(_IdentifiableElement):
     """
     `Unit` represents a unit of measurement.
 
@@ -1346,6 +1436,12 @@ class Unit:
 
     def __init__(self, element: Element, /) -> Unit: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     display_name: Optional[str]
     """display name of the unit"""
     element: Element
@@ -1,8 +1,8 @@
 # Stub file for ecu_configuration
 
-from typing import final, Iterator, List, Optional, Tuple, TypeAlias, Union
-from autosar_data import Element
-from autosar_data.abstraction import System
+from typing import final, Iterator, Dict, List, Optional, Tuple, TypeAlias, Union
+from autosar_data import Element, ItemIterator
+from autosar_data.abstraction import _IdentifiableElement, System, RemovePreview
 
 EcucContainerDef: TypeAlias = Union[EcucParamConfContainerDef, EcucChoiceContainerDef]
 EcucAnyReferenceDef: TypeAlias = Union[
@@ -28,13 +28,19 @@ EcucParameterValue: TypeAlias = Union[
 ]
 
 @final
-class EcucAddInfoParamDef:
+class EcucAddInfoParamDef(_IdentifiableElement):
     """
     `EcucAddInfoParamDef` is used to specify the need for formated text in the ECU configuration value description
     """
 
     def __init__(self, element: Element, /) -> EcucAddInfoParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
@@ -90,16 +96,28 @@ class EcucAddInfoParamValue:
 
     def __init__(self, element: Element, /) -> EcucAddInfoParamValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
 
 @final
-class EcucBooleanParamDef:
+class EcucBooleanParamDef(_IdentifiableElement):
     """
     `EcucBooleanParamDef` is used to specify a boolean parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucBooleanParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     default_value: Optional[bool]
     """set the default value of the boolean parameter"""
     element: Element
@@ -150,7 +168,7 @@ class EcucBooleanParamDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucChoiceContainerDef:
+class EcucChoiceContainerDef(_IdentifiableElement):
     """
     Marker trait for container definitions
     The `EcucChoiceContainerDef` is used to define configuration containers
@@ -159,7 +177,13 @@ class EcucChoiceContainerDef:
 
     def __init__(self, element: Element, /) -> EcucChoiceContainerDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def choices(self, /) -> Iterator[EcucParamConfContainerDef]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def choices(self, /) -> ItemIterator[EcucParamConfContainerDef]:
         """iterate over the choices in the container"""
         ...
 
@@ -182,7 +206,7 @@ class EcucChoiceContainerDef:
     When this attribute is true, the upper multiplicity attribute may not be used."""
 
 @final
-class EcucChoiceReferenceDef:
+class EcucChoiceReferenceDef(_IdentifiableElement):
     """
     The `EcucChoiceReferenceDef` specifies alternative references where only one of the specified
     references will be used in the ECU configuration.
@@ -190,11 +214,17 @@ class EcucChoiceReferenceDef:
 
     def __init__(self, element: Element, /) -> EcucChoiceReferenceDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_destination(self, /, destination) -> None:
         """add a reference to a destination container"""
         ...
 
-    def destination_refs(self, /) -> Iterator[EcucContainerDef]:
+    def destination_refs(self, /) -> ItemIterator[EcucContainerDef]:
         """get the references to the destination containers"""
         ...
     element: Element
@@ -271,13 +301,19 @@ class EcucConfigurationVariant:
     VariantPreCompile: EcucConfigurationVariant
 
 @final
-class EcucContainerValue:
+class EcucContainerValue(_IdentifiableElement):
     """
     The `EcucContainerValue` is a container in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucContainerValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_add_info_param_value(
         self, definition: EcucAddInfoParamDef, /
     ) -> EcucAddInfoParamValue:
@@ -331,48 +367,60 @@ class EcucContainerValue:
     If the container definition has `requiresIndex` set to `true`, then the container
     must have an index. Otherwise the index is meaningless."""
     name: str
-    def parameter_values(self, /) -> Iterator[EcucParameterValue]:
+    def parameter_values(self, /) -> ItemIterator[EcucParameterValue]:
         """iterate over the parameter values in the container"""
         ...
 
     def reference_values(
         self, /
-    ) -> Iterator[Union[EcucReferenceValue, EcucInstanceReferenceValue]]:
+    ) -> ItemIterator[Union[EcucReferenceValue, EcucInstanceReferenceValue]]:
         """iterate over the reference values in the container"""
         ...
 
-    def sub_containers(self, /) -> Iterator[EcucContainerValue]:
+    def sub_containers(self, /) -> ItemIterator[EcucContainerValue]:
         """iterate over the sub-containers in this container"""
         ...
 
 @final
-class EcucDefinitionCollection:
+class EcucDefinitionCollection(_IdentifiableElement):
     """
     The `EcucDefinitionCollection` is a container for all module definitions in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucDefinitionCollection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_module_def(self, module_def: EcucModuleDef, /) -> None:
         """add a reference to a module definition to the collection"""
         ...
     element: Element
-    def module_defs(self, /) -> Iterator[EcucModuleDef]:
+    def module_defs(self, /) -> ItemIterator[EcucModuleDef]:
         """iterate over all module definitions in the collection"""
         ...
     name: str
 
 @final
-class EcucDestinationUriDef:
+class EcucDestinationUriDef(_IdentifiableElement):
     """
     A `EcucDestinationUriDef` defines a target for an `EcucUriReferenceDef`
     """
 
     def __init__(self, element: Element, /) -> EcucDestinationUriDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def containers(
         self, /
-    ) -> Iterator[Union[EcucChoiceContainerDef, EcucParamConfContainerDef]]:
+    ) -> ItemIterator[Union[EcucChoiceContainerDef, EcucParamConfContainerDef]]:
         """iterate over all containers in the destination uri policy"""
         ...
 
@@ -391,20 +439,26 @@ class EcucDestinationUriDef:
     """set the nesting contract for the destination uri"""
 
 @final
-class EcucDestinationUriDefSet:
+class EcucDestinationUriDefSet(_IdentifiableElement):
     """
     A `EcucDestinationUriDefSet` contains a list of `EcucDestinationUriDef`s
     """
 
     def __init__(self, element: Element, /) -> EcucDestinationUriDefSet: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_destination_uri_def(
         self, name: str, contract: EcucDestinationUriNestingContract, /
     ) -> EcucDestinationUriDef:
         """create a new `EcucDestinationUriDef`"""
         ...
 
-    def destination_uri_defs(self, /) -> Iterator[EcucDestinationUriDef]:
+    def destination_uri_defs(self, /) -> ItemIterator[EcucDestinationUriDef]:
         """iterate over all destination uri definitions in the set"""
         ...
     element: Element
@@ -421,24 +475,36 @@ class EcucDestinationUriNestingContract:
     VertexOfTargetContainer: EcucDestinationUriNestingContract
 
 @final
-class EcucEnumerationLiteralDef:
+class EcucEnumerationLiteralDef(_IdentifiableElement):
     """
     `EcucEnumerationLiteralDef` is used to specify an enumeration literal in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucEnumerationLiteralDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class EcucEnumerationParamDef:
+class EcucEnumerationParamDef(_IdentifiableElement):
     """
     `EcucEnumerationParamDef` is used to specify an enumeration parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucEnumerationParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_enumeration_literal(self, /, name) -> EcucEnumerationLiteralDef:
         """create a new enumeration literal"""
         ...
@@ -447,7 +513,7 @@ class EcucEnumerationParamDef:
     
     Note: enumeration literals must be created first, since the default value must match one of the literals"""
     element: Element
-    def enumeration_literals(self, /) -> Iterator[EcucEnumerationLiteralDef]:
+    def enumeration_literals(self, /) -> ItemIterator[EcucEnumerationLiteralDef]:
         """iterate over all enumeration literals"""
         ...
     lower_multiplicity: Optional[int]
@@ -497,13 +563,19 @@ class EcucEnumerationParamDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucFloatParamDef:
+class EcucFloatParamDef(_IdentifiableElement):
     """
     `EcucFloatParamDef` is used to specify a float parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucFloatParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     default_value: Optional[float]
     """set the default value of the float parameter"""
     element: Element
@@ -558,7 +630,7 @@ class EcucFloatParamDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucForeignReferenceDef:
+class EcucForeignReferenceDef(_IdentifiableElement):
     """
     marker trait for all reference definitions
     The `EcucForeignReferenceDef` specifies a reference to an XML description of an entity
@@ -567,6 +639,12 @@ class EcucForeignReferenceDef:
 
     def __init__(self, element: Element, /) -> EcucForeignReferenceDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     destination_type: Optional[str]
     """set the destination type of the reference definition"""
     element: Element
@@ -617,13 +695,19 @@ class EcucForeignReferenceDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucFunctionNameDef:
+class EcucFunctionNameDef(_IdentifiableElement):
     """
     `EcucFunctionNameDef` is used to specify a function name parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucFunctionNameDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
@@ -680,7 +764,7 @@ class EcucFunctionNameDef:
     """default value of the string parameter"""
 
 @final
-class EcucInstanceReferenceDef:
+class EcucInstanceReferenceDef(_IdentifiableElement):
     """
     The `EcucInstanceReferenceDef` specifies a reference to an XML description of an entity
     described in another AUTOSAR template using INSTANCE REFERENCE semantics.
@@ -688,6 +772,12 @@ class EcucInstanceReferenceDef:
 
     def __init__(self, element: Element, /) -> EcucInstanceReferenceDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     destination_context: Optional[str]
     """set the destination context of the reference definition
     
@@ -751,6 +841,12 @@ class EcucInstanceReferenceValue:
 
     def __init__(self, element: Element, /) -> EcucInstanceReferenceValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     definition: Optional[EcucInstanceReferenceDef]
     """set the parameter definition reference"""
     definition_ref: Optional[str]
@@ -776,13 +872,19 @@ class EcucInstanceReferenceValue:
     target element. The instance reference definition specifies which context elements are required."""
 
 @final
-class EcucIntegerParamDef:
+class EcucIntegerParamDef(_IdentifiableElement):
     """
     `EcucIntegerParamDef` is used to specify an integer parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucIntegerParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     default_value: Optional[int]
     """set the default value of the integer parameter"""
     element: Element
@@ -837,13 +939,19 @@ class EcucIntegerParamDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucLinkerSymbolDef:
+class EcucLinkerSymbolDef(_IdentifiableElement):
     """
     `EcucLinkerSymbolDef` is used to specify a linker symbol parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucLinkerSymbolDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
@@ -900,14 +1008,20 @@ class EcucLinkerSymbolDef:
     """default value of the string parameter"""
 
 @final
-class EcucModuleConfigurationValues:
+class EcucModuleConfigurationValues(_IdentifiableElement):
     """
     The `EcucModuleConfigurationValues` is a container for the configuration of a single base software module
     """
 
     def __init__(self, element: Element, /) -> EcucModuleConfigurationValues: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def container_values(self, /) -> Iterator[EcucContainerValue]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def container_values(self, /) -> ItemIterator[EcucContainerValue]:
         """create an iterator over the container values in the module configuration"""
         ...
 
@@ -927,13 +1041,19 @@ class EcucModuleConfigurationValues:
     name: str
 
 @final
-class EcucModuleDef:
+class EcucModuleDef(_IdentifiableElement):
     """
     The `EcucModuleDef` is a container for the definition of a single base software module
     """
 
     def __init__(self, element: Element, /) -> EcucModuleDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     api_service_prefix: Optional[str]
     """get or set the apiServicePrefix for the module
     
@@ -943,7 +1063,7 @@ class EcucModuleDef:
     """get or set the category of the module definition"""
     def containers(
         self, /
-    ) -> Iterator[Union[EcucChoiceContainerDef, EcucParamConfContainerDef]]:
+    ) -> ItemIterator[Union[EcucChoiceContainerDef, EcucParamConfContainerDef]]:
         """iterate over all containers in the module"""
         ...
 
@@ -987,13 +1107,19 @@ class EcucModuleDefCategory:
     VendorSpecificModuleDefinition: EcucModuleDefCategory
 
 @final
-class EcucMultilineStringParamDef:
+class EcucMultilineStringParamDef(_IdentifiableElement):
     """
     `EcucMultilineStringParamDef` is used to specify a multiline string parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucMultilineStringParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
@@ -1060,6 +1186,12 @@ class EcucNumericalParamValue:
 
     def __init__(self, element: Element, /) -> EcucNumericalParamValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     definition: Optional[
         Union[EcucBooleanParamDef, EcucFloatParamDef, EcucIntegerParamDef]
     ]
@@ -1089,13 +1221,19 @@ class EcucNumericalParamValue:
     """get the numerical value as an integer"""
 
 @final
-class EcucParamConfContainerDef:
+class EcucParamConfContainerDef(_IdentifiableElement):
     """
     The `EcucParamConfContainerDef` is used to define configuration containers
     """
 
     def __init__(self, element: Element, /) -> EcucParamConfContainerDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_add_info_param_def(
         self, name: str, origin: str, /
     ) -> EcucAddInfoParamDef:
@@ -1187,15 +1325,15 @@ class EcucParamConfContainerDef:
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
     name: str
-    def parameters(self, /) -> Iterator[EcucParameterDef]:
+    def parameters(self, /) -> ItemIterator[EcucParameterDef]:
         """get the parameters in the container"""
         ...
 
-    def references(self, /) -> Iterator[EcucAnyReferenceDef]:
+    def references(self, /) -> ItemIterator[EcucAnyReferenceDef]:
         """get the references in the container"""
         ...
 
-    def sub_containers(self, /) -> Iterator[EcucContainerDef]:
+    def sub_containers(self, /) -> ItemIterator[EcucContainerDef]:
         """iterate over the sub-containers"""
         ...
     upper_multiplicity: Optional[int]
@@ -1208,13 +1346,19 @@ class EcucParamConfContainerDef:
     When this attribute is true, the upper multiplicity attribute may not be used."""
 
 @final
-class EcucReferenceDef:
+class EcucReferenceDef(_IdentifiableElement):
     """
     The `EcuReferenceDef` specifies references between parameters in the ECU configuration.
     """
 
     def __init__(self, element: Element, /) -> EcucReferenceDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     destination: Optional[EcucContainerDef]
     """destination container of the reference"""
     element: Element
@@ -1272,6 +1416,12 @@ class EcucReferenceValue:
 
     def __init__(self, element: Element, /) -> EcucReferenceValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     definition: Optional[EcucReferenceDef]
     """set the parameter definition reference"""
     definition_ref: Optional[str]
@@ -1293,13 +1443,19 @@ class EcucReferenceValue:
     """Set the target of the reference"""
 
 @final
-class EcucStringParamDef:
+class EcucStringParamDef(_IdentifiableElement):
     """
     `EcucStringParamDef` is used to specify a string parameter in the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucStringParamDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     lower_multiplicity: Optional[int]
     """get or set the lower multiplicity attribute"""
@@ -1364,6 +1520,12 @@ class EcucTextualParamValue:
 
     def __init__(self, element: Element, /) -> EcucTextualParamValue: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     definition: Optional[EcucParameterDef]
     """set the parameter definition reference"""
     definition_ref: Optional[str]
@@ -1385,13 +1547,19 @@ class EcucTextualParamValue:
     """the textual value of the parameter"""
 
 @final
-class EcucUriReferenceDef:
+class EcucUriReferenceDef(_IdentifiableElement):
     """
     The `EcucUriReferenceDef` defines a reference with a destination that is specified via a destinationUri
     """
 
     def __init__(self, element: Element, /) -> EcucUriReferenceDef: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     destination_uri: Optional[EcucDestinationUriDef]
     """set the destination uri of the reference definition"""
     element: Element
@@ -1442,13 +1610,19 @@ class EcucUriReferenceDef:
     If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true."""
 
 @final
-class EcucValueCollection:
+class EcucValueCollection(_IdentifiableElement):
     """
     `EcucValueCollection` collects references to all the separate modules that form the ECU configuration
     """
 
     def __init__(self, element: Element, /) -> EcucValueCollection: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_module_configuration(
         self, module_configuration: EcucModuleConfigurationValues
     ) -> None:
@@ -1457,7 +1631,7 @@ class EcucValueCollection:
     ecu_extract_reference: Optional[System]
     """Set the ecu extract reference, which links a `System` to the ECU configuration"""
     element: Element
-    def module_configurations(self, /) -> Iterator[EcucModuleConfigurationValues]:
+    def module_configurations(self, /) -> ItemIterator[EcucModuleConfigurationValues]:
         """Get the module configurations in the collection"""
         ...
     name: str
@@ -1,7 +1,8 @@
 # Stub file for autosar_data.abstraction.software_component
 
-from typing import final, Iterator, List, Optional, Tuple, TypeAlias, Union
-from autosar_data import Element
+from typing import final, Iterator, Dict, List, Optional, Tuple, TypeAlias, Union
+from autosar_data import Element, ItemIterator
+from autosar_data.abstraction import _IdentifiableElement, RemovePreview
 from autosar_data.abstraction.datatype import (
     AutosarDataType,
     DataTypeMappingSet,
@@ -45,7 +46,7 @@ RTEEvent: TypeAlias = Union[
 ]
 
 @final
-class ApplicationError:
+class ApplicationError(_IdentifiableElement):
     """
     An `ApplicationError` represents an error that can be returned by a client server operation
     """
@@ -57,7 +58,7 @@ class ApplicationError:
     name: str
 
 @final
-class ApplicationSwComponentType:
+class ApplicationSwComponentType(_IdentifiableElement):
     """
     An `ApplicationSwComponentType` is a software component that provides application functionality
 
@@ -66,6 +67,12 @@ class ApplicationSwComponentType:
 
     def __init__(self, element: Element, /) -> ApplicationSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_p_port(
         self, name: str, port_interface: PortInterface, /
     ) -> PPortPrototype:
@@ -82,6 +89,30 @@ class ApplicationSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -104,22 +135,28 @@ class ApplicationSwComponentType:
         """list all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
-    def swc_internal_behaviors(self, /) -> Iterator[SwcInternalBehavior]:
+    def swc_internal_behaviors(self, /) -> ItemIterator[SwcInternalBehavior]:
         """iterate over all swc internal behaviors - typically zero or one"""
         ...
 
 @final
-class ArgumentDataPrototype:
+class ArgumentDataPrototype(_IdentifiableElement):
     """
     An `ArgumentDataPrototype` represents an argument in a `ClientServerOperation`
     """
 
     def __init__(self, element: Element, /) -> ArgumentDataPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     data_type: Optional[AutosarDataType]
     """data type of the argument"""
     direction: Optional[ArgumentDirection]
@@ -142,13 +179,19 @@ class ArgumentDirection:
     Out: ArgumentDirection
 
 @final
-class AssemblySwConnector:
+class AssemblySwConnector(_IdentifiableElement):
     """
     An `AssemblySwConnector` connects ports of two `SwCompositionType`s.
     """
 
     def __init__(self, element: Element, /) -> AssemblySwConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     p_port: Optional[PortPrototype]
@@ -161,13 +204,19 @@ class AssemblySwConnector:
     """get the software component that contains the required port of the assembly connector"""
 
 @final
-class AsynchronousServerCallReturnsEvent:
+class AsynchronousServerCallReturnsEvent(_IdentifiableElement):
     """
     an asynchronous server call completed
     """
 
     def __init__(self, element: Element, /) -> AsynchronousServerCallReturnsEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -176,13 +225,19 @@ class AsynchronousServerCallReturnsEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class BackgroundEvent:
+class BackgroundEvent(_IdentifiableElement):
     """
     starts a runnable for background processing at low priority
     """
 
     def __init__(self, element: Element, /) -> BackgroundEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -191,7 +246,7 @@ class BackgroundEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class ClientServerInterface:
+class ClientServerInterface(_IdentifiableElement):
     """
     A `ClientServerInterface` defines a set of operations that can be implemented by a server and called by a client
 
@@ -200,6 +255,12 @@ class ClientServerInterface:
 
     def __init__(self, element: Element, /) -> ClientServerInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_operation(self, name: str, /) -> ClientServerOperation:
         """add an operation to the client server interface"""
         ...
@@ -210,29 +271,35 @@ class ClientServerInterface:
         ...
     element: Element
     name: str
-    def operations(self, /) -> Iterator[ClientServerOperation]:
+    def operations(self, /) -> ItemIterator[ClientServerOperation]:
         """iterate over all operations"""
         ...
 
-    def possible_errors(self, /) -> Iterator[ApplicationError]:
+    def possible_errors(self, /) -> ItemIterator[ApplicationError]:
         """iterate over all application errors"""
         ...
     is_service: Optional[bool]
     """Get/Set if the client server interface is a service interface"""
 
 @final
-class ClientServerOperation:
+class ClientServerOperation(_IdentifiableElement):
     """
     A `ClientServerOperation` defines an operation in a `ClientServerInterface`
     """
 
     def __init__(self, element: Element, /) -> ClientServerOperation: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_possible_error(self, error: ApplicationError, /) -> None:
         """add a reference to possible error to the operation"""
         ...
 
-    def arguments(self, /) -> Iterator[ArgumentDataPrototype]:
+    def arguments(self, /) -> ItemIterator[ArgumentDataPrototype]:
         """iterate over all arguments"""
         ...
 
@@ -244,12 +311,12 @@ class ClientServerOperation:
         ...
     element: Element
     name: str
-    def possible_errors(self, /) -> Iterator[ApplicationError]:
+    def possible_errors(self, /) -> ItemIterator[ApplicationError]:
         """Get the possible errors of the operation"""
         ...
 
 @final
-class ComplexDeviceDriverSwComponentType:
+class ComplexDeviceDriverSwComponentType(_IdentifiableElement):
     """
     A `ComplexDeviceDriverSwComponentType` is a software component that provides complex device driver functionality
 
@@ -258,6 +325,12 @@ class ComplexDeviceDriverSwComponentType:
 
     def __init__(self, element: Element, /) -> ComplexDeviceDriverSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_p_port(
         self, name: str, port_interface: PortInterface, /
     ) -> PPortPrototype:
@@ -274,6 +347,30 @@ class ComplexDeviceDriverSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -296,16 +393,16 @@ class ComplexDeviceDriverSwComponentType:
         """list all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
-    def swc_internal_behaviors(self, /) -> Iterator[SwcInternalBehavior]:
+    def swc_internal_behaviors(self, /) -> ItemIterator[SwcInternalBehavior]:
         """iterate over all swc internal behaviors - typically zero or one"""
         ...
 
 @final
-class CompositionSwComponentType:
+class CompositionSwComponentType(_IdentifiableElement):
     """
     A `CompositionSwComponentType` is a software component that contains other software components
 
@@ -314,11 +411,17 @@ class CompositionSwComponentType:
 
     def __init__(self, element: Element, /) -> CompositionSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
-    def components(self, /) -> Iterator[SwComponentPrototype]:
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
+    def components(self, /) -> ItemIterator[SwComponentPrototype]:
         """get an iterator over the components of the composition"""
         ...
 
-    def connectors(self, /) -> Iterator[AssemblySwConnector]:
+    def connectors(self, /) -> ItemIterator[AssemblySwConnector]:
         """iterate over all connectors"""
         ...
 
@@ -384,6 +487,30 @@ class CompositionSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -402,18 +529,24 @@ class CompositionSwComponentType:
         """iterator over all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
 @final
-class DataReceiveErrorEvent:
+class DataReceiveErrorEvent(_IdentifiableElement):
     """
     A `DataReceiveErrorEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a data receive error occurs
     """
 
     def __init__(self, element: Element, /) -> DataReceiveErrorEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -422,13 +555,19 @@ class DataReceiveErrorEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class DataReceivedEvent:
+class DataReceivedEvent(_IdentifiableElement):
     """
     A `DataReceivedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is received
     """
 
     def __init__(self, element: Element, /) -> DataReceivedEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -447,13 +586,19 @@ class DataReceivedEvent:
     """Get the `VariableDataPrototype` that triggers the `DataReceivedEvent`"""
 
 @final
-class DataSendCompletedEvent:
+class DataSendCompletedEvent(_IdentifiableElement):
     """
     A `DataSendCompletedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is sent
     """
 
     def __init__(self, element: Element, /) -> DataSendCompletedEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -462,13 +607,19 @@ class DataSendCompletedEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class DataWriteCompletedEvent:
+class DataWriteCompletedEvent(_IdentifiableElement):
     """
     A `DataWriteCompletedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is written
     """
 
     def __init__(self, element: Element, /) -> DataWriteCompletedEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -477,13 +628,19 @@ class DataWriteCompletedEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class DelegationSwConnector:
+class DelegationSwConnector(_IdentifiableElement):
     """
     A `DelegationSwConnector` connects a port of a software component that is contained inside a `SwCompositionType` with a port of the `SwCompositionType`.
     """
 
     def __init__(self, element: Element, /) -> DelegationSwConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     inner_port: Optional[PortPrototype]
@@ -494,7 +651,7 @@ class DelegationSwConnector:
     """get the outer port of the delegation connector"""
 
 @final
-class EcuAbstractionSwComponentType:
+class EcuAbstractionSwComponentType(_IdentifiableElement):
     """
     The `EcuAbstractionSwComponentType` is a special `AtomicSwComponentType` that resides between a software-component
     that wants to access ECU periphery and the Microcontroller Abstraction
@@ -504,6 +661,12 @@ class EcuAbstractionSwComponentType:
 
     def __init__(self, element: Element, /) -> EcuAbstractionSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_p_port(
         self, name: str, port_interface: PortInterface, /
     ) -> PPortPrototype:
@@ -520,6 +683,30 @@ class EcuAbstractionSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -542,22 +729,28 @@ class EcuAbstractionSwComponentType:
         """list all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
-    def swc_internal_behaviors(self, /) -> Iterator[SwcInternalBehavior]:
+    def swc_internal_behaviors(self, /) -> ItemIterator[SwcInternalBehavior]:
         """iterate over all swc internal behaviors - typically zero or one"""
         ...
 
 @final
-class ExternalTriggerOccurredEvent:
+class ExternalTriggerOccurredEvent(_IdentifiableElement):
     """
     A `ExternalTriggerOccurredEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an external trigger occurs
     """
 
     def __init__(self, element: Element, /) -> ExternalTriggerOccurredEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -566,13 +759,19 @@ class ExternalTriggerOccurredEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class InitEvent:
+class InitEvent(_IdentifiableElement):
     """
     A `InitEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when the software component is initialized
     """
 
     def __init__(self, element: Element, /) -> InitEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -581,13 +780,19 @@ class InitEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class InternalTriggerOccurredEvent:
+class InternalTriggerOccurredEvent(_IdentifiableElement):
     """
     A `InternalTriggerOccurredEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an internal trigger occurs
     """
 
     def __init__(self, element: Element, /) -> InternalTriggerOccurredEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -596,13 +801,19 @@ class InternalTriggerOccurredEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class ModeAccessPoint:
+class ModeAccessPoint(_IdentifiableElement):
     """
     A `ModeAccessPoint`provides the ability to access the current mode of a ModeDeclarationGroup
     """
 
     def __init__(self, element: Element, /) -> ModeAccessPoint: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -634,31 +845,43 @@ class ModeActivationKind:
     """
 
 @final
-class ModeDeclaration:
+class ModeDeclaration(_IdentifiableElement):
     """
     A `ModeDeclaration` represents a mode declaration in a `ModeDeclarationGroup`
     """
 
     def __init__(self, element: Element, /) -> ModeDeclaration: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     value: Optional[int]
     """value of the mode declaration, if any."""
 
 @final
-class ModeDeclarationGroup:
+class ModeDeclarationGroup(_IdentifiableElement):
     """
     A `ModeDeclarationGroup` is a collection of mode declarations.
     """
 
     def __init__(self, element: Element, /) -> ModeDeclarationGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_mode_declaration(self, name: str, /) -> ModeDeclaration:
         """Create a new mode declaration in the group"""
         ...
 
-    def mode_declarations(self, /) -> Iterator[ModeDeclaration]:
+    def mode_declarations(self, /) -> ItemIterator[ModeDeclaration]:
         """iterate over all mode declarations in the group"""
         ...
     element: Element
@@ -690,20 +913,26 @@ class ModeDeclarationGroupCategory:
     """
 
 @final
-class ModeGroup:
+class ModeGroup(_IdentifiableElement):
     """
     A `ModeGroup` represents a mode group in a `ModeSwitchInterface`
     """
 
     def __init__(self, element: Element, /) -> ModeGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     mode_declaration_group: ModeDeclarationGroup
     """Get/Set the mode declaration group of the mode group"""
 
 @final
-class ModeSwitchInterface:
+class ModeSwitchInterface(_IdentifiableElement):
     """
     A `ModeSwitchInterface` defines a set of modes that can be switched
 
@@ -712,6 +941,12 @@ class ModeSwitchInterface:
 
     def __init__(self, element: Element, /) -> ModeSwitchInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     def create_mode_group(
@@ -728,13 +963,19 @@ class ModeSwitchInterface:
     """Get/Set if the mode switch interface is a service interface"""
 
 @final
-class ModeSwitchedAckEvent:
+class ModeSwitchedAckEvent(_IdentifiableElement):
     """
     A `ModeSwitchedAckEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode switch is acknowledged
     """
 
     def __init__(self, element: Element, /) -> ModeSwitchedAckEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -743,13 +984,19 @@ class ModeSwitchedAckEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class ModeSwitchPoint:
+class ModeSwitchPoint(_IdentifiableElement):
     """
     A `ModeSwitchPoint` allows a `RunnableEntity` to switch modes in a ModeDeclarationGroup
     """
 
     def __init__(self, element: Element, /) -> ModeSwitchPoint: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -762,7 +1009,7 @@ class ModeSwitchPoint:
     mode_group: Optional[Tuple[ModeGroup, PortPrototype]]
 
 @final
-class NvDataInterface:
+class NvDataInterface(_IdentifiableElement):
     """
     An `NvDataInterface` defines non-volatile data that can be accessed through the interface
 
@@ -771,19 +1018,31 @@ class NvDataInterface:
 
     def __init__(self, element: Element, /) -> NvDataInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     is_service: Optional[bool]
     """Get/Set if the Nv-data interface is a service interface"""
 
 @final
-class OperationInvokedEvent:
+class OperationInvokedEvent(_IdentifiableElement):
     """
     A `OperationInvokedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an operation is invoked
     """
 
     def __init__(self, element: Element, /) -> OperationInvokedEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     client_server_operation: Tuple[ClientServerOperation, PPortPrototype]
     """Get the `ClientServerOperation` that triggers the `OperationInvokedEvent`"""
     element: Element
@@ -802,13 +1061,19 @@ class OperationInvokedEvent:
     """`SwcInternalBehavior` that contains the event"""
 
 @final
-class OsTaskExecutionEvent:
+class OsTaskExecutionEvent(_IdentifiableElement):
     """
     A `OsTaskExecutionEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an OS task is executed
     """
 
     def __init__(self, element: Element, /) -> OsTaskExecutionEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -817,13 +1082,19 @@ class OsTaskExecutionEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class PPortPrototype:
+class PPortPrototype(_IdentifiableElement):
     """
     `PPortPrototype` represents a provided port prototype
     """
 
     def __init__(self, element: Element, /) -> PPortPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     component_type: Optional[SwComponentType]
     """component type containing the port prototype"""
     element: Element
@@ -832,13 +1103,19 @@ class PPortPrototype:
     """port interface of the port prototype"""
 
 @final
-class PRPortPrototype:
+class PRPortPrototype(_IdentifiableElement):
     """
     `PRPortPrototype` represents a provided and required port prototype
     """
 
     def __init__(self, element: Element, /) -> PRPortPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     component_type: Optional[SwComponentType]
     """component type containing the port prototype"""
     element: Element
@@ -847,13 +1124,19 @@ class PRPortPrototype:
     """port interface of the port prototype"""
 
 @final
-class ParameterDataPrototype:
+class ParameterDataPrototype(_IdentifiableElement):
     """
     A `ParameterDataPrototype` represents a parameter in a `ParameterInterface`
     """
 
     def __init__(self, element: Element, /) -> ParameterDataPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     init_value: Optional[ValueSpecification]
@@ -863,7 +1146,7 @@ class ParameterDataPrototype:
     """Get the interface containing the parameter"""
 
 @final
-class ParameterInterface:
+class ParameterInterface(_IdentifiableElement):
     """
     A `ParameterInterface` defines a set of parameters that can be accessed
 
@@ -872,13 +1155,19 @@ class ParameterInterface:
 
     def __init__(self, element: Element, /) -> ParameterInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_parameter(
         self, name: str, data_type: AutosarDataType, /
     ) -> ParameterDataPrototype:
         """Add a new parameter to the parameter interface"""
         ...
 
-    def parameters(self, /) -> Iterator[ParameterDataPrototype]:
+    def parameters(self, /) -> ItemIterator[ParameterDataPrototype]:
         """iterate over all parameters"""
         ...
     element: Element
@@ -887,13 +1176,19 @@ class ParameterInterface:
     """Get/Set if the parameter interface is a service interface"""
 
 @final
-class PassThroughSwConnector:
+class PassThroughSwConnector(_IdentifiableElement):
     """
     A `PassThroughSwConnector` connects two ports of a `SwCompositionType`.
     """
 
     def __init__(self, element: Element, /) -> PassThroughSwConnector: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     p_port: Optional[PortPrototype]
@@ -902,24 +1197,36 @@ class PassThroughSwConnector:
     """get the required port of the pass-through connector"""
 
 @final
-class PortGroup:
+class PortGroup(_IdentifiableElement):
     """
     `PortGroup` represents a group of ports
     """
 
     def __init__(self, element: Element, /) -> PortGroup: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class RPortPrototype:
+class RPortPrototype(_IdentifiableElement):
     """
     `RPortPrototype` represents a required port prototype
     """
 
     def __init__(self, element: Element, /) -> RPortPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     component_type: Optional[SwComponentType]
     """component type containing the port prototype"""
     element: Element
@@ -928,26 +1235,38 @@ class RPortPrototype:
     """port interface of the port prototype"""
 
 @final
-class RootSwCompositionPrototype:
+class RootSwCompositionPrototype(_IdentifiableElement):
     """
     The `RootSwCompositionPrototype` is a special kind of `SwComponentPrototype` that represents the root of the composition hierarchy
     """
 
     def __init__(self, element: Element, /) -> RootSwCompositionPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     composition: Optional[CompositionSwComponentType]
     """composition that this root component is based on"""
     element: Element
     name: str
 
 @final
-class RunnableEntity:
+class RunnableEntity(_IdentifiableElement):
     """
     A `RunnableEntity` is a function that can be executed by the RTE
     """
 
     def __init__(self, element: Element, /) -> RunnableEntity: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     def events(self, /) -> List[RTEEvent]:
         """Iterate over all events that can trigger the `RunnableEntity`"""
@@ -965,7 +1284,7 @@ class RunnableEntity:
         """
         ...
 
-    def data_read_accesses(self, /) -> Iterator[VariableAccess]:
+    def data_read_accesses(self, /) -> ItemIterator[VariableAccess]:
         """iterate over all data read accesses of the runnable entity"""
         ...
 
@@ -979,7 +1298,7 @@ class RunnableEntity:
         """
         ...
 
-    def data_write_accesses(self, /) -> Iterator[VariableAccess]:
+    def data_write_accesses(self, /) -> ItemIterator[VariableAccess]:
         """iterate over all data write accesses of the runnable entity"""
         ...
 
@@ -991,7 +1310,7 @@ class RunnableEntity:
         """
         ...
 
-    def data_send_points(self, /) -> Iterator[VariableAccess]:
+    def data_send_points(self, /) -> ItemIterator[VariableAccess]:
         """iterate over all data send points of the runnable entity"""
         ...
 
@@ -1003,7 +1322,7 @@ class RunnableEntity:
         """
         ...
 
-    def data_receive_points_by_argument(self, /) -> Iterator[VariableAccess]:
+    def data_receive_points_by_argument(self, /) -> ItemIterator[VariableAccess]:
         """iterate over all data receive points by argument of the runnable entity"""
         ...
 
@@ -1015,7 +1334,7 @@ class RunnableEntity:
         """
         ...
 
-    def data_receive_points_by_value(self, /) -> Iterator[VariableAccess]:
+    def data_receive_points_by_value(self, /) -> ItemIterator[VariableAccess]:
         """iterate over all data receive points by value of the runnable entity"""
         ...
 
@@ -1027,7 +1346,7 @@ class RunnableEntity:
         """
         ...
 
-    def synchronous_server_call_points(self, /) -> Iterator[SynchronousServerCallPoint]:
+    def synchronous_server_call_points(self, /) -> ItemIterator[SynchronousServerCallPoint]:
         """iterate over all synchronous server call points of the runnable entity"""
         ...
 
@@ -1039,7 +1358,7 @@ class RunnableEntity:
         """
         ...
 
-    def mode_switch_points(self, /) -> Iterator[ModeSwitchPoint]:
+    def mode_switch_points(self, /) -> ItemIterator[ModeSwitchPoint]:
         """iterate over all mode switch points of the runnable entity"""
         ...
 
@@ -1051,12 +1370,12 @@ class RunnableEntity:
         """
         ...
 
-    def mode_access_points(self, /) -> Iterator[ModeAccessPoint]:
+    def mode_access_points(self, /) -> ItemIterator[ModeAccessPoint]:
         """iterate over all mode access points of the runnable entity"""
         ...
 
 @final
-class SenderReceiverInterface:
+class SenderReceiverInterface(_IdentifiableElement):
     """
     A `SenderReceiverInterface` defines a set of data elements that can be sent and received
 
@@ -1065,13 +1384,19 @@ class SenderReceiverInterface:
 
     def __init__(self, element: Element, /) -> SenderReceiverInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_data_element(
         self, name: str, data_type: AutosarDataType, /
     ) -> VariableDataPrototype:
         """Add a new data element to the sender receiver interface"""
         ...
 
-    def data_elements(self, /) -> Iterator[VariableDataPrototype]:
+    def data_elements(self, /) -> ItemIterator[VariableDataPrototype]:
         """iterate over all data elements"""
         ...
     element: Element
@@ -1080,7 +1405,7 @@ class SenderReceiverInterface:
     """Get/Set if the sender/receiver interface is a service interface"""
 
 @final
-class SensorActuatorSwComponentType:
+class SensorActuatorSwComponentType(_IdentifiableElement):
     """
     `SensorActuatorSwComponentType` is used to connect sensor/acutator devices to the ECU configuration
 
@@ -1089,6 +1414,12 @@ class SensorActuatorSwComponentType:
 
     def __init__(self, element: Element, /) -> SensorActuatorSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_p_port(
         self, name: str, port_interface: PortInterface, /
     ) -> PPortPrototype:
@@ -1105,6 +1436,30 @@ class SensorActuatorSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -1127,16 +1482,16 @@ class SensorActuatorSwComponentType:
         """list all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
-    def swc_internal_behaviors(self, /) -> Iterator[SwcInternalBehavior]:
+    def swc_internal_behaviors(self, /) -> ItemIterator[SwcInternalBehavior]:
         """iterate over all swc internal behaviors - typically zero or one"""
         ...
 
 @final
-class ServiceSwComponentType:
+class ServiceSwComponentType(_IdentifiableElement):
     """
     `ServiceSwComponentType` is used for configuring services for a given ECU. Instances of this class should only
     be created in ECU Configuration phase for the specific purpose of the service configuration.
@@ -1146,6 +1501,12 @@ class ServiceSwComponentType:
 
     def __init__(self, element: Element, /) -> ServiceSwComponentType: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def create_p_port(
         self, name: str, port_interface: PortInterface, /
     ) -> PPortPrototype:
@@ -1162,6 +1523,30 @@ class ServiceSwComponentType:
         """create a new provided required port with the given name and port interface"""
         ...
 
+    def get_or_create_r_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> RPortPrototype:
+        """get the required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_p_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PPortPrototype:
+        """get the provided port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
+    def get_or_create_pr_port(
+        self, name: str, port_interface: PortInterface, /
+    ) -> PRPortPrototype:
+        """get the provided required port with the given name, or create it if it does not exist
+
+        Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface"""
+        ...
+
     def create_r_port(
         self, name: str, port_interface: PortInterface, /
     ) -> RPortPrototype:
@@ -1184,27 +1569,33 @@ class ServiceSwComponentType:
         """list all compositions containing instances of the component type"""
         ...
 
-    def ports(self, /) -> Iterator[PortPrototype]:
+    def ports(self, /) -> ItemIterator[PortPrototype]:
         """get an iterator over the ports of the component"""
         ...
 
-    def swc_internal_behaviors(self, /) -> Iterator[SwcInternalBehavior]:
+    def swc_internal_behaviors(self, /) -> ItemIterator[SwcInternalBehavior]:
         """iterate over all swc internal behaviors - typically zero or one"""
         ...
 
 @final
-class SwComponentPrototype:
+class SwComponentPrototype(_IdentifiableElement):
     """
     A `SwComponentPrototype` is an instance of a software component type
     """
 
     def __init__(self, element: Element, /) -> SwComponentPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
 
 @final
-class SwcInternalBehavior:
+class SwcInternalBehavior(_IdentifiableElement):
     """
     The `SwcInternalBehavior` of a software component type describes the
     details that are needed to generate the RTE.
@@ -1212,6 +1603,12 @@ class SwcInternalBehavior:
 
     def __init__(self, element: Element, /) -> SwcInternalBehavior: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     def add_data_type_mapping_set(
         self, data_type_mapping_set: DataTypeMappingSet, /
     ) -> None:
@@ -1255,15 +1652,15 @@ class SwcInternalBehavior:
         """Create a timing event that triggers a runnable in the `SwcInternalBehavior`"""
         ...
 
-    def data_type_mapping_sets(self, /) -> Iterator[DataTypeMappingSet]:
+    def data_type_mapping_sets(self, /) -> ItemIterator[DataTypeMappingSet]:
         """iterator over all `DataTypeMappingSet` references in the `SwcInternalBehavior`"""
         ...
     element: Element
-    def events(self, /) -> Iterator[RTEEvent]:
+    def events(self, /) -> ItemIterator[RTEEvent]:
         """create an iterator over all events in the `SwcInternalBehavior`"""
         ...
     name: str
-    def runnable_entities(self, /) -> Iterator[RunnableEntity]:
+    def runnable_entities(self, /) -> ItemIterator[RunnableEntity]:
         """Get an iterator over all RunnableEntities in the SwcInternalBehavior"""
         ...
     sw_component_type: Optional[SwComponentType]
@@ -1293,13 +1690,19 @@ class SwcInternalBehavior:
         ...
 
 @final
-class SwcModeManagerErrorEvent:
+class SwcModeManagerErrorEvent(_IdentifiableElement):
     """
     A `SwcModeManagerErrorEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode manager error occurs
     """
 
     def __init__(self, element: Element, /) -> SwcModeManagerErrorEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -1308,13 +1711,19 @@ class SwcModeManagerErrorEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class SwcModeSwitchEvent:
+class SwcModeSwitchEvent(_IdentifiableElement):
     """
     A `SwcModeSwitchEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode switch occurs
     """
 
     def __init__(self, element: Element, /) -> SwcModeSwitchEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     runnable_entity: Optional[RunnableEntity]
@@ -1349,13 +1758,19 @@ class SwcModeSwitchEvent:
         ...
 
 @final
-class SynchronousServerCallPoint:
+class SynchronousServerCallPoint(_IdentifiableElement):
     """
     A `SynchronousServerCallPoint` allows a `RunnableEntity` to call a server operation synchronously
     """
 
     def __init__(self, element: Element, /) -> SynchronousServerCallPoint: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     def set_client_server_operation(
@@ -1372,13 +1787,19 @@ class SynchronousServerCallPoint:
     """`RunnableEntity` that contains the `SynchronousServerCallPoint`"""
 
 @final
-class TimingEvent:
+class TimingEvent(_IdentifiableElement):
     """
     A `TimingEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` periodically
     """
 
     def __init__(self, element: Element, /) -> TimingEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     period: Optional[float]
@@ -1389,13 +1810,19 @@ class TimingEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class TransformerHardErrorEvent:
+class TransformerHardErrorEvent(_IdentifiableElement):
     """
     A `TransformerHardErrorEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a transformer hard error occurs
     """
 
     def __init__(self, element: Element, /) -> TransformerHardErrorEvent: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     """element of the `TransformerHardErrorEvent`"""
     name: str
@@ -1406,7 +1833,7 @@ class TransformerHardErrorEvent:
     """Get the `SwcInternalBehavior` that contains the event"""
 
 @final
-class TriggerInterface:
+class TriggerInterface(_IdentifiableElement):
     """
     A `TriggerInterface` declares a number of triggers that can be sent by an trigger source
 
@@ -1415,19 +1842,31 @@ class TriggerInterface:
 
     def __init__(self, element: Element, /) -> TriggerInterface: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     is_service: Optional[bool]
     """Get/Set if the trigger interface is a service interface"""
 
 @final
-class VariableAccess:
+class VariableAccess(_IdentifiableElement):
     """
     A `VariableAccess` allows a `RunnableEntity` to access a variable in various contexts
     """
 
     def __init__(self, element: Element, /) -> VariableAccess: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     element: Element
     name: str
     def set_accessed_variable(
@@ -1441,13 +1880,19 @@ class VariableAccess:
     """`RunnableEntity` that contains the `VariableAccess`"""
 
 @final
-class VariableDataPrototype:
+class VariableDataPrototype(_IdentifiableElement):
     """
     A `VariableDataPrototype` represents a data element in a `SenderReceiverInterface`
     """
 
     def __init__(self, element: Element, /) -> VariableDataPrototype: ...
     def remove(self, /, *, deep: bool = False) -> None: ...
+    def preview_remove(self, /, *, deep: bool = True) -> RemovePreview:
+        """preview the effect of `remove` without modifying the model
+
+        The removal is performed on a copy of the model. Each call duplicates the whole model
+        and checks all references twice, so this is expensive on large models."""
+        ...
     data_type: Optional[AutosarDataType]
     """data type of the data element"""
     element: Element
@@ -0,0 +1,485 @@
+"""Command line tool for common operations on arxml files
+
+Run `python -m autosar_data --help` for a list of the available commands.
+
+All commands use the same exit codes:
+
+- 0: success
+- 1: the command found problems, e.g. invalid files, broken references or differences
+- 2: the command could not be run, e.g. because an input file could not be loaded
+
+If `--json` is given, then the result is printed as a single JSON document on stdout.
+"""
+
+import argparse
+import json
+import os
+import sys
+from collections import Counter
+from typing import Any, Dict, List, Optional, Sequence, Tuple
+
+from autosar_data._autosar_data import (
+    ArxmlFile,
+    AutosarDataError,
+    AutosarModel,
+    AutosarVersion,
+    Element,
+)
+
+EXIT_OK = 0
+EXIT_FINDINGS = 1
+EXIT_ERROR = 2
+
+# the result of a command: exit code, JSON result and text output
+CommandResult = Tuple[int, Dict[str, Any], List[str]]
+
+
+class CliError(Exception):
+    """an error which prevents a command from running"""
+
+
+def main(argv: Optional[Sequence[str]] = None) -> int:
+    """run the command line tool with the given arguments and return the exit code"""
+    parser = _build_parser()
+    args = parser.parse_args(argv)
+    try:
+        exit_code, result, lines = args.command(args)
+    except CliError as error:
+        if args.json:
+            print(json.dumps({"error": str(error)}, indent=2))
+        print(f"error: {error}", file=sys.stderr)
+        return EXIT_ERROR
+
+    if args.json:
+        print(json.dumps(result, indent=2))
+    else:
+        for line in lines:
+            print(line)
+    return exit_code
+
+
+def _build_parser() -> argparse.ArgumentParser:
+    common = argparse.ArgumentParser(add_help=False)
+    common.add_argument(
+        "--json", action="store_true", help="print the result as JSON on stdout"
+    )
+
+    parser = argparse.ArgumentParser(
+        prog="python -m autosar_data",
+        description="Validate, inspect and transform arxml files",
+    )
+    commands = parser.add_subparsers(title="commands", required=True)
+
+    validate = commands.add_parser(
+        "validate",
+        parents=[common],
+        help="load the files and check that their content matches their Autosar version",
+    )
+    validate.add_argument("files", nargs="+", help="arxml files")
+    validate.add_argument(
+        "--strict", action="store_true", help="treat parser warnings as errors"
+    )
+    validate.set_defaults(command=cmd_validate)
+
+    check_refs = commands.add_parser(
+        "check-refs",
+        parents=[common],
+        help="find references whose target does not exist",
+    )
+    check_refs.add_argument("files", nargs="+", help="arxml files")
+    check_refs.set_defaults(command=cmd_check_refs)
+
+    diff = commands.add_parser(
+        "diff",
+        parents=[common],
+        help="compare the identifiable elements of two files",
+    )
+    diff.add_argument("old", help="the original arxml file")
+    diff.add_argument("new", help="the modified arxml file")
+    diff.add_argument(
+        "--ignore-attribute",
+        action="append",
+        default=[],
+        metavar="ATTRIBUTE",
+        help="attribute that is ignored in the comparison, e.g. UUID; can be given several times",
+    )
+    diff.set_defaults(command=cmd_diff)
+
+    convert = commands.add_parser(
+        "convert",
+        parents=[common],
+        help="change the Autosar version of the files",
+    )
+    convert.add_argument("files", nargs="+", help="arxml files")
+    convert.add_argument(
+        "--version",
+        required=True,
+        help="target version, e.g. 4.3.0, 00051 or AUTOSAR_00051",
+    )
+    convert.add_argument(
+        "--force",
+        action="store_true",
+        help="convert the files even if they contain elements that are incompatible with the target version",
+    )
+    _add_output_dir(convert)
+    convert.set_defaults(command=cmd_convert)
+
+    sort = commands.add_parser(
+        "sort", parents=[common], help="sort the content of the files"
+    )
+    sort.add_argument("files", nargs="+", help="arxml files")
+    _add_output_dir(sort)
+    sort.set_defaults(command=cmd_sort)
+
+    split = commands.add_parser(
+        "split",
+        parents=[common],
+        help="write each top-level AR-PACKAGE of the model into a separate file",
+    )
+    split.add_argument("files", nargs="+", help="arxml files")
+    split.add_argument(
+        "--output-dir", required=True, help="directory for the new files"
+    )
+    split.set_defaults(command=cmd_split)
+
+    merge = commands.add_parser(
+        "merge", parents=[common], help="merge the files into a single file"
+    )
+    merge.add_argument("files", nargs="+", help="arxml files")
+    merge.add_argument("-o", "--output", required=True, help="the merged file")
+    merge.add_argument(
+        "--version",
+        help="Autosar version of the merged file; default: version of the first file",
+    )
+    merge.set_defaults(command=cmd_merge)
+
+    stats = commands.add_parser(
+        "stats", parents=[common], help="print statistics about the files"
+    )
+    stats.add_argument("files", nargs="+", help="arxml files")
+    stats.add_argument(
+        "--top",
+        type=int,
+        default=10,
+        help="number of element types to list; default: 10",
+    )
+    stats.set_defaults(command=cmd_stats)
+
+    extract = commands.add_parser(
+        "extract",
+        parents=[common],
+        help="copy elements and their dependencies into a new file",
+    )
+    extract.add_argument("files", nargs="+", help="arxml files")
+    extract.add_argument(
+        "-p",
+        "--path",
+        action="append",
+        required=True,
+        dest="paths",
+        metavar="PATH",
+        help="Autosar path of an element to extract; can be given several times",
+    )
+    extract.add_argument("-o", "--output", required=True, help="the new file")
+    extract.add_argument(
+        "--no-dependencies",
+        action="store_true",
+        help="do not copy the elements that are referenced by the extracted elements",
+    )
+    extract.set_defaults(command=cmd_extract)
+
+    return parser
+
+
+def _add_output_dir(parser: argparse.ArgumentParser) -> None:
+    parser.add_argument(
+        "--output-dir",
+        help="write the files into this directory instead of overwriting the input files",
+    )
+
+
+#######################################################################
+# commands
+
+
+def cmd_validate(args: argparse.Namespace) -> CommandResult:
+    """load the files and check that their content matches their Autosar version"""
+    model = AutosarModel()
+    findings: List[Dict[str, Any]] = []
+    for filename in args.files:
+        try:
+            _, warnings = model.load_file(filename, strict=args.strict)
+        except AutosarDataError as error:
+            findings.append(
+                {"file": filename, "severity": "error", "message": str(error)}
+            )
+            continue
+        findings.extend(
+            {"file": filename, "severity": "warning", "message": warning}
+            for warning in warnings
+        )
+
+    for arxml_file in model.files:
+        for item in arxml_file.check_version_compatibility(arxml_file.version):
+            findings.append(
+                {
+                    "file": arxml_file.filename,
+                    "severity": "error",
+                    "message": str(item),
+                    "xml_path": item.element.xml_path,
+                }
+            )
+
+    errors = sum(1 for finding in findings if finding["severity"] == "error")
+    lines = [
+        f"{finding['file']}: {finding['severity']}: {finding['message']}"
+        for finding in findings
+    ]
+    lines.append(
+        f"{len(args.files)} file(s) checked, {errors} error(s), {len(findings) - errors} warning(s)"
+    )
+    result = {"valid": errors == 0, "findings": findings}
+    return (EXIT_FINDINGS if errors else EXIT_OK), result, lines
+
+
+def cmd_check_refs(args: argparse.Namespace) -> CommandResult:
+    """find references whose target does not exist"""
+    model = _load_model(args.files)
+    broken = []
+    lines = []
+    for report in model.reference_report():
+        candidates = [
+            {"path": candidate.path, "score": score}
+            for candidate, score in report.candidates
+        ]
+        broken.append(
+            {
+                "xml_path": report.element.xml_path,
+                "target": report.target,
+                "dest": report.dest,
+                "candidates": candidates,
+            }
+        )
+        line = f"{report.element.xml_path}: missing target {report.target}"
+        if candidates:
+            line += f" (did you mean {candidates[0]['path']}?)"
+        lines.append(line)
+    lines.append(f"{len(broken)} broken reference(s)")
+    return (EXIT_FINDINGS if broken else EXIT_OK), {"broken_references": broken}, lines
+
+
+def cmd_diff(args: argparse.Namespace) -> CommandResult:
+    """compare the identifiable elements of two files"""
+    old_model = _load_model([args.old])
+    new_model = _load_model([args.new])
+    ignore = args.ignore_attribute or None
+    try:
+        old_hashes = old_model.fingerprints(ignore=ignore)
+        new_hashes = new_model.fingerprints(ignore=ignore)
+    except (AutosarDataError, ValueError) as error:
+        raise CliError(str(error)) from error
+
+    added = sorted(new_hashes.keys() - old_hashes.keys())
+    removed = sorted(old_hashes.keys() - new_hashes.keys())
+    changed = sorted(
+        path
+        for path in old_hashes.keys() & new_hashes.keys()
+        if old_hashes[path] != new_hashes[path]
+    )
+    # the hash of a package changes whenever its content changes, so packages are
+    # only listed if there is no other difference inside of them
+    differences = added + removed + changed
+    changed = [
+        path
+        for path in changed
+        if new_model.get_element_by_path(path).element_name != "AR-PACKAGE"
+        or not any(other.startswith(path + "/") for other in differences)
+    ]
+
+    lines = (
+        [f"+ {path}" for path in added]
+        + [f"- {path}" for path in removed]
+        + [f"~ {path}" for path in changed]
+    )
+    lines.append(
+        f"{len(added)} added, {len(removed)} removed, {len(changed)} changed"
+    )
+    result = {"added": added, "removed": removed, "changed": changed}
+    has_differences = bool(added or removed or changed)
+    return (EXIT_FINDINGS if has_differences else EXIT_OK), result, lines
+
+
+def cmd_convert(args: argparse.Namespace) -> CommandResult:
+    """change the Autosar version of the files"""
+    version = _parse_version(args.version)
+    model = _load_model(args.files)
+
+    incompatible = []
+    for arxml_file in model.files:
+        for item in arxml_file.check_version_compatibility(version):
+            incompatible.append(
+                {
+                    "file": arxml_file.filename,
+                    "message": str(item),
+                    "xml_path": item.element.xml_path,
+                }
+            )
+    lines = [f"{item['file']}: {item['message']}" for item in incompatible]
+    if incompatible and not args.force:
+        lines.append(
+            f"{len(incompatible)} incompatible item(s), no files were written; use --force to convert anyway"
+        )
+        result = {"written": [], "incompatible": incompatible}
+        return EXIT_FINDINGS, result, lines
+
+    for arxml_file in model.files:
+        arxml_file.version = version
+    written = _write_files(model, args.output_dir)
+    lines.extend(f"wrote {filename}" for filename in written)
+    return EXIT_OK, {"written": written, "incompatible": incompatible}, lines
+
+
+def cmd_sort(args: argparse.Namespace) -> CommandResult:
+    """sort the content of the files"""
+    model = _load_model(args.files)
+    model.sort()
+    written = _write_files(model, args.output_dir)
+    return EXIT_OK, {"written": written}, [f"wrote {filename}" for filename in written]
+
+
+def cmd_split(args: argparse.Namespace) -> CommandResult:
+    """write each top-level AR-PACKAGE of the model into a separate file"""
+    model = _load_model(args.files)
+    written = []
+    for package in _top_level_packages(model):
+        extracted, _ = model.extract([package.path], include_dependencies=False)
+        filename = os.path.join(args.output_dir, f"{package.item_name}.arxml")
+        _write_file(extracted.files[0], filename)
+        written.append(filename)
+    return EXIT_OK, {"written": written}, [f"wrote {filename}" for filename in written]
+
+
+def cmd_merge(args: argparse.Namespace) -> CommandResult:
+    """merge the files into a single file"""
+    model = _load_model(args.files)
+    paths = [package.path for package in _top_level_packages(model)]
+    merged, _ = model.extract(paths, include_dependencies=False)
+    merged_file = merged.files[0]
+    if args.version is not None:
+        merged_file.version = _parse_version(args.version)
+    _write_file(merged_file, args.output)
+    return EXIT_OK, {"written": [args.output]}, [f"wrote {args.output}"]
+
+
+def cmd_stats(args: argparse.Namespace) -> CommandResult:
+    """print statistics about the files"""
+    model = _load_model(args.files)
+    element_counts = Counter(
+        element.element_name for _, element in model.elements_dfs
+    )
+    files = [
+        {
+            "filename": arxml_file.filename,
+            "version": str(arxml_file.version),
+            "elements": len(arxml_file.elements_dfs),
+        }
+        for arxml_file in model.files
+    ]
+    result = {
+        "files": files,
+        "elements": sum(element_counts.values()),
+        "identifiable_elements": len(model.identifiable_elements),
+        "broken_references": len(model.check_references()),
+        "element_types": dict(element_counts.most_common(args.top)),
+    }
+
+    lines = [
+        f"{entry['filename']}: {entry['version']}, {entry['elements']} elements"
+        for entry in files
+    ]
+    lines.append(f"elements: {result['elements']}")
+    lines.append(f"identifiable elements: {result['identifiable_elements']}")
+    lines.append(f"broken references: {result['broken_references']}")
+    lines.append("most common element types:")
+    lines.extend(
+        f"  {count:8} {name}" for name, count in result["element_types"].items()
+    )
+    return EXIT_OK, result, lines
+
+
+def cmd_extract(args: argparse.Namespace) -> CommandResult:
+    """copy elements and their dependencies into a new file"""
+    model = _load_model(args.files)
+    try:
+        extracted, unresolved = model.extract(
+            args.paths, include_dependencies=not args.no_dependencies
+        )
+    except AutosarDataError as error:
+        raise CliError(str(error)) from error
+    _write_file(extracted.files[0], args.output)
+
+    unresolved_references = [
+        {"path": path, "target": target} for path, target in unresolved
+    ]
+    lines = [f"{path}: unresolved reference to {target}" for path, target in unresolved]
+    lines.append(f"wrote {args.output}")
+    result = {"written": [args.output], "unresolved_references": unresolved_references}
+    return (EXIT_FINDINGS if unresolved else EXIT_OK), result, lines
+
+
+#######################################################################
+# helpers
+
+
+def _load_model(filenames: Sequence[str]) -> AutosarModel:
+    """load all files into one model"""
+    model = AutosarModel()
+    for filename in filenames:
+        try:
+            model.load_file(filename)
+        except AutosarDataError as error:
+            raise CliError(f"{filename}: {error}") from error
+    return model
+
+
+def _parse_version(text: str) -> AutosarVersion:
+    """parse a version given as e.g. 4.3.0, 00051, AUTOSAR_00051 or AUTOSAR_4-0-1.xsd"""
+    name = text.strip().upper().removesuffix(".XSD")
+    for separator in ".- ":
+        name = name.replace(separator, "_")
+    if not name.startswith("AUTOSAR_"):
+        name = "AUTOSAR_" + name
+    version = getattr(AutosarVersion, name, None)
+    if not isinstance(version, AutosarVersion):
+        raise CliError(f"\"{text}\" is not a valid Autosar version")
+    return version
+
+
+def _top_level_packages(model: AutosarModel) -> List[Element]:
+    packages = model.root_element.get_sub_element("AR-PACKAGES")
+    if packages is None:
+        return []
+    return list(packages.sub_elements_named("AR-PACKAGE"))
+
+
+def _write_files(model: AutosarModel, output_dir: Optional[str]) -> List[str]:
+    """write all files of the model, either in place or into the output directory"""
+    written = []
+    for arxml_file in model.files:
+        filename = arxml_file.filename
+        if output_dir is not None:
+            filename = os.path.join(output_dir, os.path.basename(filename))
+        _write_file(arxml_file, filename)
+        written.append(filename)
+    return written
+
+
+def _write_file(arxml_file: ArxmlFile, filename: str) -> None:
+    directory = os.path.dirname(filename)
+    try:
+        if directory:
+            os.makedirs(directory, exist_ok=True)
+        with open(filename, "w", encoding="utf-8") as output:
+            output.write(arxml_file.serialize())
+    except OSError as error:
+        raise CliError(f"{filename}: {error}") from error
@@ -0,0 +1,13 @@
+import autosar_data._autosar_data._lint
+from autosar_data._autosar_data._lint import *
+
+__doc__ = autosar_data._autosar_data._lint.__doc__
+
+# explicitly set __all__ to avoid exporting modules that are not part of the public API
+__all__ = [
+    "Linter",
+    "LintFinding",
+    "Severity",
+    "to_junit",
+    "to_sarif",
+]
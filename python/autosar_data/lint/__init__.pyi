@@ -0,0 +1,104 @@
+# Stub file for autosar_data.lint
+
+from typing import final, Callable, List, Optional, Union
+from autosar_data import AutosarModel, Element, ElementName
+
+@final
+class Severity:
+    """
+    The severity of a lint finding
+    """
+
+    Error: Severity
+    Info: Severity
+    Warning: Severity
+
+@final
+class LintFinding:
+    """
+    A single violation of a lint rule
+    """
+
+    rule: str
+    """the id of the rule that produced the finding"""
+    severity: Severity
+    """the severity of the finding"""
+    element: Element
+    """the element that violates the rule"""
+    message: str
+    """a description of the problem"""
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+@final
+class Linter:
+    """
+    Checks a model against a configurable set of rules
+
+    All built-in rules are enabled when a `Linter` is created. Additional rules can be
+    implemented in Python and added with `add_rule`.
+
+    Built-in rules:
+
+    - broken-reference
+    - duplicate-can-id
+    - empty-package
+    - isignal-without-system-signal
+    - missing-desc
+    - short-name-convention
+    - uuid-missing
+    """
+
+    def __init__(self) -> Linter: ...
+    rules: List[str]
+    """the names of all rules, both built-in and user defined"""
+    @staticmethod
+    def describe_rule(rule: str, /) -> Optional[str]:
+        """get a description of a built-in rule"""
+        ...
+
+    def get_severity(self, rule: str, /) -> Optional[Severity]:
+        """get the severity of a rule. Returns None if the rule is disabled"""
+        ...
+
+    def set_severity(self, rule: str, severity: Optional[Severity], /) -> None:
+        """set the severity of a rule. Setting the severity to None disables the rule"""
+        ...
+
+    def set_naming_convention(
+        self, element_name: ElementName, pattern: Optional[str], /
+    ) -> None:
+        """set the naming convention for the SHORT-NAMEs of one element type
+
+        The regular expression must match the complete name. If the pattern is None, then the
+        naming convention for the element type is removed."""
+        ...
+
+    def add_rule(
+        self,
+        name: str,
+        check: Callable[[Element], Union[None, str, List[str]]],
+        /,
+        element_names: Optional[List[ElementName]] = None,
+        severity: Severity = Severity.Warning,
+    ) -> None:
+        """add a rule that is implemented in Python
+
+        The check function is called for each element in the model whose name is contained in
+        element_names, or for all elements if element_names is None. It can return None if the element
+        is fine, or a message string or a list of message strings describing the problems."""
+        ...
+
+    def run(self, model: AutosarModel, /) -> List[LintFinding]:
+        """check the model and return all findings of the enabled rules"""
+        ...
+
+def to_sarif(findings: List[LintFinding], /) -> str:
+    """convert a list of findings to a SARIF 2.1.0 log"""
+    ...
+
+def to_junit(findings: List[LintFinding], /) -> str:
+    """convert a list of findings to a JUnit XML report
+
+    Each rule with findings becomes a test suite, and each finding becomes a failed test case."""
+    ...
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::abstraction::{
     AutosarAbstractionError, ByteOrder, System, abstraction_err_to_pyerr,
     communication::{
@@ -16,6 +17,7 @@ use crate::abstraction::{
         EcucDefinitionCollection, EcucDestinationUriDefSet, EcucModuleConfigurationValues,
         EcucModuleDef, EcucValueCollection,
     },
+    find_existing_element, incompatible_element_err,
     software_component::{
         ApplicationSwComponentType, ClientServerInterface, ComplexDeviceDriverSwComponentType,
         CompositionSwComponentType, EcuAbstractionSwComponentType, ModeDeclarationGroup,
@@ -24,8 +26,10 @@ use crate::abstraction::{
         TriggerInterface,
     },
     system::SystemCategory,
+    wrap_or_element,
 };
 use crate::{Element, iterator_wrapper};
+use ::autosar_data::ElementName;
 use autosar_data_abstraction::AbstractionElement;
 use autosar_data_abstraction::{self, IdentifiableAbstractionElement};
 use pyo3::prelude::*;
@@ -55,14 +59,28 @@ impl ArPackage {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -80,6 +98,10 @@ impl ArPackage {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `ApplicationArrayDataType` in the package
     #[pyo3(
         text_signature = "(self, name: str, element_type: ApplicationDataType, size: ApplicationArraySize, /)"
@@ -90,6 +112,7 @@ impl ArPackage {
         element_type: &Bound<'_, PyAny>,
         size: ApplicationArraySize,
     ) -> PyResult<ApplicationArrayDataType> {
+        crate::frozen::check_element(self.0.element())?;
         let element_type = if let Ok(app_array) = element_type.extract::<ApplicationArrayDataType>()
         {
             autosar_data_abstraction::datatype::ApplicationDataType::Array(app_array.0)
@@ -124,6 +147,7 @@ impl ArPackage {
         unit: Option<&Unit>,
         data_constraint: Option<&DataConstr>,
     ) -> PyResult<ApplicationPrimitiveDataType> {
+        crate::frozen::check_element(self.0.element())?;
         let compu_method = compu_method.map(|cm| cm.0.clone());
         let unit = unit.map(|u| u.0.clone());
         let data_constraint = data_constraint.map(|dc| dc.0.clone());
@@ -145,60 +169,187 @@ impl ArPackage {
         &self,
         name: &str,
     ) -> PyResult<ApplicationRecordDataType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_application_record_data_type(name) {
             Ok(value) => Ok(ApplicationRecordDataType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ApplicationRecordDataType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an APPLICATION-RECORD-DATA-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_application_record_data_type(
+        &self,
+        name: &str,
+    ) -> PyResult<ApplicationRecordDataType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ApplicationRecordDataType,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::datatype::ApplicationRecordDataType::try_from(existing)
+                    .map(ApplicationRecordDataType)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_application_record_data_type(name),
+        }
+    }
+
     /// create a new `ApplicationSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_application_sw_component_type(
         &self,
         name: &str,
     ) -> PyResult<ApplicationSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_application_sw_component_type(name) {
             Ok(value) => Ok(ApplicationSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ApplicationSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an APPLICATION-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_application_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<ApplicationSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ApplicationSwComponentType,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::ApplicationSwComponentType::try_from(
+                    existing,
+                )
+                .map(ApplicationSwComponentType)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_application_sw_component_type(name),
+        }
+    }
+
     /// create a new `ClientServerInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_client_server_interface(&self, name: &str) -> PyResult<ClientServerInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_client_server_interface(name) {
             Ok(value) => Ok(ClientServerInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ClientServerInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a CLIENT-SERVER-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_client_server_interface(&self, name: &str) -> PyResult<ClientServerInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ClientServerInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::ClientServerInterface::try_from(
+                    existing,
+                )
+                .map(ClientServerInterface)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_client_server_interface(name),
+        }
+    }
+
     /// create a new `ComplexDeviceDriverSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_complex_device_driver_sw_component_type(
         &self,
         name: &str,
     ) -> PyResult<ComplexDeviceDriverSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_complex_device_driver_sw_component_type(name) {
             Ok(value) => Ok(ComplexDeviceDriverSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ComplexDeviceDriverSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a COMPLEX-DEVICE-DRIVER-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_complex_device_driver_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<ComplexDeviceDriverSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ComplexDeviceDriverSwComponentType,
+        )? {
+            Some(existing) => autosar_data_abstraction::software_component::ComplexDeviceDriverSwComponentType::try_from(existing)
+                .map(ComplexDeviceDriverSwComponentType)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_complex_device_driver_sw_component_type(name),
+        }
+    }
+
     /// create a new `CompositionSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_composition_sw_component_type(
         &self,
         name: &str,
     ) -> PyResult<CompositionSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_composition_sw_component_type(name) {
             Ok(value) => Ok(CompositionSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `CompositionSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a COMPOSITION-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_composition_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<CompositionSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::CompositionSwComponentType,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::CompositionSwComponentType::try_from(
+                    existing,
+                )
+                .map(CompositionSwComponentType)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_composition_sw_component_type(name),
+        }
+    }
+
     /// create a new `CompuMethod` in the package
     #[pyo3(text_signature = "(self, name: str, content: CompuMethodContent)")]
     fn create_compu_method(&self, name: &str, content: &Bound<'_, PyAny>) -> PyResult<CompuMethod> {
+        crate::frozen::check_element(self.0.element())?;
         let content = pyany_to_compu_method_content(content)?;
         match self.0.create_compu_method(name, content) {
             Ok(value) => Ok(CompuMethod(value)),
@@ -206,6 +357,37 @@ impl ArPackage {
         }
     }
 
+    /// get the `CompuMethod` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a COMPU-METHOD, or if its content differs
+    #[pyo3(signature = (name, content, /))]
+    #[pyo3(text_signature = "(self, name: str, content: CompuMethodContent, /)")]
+    fn get_or_create_compu_method(
+        &self,
+        name: &str,
+        content: &Bound<'_, PyAny>,
+    ) -> PyResult<CompuMethod> {
+        let Some(existing) = find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::CompuMethod,
+        )?
+        else {
+            return self.create_compu_method(name, content);
+        };
+        let compu_method =
+            autosar_data_abstraction::datatype::CompuMethod::try_from(existing.clone())
+                .map_err(abstraction_err_to_pyerr)?;
+        if compu_method.content() != Some(pyany_to_compu_method_content(content)?) {
+            return Err(incompatible_element_err(
+                &existing,
+                "its content is different",
+            ));
+        }
+        Ok(CompuMethod(compu_method))
+    }
+
     /// create a new `ConstantSpecification` in the package
     #[pyo3(text_signature = "(self, name: str, value: ValueSpecification)")]
     pub fn create_constant_specification(
@@ -213,6 +395,7 @@ impl ArPackage {
         name: &str,
         value: &Bound<'_, PyAny>,
     ) -> PyResult<ConstantSpecification> {
+        crate::frozen::check_element(self.0.element())?;
         let value = pyany_to_value_specification(value)?;
         match self.0.create_constant_specification(name, value) {
             Ok(value) => Ok(ConstantSpecification(value)),
@@ -223,63 +406,204 @@ impl ArPackage {
     /// create a new `DataConstr` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_data_constr(&self, name: &str) -> PyResult<DataConstr> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_data_constr(name) {
             Ok(value) => Ok(DataConstr(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `DataConstr` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a DATA-CONSTR
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_data_constr(&self, name: &str) -> PyResult<DataConstr> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::DataConstr,
+        )? {
+            Some(existing) => autosar_data_abstraction::datatype::DataConstr::try_from(existing)
+                .map(DataConstr)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_data_constr(name),
+        }
+    }
+
     /// create a new `DataTransformationSet` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_data_transformation_set(&self, name: &str) -> PyResult<DataTransformationSet> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_data_transformation_set(name) {
             Ok(value) => Ok(DataTransformationSet(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `DataTransformationSet` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a DATA-TRANSFORMATION-SET
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_data_transformation_set(&self, name: &str) -> PyResult<DataTransformationSet> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::DataTransformationSet,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::communication::DataTransformationSet::try_from(existing)
+                    .map(DataTransformationSet)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_data_transformation_set(name),
+        }
+    }
+
     /// create a new `DataTypeMappingSet` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_data_type_mapping_set(&self, name: &str) -> PyResult<DataTypeMappingSet> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_data_type_mapping_set(name) {
             Ok(value) => Ok(DataTypeMappingSet(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `DataTypeMappingSet` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a DATA-TYPE-MAPPING-SET
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_data_type_mapping_set(&self, name: &str) -> PyResult<DataTypeMappingSet> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::DataTypeMappingSet,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::datatype::DataTypeMappingSet::try_from(existing)
+                    .map(DataTypeMappingSet)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_data_type_mapping_set(name),
+        }
+    }
+
     /// create a new `EcuAbstractionSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_ecu_abstraction_sw_component_type(
         &self,
         name: &str,
     ) -> PyResult<EcuAbstractionSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecu_abstraction_sw_component_type(name) {
             Ok(value) => Ok(EcuAbstractionSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `EcuAbstractionSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECU-ABSTRACTION-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_ecu_abstraction_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<EcuAbstractionSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcuAbstractionSwComponentType,
+        )? {
+            Some(existing) => autosar_data_abstraction::software_component::EcuAbstractionSwComponentType::try_from(existing)
+                .map(EcuAbstractionSwComponentType)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_ecu_abstraction_sw_component_type(name),
+        }
+    }
+
     /// create a new `EcucDefinitionCollection` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_ecuc_definition_collection(&self, name: &str) -> PyResult<EcucDefinitionCollection> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecuc_definition_collection(name) {
             Ok(value) => Ok(EcucDefinitionCollection(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `EcucDefinitionCollection` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECUC-DEFINITION-COLLECTION
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_ecuc_definition_collection(
+        &self,
+        name: &str,
+    ) -> PyResult<EcucDefinitionCollection> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcucDefinitionCollection,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::ecu_configuration::EcucDefinitionCollection::try_from(
+                    existing,
+                )
+                .map(EcucDefinitionCollection)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_ecuc_definition_collection(name),
+        }
+    }
+
     /// create a new `EcucDestinationUriDefSet` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_ecuc_destination_uri_def_set(
         &self,
         name: &str,
     ) -> PyResult<EcucDestinationUriDefSet> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecuc_destination_uri_def_set(name) {
             Ok(value) => Ok(EcucDestinationUriDefSet(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `EcucDestinationUriDefSet` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECUC-DESTINATION-URI-DEF-SET
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_ecuc_destination_uri_def_set(
+        &self,
+        name: &str,
+    ) -> PyResult<EcucDestinationUriDefSet> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcucDestinationUriDefSet,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::ecu_configuration::EcucDestinationUriDefSet::try_from(
+                    existing,
+                )
+                .map(EcucDestinationUriDefSet)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_ecuc_destination_uri_def_set(name),
+        }
+    }
+
     /// create a new `EcucModuleConfigurationValues` in the package
     #[pyo3(text_signature = "(self, name: str, definition: EcucModuleDef)")]
     fn create_ecuc_module_configuration_values(
@@ -287,6 +611,7 @@ impl ArPackage {
         name: &str,
         definition: &EcucModuleDef,
     ) -> PyResult<EcucModuleConfigurationValues> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_ecuc_module_configuration_values(name, &definition.0)
@@ -299,27 +624,72 @@ impl ArPackage {
     /// create a new `EcucModuleDef` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_ecuc_module_def(&self, name: &str) -> PyResult<EcucModuleDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecuc_module_def(name) {
             Ok(value) => Ok(EcucModuleDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `EcucModuleDef` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECUC-MODULE-DEF
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_ecuc_module_def(&self, name: &str) -> PyResult<EcucModuleDef> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcucModuleDef,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::ecu_configuration::EcucModuleDef::try_from(existing)
+                    .map(EcucModuleDef)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_ecuc_module_def(name),
+        }
+    }
+
     /// create a new `EcucValueCollection` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_ecuc_value_collection(&self, name: &str) -> PyResult<EcucValueCollection> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecuc_value_collection(name) {
             Ok(value) => Ok(EcucValueCollection(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `EcucValueCollection` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECUC-VALUE-COLLECTION
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_ecuc_value_collection(&self, name: &str) -> PyResult<EcucValueCollection> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcucValueCollection,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::ecu_configuration::EcucValueCollection::try_from(existing)
+                    .map(EcucValueCollection)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_ecuc_value_collection(name),
+        }
+    }
+
     /// create a new `ImplementationDataType` in the package
     #[pyo3(text_signature = "(self, settings: ImplementationDataTypeSettings)")]
     fn create_implementation_data_type(
         &self,
         settings: &Bound<'_, PyAny>,
     ) -> PyResult<ImplementationDataType> {
+        crate::frozen::check_element(self.0.element())?;
         let settings = pyany_to_implmentation_settings(settings)?;
         match self.0.create_implementation_data_type(&settings) {
             Ok(value) => Ok(ImplementationDataType(value)),
@@ -337,6 +707,7 @@ impl ArPackage {
         name: &str,
         category: Option<ModeDeclarationGroupCategory>,
     ) -> PyResult<ModeDeclarationGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_mode_declaration_group(name, category.map(Into::into))
@@ -349,60 +720,205 @@ impl ArPackage {
     /// create a new `ModeSwitchInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_mode_switch_interface(&self, name: &str) -> PyResult<ModeSwitchInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_mode_switch_interface(name) {
             Ok(value) => Ok(ModeSwitchInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ModeSwitchInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a MODE-SWITCH-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_mode_switch_interface(&self, name: &str) -> PyResult<ModeSwitchInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ModeSwitchInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::ModeSwitchInterface::try_from(
+                    existing,
+                )
+                .map(ModeSwitchInterface)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_mode_switch_interface(name),
+        }
+    }
+
     /// create a new `NvDataInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_nv_data_interface(&self, name: &str) -> PyResult<NvDataInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_nv_data_interface(name) {
             Ok(value) => Ok(NvDataInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `NvDataInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a NV-DATA-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_nv_data_interface(&self, name: &str) -> PyResult<NvDataInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::NvDataInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::NvDataInterface::try_from(existing)
+                    .map(NvDataInterface)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_nv_data_interface(name),
+        }
+    }
+
     /// create a new `ParameterInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_parameter_interface(&self, name: &str) -> PyResult<ParameterInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_parameter_interface(name) {
             Ok(value) => Ok(ParameterInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ParameterInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a PARAMETER-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_parameter_interface(&self, name: &str) -> PyResult<ParameterInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ParameterInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::ParameterInterface::try_from(existing)
+                    .map(ParameterInterface)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_parameter_interface(name),
+        }
+    }
+
     /// create a new `SenderReceiverInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_sender_receiver_interface(&self, name: &str) -> PyResult<SenderReceiverInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_sender_receiver_interface(name) {
             Ok(value) => Ok(SenderReceiverInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `SenderReceiverInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SENDER-RECEIVER-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_sender_receiver_interface(
+        &self,
+        name: &str,
+    ) -> PyResult<SenderReceiverInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SenderReceiverInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::SenderReceiverInterface::try_from(
+                    existing,
+                )
+                .map(SenderReceiverInterface)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_sender_receiver_interface(name),
+        }
+    }
+
     /// create a new `SensorActuatorSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_sensor_actuator_sw_component_type(
         &self,
         name: &str,
     ) -> PyResult<SensorActuatorSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_sensor_actuator_sw_component_type(name) {
             Ok(value) => Ok(SensorActuatorSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `SensorActuatorSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SENSOR-ACTUATOR-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_sensor_actuator_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<SensorActuatorSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SensorActuatorSwComponentType,
+        )? {
+            Some(existing) => autosar_data_abstraction::software_component::SensorActuatorSwComponentType::try_from(existing)
+                .map(SensorActuatorSwComponentType)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_sensor_actuator_sw_component_type(name),
+        }
+    }
+
     /// create a new `ServiceSwComponentType` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_service_sw_component_type(&self, name: &str) -> PyResult<ServiceSwComponentType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_service_sw_component_type(name) {
             Ok(value) => Ok(ServiceSwComponentType(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `ServiceSwComponentType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SERVICE-SW-COMPONENT-TYPE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_service_sw_component_type(
+        &self,
+        name: &str,
+    ) -> PyResult<ServiceSwComponentType> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ServiceSwComponentType,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::ServiceSwComponentType::try_from(
+                    existing,
+                )
+                .map(ServiceSwComponentType)
+                .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_service_sw_component_type(name),
+        }
+    }
+
     /// create a new `SomeipSdClientEventGroupTimingConfig` in the package
     #[pyo3(text_signature = "(self, name: str, time_to_live: int)")]
     fn create_someip_sd_client_event_group_timing_config(
@@ -410,6 +926,7 @@ impl ArPackage {
         name: &str,
         time_to_live: u32,
     ) -> PyResult<SomeipSdClientEventGroupTimingConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_someip_sd_client_event_group_timing_config(name, time_to_live)
@@ -425,12 +942,35 @@ impl ArPackage {
         &self,
         name: &str,
     ) -> PyResult<SomeipSdClientServiceInstanceConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_someip_sd_client_service_instance_config(name) {
             Ok(value) => Ok(SomeipSdClientServiceInstanceConfig(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `SomeipSdClientServiceInstanceConfig` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SOMEIP-SD-CLIENT-SERVICE-INSTANCE-CONFIG
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_someip_sd_client_service_instance_config(
+        &self,
+        name: &str,
+    ) -> PyResult<SomeipSdClientServiceInstanceConfig> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SomeipSdClientServiceInstanceConfig,
+        )? {
+            Some(existing) => autosar_data_abstraction::communication::SomeipSdClientServiceInstanceConfig::try_from(existing)
+                .map(SomeipSdClientServiceInstanceConfig)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_someip_sd_client_service_instance_config(name),
+        }
+    }
+
     /// create a new `SomeipSdServerEventGroupTimingConfig` in the package
     #[pyo3(text_signature = "(self, name: str, request_response_delay: RequestResponseDelay)")]
     fn create_someip_sd_server_event_group_timing_config(
@@ -438,6 +978,7 @@ impl ArPackage {
         name: &str,
         request_response_delay: &RequestResponseDelay,
     ) -> PyResult<SomeipSdServerEventGroupTimingConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_someip_sd_server_event_group_timing_config(name, &request_response_delay.0)
@@ -454,6 +995,7 @@ impl ArPackage {
         name: &str,
         ttl: u32,
     ) -> PyResult<SomeipSdServerServiceInstanceConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_someip_sd_server_service_instance_config(name, ttl)
@@ -477,6 +1019,7 @@ impl ArPackage {
         mem_alignment: Option<u32>,
         native_declaration: Option<&str>,
     ) -> PyResult<SwBaseType> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_sw_base_type(
             name,
             bit_length,
@@ -490,49 +1033,197 @@ impl ArPackage {
         }
     }
 
+    /// get the `SwBaseType` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SW-BASE-TYPE, or if
+    /// its bit length or encoding differ from the requested ones. The optional settings are only used
+    /// when the base type is created.
+    #[pyo3(signature = (name, bit_length, base_type_encoding, /, *, byte_order=None, mem_alignment=None, native_declaration=None))]
+    #[pyo3(
+        text_signature = "(self, name: str, bit_length: int, base_type_encoding: BaseTypeEncoding, /, *, byte_order: Optional[ByteOrder] = None, mem_alignment: Optional[int] = None, native_declaration: Optional[str] = None)"
+    )]
+    fn get_or_create_sw_base_type(
+        &self,
+        name: &str,
+        bit_length: u32,
+        base_type_encoding: BaseTypeEncoding,
+        byte_order: Option<ByteOrder>,
+        mem_alignment: Option<u32>,
+        native_declaration: Option<&str>,
+    ) -> PyResult<SwBaseType> {
+        let Some(existing) = find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SwBaseType,
+        )?
+        else {
+            return self.create_sw_base_type(
+                name,
+                bit_length,
+                base_type_encoding,
+                byte_order,
+                mem_alignment,
+                native_declaration,
+            );
+        };
+        let base_type = autosar_data_abstraction::datatype::SwBaseType::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        if base_type.bit_length() != Some(bit_length) {
+            return Err(incompatible_element_err(
+                &existing,
+                &format!(
+                    "its bit length is {:?} instead of {bit_length}",
+                    base_type.bit_length()
+                ),
+            ));
+        }
+        if base_type.base_type_encoding().map(BaseTypeEncoding::from) != Some(base_type_encoding) {
+            return Err(incompatible_element_err(
+                &existing,
+                "it uses a different encoding",
+            ));
+        }
+        Ok(SwBaseType(base_type))
+    }
+
     /// create a new System in the package
     ///
     /// Note that an Autosar model should ony contain one SYSTEM. This is not checked here.
     #[pyo3(signature = (name, category, /))]
     #[pyo3(text_signature = "(self, name: str, category: SystemCategory, /)")]
     fn create_system(&self, name: &str, category: SystemCategory) -> PyResult<System> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_system(name, category.into()) {
             Ok(system) => Ok(System(system)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the System with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SYSTEM, or if its category differs
+    #[pyo3(signature = (name, category, /))]
+    #[pyo3(text_signature = "(self, name: str, category: SystemCategory, /)")]
+    fn get_or_create_system(&self, name: &str, category: SystemCategory) -> PyResult<System> {
+        let Some(existing) = find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::System,
+        )?
+        else {
+            return self.create_system(name, category);
+        };
+        let system = autosar_data_abstraction::System::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        if system.category().map(SystemCategory::from) != Some(category) {
+            return Err(incompatible_element_err(
+                &existing,
+                &format!("its category is not {category:?}"),
+            ));
+        }
+        Ok(System(system))
+    }
+
     /// create a new `SystemSignal` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_system_signal(&self, name: &str) -> PyResult<SystemSignal> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_system_signal(name) {
             Ok(value) => Ok(SystemSignal(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `SystemSignal` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SYSTEM-SIGNAL
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_system_signal(&self, name: &str) -> PyResult<SystemSignal> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SystemSignal,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::communication::SystemSignal::try_from(existing)
+                    .map(SystemSignal)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_system_signal(name),
+        }
+    }
+
     /// create a new `SystemSignalGroup` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_system_signal_group(&self, name: &str) -> PyResult<SystemSignalGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_system_signal_group(name) {
             Ok(value) => Ok(SystemSignalGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `SystemSignalGroup` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a SYSTEM-SIGNAL-GROUP
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_system_signal_group(&self, name: &str) -> PyResult<SystemSignalGroup> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::SystemSignalGroup,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::communication::SystemSignalGroup::try_from(existing)
+                    .map(SystemSignalGroup)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_system_signal_group(name),
+        }
+    }
+
     /// create a new `TriggerInterface` in the package
     #[pyo3(text_signature = "(self, name: str)")]
     fn create_trigger_interface(&self, name: &str) -> PyResult<TriggerInterface> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_trigger_interface(name) {
             Ok(value) => Ok(TriggerInterface(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
         }
     }
 
+    /// get the `TriggerInterface` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a TRIGGER-INTERFACE
+    #[pyo3(signature = (name, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn get_or_create_trigger_interface(&self, name: &str) -> PyResult<TriggerInterface> {
+        match find_existing_element(
+            self.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::TriggerInterface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::TriggerInterface::try_from(existing)
+                    .map(TriggerInterface)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_trigger_interface(name),
+        }
+    }
+
     /// create a new `Unit` in the package
     #[pyo3(signature = (name, /, *, display_name=None))]
     #[pyo3(text_signature = "(self, name: str, /, *, display_name: Optional[str] = None)")]
     fn create_unit(&self, name: &str, display_name: Option<&str>) -> PyResult<Unit> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_unit(name, display_name) {
             Ok(value) => Ok(Unit(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -543,6 +1234,7 @@ impl ArPackage {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_sub_package(&self, name: &str) -> PyResult<ArPackage> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_sub_package(name) {
             Ok(value) => Ok(ArPackage(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -560,7 +1252,22 @@ impl ArPackage {
     fn elements(&self) -> ElementsIterator {
         ElementsIterator::new(self.0.elements().map(Element))
     }
+
+    /// iterate over all elements in the package, wrapped in the matching abstraction classes
+    ///
+    /// Elements without an abstraction class are returned as plain `Element`s.
+    #[pyo3(text_signature = "(self)")]
+    fn typed_elements(&self) -> TypedElementsIterator {
+        TypedElementsIterator::new(
+            self.0
+                .elements()
+                .filter_map(|element| Python::attach(|py| wrap_or_element(py, element).ok())),
+        )
+    }
 }
 
+identifiable_pymethods!(ArPackage);
+
 iterator_wrapper!(ElementsIterator, Element);
+iterator_wrapper!(TypedElementsIterator, Py<PyAny>, "Union[Element, ...]");
 iterator_wrapper!(ArPackagesIterator, ArPackage);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -36,14 +37,28 @@ impl CanCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -61,9 +76,14 @@ impl CanCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the CAN baudrate for the cluster
     #[setter]
     fn set_baudrate(&self, baudrate: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_baudrate(baudrate)
             .map_err(abstraction_err_to_pyerr)
@@ -78,6 +98,7 @@ impl CanCluster {
     /// set the CAN FD baudrate for the cluster
     #[setter]
     fn set_can_fd_baudrate(&self, baudrate: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_can_fd_baudrate(baudrate)
             .map_err(abstraction_err_to_pyerr)
@@ -92,6 +113,7 @@ impl CanCluster {
     /// set the CAN XL baudrate for the cluster
     #[setter]
     fn set_can_xl_baudrate(&self, baudrate: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_can_xl_baudrate(baudrate)
             .map_err(abstraction_err_to_pyerr)
@@ -109,6 +131,7 @@ impl CanCluster {
     #[pyo3(signature = (channel_name, /))]
     #[pyo3(text_signature = "(self, channel_name: str, /)")]
     fn create_physical_channel(&self, channel_name: &str) -> PyResult<CanPhysicalChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_physical_channel(channel_name) {
             Ok(channel) => Ok(CanPhysicalChannel(channel)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -127,3 +150,5 @@ impl CanCluster {
         self.0.system().map(System)
     }
 }
+
+identifiable_pymethods!(CanCluster);
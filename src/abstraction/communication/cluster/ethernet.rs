@@ -33,14 +33,28 @@ impl EthernetCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -58,6 +72,10 @@ impl EthernetCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a new physical channel for the cluster
     ///
     /// The supplied VLAN info must be unique - there cannot be two VLANs with the same vlan identifier.
@@ -71,6 +89,7 @@ impl EthernetCluster {
         channel_name: &str,
         vlan_info: Option<EthernetVlanInfo>,
     ) -> PyResult<EthernetPhysicalChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_physical_channel(channel_name, vlan_info.as_ref().map(|v| &v.0))
@@ -94,6 +113,8 @@ impl EthernetCluster {
     }
 }
 
+identifiable_pymethods!(EthernetCluster);
+
 //##################################################################
 
 iterator_wrapper!(EthernetPhysicalChannelsIterator, EthernetPhysicalChannel);
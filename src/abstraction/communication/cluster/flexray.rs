@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -38,14 +39,28 @@ impl FlexrayCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,6 +78,10 @@ impl FlexrayCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// update the cluster settings
     ///
     /// The settings of a flexray cluster determine all the details of timing and slot layout.
@@ -72,8 +91,10 @@ impl FlexrayCluster {
     ///
     /// However, the update function does not require that the settings are valid, and will
     /// also update the model with invalid settings if desired.
-    fn set_settings(&self, settings: &FlexrayClusterSettings) {
+    fn set_settings(&self, settings: &FlexrayClusterSettings) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.update_settings(&settings.0);
+        Ok(())
     }
 
     /// retrieve the current flexray cluster settings from a [`FlexrayCluster`]
@@ -91,6 +112,7 @@ impl FlexrayCluster {
         name: &str,
         channel_name: FlexrayChannelName,
     ) -> PyResult<FlexrayPhysicalChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_physical_channel(name, channel_name.into()) {
             Ok(channel) => Ok(FlexrayPhysicalChannel(channel)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -110,6 +132,8 @@ impl FlexrayCluster {
     }
 }
 
+identifiable_pymethods!(FlexrayCluster);
+
 //##################################################################
 
 /// Information about the flexray physical channels present inside a cluster
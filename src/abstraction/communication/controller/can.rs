@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -41,14 +42,28 @@ impl CanCommunicationController {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,6 +81,10 @@ impl CanCommunicationController {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// return an iterator over the [`CanPhysicalChannel`]s connected to this controller
     fn connected_channels(&self) -> CanCCPhysicalChannelIterator {
         CanCCPhysicalChannelIterator::new(self.0.connected_channels().map(CanPhysicalChannel))
@@ -85,6 +104,7 @@ impl CanCommunicationController {
         connection_name: &str,
         can_channel: &CanPhysicalChannel,
     ) -> PyResult<CanCommunicationConnector> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .connect_physical_channel(connection_name, &can_channel.0)
@@ -104,6 +124,8 @@ impl CanCommunicationController {
     }
 }
 
+identifiable_pymethods!(CanCommunicationController);
+
 //##################################################################
 
 iterator_wrapper!(CanCCPhysicalChannelIterator, CanPhysicalChannel);
@@ -136,6 +158,7 @@ impl CanCommunicationConnector {
 
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -153,6 +176,10 @@ impl CanCommunicationConnector {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `EcuInstance` that contains this `CommunicationConnector`
     #[getter]
     fn ecu_instance(&self) -> PyResult<EcuInstance> {
@@ -171,3 +198,5 @@ impl CanCommunicationConnector {
         }
     }
 }
+
+identifiable_pymethods!(CanCommunicationConnector);
@@ -35,14 +35,28 @@ impl EthernetCommunicationController {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -60,6 +74,10 @@ impl EthernetCommunicationController {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// return an iterator over the [`EthernetPhysicalChannel`]s connected to this controller
     fn connected_channels(&self) -> EthernetCCPhysicalChannelIterator {
         EthernetCCPhysicalChannelIterator::new(
@@ -83,6 +101,7 @@ impl EthernetCommunicationController {
         connection_name: &str,
         eth_channel: &EthernetPhysicalChannel,
     ) -> PyResult<EthernetCommunicationConnector> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .connect_physical_channel(connection_name, &eth_channel.0)
@@ -102,6 +121,8 @@ impl EthernetCommunicationController {
     }
 }
 
+identifiable_pymethods!(EthernetCommunicationController);
+
 //##################################################################
 
 iterator_wrapper!(EthernetCCPhysicalChannelIterator, EthernetPhysicalChannel);
@@ -134,6 +155,7 @@ impl EthernetCommunicationConnector {
 
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -151,6 +173,10 @@ impl EthernetCommunicationConnector {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `EcuInstance` that contains this `CommunicationConnector`
     #[getter]
     fn ecu_instance(&self) -> PyResult<EcuInstance> {
@@ -169,3 +195,5 @@ impl EthernetCommunicationConnector {
         }
     }
 }
+
+identifiable_pymethods!(EthernetCommunicationConnector);
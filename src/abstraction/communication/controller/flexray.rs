@@ -35,14 +35,28 @@ impl FlexrayCommunicationController {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -60,6 +74,10 @@ impl FlexrayCommunicationController {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// return an iterator over the [`FlexrayPhysicalChannel`]s connected to this controller
     fn connected_channels(&self) -> FlexrayPhysicalChannelIterator {
         FlexrayPhysicalChannelIterator::new(self.0.connected_channels().map(FlexrayPhysicalChannel))
@@ -79,6 +97,7 @@ impl FlexrayCommunicationController {
         connection_name: &str,
         flx_channel: &FlexrayPhysicalChannel,
     ) -> PyResult<FlexrayCommunicationConnector> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .connect_physical_channel(connection_name, &flx_channel.0)
@@ -98,6 +117,8 @@ impl FlexrayCommunicationController {
     }
 }
 
+identifiable_pymethods!(FlexrayCommunicationController);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayPhysicalChannelIterator, FlexrayPhysicalChannel);
@@ -130,6 +151,7 @@ impl FlexrayCommunicationConnector {
 
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -147,6 +169,10 @@ impl FlexrayCommunicationConnector {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `EcuInstance` that contains this `CommunicationConnector`
     #[getter]
     fn ecu_instance(&self) -> PyResult<EcuInstance> {
@@ -166,6 +192,8 @@ impl FlexrayCommunicationConnector {
     }
 }
 
+identifiable_pymethods!(FlexrayCommunicationConnector);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -40,14 +41,28 @@ impl LinMaster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +80,10 @@ impl LinMaster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// return an iterator over the [`LinPhysicalChannel`]s connected to this controller
     fn connected_channels(&self) -> LinCCPhysicalChannelIterator {
         LinCCPhysicalChannelIterator::new(self.0.connected_channels().map(LinPhysicalChannel))
@@ -84,6 +103,7 @@ impl LinMaster {
         connection_name: &str,
         lin_channel: &LinPhysicalChannel,
     ) -> PyResult<LinCommunicationConnector> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .connect_physical_channel(connection_name, &lin_channel.0)
@@ -103,6 +123,8 @@ impl LinMaster {
     }
 }
 
+identifiable_pymethods!(LinMaster);
+
 //##################################################################
 
 /// An `EcuInstance` needs a `LinMaster` or `LinSlave` in order to connect to a LIN cluster.
@@ -127,6 +149,7 @@ impl LinSlave {
 
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -144,6 +167,10 @@ impl LinSlave {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// return an iterator over the [`LinPhysicalChannel`]s connected to this controller
     fn connected_channels(&self) -> LinCCPhysicalChannelIterator {
         LinCCPhysicalChannelIterator::new(self.0.connected_channels().map(LinPhysicalChannel))
@@ -163,6 +190,7 @@ impl LinSlave {
         connection_name: &str,
         lin_channel: &LinPhysicalChannel,
     ) -> PyResult<LinCommunicationConnector> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .connect_physical_channel(connection_name, &lin_channel.0)
@@ -182,6 +210,8 @@ impl LinSlave {
     }
 }
 
+identifiable_pymethods!(LinSlave);
+
 //##################################################################
 
 iterator_wrapper!(LinCCPhysicalChannelIterator, LinPhysicalChannel);
@@ -215,14 +245,28 @@ impl LinCommunicationConnector {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -240,6 +284,10 @@ impl LinCommunicationConnector {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `EcuInstance` that contains this `CommunicationConnector`
     #[getter]
     fn ecu_instance(&self) -> PyResult<EcuInstance> {
@@ -263,3 +311,5 @@ impl LinCommunicationConnector {
         }
     }
 }
+
+identifiable_pymethods!(LinCommunicationConnector);
@@ -33,14 +33,28 @@ impl DataTransformationSet {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -58,6 +72,10 @@ impl DataTransformationSet {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a new `DataTransformation` in the `DataTransformationSet`
     #[pyo3(signature = (name, transformations, execute_despite_data_unavailability, /))]
     #[pyo3(
@@ -69,6 +87,7 @@ impl DataTransformationSet {
         transformations: Vec<TransformationTechnology>,
         execute_despite_data_unavailability: bool,
     ) -> PyResult<DataTransformation> {
+        crate::frozen::check_element(self.0.element())?;
         let transformations = transformations.iter().map(|t| &t.0).collect::<Vec<_>>();
         match self.0.create_data_transformation(
             name,
@@ -93,6 +112,7 @@ impl DataTransformationSet {
         name: &str,
         config: &Bound<'_, PyAny>, // some variant of TransformationTechnologyConfig
     ) -> PyResult<TransformationTechnology> {
+        crate::frozen::check_element(self.0.element())?;
         let config = transformation_technology_config_from_pyany(config)?;
         match self.0.create_transformation_technology(name, &config) {
             Ok(value) => Ok(TransformationTechnology(value)),
@@ -110,6 +130,8 @@ impl DataTransformationSet {
     }
 }
 
+identifiable_pymethods!(DataTransformationSet);
+
 //##################################################################
 
 /// A `DataTransformation` is a chain of `TransformationTechnology`s that are used to transform data
@@ -139,14 +161,28 @@ impl DataTransformation {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -164,6 +200,10 @@ impl DataTransformation {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the `DataTransformationSet` that contains this `DataTransformation`
     #[getter]
     fn data_transformation_set(&self) -> Option<DataTransformationSet> {
@@ -180,6 +220,8 @@ impl DataTransformation {
     }
 }
 
+identifiable_pymethods!(DataTransformation);
+
 //##################################################################
 
 iterator_wrapper!(DataTransformationIterator, DataTransformation);
@@ -213,14 +255,28 @@ impl TransformationTechnology {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -238,6 +294,10 @@ impl TransformationTechnology {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the protocol of the `TransformationTechnology`
     #[getter]
     fn protocol(&self) -> Option<String> {
@@ -260,6 +320,7 @@ impl TransformationTechnology {
     #[pyo3(signature = (config, /))]
     #[pyo3(text_signature = "(self, config: TransformationTechnologyConfig, /)")]
     fn set_config(&self, config: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config = transformation_technology_config_from_pyany(config)?;
         self.0.set_config(&config).map_err(abstraction_err_to_pyerr)
     }
@@ -272,6 +333,8 @@ impl TransformationTechnology {
     }
 }
 
+identifiable_pymethods!(TransformationTechnology);
+
 //##################################################################
 
 iterator_wrapper!(TransformationTechnologyIterator, TransformationTechnology);
@@ -937,12 +1000,25 @@ impl EndToEndTransformationISignalProps {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -952,9 +1028,14 @@ impl EndToEndTransformationISignalProps {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the transformer reference of the E2E transformation properties
     #[setter]
     fn set_transformer(&self, transformer: &TransformationTechnology) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transformer(&transformer.0)
             .map_err(abstraction_err_to_pyerr)
@@ -969,6 +1050,7 @@ impl EndToEndTransformationISignalProps {
     /// set the data IDs that are used for the E2E transformation
     #[setter]
     fn set_data_ids(&self, data_ids: Vec<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_data_ids(&data_ids)
             .map_err(abstraction_err_to_pyerr)
@@ -983,6 +1065,7 @@ impl EndToEndTransformationISignalProps {
     /// set the length of payload and E2E header in bits
     #[setter]
     fn set_data_length(&self, data_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_data_length(data_length)
             .map_err(abstraction_err_to_pyerr)
@@ -997,6 +1080,7 @@ impl EndToEndTransformationISignalProps {
     /// set the maximum data length
     #[setter]
     fn set_max_data_length(&self, max_data_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_data_length(max_data_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1011,6 +1095,7 @@ impl EndToEndTransformationISignalProps {
     /// set the minimum data length
     #[setter]
     fn set_min_data_length(&self, min_data_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_min_data_length(min_data_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1025,6 +1110,7 @@ impl EndToEndTransformationISignalProps {
     /// set the source ID
     #[setter]
     fn set_source_id(&self, source_id: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_source_id(source_id)
             .map_err(abstraction_err_to_pyerr)
@@ -1066,12 +1152,25 @@ impl SomeIpTransformationISignalProps {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -1081,9 +1180,14 @@ impl SomeIpTransformationISignalProps {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the transformer reference of the E2E transformation properties
     #[setter]
     fn set_transformer(&self, transformer: &TransformationTechnology) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transformer(&transformer.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1098,6 +1202,7 @@ impl SomeIpTransformationISignalProps {
     /// set the legacy strings property
     #[setter]
     fn set_legacy_strings(&self, legacy_strings: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_legacy_strings(legacy_strings)
             .map_err(abstraction_err_to_pyerr)
@@ -1112,6 +1217,7 @@ impl SomeIpTransformationISignalProps {
     /// set the interface version property
     #[setter]
     fn set_interface_version(&self, interface_version: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_interface_version(interface_version)
             .map_err(abstraction_err_to_pyerr)
@@ -1126,6 +1232,7 @@ impl SomeIpTransformationISignalProps {
     /// set the dynamic length property
     #[setter]
     fn set_dynamic_length(&self, dynamic_length: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_dynamic_length(dynamic_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1140,6 +1247,7 @@ impl SomeIpTransformationISignalProps {
     /// set the message type property
     #[setter]
     fn set_message_type(&self, message_type: Option<SomeIpMessageType>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_message_type(message_type.map(std::convert::Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -1154,6 +1262,7 @@ impl SomeIpTransformationISignalProps {
     /// set the size of array length property
     #[setter]
     fn set_size_of_array_length(&self, size_of_array_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_size_of_array_length(size_of_array_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1168,6 +1277,7 @@ impl SomeIpTransformationISignalProps {
     /// set the size of string length property
     #[setter]
     fn set_size_of_string_length(&self, size_of_string_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_size_of_string_length(size_of_string_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1182,6 +1292,7 @@ impl SomeIpTransformationISignalProps {
     /// set the size of struct length property
     #[setter]
     fn set_size_of_struct_length(&self, size_of_struct_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_size_of_struct_length(size_of_struct_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1196,6 +1307,7 @@ impl SomeIpTransformationISignalProps {
     /// set the size of union length property
     #[setter]
     fn set_size_of_union_length(&self, size_of_union_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_size_of_union_length(size_of_union_length)
             .map_err(abstraction_err_to_pyerr)
@@ -32,14 +32,28 @@ impl CanFrame {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -57,6 +71,10 @@ impl CanFrame {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// returns an iterator over all PDUs in the frame
     fn mapped_pdus(&self) -> PduToFrameMappingIterator {
         PduToFrameMappingIterator::new(self.0.mapped_pdus().map(PduToFrameMapping))
@@ -83,6 +101,7 @@ impl CanFrame {
         byte_order: ByteOrder,
         update_bit: Option<u32>,
     ) -> PyResult<PduToFrameMapping> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu_int = pyany_to_pdu(pdu)?;
 
         match self
@@ -97,6 +116,7 @@ impl CanFrame {
     /// set the length of the frame
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -107,6 +127,8 @@ impl CanFrame {
     }
 }
 
+identifiable_pymethods!(CanFrame);
+
 //##################################################################
 
 /// The frame triggering connects a frame to a physical channel
@@ -136,14 +158,28 @@ impl CanFrameTriggering {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -161,6 +197,10 @@ impl CanFrameTriggering {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the frame that is triggered
     #[getter]
     fn frame(&self) -> Option<CanFrame> {
@@ -170,6 +210,7 @@ impl CanFrameTriggering {
     /// set the can id associated with this frame
     #[setter]
     fn set_identifier(&self, identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -184,6 +225,7 @@ impl CanFrameTriggering {
     /// set the addressing mode for this frame triggering
     #[setter]
     fn set_addressing_mode(&self, addressing_mode: CanAddressingMode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_addressing_mode(addressing_mode.into())
             .map_err(abstraction_err_to_pyerr)
@@ -198,6 +240,7 @@ impl CanFrameTriggering {
     /// set the frame type for this frame triggering
     #[setter]
     fn set_frame_type(&self, frame_type: CanFrameType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_frame_type(frame_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -228,6 +271,7 @@ impl CanFrameTriggering {
         ecu: &EcuInstance,
         direction: CommunicationDirection,
     ) -> PyResult<FramePort> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.connect_to_ecu(&ecu.0, direction.into()) {
             Ok(port) => Ok(FramePort(port)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -245,6 +289,8 @@ impl CanFrameTriggering {
     }
 }
 
+identifiable_pymethods!(CanFrameTriggering);
+
 //##################################################################
 
 /// The addressing mode for a CAN frame
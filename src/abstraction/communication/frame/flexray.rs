@@ -33,14 +33,28 @@ impl FlexrayFrame {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -58,6 +72,10 @@ impl FlexrayFrame {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// returns an iterator over all PDUs in the frame
     fn mapped_pdus(&self) -> PduToFrameMappingIterator {
         PduToFrameMappingIterator::new(self.0.mapped_pdus().map(PduToFrameMapping))
@@ -84,6 +102,7 @@ impl FlexrayFrame {
         byte_order: ByteOrder,
         update_bit: Option<u32>,
     ) -> PyResult<PduToFrameMapping> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu_int = pyany_to_pdu(pdu)?;
 
         match self
@@ -98,6 +117,7 @@ impl FlexrayFrame {
     /// set the length of the frame
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -108,6 +128,8 @@ impl FlexrayFrame {
     }
 }
 
+identifiable_pymethods!(FlexrayFrame);
+
 //##################################################################
 
 /// Iterator over all [`FlexrayFrameTriggering`]s using this frame
@@ -139,14 +161,28 @@ impl FlexrayFrameTriggering {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -164,6 +200,10 @@ impl FlexrayFrameTriggering {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the frame that is triggered
     #[getter]
     fn frame(&self) -> Option<FlexrayFrame> {
@@ -173,6 +213,7 @@ impl FlexrayFrameTriggering {
     /// set the slot id for the flexray frame triggering
     #[setter]
     fn set_slot(&self, slot_id: u16) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_slot(slot_id).map_err(abstraction_err_to_pyerr)
     }
 
@@ -188,6 +229,7 @@ impl FlexrayFrameTriggering {
     #[pyo3(signature = (timing, /))]
     #[pyo3(text_signature = "(self, timing: FlexrayCommunicationCycle, /)")]
     fn set_timing(&self, timing: &FlexrayCommunicationCycle) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_timing(&(*timing).into())
             .map_err(abstraction_err_to_pyerr)
@@ -219,6 +261,7 @@ impl FlexrayFrameTriggering {
         ecu: &EcuInstance,
         direction: CommunicationDirection,
     ) -> PyResult<FramePort> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.connect_to_ecu(&ecu.0, direction.into()) {
             Ok(value) => Ok(FramePort(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -236,6 +279,8 @@ impl FlexrayFrameTriggering {
     }
 }
 
+identifiable_pymethods!(FlexrayFrameTriggering);
+
 //##################################################################
 
 /// The timing settings of a Flexray frame
@@ -36,14 +36,28 @@ impl LinEventTriggeredFrame {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -61,6 +75,10 @@ impl LinEventTriggeredFrame {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// returns an iterator over all PDUs in the frame
     fn mapped_pdus(&self) -> PduToFrameMappingIterator {
         PduToFrameMappingIterator::new(self.0.mapped_pdus().map(PduToFrameMapping))
@@ -87,6 +105,7 @@ impl LinEventTriggeredFrame {
         byte_order: ByteOrder,
         update_bit: Option<u32>,
     ) -> PyResult<PduToFrameMapping> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu_int = pyany_to_pdu(pdu)?;
 
         match self
@@ -101,6 +120,7 @@ impl LinEventTriggeredFrame {
     /// set the length of the frame
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -111,6 +131,8 @@ impl LinEventTriggeredFrame {
     }
 }
 
+identifiable_pymethods!(LinEventTriggeredFrame);
+
 //##################################################################
 
 /// A sporadic frame on a LIN bus
@@ -125,6 +147,60 @@ pub(crate) struct LinSporadicFrame(
     pub(crate) autosar_data_abstraction::communication::LinSporadicFrame,
 );
 
+#[pymethods]
+impl LinSporadicFrame {
+    #[new]
+    fn new(element: &Element) -> PyResult<Self> {
+        match autosar_data_abstraction::communication::LinSporadicFrame::try_from(element.0.clone())
+        {
+            Ok(value) => Ok(Self(value)),
+            Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
+        }
+    }
+
+    #[pyo3(signature = (/, *, deep = false))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
+    fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
+        self.clone()
+            .0
+            .remove(deep)
+            .map_err(abstraction_err_to_pyerr)
+    }
+
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
+    #[setter]
+    fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
+        self.0.set_name(name).map_err(abstraction_err_to_pyerr)
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.0.name()
+    }
+
+    #[getter]
+    fn element(&self) -> Element {
+        Element(self.0.element().clone())
+    }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+}
+
 //##################################################################
 
 /// An unconditional frame on a LIN bus
@@ -139,6 +215,61 @@ pub(crate) struct LinUnconditionalFrame(
     pub(crate) autosar_data_abstraction::communication::LinUnconditionalFrame,
 );
 
+#[pymethods]
+impl LinUnconditionalFrame {
+    #[new]
+    fn new(element: &Element) -> PyResult<Self> {
+        match autosar_data_abstraction::communication::LinUnconditionalFrame::try_from(
+            element.0.clone(),
+        ) {
+            Ok(value) => Ok(Self(value)),
+            Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
+        }
+    }
+
+    #[pyo3(signature = (/, *, deep = false))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
+    fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
+        self.clone()
+            .0
+            .remove(deep)
+            .map_err(abstraction_err_to_pyerr)
+    }
+
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
+    #[setter]
+    fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
+        self.0.set_name(name).map_err(abstraction_err_to_pyerr)
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.0.name()
+    }
+
+    #[getter]
+    fn element(&self) -> Element {
+        Element(self.0.element().clone())
+    }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+}
+
 //##################################################################
 
 /// The frame triggering connects a frame to a physical channel
@@ -168,14 +299,28 @@ impl LinFrameTriggering {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -193,9 +338,14 @@ impl LinFrameTriggering {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the identifier of the frame that is triggered
     #[setter]
     fn set_identifier(&self, identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -239,6 +389,7 @@ impl LinFrameTriggering {
         ecu: &EcuInstance,
         direction: CommunicationDirection,
     ) -> PyResult<FramePort> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.connect_to_ecu(&ecu.0, direction.into()) {
             Ok(port) => Ok(FramePort(port)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -265,6 +416,8 @@ impl LinFrameTriggering {
     }
 }
 
+identifiable_pymethods!(LinFrameTriggering);
+
 //##################################################################
 
 pub(crate) fn pyany_to_lin_frame(
@@ -40,14 +40,28 @@ impl PduToFrameMapping {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +79,10 @@ impl PduToFrameMapping {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Reference to the PDU that is mapped into the frame. The PDU reference is mandatory.
     #[getter]
     fn pdu(&self) -> Option<Py<PyAny>> {
@@ -79,6 +97,7 @@ impl PduToFrameMapping {
     /// Note: If the byte order is swapped, then the start position must be adjusted accordingly.
     #[setter]
     fn set_byte_order(&self, byte_order: ByteOrder) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_byte_order(byte_order.into())
             .map_err(abstraction_err_to_pyerr)
@@ -103,6 +122,7 @@ impl PduToFrameMapping {
     /// New values set here must match the configured byte order.
     #[setter]
     fn set_start_position(&self, start_position: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_start_position(start_position)
             .map_err(abstraction_err_to_pyerr)
@@ -121,6 +141,7 @@ impl PduToFrameMapping {
     /// set or clear the bit position of the update bit for the mapped PDU.
     #[setter]
     fn set_update_bit(&self, update_bit: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_update_bit(update_bit)
             .map_err(abstraction_err_to_pyerr)
@@ -133,6 +154,8 @@ impl PduToFrameMapping {
     }
 }
 
+identifiable_pymethods!(PduToFrameMapping);
+
 //##################################################################
 
 iterator_wrapper!(PduToFrameMappingIterator, PduToFrameMapping);
@@ -162,14 +185,28 @@ impl FramePort {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -187,6 +224,10 @@ impl FramePort {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the ECU instance that contains this frame port
     #[getter]
     fn ecu(&self) -> PyResult<EcuInstance> {
@@ -199,6 +240,7 @@ impl FramePort {
     /// set the communication direction of the frame port
     #[setter]
     fn set_communication_direction(&self, direction: CommunicationDirection) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_direction(direction.into())
             .map_err(abstraction_err_to_pyerr)
@@ -213,6 +255,8 @@ impl FramePort {
     }
 }
 
+identifiable_pymethods!(FramePort);
+
 //##################################################################
 
 iterator_wrapper!(FramePortIterator, FramePort);
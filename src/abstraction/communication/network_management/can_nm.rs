@@ -33,14 +33,28 @@ impl CanNmCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -58,9 +72,14 @@ impl CanNmCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nmBusloadReductionActive flag
     #[setter]
     fn set_nm_busload_reduction_active(&self, nm_busload_reduction_active: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_busload_reduction_active(nm_busload_reduction_active)
             .map_err(abstraction_err_to_pyerr)
@@ -78,6 +97,7 @@ impl CanNmCluster {
         &self,
         nm_immediate_nm_transmissions: u32,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_immediate_nm_transmissions(nm_immediate_nm_transmissions)
             .map_err(abstraction_err_to_pyerr)
@@ -92,6 +112,7 @@ impl CanNmCluster {
     /// set the nmMessageTimeoutTime
     #[setter]
     fn set_nm_message_timeout_time(&self, nm_message_timeout_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_message_timeout_time(nm_message_timeout_time)
             .map_err(abstraction_err_to_pyerr)
@@ -106,6 +127,7 @@ impl CanNmCluster {
     /// set the nmMsgCycleTime
     #[setter]
     fn set_nm_msg_cycle_time(&self, cycle_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_msg_cycle_time(cycle_time)
             .map_err(abstraction_err_to_pyerr)
@@ -120,6 +142,7 @@ impl CanNmCluster {
     /// set the nmNetworkTimeout
     #[setter]
     fn set_nm_network_timeout(&self, nm_network_timeout: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_network_timeout(nm_network_timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -137,6 +160,7 @@ impl CanNmCluster {
         &self,
         nm_remote_sleep_indication_time: f64,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_remote_sleep_indication_time(nm_remote_sleep_indication_time)
             .map_err(abstraction_err_to_pyerr)
@@ -151,6 +175,7 @@ impl CanNmCluster {
     /// set the nmRepeatMessageTime
     #[setter]
     fn set_nm_repeat_message_time(&self, nm_repeat_message_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_repeat_message_time(nm_repeat_message_time)
             .map_err(abstraction_err_to_pyerr)
@@ -165,6 +190,7 @@ impl CanNmCluster {
     /// set the nmWaitBusSleepTime
     #[setter]
     fn set_nm_wait_bus_sleep_time(&self, nm_wait_bus_sleep_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_wait_bus_sleep_time(nm_wait_bus_sleep_time)
             .map_err(abstraction_err_to_pyerr)
@@ -187,6 +213,7 @@ impl CanNmCluster {
         controller: &CanCommunicationController,
         nm_ecu: &NmEcu,
     ) -> PyResult<CanNmNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_nm_node(name, &controller.0, &nm_ecu.0) {
             Ok(value) => Ok(CanNmNode(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -198,6 +225,7 @@ impl CanNmCluster {
     /// set the referenced `CommunicationCluster`
     #[setter]
     fn set_communication_cluster(&self, communication_cluster: &CanCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_cluster(&communication_cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -217,6 +245,7 @@ impl CanNmCluster {
     /// set or remove the nmChannelSleepMaster flag
     #[setter]
     fn set_channel_sleep_master(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel_sleep_master(value)
             .map_err(abstraction_err_to_pyerr)
@@ -231,6 +260,7 @@ impl CanNmCluster {
     /// set the nmNodeDetectionEnabled flag
     #[setter]
     fn set_node_detection_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_detection_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -245,6 +275,7 @@ impl CanNmCluster {
     /// set the nmNodeIdEnabled flag
     #[setter]
     fn set_node_id_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_id_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -259,6 +290,7 @@ impl CanNmCluster {
     /// set the nmPncParticipation flag
     #[setter]
     fn set_pnc_participation(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_participation(value)
             .map_err(abstraction_err_to_pyerr)
@@ -273,6 +305,7 @@ impl CanNmCluster {
     /// set the nmRepeatMsgIndEnabled flag
     #[setter]
     fn set_repeat_msg_ind_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_repeat_msg_ind_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -287,6 +320,7 @@ impl CanNmCluster {
     /// set the nmSynchronizingNetwork flag
     #[setter]
     fn set_synchronizing_network(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_synchronizing_network(value)
             .map_err(abstraction_err_to_pyerr)
@@ -301,6 +335,7 @@ impl CanNmCluster {
     /// set the pncClusterVectorLength
     #[setter]
     fn set_pnc_cluster_vector_length(&self, value: Option<u8>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_cluster_vector_length(value)
             .map_err(abstraction_err_to_pyerr)
@@ -313,6 +348,8 @@ impl CanNmCluster {
     }
 }
 
+identifiable_pymethods!(CanNmCluster);
+
 //##################################################################
 
 iterator_wrapper!(CanNmClusterIterator, CanNmCluster);
@@ -432,12 +469,25 @@ impl CanNmClusterCoupling {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -447,9 +497,14 @@ impl CanNmClusterCoupling {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nmBusloadReductionEnabled flag
     #[setter]
     fn set_nm_busload_reduction_enabled(&self, nm_busload_reduction_enabled: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_busload_reduction_enabled(nm_busload_reduction_enabled)
             .map_err(abstraction_err_to_pyerr)
@@ -464,6 +519,7 @@ impl CanNmClusterCoupling {
     /// set the nmImmediateRestartEnabled flag
     #[setter]
     fn set_nm_immediate_restart_enabled(&self, nm_immediate_restart_enabled: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_immediate_restart_enabled(nm_immediate_restart_enabled)
             .map_err(abstraction_err_to_pyerr)
@@ -481,6 +537,7 @@ impl CanNmClusterCoupling {
     #[pyo3(signature = (cluster, /))]
     #[pyo3(text_signature = "(self, cluster: CanNmCluster, /)")]
     fn add_coupled_cluster(&self, cluster: &CanNmCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_coupled_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -519,14 +576,28 @@ impl CanNmNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -544,6 +615,10 @@ impl CanNmNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ----- items from the AbstractNmNode trait -----
 
     /// set the referenced `CommunicationController`
@@ -552,6 +627,7 @@ impl CanNmNode {
         &self,
         controller: &CanCommunicationController,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_controller(&controller.0)
             .map_err(abstraction_err_to_pyerr)
@@ -568,6 +644,7 @@ impl CanNmNode {
     /// set the referenced `NmEcu`
     #[setter]
     fn set_nm_ecu(&self, ecu: &NmEcu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_nm_ecu(&ecu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -581,6 +658,7 @@ impl CanNmNode {
     /// This value is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed.
     #[setter]
     fn set_node_id(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_node_id(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -595,6 +673,7 @@ impl CanNmNode {
     /// This flag is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed.
     #[setter]
     fn set_passive_mode(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_passive_mode(value)
             .map_err(abstraction_err_to_pyerr)
@@ -612,6 +691,7 @@ impl CanNmNode {
     #[pyo3(signature = (nm_pdu, /))]
     #[pyo3(text_signature = "(self, nm_pdu: NmPdu, /)")]
     fn add_rx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_rx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -628,6 +708,7 @@ impl CanNmNode {
     #[pyo3(signature = (nm_pdu, /))]
     #[pyo3(text_signature = "(self, nm_pdu: NmPdu, /)")]
     fn add_tx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_tx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -639,6 +720,8 @@ impl CanNmNode {
     }
 }
 
+identifiable_pymethods!(CanNmNode);
+
 //##################################################################
 
 iterator_wrapper!(CanNmNodeIterator, CanNmNode);
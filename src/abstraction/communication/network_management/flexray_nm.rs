@@ -36,14 +36,28 @@ impl FlexrayNmCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -61,11 +75,16 @@ impl FlexrayNmCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nmDataCycle
     ///
     /// Number of Flexray Communication Cycles needed to transmit the Nm Data PDUs of all Flexray Nm Ecus of this `FlexrayNmCluster`.
     #[setter]
     fn set_nm_data_cycle(&self, nm_data_cycle: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_data_cycle(nm_data_cycle)
             .map_err(abstraction_err_to_pyerr)
@@ -87,6 +106,7 @@ impl FlexrayNmCluster {
         &self,
         nm_remote_sleep_indication_time: f64,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_remote_sleep_indication_time(nm_remote_sleep_indication_time)
             .map_err(abstraction_err_to_pyerr)
@@ -105,6 +125,7 @@ impl FlexrayNmCluster {
     /// Timeout for Repeat Message State in seconds.
     #[setter]
     fn set_nm_repeat_message_time(&self, nm_repeat_message_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_repeat_message_time(nm_repeat_message_time)
             .map_err(abstraction_err_to_pyerr)
@@ -124,6 +145,7 @@ impl FlexrayNmCluster {
     /// Flexray `NmEcus` of this `FlexrayNmCluster`. This value shall be an integral multiple of nmVotingCycle.
     #[setter]
     fn set_nm_repetition_cycle(&self, nm_repetition_cycle: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_repetition_cycle(nm_repetition_cycle)
             .map_err(abstraction_err_to_pyerr)
@@ -143,6 +165,7 @@ impl FlexrayNmCluster {
     /// The number of Fexray Communication Cycles used to transmit the Nm Vote PDUs of all Fexray Nm Ecus of this `FlexrayNmCluster`.
     #[setter]
     fn set_nm_voting_cycle(&self, nm_voting_cycle: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_voting_cycle(nm_voting_cycle)
             .map_err(abstraction_err_to_pyerr)
@@ -167,6 +190,7 @@ impl FlexrayNmCluster {
         controller: &FlexrayCommunicationController,
         nm_ecu: &NmEcu,
     ) -> PyResult<FlexrayNmNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_nm_node(name, &controller.0, &nm_ecu.0)
@@ -181,6 +205,7 @@ impl FlexrayNmCluster {
     /// set the referenced `FlexrayCluster`
     #[setter]
     fn set_communication_cluster(&self, communication_cluster: &FlexrayCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_cluster(&communication_cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -200,6 +225,7 @@ impl FlexrayNmCluster {
     /// set or remove the nmChannelSleepMaster flag
     #[setter]
     fn set_channel_sleep_master(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel_sleep_master(value)
             .map_err(abstraction_err_to_pyerr)
@@ -214,6 +240,7 @@ impl FlexrayNmCluster {
     /// set the nmNodeDetectionEnabled flag
     #[setter]
     fn set_node_detection_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_detection_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -228,6 +255,7 @@ impl FlexrayNmCluster {
     /// set the nmNodeIdEnabled flag
     #[setter]
     fn set_node_id_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_id_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -242,6 +270,7 @@ impl FlexrayNmCluster {
     /// set the nmPncParticipation flag
     #[setter]
     fn set_pnc_participation(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_participation(value)
             .map_err(abstraction_err_to_pyerr)
@@ -256,6 +285,7 @@ impl FlexrayNmCluster {
     /// set the nmRepeatMsgIndEnabled flag
     #[setter]
     fn set_repeat_msg_ind_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_repeat_msg_ind_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -270,6 +300,7 @@ impl FlexrayNmCluster {
     /// set the nmSynchronizingNetwork flag
     #[setter]
     fn set_synchronizing_network(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_synchronizing_network(value)
             .map_err(abstraction_err_to_pyerr)
@@ -284,6 +315,7 @@ impl FlexrayNmCluster {
     /// set the pncClusterVectorLength
     #[setter]
     fn set_pnc_cluster_vector_length(&self, value: Option<u8>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_cluster_vector_length(value)
             .map_err(abstraction_err_to_pyerr)
@@ -296,6 +328,8 @@ impl FlexrayNmCluster {
     }
 }
 
+identifiable_pymethods!(FlexrayNmCluster);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayNmClusterIterator, FlexrayNmCluster);
@@ -400,12 +434,25 @@ impl FlexrayNmClusterCoupling {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -415,12 +462,17 @@ impl FlexrayNmClusterCoupling {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nmScheduleVariant
     #[setter]
     fn set_nm_schedule_variant(
         &self,
         nm_schedule_variant: FlexrayNmScheduleVariant,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_schedule_variant(nm_schedule_variant.into())
             .map_err(abstraction_err_to_pyerr)
@@ -440,6 +492,7 @@ impl FlexrayNmClusterCoupling {
     #[pyo3(signature = (cluster, /))]
     #[pyo3(text_signature = "(self, cluster: FlexrayNmCluster, /)")]
     fn add_coupled_cluster(&self, cluster: &FlexrayNmCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_coupled_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -565,14 +618,28 @@ impl FlexrayNmNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -590,6 +657,10 @@ impl FlexrayNmNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ----- items from the AbstractNmNode trait -----
 
     /// set the referenced `FlexrayCommunicationController`
@@ -598,6 +669,7 @@ impl FlexrayNmNode {
         &self,
         controller: &FlexrayCommunicationController,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_controller(&controller.0)
             .map_err(abstraction_err_to_pyerr)
@@ -614,6 +686,7 @@ impl FlexrayNmNode {
     /// set the referenced `NmEcu`
     #[setter]
     fn set_nm_ecu(&self, ecu: &NmEcu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_nm_ecu(&ecu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -627,6 +700,7 @@ impl FlexrayNmNode {
     /// This value is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed.
     #[setter]
     fn set_node_id(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_node_id(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -641,6 +715,7 @@ impl FlexrayNmNode {
     /// This flag is optional; if it is set to Some(x) the value is created, if it is set to None the value is removed.
     #[setter]
     fn set_passive_mode(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_passive_mode(value)
             .map_err(abstraction_err_to_pyerr)
@@ -658,6 +733,7 @@ impl FlexrayNmNode {
     #[pyo3(signature = (nm_pdu, /))]
     #[pyo3(text_signature = "(self, nm_pdu: NmPdu, /)")]
     fn add_rx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_rx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -674,6 +750,7 @@ impl FlexrayNmNode {
     #[pyo3(signature = (nm_pdu, /))]
     #[pyo3(text_signature = "(self, nm_pdu: NmPdu, /)")]
     fn add_tx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_tx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -685,6 +762,8 @@ impl FlexrayNmNode {
     }
 }
 
+identifiable_pymethods!(FlexrayNmNode);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayNmNodeIterator, FlexrayNmNode);
@@ -40,14 +40,28 @@ impl NmConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +79,10 @@ impl NmConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `CanNmCluster`
     #[pyo3(signature = (name, settings, can_cluster, /))]
     #[pyo3(
@@ -76,6 +94,7 @@ impl NmConfig {
         settings: &CanNmClusterSettings,
         can_cluster: &CanCluster,
     ) -> PyResult<CanNmCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_can_nm_cluster(name, &settings.into(), &can_cluster.0)
@@ -96,6 +115,7 @@ impl NmConfig {
         settings: &FlexrayNmClusterSettings,
         flexray_cluster: &FlexrayCluster,
     ) -> PyResult<FlexrayNmCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_nm_cluster(name, &settings.into(), &flexray_cluster.0)
@@ -116,6 +136,7 @@ impl NmConfig {
         settings: &UdpNmClusterSettings,
         ethernet_cluster: &EthernetCluster,
     ) -> PyResult<UdpNmCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_udp_nm_cluster(name, &settings.into(), &ethernet_cluster.0)
@@ -150,6 +171,7 @@ impl NmConfig {
         nm_busload_reduction_enabled: bool,
         nm_immediate_restart_enabled: bool,
     ) -> PyResult<CanNmClusterCoupling> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_nm_cluster_coupling(
             nm_busload_reduction_enabled,
             nm_immediate_restart_enabled,
@@ -166,6 +188,7 @@ impl NmConfig {
         &self,
         nm_schedule_variant: FlexrayNmScheduleVariant,
     ) -> PyResult<FlexrayNmClusterCoupling> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_nm_cluster_coupling(nm_schedule_variant.into())
@@ -177,6 +200,7 @@ impl NmConfig {
 
     /// create a new `UdpNmClusterCoupling`
     fn create_udp_nm_cluster_coupling(&self) -> PyResult<UdpNmClusterCoupling> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_udp_nm_cluster_coupling() {
             Ok(value) => Ok(UdpNmClusterCoupling(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -202,6 +226,7 @@ impl NmConfig {
     #[pyo3(signature = (name, ecu_instance, /))]
     #[pyo3(text_signature = "(self, name: str, ecu_instance: EcuInstance, /)")]
     fn create_nm_ecu(&self, name: &str, ecu_instance: &EcuInstance) -> PyResult<NmEcu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_nm_ecu(name, &ecu_instance.0) {
             Ok(value) => Ok(NmEcu(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -214,6 +239,8 @@ impl NmConfig {
     }
 }
 
+identifiable_pymethods!(NmConfig);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -253,14 +280,28 @@ impl NmEcu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -278,9 +319,14 @@ impl NmEcu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the referenced `EcuInstance`
     #[setter]
     fn set_ecu_instance(&self, ecu_instance: &EcuInstance) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_ecu_instance(&ecu_instance.0)
             .map_err(abstraction_err_to_pyerr)
@@ -295,6 +341,7 @@ impl NmEcu {
     /// set the nmBusSynchronizationEnabled flag
     #[setter]
     fn set_nm_bus_synchronization_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_bus_synchronization_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -309,6 +356,7 @@ impl NmEcu {
     /// set the nmComControlEnabled flag
     #[setter]
     fn set_nm_com_control_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_com_control_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -323,6 +371,7 @@ impl NmEcu {
     /// set or remove the nmCycletimeMainFunction value
     #[setter]
     fn set_cycle_time_main_function(&self, value: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cycle_time_main_function(value)
             .map_err(abstraction_err_to_pyerr)
@@ -335,6 +384,8 @@ impl NmEcu {
     }
 }
 
+identifiable_pymethods!(NmEcu);
+
 //##################################################################
 
 iterator_wrapper!(NmPduIterator, NmPdu);
@@ -34,14 +34,28 @@ impl UdpNmCluster {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -59,9 +73,14 @@ impl UdpNmCluster {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nmMsgCycleTime
     #[setter]
     fn set_nm_msg_cycle_time(&self, cycle_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_msg_cycle_time(cycle_time)
             .map_err(abstraction_err_to_pyerr)
@@ -76,6 +95,7 @@ impl UdpNmCluster {
     /// set the nmMessageTimeoutTime
     #[setter]
     fn set_nm_message_timeout_time(&self, timeout_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_message_timeout_time(timeout_time)
             .map_err(abstraction_err_to_pyerr)
@@ -90,6 +110,7 @@ impl UdpNmCluster {
     /// set the `NmNetworkTimeout`
     #[setter]
     fn set_nm_network_timeout(&self, timeout: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_network_timeout(timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -104,6 +125,7 @@ impl UdpNmCluster {
     /// set the `NmRemoteSleepIndicationTime`
     #[setter]
     fn set_nm_remote_sleep_indication_time(&self, time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_remote_sleep_indication_time(time)
             .map_err(abstraction_err_to_pyerr)
@@ -118,6 +140,7 @@ impl UdpNmCluster {
     /// set the `NmRepeatMessageTime`
     #[setter]
     fn set_nm_repeat_message_time(&self, time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_repeat_message_time(time)
             .map_err(abstraction_err_to_pyerr)
@@ -132,6 +155,7 @@ impl UdpNmCluster {
     /// set the `NmWaitBusSleepTime`
     #[setter]
     fn set_nm_wait_bus_sleep_time(&self, time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_wait_bus_sleep_time(time)
             .map_err(abstraction_err_to_pyerr)
@@ -155,6 +179,7 @@ impl UdpNmCluster {
         nm_ecu: &NmEcu,
         nm_msg_cycle_offset: f64,
     ) -> PyResult<UdpNmNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_udp_nm_node(name, &controller.0, &nm_ecu.0, nm_msg_cycle_offset)
@@ -167,6 +192,7 @@ impl UdpNmCluster {
     /// set or delete the Vlan associated with the cluster through an `EthernetPhysicalChannel` reference.
     #[setter]
     fn set_vlan(&self, vlan: Option<&EthernetPhysicalChannel>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_vlan(vlan.map(|v| &v.0))
             .map_err(abstraction_err_to_pyerr)
@@ -181,6 +207,7 @@ impl UdpNmCluster {
     /// set or delete the value nmImmediateNmTransmissions
     #[setter]
     fn set_nm_immediate_nm_transmissions(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_immediate_nm_transmissions(value)
             .map_err(abstraction_err_to_pyerr)
@@ -195,6 +222,7 @@ impl UdpNmCluster {
     /// set or delete the value nmCbvPosition
     #[setter]
     fn set_nm_cbv_position(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_cbv_position(value)
             .map_err(abstraction_err_to_pyerr)
@@ -209,6 +237,7 @@ impl UdpNmCluster {
     /// set or delete the value nmNidPosition
     #[setter]
     fn set_nm_nid_position(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_nid_position(value)
             .map_err(abstraction_err_to_pyerr)
@@ -225,6 +254,7 @@ impl UdpNmCluster {
     /// set the referenced `EthernetCluster`
     #[setter]
     fn set_communication_cluster(&self, communication_cluster: &EthernetCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_cluster(&communication_cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -244,6 +274,7 @@ impl UdpNmCluster {
     /// set or remove the nmChannelSleepMaster flag
     #[setter]
     fn set_channel_sleep_master(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel_sleep_master(value)
             .map_err(abstraction_err_to_pyerr)
@@ -258,6 +289,7 @@ impl UdpNmCluster {
     /// set the nmNodeDetectionEnabled flag
     #[setter]
     fn set_node_detection_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_detection_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -272,6 +304,7 @@ impl UdpNmCluster {
     /// set the nmNodeIdEnabled flag
     #[setter]
     fn set_node_id_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_node_id_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -286,6 +319,7 @@ impl UdpNmCluster {
     /// set the nmPncParticipation flag
     #[setter]
     fn set_pnc_participation(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_participation(value)
             .map_err(abstraction_err_to_pyerr)
@@ -300,6 +334,7 @@ impl UdpNmCluster {
     /// set the nmRepeatMsgIndEnabled flag
     #[setter]
     fn set_repeat_msg_ind_enabled(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_repeat_msg_ind_enabled(value)
             .map_err(abstraction_err_to_pyerr)
@@ -314,6 +349,7 @@ impl UdpNmCluster {
     /// set the nmSynchronizingNetwork flag
     #[setter]
     fn set_synchronizing_network(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_synchronizing_network(value)
             .map_err(abstraction_err_to_pyerr)
@@ -328,6 +364,7 @@ impl UdpNmCluster {
     /// set the pncClusterVectorLength
     #[setter]
     fn set_pnc_cluster_vector_length(&self, value: Option<u8>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_cluster_vector_length(value)
             .map_err(abstraction_err_to_pyerr)
@@ -340,6 +377,8 @@ impl UdpNmCluster {
     }
 }
 
+identifiable_pymethods!(UdpNmCluster);
+
 //##################################################################
 
 iterator_wrapper!(UdpNmClusterIterator, UdpNmCluster);
@@ -445,12 +484,25 @@ impl UdpNmClusterCoupling {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -460,9 +512,14 @@ impl UdpNmClusterCoupling {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set or remove the nmImmediateRestartEnabled flag
     #[setter]
     fn set_nm_immediate_restart_enabled(&self, enabled: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_immediate_restart_enabled(enabled)
             .map_err(abstraction_err_to_pyerr)
@@ -480,6 +537,7 @@ impl UdpNmClusterCoupling {
     #[pyo3(signature = (cluster, /))]
     #[pyo3(text_signature = "(self, cluster: UdpNmCluster, /)")]
     fn add_coupled_cluster(&self, cluster: &UdpNmCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_coupled_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -516,14 +574,28 @@ impl UdpNmNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -541,9 +613,14 @@ impl UdpNmNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `NmMsgCycleOffset`
     #[setter]
     fn set_nm_msg_cycle_offset(&self, offset: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nm_msg_cycle_offset(offset)
             .map_err(abstraction_err_to_pyerr)
@@ -558,6 +635,7 @@ impl UdpNmNode {
     /// set ot remove the allNmMessagesKeepAwake flag
     #[setter]
     fn set_all_nm_messages_keep_awake(&self, enabled: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_all_nm_messages_keep_awake(enabled)
             .map_err(abstraction_err_to_pyerr)
@@ -577,6 +655,7 @@ impl UdpNmNode {
         &self,
         controller: &EthernetCommunicationController,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_controller(&controller.0)
             .map_err(abstraction_err_to_pyerr)
@@ -593,6 +672,7 @@ impl UdpNmNode {
     /// set the referenced `NmEcu`
     #[setter]
     fn set_nm_ecu(&self, ecu: &NmEcu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_nm_ecu(&ecu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -605,6 +685,7 @@ impl UdpNmNode {
     /// set the nmNodeId
     #[setter]
     fn set_node_id(&self, value: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_node_id(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -617,6 +698,7 @@ impl UdpNmNode {
     /// set ot remove the nmPassiveModeEnabled flag
     #[setter]
     fn set_passive_mode(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_passive_mode(value)
             .map_err(abstraction_err_to_pyerr)
@@ -632,6 +714,7 @@ impl UdpNmNode {
     ///
     /// Every `NmNode` must have at least one Rx `NmPdu`
     fn add_rx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_rx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -646,6 +729,7 @@ impl UdpNmNode {
     ///
     /// Active `NmNodes` must have at least one Tx `NmPdu`, while passive `NmNodes` may have none.
     fn add_tx_nm_pdu(&self, nm_pdu: &NmPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_tx_nm_pdu(&nm_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -657,6 +741,8 @@ impl UdpNmNode {
     }
 }
 
+identifiable_pymethods!(UdpNmNode);
+
 //##################################################################
 
 iterator_wrapper!(UdpNmNodeIterator, UdpNmNode);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -39,14 +40,28 @@ impl ContainerIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -64,9 +79,14 @@ impl ContainerIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the header type of this `ContainerIPdu`
     #[setter]
     fn set_header_type(&self, header_type: ContainerIPduHeaderType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_header_type(header_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -81,6 +101,7 @@ impl ContainerIPdu {
     /// set the rx accept of this `ContainerIPdu`
     #[setter]
     fn set_rx_accept_contained_ipdu(&self, rx_accept: RxAcceptContainedIPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_rx_accept_contained_ipdu(rx_accept.into())
             .map_err(abstraction_err_to_pyerr)
@@ -95,6 +116,7 @@ impl ContainerIPdu {
     /// set the container timeout of this `ContainerIPdu`
     #[setter]
     fn set_container_timeout(&self, timeout: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_container_timeout(timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -109,6 +131,7 @@ impl ContainerIPdu {
     /// set the container trigger of this `ContainerIPdu`
     #[setter]
     fn set_container_trigger(&self, trigger: Option<ContainerIPduTrigger>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_container_trigger(trigger.map(Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -128,6 +151,7 @@ impl ContainerIPdu {
         ipdu: &Bound<'_, PyAny>,
         physical_channel: &Bound<'_, PyAny>,
     ) -> PyResult<PduTriggering> {
+        crate::frozen::check_element(self.0.element())?;
         let ipdu = pyany_to_ipdu(ipdu)?;
         let physical_channel = pyany_to_physical_channel(physical_channel)?;
         match self.0.map_ipdu(&ipdu, &physical_channel) {
@@ -146,6 +170,7 @@ impl ContainerIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -171,6 +196,7 @@ impl ContainerIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -183,6 +209,8 @@ impl ContainerIPdu {
     }
 }
 
+identifiable_pymethods!(ContainerIPdu);
+
 //##################################################################
 
 /// The header type of a `ContainerIPdu`
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -40,14 +41,28 @@ impl ISignalIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +80,10 @@ impl ISignalIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// returns an iterator over all signals and signal groups mapped to the PDU
     fn mapped_signals(&self) -> ISignalToIPduMappingIterator {
         ISignalToIPduMappingIterator::new(self.0.mapped_signals().map(ISignalToIPduMapping))
@@ -85,6 +104,7 @@ impl ISignalIPdu {
         update_bit: Option<u32>,
         transfer_property: TransferProperty,
     ) -> PyResult<ISignalToIPduMapping> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.map_signal(
             &signal.0,
             start_position,
@@ -101,6 +121,7 @@ impl ISignalIPdu {
     #[pyo3(signature = (signal_group, /))]
     #[pyo3(text_signature = "(self, signal_group: ISignalGroup, /)")]
     fn map_signal_group(&self, signal_group: &ISignalGroup) -> PyResult<ISignalToIPduMapping> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.map_signal_group(&signal_group.0) {
             Ok(value) => Ok(ISignalToIPduMapping(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -111,6 +132,7 @@ impl ISignalIPdu {
     #[pyo3(signature = (timing_spec, /))]
     #[pyo3(text_signature = "(self, timing_spec: IpduTiming, /)")]
     fn set_timing(&self, timing_spec: &IpduTiming) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_timing(&timing_spec.into())
             .map_err(abstraction_err_to_pyerr)
@@ -127,6 +149,7 @@ impl ISignalIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -152,6 +175,7 @@ impl ISignalIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -164,6 +188,8 @@ impl ISignalIPdu {
     }
 }
 
+identifiable_pymethods!(ISignalIPdu);
+
 //##################################################################
 
 /// `ISignalToIPduMapping` connects an `ISignal` or `ISignalGroup` to an `ISignalToIPdu`
@@ -193,14 +219,28 @@ impl ISignalToIPduMapping {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -218,6 +258,10 @@ impl ISignalToIPduMapping {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Reference to the signal that is mapped to the PDU.
     /// Every mapping contains either a signal or a signal group.
     #[getter]
@@ -228,6 +272,7 @@ impl ISignalToIPduMapping {
     /// Set the byte order of the data in the mapped signal.
     #[setter]
     fn set_byte_order(&self, byte_order: ByteOrder) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_byte_order(byte_order.into())
             .map_err(abstraction_err_to_pyerr)
@@ -256,6 +301,7 @@ impl ISignalToIPduMapping {
     /// Set the transfer property of the mapped signal
     #[setter]
     fn set_transfer_property(&self, transfer_property: TransferProperty) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transfer_property(transfer_property.into())
             .map_err(abstraction_err_to_pyerr)
@@ -275,6 +321,8 @@ impl ISignalToIPduMapping {
     }
 }
 
+identifiable_pymethods!(ISignalToIPduMapping);
+
 //##################################################################
 
 iterator_wrapper!(ISignalToIPduMappingIterator, ISignalToIPduMapping);
@@ -667,14 +715,28 @@ impl ISignalIPduGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -692,12 +754,17 @@ impl ISignalIPduGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the communication direction
     #[setter]
     fn set_communication_direction(
         &self,
         communication_direction: CommunicationDirection,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_direction(communication_direction.into())
             .map_err(abstraction_err_to_pyerr)
@@ -711,6 +778,7 @@ impl ISignalIPduGroup {
 
     /// add a PDU to the PDU group
     fn add_pdu(&self, pdu: &ISignalIPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.add_pdu(&pdu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -720,6 +788,8 @@ impl ISignalIPduGroup {
     }
 }
 
+identifiable_pymethods!(ISignalIPduGroup);
+
 //##################################################################
 
 iterator_wrapper!(ISignalIPduIterator, ISignalIPdu);
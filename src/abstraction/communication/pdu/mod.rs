@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -49,14 +50,28 @@ impl NmPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -74,6 +89,10 @@ impl NmPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// returns an iterator over all signals and signal groups mapped to the PDU
     fn mapped_signals(&self) -> ISignalToIPduMappingIterator {
         ISignalToIPduMappingIterator::new(self.0.mapped_signals().map(ISignalToIPduMapping))
@@ -94,6 +113,7 @@ impl NmPdu {
         update_bit: Option<u32>,
         transfer_property: TransferProperty,
     ) -> PyResult<ISignalToIPduMapping> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.map_signal(
             &signal.0,
             start_position,
@@ -110,6 +130,7 @@ impl NmPdu {
     #[pyo3(signature = (signal_group, /))]
     #[pyo3(text_signature = "(self, signal_group: ISignalGroup, /)")]
     fn map_signal_group(&self, signal_group: &ISignalGroup) -> PyResult<ISignalToIPduMapping> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.map_signal_group(&signal_group.0) {
             Ok(value) => Ok(ISignalToIPduMapping(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -119,6 +140,7 @@ impl NmPdu {
     /// set the unused bit pattern for this PDU
     #[setter]
     fn set_unused_bit_pattern(&self, pattern: u8) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_unused_bit_pattern(pattern)
             .map_err(abstraction_err_to_pyerr)
@@ -135,6 +157,7 @@ impl NmPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -154,6 +177,8 @@ impl NmPdu {
     }
 }
 
+identifiable_pymethods!(NmPdu);
+
 //##################################################################
 
 /// This is a Pdu of the transport layer. The main purpose of the TP layer is to segment and reassemble `IPdus`.
@@ -179,14 +204,28 @@ impl NPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -204,11 +243,16 @@ impl NPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // --------- AbstractPdu methods ---------
 
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -234,6 +278,7 @@ impl NPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -246,6 +291,8 @@ impl NPdu {
     }
 }
 
+identifiable_pymethods!(NPdu);
+
 //##################################################################
 
 iterator_wrapper!(NPduIterator, NPdu);
@@ -275,14 +322,28 @@ impl DcmIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -300,6 +361,10 @@ impl DcmIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     #[getter]
     fn diag_pdu_type(&self) -> Option<DiagPduType> {
         self.0.diag_pdu_type().map(Into::into)
@@ -307,6 +372,7 @@ impl DcmIPdu {
 
     #[setter]
     fn set_diag_pdu_type(&self, diag_pdu_type: DiagPduType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_diag_pdu_type(diag_pdu_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -317,6 +383,7 @@ impl DcmIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -342,6 +409,7 @@ impl DcmIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -354,6 +422,8 @@ impl DcmIPdu {
     }
 }
 
+identifiable_pymethods!(DcmIPdu);
+
 //##################################################################
 
 /// The category of a `GeneralPurposePdu`
@@ -432,14 +502,28 @@ impl GeneralPurposePdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -457,9 +541,14 @@ impl GeneralPurposePdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the category of this PDU
     #[setter]
     fn set_category(&self, category: GeneralPurposePduCategory) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.into())
             .map_err(abstraction_err_to_pyerr)
@@ -476,6 +565,7 @@ impl GeneralPurposePdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -495,6 +585,8 @@ impl GeneralPurposePdu {
     }
 }
 
+identifiable_pymethods!(GeneralPurposePdu);
+
 //##################################################################
 
 /// The category of a `GeneralPurposePdu`
@@ -585,14 +677,28 @@ impl GeneralPurposeIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -610,9 +716,14 @@ impl GeneralPurposeIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the category of this PDU
     #[setter]
     fn set_category(&self, category: GeneralPurposeIPduCategory) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.into())
             .map_err(abstraction_err_to_pyerr)
@@ -629,6 +740,7 @@ impl GeneralPurposeIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -654,6 +766,7 @@ impl GeneralPurposeIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -666,6 +779,8 @@ impl GeneralPurposeIPdu {
     }
 }
 
+identifiable_pymethods!(GeneralPurposeIPdu);
+
 //##################################################################
 
 /// The category of a `GeneralPurposeIPdu`
@@ -743,14 +858,28 @@ impl MultiplexedIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -768,8 +897,13 @@ impl MultiplexedIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     #[setter]
     fn set_static_part(&self, static_part: &ISignalIPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_static_part(&static_part.0)
             .map_err(abstraction_err_to_pyerr)
@@ -785,6 +919,7 @@ impl MultiplexedIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -814,6 +949,7 @@ impl MultiplexedIPdu {
         selector_code: u16,
         initial_dynamic_part: bool,
     ) -> PyResult<DynamicPartAlternative> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .add_dynamic_part(&dynamic_ipdu.0, selector_code, initial_dynamic_part)
@@ -838,6 +974,7 @@ impl MultiplexedIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -850,6 +987,8 @@ impl MultiplexedIPdu {
     }
 }
 
+identifiable_pymethods!(MultiplexedIPdu);
+
 //##################################################################
 
 iterator_wrapper!(DynamicPartAlternativesIterator, DynamicPartAlternative);
@@ -883,12 +1022,25 @@ impl DynamicPartAlternative {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -897,6 +1049,10 @@ impl DynamicPartAlternative {
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
 }
 
 //##################################################################
@@ -926,14 +1082,28 @@ impl UserDefinedPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -951,11 +1121,16 @@ impl UserDefinedPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // --------- AbstractPdu methods ---------
 
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -975,6 +1150,8 @@ impl UserDefinedPdu {
     }
 }
 
+identifiable_pymethods!(UserDefinedPdu);
+
 //##################################################################
 
 /// a `PduTriggering` triggers a PDU in a frame or ethernet connection
@@ -1000,14 +1177,28 @@ impl PduTriggering {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1025,6 +1216,10 @@ impl PduTriggering {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the Pdu that is triggered by this pdu triggering
     #[getter]
     fn pdu(&self) -> Option<Py<PyAny>> {
@@ -1061,6 +1256,7 @@ impl PduTriggering {
         ecu: &EcuInstance,
         direction: CommunicationDirection,
     ) -> PyResult<IPduPort> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_pdu_port(&ecu.0, direction.into()) {
             Ok(value) => Ok(IPduPort(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -1078,6 +1274,8 @@ impl PduTriggering {
     }
 }
 
+identifiable_pymethods!(PduTriggering);
+
 //##################################################################
 
 iterator_wrapper!(PduTriggeringIterator, PduTriggering);
@@ -1109,14 +1307,28 @@ impl IPduPort {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1134,6 +1346,10 @@ impl IPduPort {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the ECU instance that contains this `IPduPort`
     #[getter]
     fn ecu(&self) -> PyResult<EcuInstance> {
@@ -1146,6 +1362,7 @@ impl IPduPort {
     /// set the communication direction of this `IPduPort`
     #[setter]
     fn set_communication_direction(&self, direction: CommunicationDirection) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_direction(direction.into())
             .map_err(abstraction_err_to_pyerr)
@@ -1160,6 +1377,8 @@ impl IPduPort {
     }
 }
 
+identifiable_pymethods!(IPduPort);
+
 //##################################################################
 
 /// The collction trigger defines whether a Pdu contributes to the triggering
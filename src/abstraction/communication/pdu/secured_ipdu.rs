@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -38,14 +39,28 @@ impl SecuredIPdu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,9 +78,14 @@ impl SecuredIPdu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the properties of the secured communication
     #[setter]
     fn set_secure_communication_props(&self, props: &SecureCommunicationProps) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_secure_communication_props(&props.into())
             .map_err(abstraction_err_to_pyerr)
@@ -80,6 +100,7 @@ impl SecuredIPdu {
     /// set or remove the useAsCryptographicIPdu flag
     #[setter]
     fn set_use_as_cryptographic_ipdu(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_use_as_cryptographic_ipdu(value)
             .map_err(abstraction_err_to_pyerr)
@@ -102,6 +123,7 @@ impl SecuredIPdu {
         ipdu: &Bound<'_, PyAny>,
         physical_channel: &Bound<'_, PyAny>,
     ) -> PyResult<PduTriggering> {
+        crate::frozen::check_element(self.0.element())?;
         let ipdu = pyany_to_ipdu(ipdu)?;
         let physical_channel = pyany_to_physical_channel(physical_channel)?;
         match self.0.set_payload_ipdu(&ipdu, &physical_channel) {
@@ -117,6 +139,7 @@ impl SecuredIPdu {
     /// cryptographic data, so the PduTriggering already exists.
     #[setter]
     fn set_payload_pdu_triggering(&self, pdu_triggering: &PduTriggering) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_payload_pdu_triggering(&pdu_triggering.0)
             .map_err(abstraction_err_to_pyerr)
@@ -133,6 +156,7 @@ impl SecuredIPdu {
     /// set the length of this PDU
     #[setter]
     fn set_length(&self, length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_length(length).map_err(abstraction_err_to_pyerr)
     }
 
@@ -158,6 +182,7 @@ impl SecuredIPdu {
     /// This is only relevant for IPdus that will be transmitted in `ContainerIPdus`
     #[setter]
     fn set_contained_ipdu_props(&self, props: Option<&ContainedIPduProps>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_contained_ipdu_props(props.map(Into::into).as_ref())
             .map_err(abstraction_err_to_pyerr)
@@ -170,6 +195,8 @@ impl SecuredIPdu {
     }
 }
 
+identifiable_pymethods!(SecuredIPdu);
+
 //##################################################################
 
 /// The properties of a `SecuredIPdu`
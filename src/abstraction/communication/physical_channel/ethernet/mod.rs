@@ -98,14 +98,28 @@ impl EthernetPhysicalChannel {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -123,6 +137,10 @@ impl EthernetPhysicalChannel {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the VLAN information for this channel
     ///
     /// In an EthernetCluster, each physical channel must have unique VLAN settings; only
@@ -131,6 +149,7 @@ impl EthernetPhysicalChannel {
     #[pyo3(signature = (vlan_info = None, /))]
     #[pyo3(text_signature = "(self, vlan_info: Optional[EthernetVlanInfo], /)")]
     fn set_vlan_info(&self, vlan_info: Option<EthernetVlanInfo>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_vlan_info(vlan_info.as_ref().map(|info| &info.0))
             .map_err(abstraction_err_to_pyerr)
@@ -167,6 +186,7 @@ impl EthernetPhysicalChannel {
         address: NetworkEndpointAddress,
         ecu: Option<&EcuInstance>,
     ) -> PyResult<NetworkEndpoint> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_network_endpoint(name, address.into(), ecu.map(|ecu| &ecu.0))
@@ -196,6 +216,7 @@ impl EthernetPhysicalChannel {
         tp_config: &TpConfig,
         sa_type: SocketAddressType,
     ) -> PyResult<SocketAddress> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_socket_address(
             name,
             &network_endpoint.0,
@@ -223,6 +244,7 @@ impl EthernetPhysicalChannel {
         name: &str,
         server_port: &SocketAddress,
     ) -> PyResult<SocketConnectionBundle> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_socket_connection_bundle(name, &server_port.0) {
             Ok(bundle) => Ok(SocketConnectionBundle(bundle)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -264,6 +286,7 @@ impl EthernetPhysicalChannel {
         port_2: &SocketAddress,
         tcp_connect_timeout: Option<f64>,
     ) -> PyResult<(StaticSocketConnection, StaticSocketConnection)> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_static_socket_connection_pair(
             name,
             &port_1.0,
@@ -311,6 +334,7 @@ impl EthernetPhysicalChannel {
         unicast_tx_pdu: &GeneralPurposePdu,
         common_config: &CommonServiceDiscoveryConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .configure_service_discovery_for_ecu(
                 &ecu.0,
@@ -338,6 +362,8 @@ impl EthernetPhysicalChannel {
     }
 }
 
+identifiable_pymethods!(EthernetPhysicalChannel);
+
 //##################################################################
 
 /// A `CommonServiceDiscoveryConfig` contains common configuration settings for `System::configure_service_discovery_for_ecu`.
@@ -443,14 +469,28 @@ impl StaticSocketConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -468,6 +508,10 @@ impl StaticSocketConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the socket address containing this static socket connection
     #[getter]
     fn socket_address(&self) -> PyResult<SocketAddress> {
@@ -480,6 +524,7 @@ impl StaticSocketConnection {
     /// set the remote socket of this connection
     #[setter]
     fn set_remote_socket(&self, remote_socket: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_remote_socket(&remote_socket.0)
             .map_err(abstraction_err_to_pyerr)
@@ -495,6 +540,7 @@ impl StaticSocketConnection {
     #[pyo3(signature = (identifier, /))]
     #[pyo3(text_signature = "(self, identifier: SoConIPduIdentifier, /)")]
     fn add_ipdu_identifier(&self, identifier: &SoConIPduIdentifier) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_ipdu_identifier(&identifier.0)
             .map_err(abstraction_err_to_pyerr)
@@ -508,6 +554,7 @@ impl StaticSocketConnection {
     /// set the TCP role of this static socket connection
     #[setter]
     fn set_tcp_role(&self, role: Option<TcpRole>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tcp_role(role.map(std::convert::Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -522,6 +569,7 @@ impl StaticSocketConnection {
     /// set the TCP connect timeout of this static socket connection
     #[setter]
     fn set_tcp_connect_timeout(&self, timeout: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tcp_connect_timeout(timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -534,6 +582,8 @@ impl StaticSocketConnection {
     }
 }
 
+identifiable_pymethods!(StaticSocketConnection);
+
 //#########################################################
 
 iterator_wrapper!(StaticSocketConnectionIterator, StaticSocketConnection);
@@ -567,14 +617,28 @@ impl SocketConnectionIpduIdentifierSet {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -592,6 +656,10 @@ impl SocketConnectionIpduIdentifierSet {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `SoConIPduIdentifier` in this set
     #[pyo3(signature = (name, pdu, channel, /, *, header_id=None, timeout=None, collection_trigger=None))]
     #[pyo3(
@@ -606,6 +674,7 @@ impl SocketConnectionIpduIdentifierSet {
         timeout: Option<f64>,
         collection_trigger: Option<PduCollectionTrigger>,
     ) -> PyResult<SoConIPduIdentifier> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu = pyany_to_pdu(pdu)?;
         match self.0.create_socon_ipdu_identifier(
             name,
@@ -626,6 +695,8 @@ impl SocketConnectionIpduIdentifierSet {
     }
 }
 
+identifiable_pymethods!(SocketConnectionIpduIdentifierSet);
+
 //#########################################################
 
 /// A `SoConIPduIdentifier` describes a PDU that is transported over a static socket connection.
@@ -655,14 +726,28 @@ impl SoConIPduIdentifier {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -680,10 +765,15 @@ impl SoConIPduIdentifier {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `PduTriggering` for the pdu and reference it in this `SoConIPduIdentifier`
     #[pyo3(signature = (pdu, channel, /))]
     #[pyo3(text_signature = "(self, pdu: Pdu, channel: EthernetPhysicalChannel, /)")]
     fn set_pdu(&self, pdu: &Bound<'_, PyAny>, channel: &EthernetPhysicalChannel) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu = pyany_to_pdu(pdu)?;
         self.0
             .set_pdu(&pdu, &channel.0)
@@ -699,6 +789,7 @@ impl SoConIPduIdentifier {
     /// set the header id for this `SoConIPduIdentifier`
     #[setter]
     fn set_header_id(&self, header_id: u64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_header_id(header_id)
             .map_err(abstraction_err_to_pyerr)
@@ -713,6 +804,7 @@ impl SoConIPduIdentifier {
     /// set the timeout for this `SoConIPduIdentifier`
     #[setter]
     fn set_timeout(&self, timeout: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_timeout(timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -727,6 +819,7 @@ impl SoConIPduIdentifier {
     /// set the collection trigger for this `SoConIPduIdentifier`
     #[setter]
     fn set_collection_trigger(&self, trigger: PduCollectionTrigger) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_collection_trigger(trigger.into())
             .map_err(abstraction_err_to_pyerr)
@@ -739,6 +832,8 @@ impl SoConIPduIdentifier {
     }
 }
 
+identifiable_pymethods!(SoConIPduIdentifier);
+
 //#########################################################
 
 iterator_wrapper!(SoConIPduIdentifierIterator, SoConIPduIdentifier);
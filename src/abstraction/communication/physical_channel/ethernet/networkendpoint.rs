@@ -28,14 +28,28 @@ impl NetworkEndpoint {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -53,6 +67,10 @@ impl NetworkEndpoint {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// add a network endpoint address to this `NetworkEndpoint`
     ///
     /// A `NetworkEndpoint` may have multiple sets of address information. The following restrictions apply:
@@ -62,6 +80,7 @@ impl NetworkEndpoint {
     #[pyo3(signature = (address, /))]
     #[pyo3(text_signature = "(self, address: NetworkEndpointAddress, /)")]
     fn add_network_endpoint_address(&self, address: NetworkEndpointAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_network_endpoint_address(address.into())
             .map_err(abstraction_err_to_pyerr)
@@ -73,6 +92,8 @@ impl NetworkEndpoint {
     }
 }
 
+identifiable_pymethods!(NetworkEndpoint);
+
 //##################################################################
 
 iterator_wrapper!(NetworkEndpointIterator, NetworkEndpoint);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -40,14 +41,28 @@ impl SocketConnectionBundle {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +80,10 @@ impl SocketConnectionBundle {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the physical channel containing this socket connection bundle
     #[getter]
     fn physical_channel(&self) -> PyResult<EthernetPhysicalChannel> {
@@ -77,6 +96,7 @@ impl SocketConnectionBundle {
     ///set the server port of this socket connection bundle
     #[setter]
     fn set_server_port(&self, server_port: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_server_port(&server_port.0)
             .map_err(abstraction_err_to_pyerr)
@@ -92,6 +112,7 @@ impl SocketConnectionBundle {
     #[pyo3(signature = (client_port, /))]
     #[pyo3(text_signature = "(self, client_port: SocketAddress, /)")]
     fn create_bundled_connection(&self, client_port: &SocketAddress) -> PyResult<SocketConnection> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_bundled_connection(&client_port.0) {
             Ok(value) => Ok(SocketConnection(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -104,6 +125,8 @@ impl SocketConnectionBundle {
     }
 }
 
+identifiable_pymethods!(SocketConnectionBundle);
+
 //##################################################################
 
 iterator_wrapper!(SocketConnectionBundleIterator, SocketConnectionBundle);
@@ -136,12 +159,25 @@ impl SocketConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -151,6 +187,10 @@ impl SocketConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the socket connection bundle containing this socket connection
     #[getter]
     fn socket_connection_bundle(&self) -> PyResult<SocketConnectionBundle> {
@@ -163,6 +203,7 @@ impl SocketConnection {
     /// set the client port of this socket connection
     #[setter]
     fn set_client_port(&self, client_port: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_client_port(&client_port.0)
             .map_err(abstraction_err_to_pyerr)
@@ -186,6 +227,7 @@ impl SocketConnection {
         timeout: Option<f64>,
         collection_trigger: Option<PduCollectionTrigger>,
     ) -> PyResult<(SocketConnectionIpduIdentifier, PduTriggering)> {
+        crate::frozen::check_element(self.0.element())?;
         let pdu = pyany_to_pdu(pdu)?;
         match self.0.create_socket_connection_ipdu_identifier(
             &pdu,
@@ -222,6 +264,7 @@ impl SocketConnection {
     /// if the value is None, the attribute is removed
     #[setter]
     fn set_client_ip_addr_from_connection_request(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_client_ip_addr_from_connection_request(value)
             .map_err(abstraction_err_to_pyerr)
@@ -240,6 +283,7 @@ impl SocketConnection {
     /// if the value is None, the attribute is removed
     #[setter]
     fn set_client_port_from_connection_request(&self, value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_client_port_from_connection_request(value)
             .map_err(abstraction_err_to_pyerr)
@@ -254,6 +298,7 @@ impl SocketConnection {
     /// set the value of the `runtime_ip_address_configuration` attribute for this socket connection
     #[setter]
     fn set_runtime_ip_address_configuration(&self, value: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runtime_ip_address_configuration(value)
             .map_err(abstraction_err_to_pyerr)
@@ -268,6 +313,7 @@ impl SocketConnection {
     /// set the value of the `runtime_port_configuration` attribute for this socket connection
     #[setter]
     fn set_runtime_port_configuration(&self, value: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runtime_port_configuration(value)
             .map_err(abstraction_err_to_pyerr)
@@ -316,12 +362,25 @@ impl SocketConnectionIpduIdentifier {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -331,6 +390,10 @@ impl SocketConnectionIpduIdentifier {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the SocketConnection containing this `SocketConnectionIpduIdentifier`
     #[getter]
     fn socket_connection(&self) -> PyResult<SocketConnection> {
@@ -360,6 +423,7 @@ impl SocketConnectionIpduIdentifier {
     /// set the header id for this `SocketConnectionIpduIdentifier`
     #[setter]
     fn set_header_id(&self, header_id: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_header_id(header_id)
             .map_err(abstraction_err_to_pyerr)
@@ -374,6 +438,7 @@ impl SocketConnectionIpduIdentifier {
     /// set the timeout for this `SocketConnectionIpduIdentifier`
     #[setter]
     fn set_timeout(&self, timeout: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_timeout(timeout)
             .map_err(abstraction_err_to_pyerr)
@@ -388,6 +453,7 @@ impl SocketConnectionIpduIdentifier {
     /// set the collection trigger for this `SocketConnectionIpduIdentifier`
     #[setter]
     fn set_collection_trigger(&self, trigger: Option<PduCollectionTrigger>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_collection_trigger(trigger.map(Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -403,6 +469,7 @@ impl SocketConnectionIpduIdentifier {
     #[pyo3(signature = (routing_group, /))]
     #[pyo3(text_signature = "(self, routing_group: SoAdRoutingGroup, /)")]
     fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_routing_group(&routing_group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -451,14 +518,28 @@ impl SoAdRoutingGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -476,9 +557,14 @@ impl SoAdRoutingGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `EventGroupControlType` of this `SoAdRoutingGroup`
     #[setter]
     fn set_control_type(&self, control_type: EventGroupControlType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_control_type(control_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -491,6 +577,8 @@ impl SoAdRoutingGroup {
     }
 }
 
+identifiable_pymethods!(SoAdRoutingGroup);
+
 //##################################################################
 
 iterator_wrapper!(SoAdRoutingGroupIterator, SoAdRoutingGroup);
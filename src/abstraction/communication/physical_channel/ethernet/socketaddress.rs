@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -37,14 +38,28 @@ impl SocketAddress {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -62,6 +77,10 @@ impl SocketAddress {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the network endpoint of this `SocketAddress`
     #[getter]
     fn network_endpoint(&self) -> Option<NetworkEndpoint> {
@@ -78,6 +97,7 @@ impl SocketAddress {
     #[pyo3(signature = (ecu, /))]
     #[pyo3(text_signature = "(self, ecu: EcuInstance, /)")]
     fn add_multicast_ecu(&self, ecu: &EcuInstance) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_multicast_ecu(&ecu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -87,6 +107,7 @@ impl SocketAddress {
     #[pyo3(signature = (ecu, /))]
     #[pyo3(text_signature = "(self, ecu: EcuInstance, /)")]
     fn set_unicast_ecu(&self, ecu: &EcuInstance) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_unicast_ecu(&ecu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -119,6 +140,7 @@ impl SocketAddress {
         tcp_role: Option<TcpRole>,
         tcp_connect_timeout: Option<f64>,
     ) -> PyResult<StaticSocketConnection> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_static_socket_connection(
             name,
             &remote_address.0,
@@ -155,6 +177,7 @@ impl SocketAddress {
         service_identifier: u16,
         instance_identifier: u16,
     ) -> PyResult<ProvidedServiceInstanceV1> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_provided_service_instance(name, service_identifier, instance_identifier)
@@ -188,6 +211,7 @@ impl SocketAddress {
         name: &str,
         provided_service_instance: &ProvidedServiceInstanceV1,
     ) -> PyResult<ConsumedServiceInstanceV1> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_consumed_service_instance(name, &provided_service_instance.0)
@@ -207,6 +231,8 @@ impl SocketAddress {
     }
 }
 
+identifiable_pymethods!(SocketAddress);
+
 //##################################################################
 
 iterator_wrapper!(SocketAddressIterator, SocketAddress);
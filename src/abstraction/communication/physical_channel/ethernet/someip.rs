@@ -3,6 +3,7 @@ use crate::abstraction::communication::{
     PduTriggering, SoConIPduIdentifier, SoConIPduIdentifierIterator, SocketAddress,
     SocketAddressIterator,
 };
+use crate::abstraction::identifiable_pymethods;
 use crate::abstraction::{AutosarAbstractionError, abstraction_err_to_pyerr};
 use crate::{Element, iterator_wrapper};
 use autosar_data_abstraction::{self, AbstractionElement, IdentifiableAbstractionElement};
@@ -38,14 +39,28 @@ impl ServiceInstanceCollectionSet {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,6 +78,10 @@ impl ServiceInstanceCollectionSet {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `ProvidedServiceInstance` in this `ServiceInstanceCollectionSet`
     #[pyo3(signature = (name, service_identifier, instance_identifier, major_version, minor_version, /))]
     #[pyo3(
@@ -76,6 +95,7 @@ impl ServiceInstanceCollectionSet {
         major_version: u32,
         minor_version: u32,
     ) -> PyResult<ProvidedServiceInstance> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_provided_service_instance(
             name,
             service_identifier,
@@ -101,6 +121,7 @@ impl ServiceInstanceCollectionSet {
         major_version: u32,
         minor_version: &str,
     ) -> PyResult<ConsumedServiceInstance> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_consumed_service_instance(
             name,
             service_identifier,
@@ -128,6 +149,8 @@ impl ServiceInstanceCollectionSet {
     }
 }
 
+identifiable_pymethods!(ServiceInstanceCollectionSet);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -165,14 +188,28 @@ impl ProvidedServiceInstance {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -190,9 +227,14 @@ impl ProvidedServiceInstance {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the service identifier of this `ProvidedServiceInstance`
     #[setter]
     fn set_service_identifier(&self, identifier: u16) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_service_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -207,6 +249,7 @@ impl ProvidedServiceInstance {
     /// set the instance identifier of this `ProvidedServiceInstance`
     #[setter]
     fn set_instance_identifier(&self, identifier: u16) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_instance_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -221,6 +264,7 @@ impl ProvidedServiceInstance {
     /// set the major version of this `ProvidedServiceInstance`
     #[setter]
     fn set_major_version(&self, version: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_major_version(version)
             .map_err(abstraction_err_to_pyerr)
@@ -235,6 +279,7 @@ impl ProvidedServiceInstance {
     /// set the minor version of this `ProvidedServiceInstance`
     #[setter]
     fn set_minor_version(&self, version: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_minor_version(version)
             .map_err(abstraction_err_to_pyerr)
@@ -254,6 +299,7 @@ impl ProvidedServiceInstance {
         name: &str,
         event_group_identifier: u32,
     ) -> PyResult<EventHandler> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_event_handler(name, event_group_identifier) {
             Ok(value) => Ok(EventHandler(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -272,6 +318,7 @@ impl ProvidedServiceInstance {
     #[pyo3(signature = (address, /))]
     #[pyo3(text_signature = "(self, address: SocketAddress, /)")]
     fn set_local_unicast_address(&self, address: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_local_unicast_address(&address.0)
             .map_err(abstraction_err_to_pyerr)
@@ -292,6 +339,7 @@ impl ProvidedServiceInstance {
         &self,
         config: &SomeipSdServerServiceInstanceConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_server_instance_config(&config.0)
             .map_err(abstraction_err_to_pyerr)
@@ -306,6 +354,8 @@ impl ProvidedServiceInstance {
     }
 }
 
+identifiable_pymethods!(ProvidedServiceInstance);
+
 //##################################################################
 
 /// An `EventHandler` describes the handling of a single event in a `ProvidedServiceInstance`
@@ -331,14 +381,28 @@ impl EventHandler {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -356,9 +420,14 @@ impl EventHandler {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the event group identifier of this `EventHandler`
     #[setter]
     fn set_event_group_identifier(&self, identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_event_group_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -380,6 +449,7 @@ impl EventHandler {
         name: &str,
         event_group_control_type: EventGroupControlType,
     ) -> PyResult<PduActivationRoutingGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_pdu_activation_routing_group(name, event_group_control_type.into())
@@ -404,6 +474,7 @@ impl EventHandler {
         &self,
         config: &SomeipSdServerEventGroupTimingConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_server_event_group_timing_config(&config.0)
             .map_err(abstraction_err_to_pyerr)
@@ -418,6 +489,8 @@ impl EventHandler {
     }
 }
 
+identifiable_pymethods!(EventHandler);
+
 //##################################################################
 
 iterator_wrapper!(EventHandlerIterator, EventHandler);
@@ -451,14 +524,28 @@ impl ConsumedServiceInstance {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -476,9 +563,14 @@ impl ConsumedServiceInstance {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the service identifier of this `ConsumedServiceInstance`
     #[setter]
     fn set_service_identifier(&self, identifier: u16) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_service_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -493,6 +585,7 @@ impl ConsumedServiceInstance {
     /// set the instance identifier of this `ConsumedServiceInstance`
     #[setter]
     fn set_instance_identifier(&self, identifier: u16) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_instance_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -507,6 +600,7 @@ impl ConsumedServiceInstance {
     /// set the major version of this `ConsumedServiceInstance`
     #[setter]
     fn set_major_version(&self, version: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_major_version(version)
             .map_err(abstraction_err_to_pyerr)
@@ -523,6 +617,7 @@ impl ConsumedServiceInstance {
     /// The minor version can be a number or the String "ANY".
     #[setter]
     fn set_minor_version(&self, any: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let version = if let Ok(version_numeric) = any.extract::<u32>() {
             version_numeric.to_string()
         } else if let Ok(version) = any.extract::<String>() {
@@ -554,6 +649,7 @@ impl ConsumedServiceInstance {
         name: &str,
         event_group_identifier: u32,
     ) -> PyResult<ConsumedEventGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_consumed_event_group(name, event_group_identifier)
@@ -577,6 +673,7 @@ impl ConsumedServiceInstance {
     #[pyo3(signature = (address, /))]
     #[pyo3(text_signature = "(self, address: SocketAddress, /)")]
     fn set_local_unicast_address(&self, address: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_local_unicast_address(&address.0)
             .map_err(abstraction_err_to_pyerr)
@@ -597,6 +694,7 @@ impl ConsumedServiceInstance {
         &self,
         config: &SomeipSdClientServiceInstanceConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_client_instance_config(&config.0)
             .map_err(abstraction_err_to_pyerr)
@@ -611,6 +709,8 @@ impl ConsumedServiceInstance {
     }
 }
 
+identifiable_pymethods!(ConsumedServiceInstance);
+
 //##################################################################
 
 /// A `ConsumedEventGroup` is a group of events in a `ConsumedServiceInstance` that are consumed by an ECU
@@ -640,14 +740,28 @@ impl ConsumedEventGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -665,9 +779,14 @@ impl ConsumedEventGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the event group identifier of this `ConsumedEventGroup`
     #[setter]
     fn set_event_group_identifier(&self, identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_event_group_identifier(identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -689,6 +808,7 @@ impl ConsumedEventGroup {
         name: &str,
         event_group_control_type: EventGroupControlType,
     ) -> PyResult<PduActivationRoutingGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_pdu_activation_routing_group(name, event_group_control_type.into())
@@ -711,6 +831,7 @@ impl ConsumedEventGroup {
     #[pyo3(signature = (address, /))]
     #[pyo3(text_signature = "(self, address: SocketAddress, /)")]
     fn add_event_multicast_address(&self, address: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_event_multicast_address(&address.0)
             .map_err(abstraction_err_to_pyerr)
@@ -727,6 +848,7 @@ impl ConsumedEventGroup {
         &self,
         config: &SomeipSdClientEventGroupTimingConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_client_timer_config(&config.0)
             .map_err(abstraction_err_to_pyerr)
@@ -741,6 +863,8 @@ impl ConsumedEventGroup {
     }
 }
 
+identifiable_pymethods!(ConsumedEventGroup);
+
 //##################################################################
 
 iterator_wrapper!(ConsumedEventGroupIterator, ConsumedEventGroup);
@@ -830,14 +954,28 @@ impl PduActivationRoutingGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -855,9 +993,14 @@ impl PduActivationRoutingGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the event group control type of this `PduActivationRoutingGroup`
     #[setter]
     fn set_event_group_control_type(&self, control_type: EventGroupControlType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_event_group_control_type(control_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -875,6 +1018,7 @@ impl PduActivationRoutingGroup {
     #[pyo3(signature = (ipdu_identifier, /))]
     #[pyo3(text_signature = "(self, ipdu_identifier: SoConIPduIdentifier, /)")]
     fn add_ipdu_identifier_udp(&self, ipdu_identifier: &SoConIPduIdentifier) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_ipdu_identifier_udp(&ipdu_identifier.0)
             .map_err(abstraction_err_to_pyerr)
@@ -889,6 +1033,7 @@ impl PduActivationRoutingGroup {
     #[pyo3(signature = (ipdu_identifier, /))]
     #[pyo3(text_signature = "(self, ipdu_identifier: SoConIPduIdentifier, /)")]
     fn add_ipdu_identifier_tcp(&self, ipdu_identifier: &SoConIPduIdentifier) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_ipdu_identifier_tcp(&ipdu_identifier.0)
             .map_err(abstraction_err_to_pyerr)
@@ -900,6 +1045,8 @@ impl PduActivationRoutingGroup {
     }
 }
 
+identifiable_pymethods!(PduActivationRoutingGroup);
+
 //##################################################################
 
 iterator_wrapper!(PduActivationRoutingGroupIterator, PduActivationRoutingGroup);
@@ -937,14 +1084,28 @@ impl SomeipSdServerServiceInstanceConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -962,9 +1123,14 @@ impl SomeipSdServerServiceInstanceConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the service offer time to live of this `SomeipSdServerServiceInstanceConfig`
     #[setter]
     fn set_service_offer_time_to_live(&self, ttl: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_service_offer_time_to_live(ttl)
             .map_err(abstraction_err_to_pyerr)
@@ -979,6 +1145,7 @@ impl SomeipSdServerServiceInstanceConfig {
     /// set the offer cyclic delay of this `SomeipSdServerServiceInstanceConfig`
     #[setter]
     fn set_offer_cyclic_delay(&self, delay: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_offer_cyclic_delay(delay)
             .map_err(abstraction_err_to_pyerr)
@@ -995,6 +1162,7 @@ impl SomeipSdServerServiceInstanceConfig {
     /// Available since R21-11 (`AUTOSAR_00050`)
     #[setter]
     fn set_priority(&self, priority: u8) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_priority(priority)
             .map_err(abstraction_err_to_pyerr)
@@ -1011,6 +1179,7 @@ impl SomeipSdServerServiceInstanceConfig {
         &self,
         initial_offer_behavior: &InitialSdDelayConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_initial_offer_behavior(&initial_offer_behavior.into())
             .map_err(abstraction_err_to_pyerr)
@@ -1028,6 +1197,7 @@ impl SomeipSdServerServiceInstanceConfig {
         &self,
         request_response_delay: &RequestResponseDelay,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_request_response_delay(&request_response_delay.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1039,6 +1209,8 @@ impl SomeipSdServerServiceInstanceConfig {
     }
 }
 
+identifiable_pymethods!(SomeipSdServerServiceInstanceConfig);
+
 //##################################################################
 
 /// A `SomeipSdServerEventGroupTimingConfig` contains the configuration for the timing of an `EventHandler`
@@ -1072,14 +1244,28 @@ impl SomeipSdServerEventGroupTimingConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1097,11 +1283,16 @@ impl SomeipSdServerEventGroupTimingConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the request response delay of this `SomeipSdServerEventGroupTimingConfig`
     fn set_request_response_delay(
         &self,
         request_response_delay: &RequestResponseDelay,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_request_response_delay(&request_response_delay.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1113,6 +1304,8 @@ impl SomeipSdServerEventGroupTimingConfig {
     }
 }
 
+identifiable_pymethods!(SomeipSdServerEventGroupTimingConfig);
+
 //##################################################################
 
 /// A `SomeipSdClientServiceInstanceConfig` is a configuration for a `ConsumedServiceInstance`
@@ -1146,14 +1339,28 @@ impl SomeipSdClientServiceInstanceConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1171,6 +1378,10 @@ impl SomeipSdClientServiceInstanceConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the initial find behavior of this `SomeipSdClientServiceInstanceConfig`
     #[pyo3(signature = (initial_find_behavior, /))]
     #[pyo3(text_signature = "(self, initial_find_behavior: InitialSdDelayConfig, /)")]
@@ -1178,6 +1389,7 @@ impl SomeipSdClientServiceInstanceConfig {
         &self,
         initial_find_behavior: &InitialSdDelayConfig,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_initial_find_behavior(&initial_find_behavior.into())
             .map_err(abstraction_err_to_pyerr)
@@ -1195,6 +1407,7 @@ impl SomeipSdClientServiceInstanceConfig {
     /// Available since R21-11 (`AUTOSAR_00050`)
     #[setter]
     fn set_priority(&self, priority: u8) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_priority(priority)
             .map_err(abstraction_err_to_pyerr)
@@ -1207,6 +1420,8 @@ impl SomeipSdClientServiceInstanceConfig {
     }
 }
 
+identifiable_pymethods!(SomeipSdClientServiceInstanceConfig);
+
 //##################################################################
 
 /// A `SomeipSdClientEventGroupTimingConfig` contains the configuration for the timing of a `ConsumedEventGroup`
@@ -1240,14 +1455,28 @@ impl SomeipSdClientEventGroupTimingConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1265,9 +1494,14 @@ impl SomeipSdClientEventGroupTimingConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the time to live of this `SomeipSdClientEventGroupTimingConfig`
     #[setter]
     fn set_time_to_live(&self, time_to_live: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_time_to_live(time_to_live)
             .map_err(abstraction_err_to_pyerr)
@@ -1284,6 +1518,7 @@ impl SomeipSdClientEventGroupTimingConfig {
         &self,
         request_response_delay: &RequestResponseDelay,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_request_response_delay(&request_response_delay.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1300,6 +1535,7 @@ impl SomeipSdClientEventGroupTimingConfig {
         &self,
         subscribe_eventgroup_retry_delay: f64,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_subscribe_eventgroup_retry_delay(subscribe_eventgroup_retry_delay)
             .map_err(abstraction_err_to_pyerr)
@@ -1317,6 +1553,7 @@ impl SomeipSdClientEventGroupTimingConfig {
         &self,
         subscribe_eventgroup_retry_max: u32,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_subscribe_eventgroup_retry_max(subscribe_eventgroup_retry_max)
             .map_err(abstraction_err_to_pyerr)
@@ -1329,6 +1566,8 @@ impl SomeipSdClientEventGroupTimingConfig {
     }
 }
 
+identifiable_pymethods!(SomeipSdClientEventGroupTimingConfig);
+
 //##################################################################
 
 /// A `RequestResponseDelay` contains the minimum and maximum delay for a request-response communication
@@ -1481,14 +1720,28 @@ impl SomeipTpConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1506,6 +1759,10 @@ impl SomeipTpConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the communication cluster of this `SomeipTpConfig`
     #[getter]
     fn cluster(&self) -> Option<Py<PyAny>> {
@@ -1533,6 +1790,7 @@ impl SomeipTpConfig {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_someip_tp_channel(&self, name: &str) -> PyResult<SomeipTpChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_someip_tp_channel(name) {
             Ok(value) => Ok(SomeipTpChannel(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -1555,6 +1813,7 @@ impl SomeipTpConfig {
         transport_pdu_triggering: &PduTriggering,
         tp_channel: Option<SomeipTpChannel>,
     ) -> PyResult<SomeipTpConnection> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_someip_tp_connection(
             &tp_sdu.0,
             &transport_pdu_triggering.0,
@@ -1571,6 +1830,8 @@ impl SomeipTpConfig {
     }
 }
 
+identifiable_pymethods!(SomeipTpConfig);
+
 //##################################################################
 
 /// A `SomeipTpConnection` contains the configuration of a single `SomeIp` TP connection
@@ -1600,12 +1861,25 @@ impl SomeipTpConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -1615,6 +1889,10 @@ impl SomeipTpConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the `SomeipTpConfig` that contains this `SomeipTpConnection`
     #[getter]
     fn someip_tp_config(&self) -> PyResult<SomeipTpConfig> {
@@ -1630,6 +1908,7 @@ impl SomeipTpConnection {
         &self,
         transport_pdu_triggering: &PduTriggering,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transport_pdu_triggering(&transport_pdu_triggering.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1644,6 +1923,7 @@ impl SomeipTpConnection {
     /// set the `TpSdu` of this `SomeipTpConnection`
     #[setter]
     fn set_tp_sdu(&self, tp_sdu: &ISignalIPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_sdu(&tp_sdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1658,6 +1938,7 @@ impl SomeipTpConnection {
     /// set the `TpChannel` of this `SomeipTpConnection`
     #[setter]
     fn set_tp_channel(&self, tp_channel: Option<SomeipTpChannel>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_channel(tp_channel.map(|c| c.0))
             .map_err(abstraction_err_to_pyerr)
@@ -1704,14 +1985,28 @@ impl SomeipTpChannel {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1729,9 +2024,14 @@ impl SomeipTpChannel {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the rxTimeoutTime for the `SomeIpTpChannel`
     #[setter]
     fn set_rx_timeout_time(&self, rx_timeout_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_rx_timeout_time(rx_timeout_time)
             .map_err(abstraction_err_to_pyerr)
@@ -1746,6 +2046,7 @@ impl SomeipTpChannel {
     /// set the separationTime for the `SomeIpTpChannel`
     #[setter]
     fn set_separation_time(&self, separation_time: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_separation_time(separation_time)
             .map_err(abstraction_err_to_pyerr)
@@ -1758,6 +2059,8 @@ impl SomeipTpChannel {
     }
 }
 
+identifiable_pymethods!(SomeipTpChannel);
+
 //##################################################################
 
 iterator_wrapper!(SomeipTpChannelIterator, SomeipTpChannel);
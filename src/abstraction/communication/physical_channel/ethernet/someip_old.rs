@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -38,14 +39,28 @@ impl ProvidedServiceInstanceV1 {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,9 +78,14 @@ impl ProvidedServiceInstanceV1 {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the service identifier of this `ProvidedServiceInstance`
     #[setter]
     fn set_service_identifier(&self, service_identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_service_identifier(service_identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -80,6 +100,7 @@ impl ProvidedServiceInstanceV1 {
     /// set the instance identifier of this `ProvidedServiceInstance`
     #[setter]
     fn set_instance_identifier(&self, instance_identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_instance_identifier(instance_identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -95,6 +116,7 @@ impl ProvidedServiceInstanceV1 {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_event_handler(&self, name: &str) -> PyResult<EventHandlerV1> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_event_handler(name) {
             Ok(value) => Ok(EventHandlerV1(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -110,6 +132,7 @@ impl ProvidedServiceInstanceV1 {
     #[pyo3(signature = (sd_server_config, /))]
     #[pyo3(text_signature = "(self, sd_server_config: SdConfig, /)")]
     fn set_sd_server_config(&self, sd_server_config: &SdConfig) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_server_config(&sd_server_config.clone().into())
             .map_err(abstraction_err_to_pyerr)
@@ -121,6 +144,8 @@ impl ProvidedServiceInstanceV1 {
     }
 }
 
+identifiable_pymethods!(ProvidedServiceInstanceV1);
+
 //##################################################################
 
 iterator_wrapper!(ProvidedServiceInstanceV1Iterator, ProvidedServiceInstanceV1);
@@ -154,14 +179,28 @@ impl EventHandlerV1 {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -179,6 +218,10 @@ impl EventHandlerV1 {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// add a reference to a `ConsumedEventGroupV1` to this `EventHandlerV1`
     #[pyo3(signature = (consumed_event_group, /))]
     #[pyo3(text_signature = "(self, consumed_event_group: ConsumedEventGroupV1, /)")]
@@ -186,6 +229,7 @@ impl EventHandlerV1 {
         &self,
         consumed_event_group: &ConsumedEventGroupV1,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_consumed_event_group(&consumed_event_group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -195,6 +239,7 @@ impl EventHandlerV1 {
     #[pyo3(signature = (routing_group, /))]
     #[pyo3(text_signature = "(self, routing_group: SoAdRoutingGroup, /)")]
     fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_routing_group(&routing_group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -209,6 +254,7 @@ impl EventHandlerV1 {
     #[pyo3(signature = (sd_event_config, /))]
     #[pyo3(text_signature = "(self, sd_event_config: SdEventConfig, /)")]
     fn set_sd_server_config(&self, sd_event_config: &SdEventConfig) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_server_config(&sd_event_config.into())
             .map_err(abstraction_err_to_pyerr)
@@ -225,6 +271,8 @@ impl EventHandlerV1 {
     }
 }
 
+identifiable_pymethods!(EventHandlerV1);
+
 //##################################################################
 
 iterator_wrapper!(EventHandlerV1Iterator, EventHandlerV1);
@@ -260,14 +308,28 @@ impl ConsumedServiceInstanceV1 {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -285,6 +347,10 @@ impl ConsumedServiceInstanceV1 {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the `ProvidedServiceInstanceV1` referenced by this `ConsumedServiceInstanceV1`
     #[getter]
     fn provided_service_instance(&self) -> Option<ProvidedServiceInstanceV1> {
@@ -304,6 +370,7 @@ impl ConsumedServiceInstanceV1 {
         event_group_identifier: u32,
         event_handler: &EventHandlerV1,
     ) -> PyResult<ConsumedEventGroupV1> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_consumed_event_group(name, event_group_identifier, &event_handler.0)
@@ -322,6 +389,7 @@ impl ConsumedServiceInstanceV1 {
     #[pyo3(signature = (sd_client_config, /))]
     #[pyo3(text_signature = "(self, sd_client_config: SdConfig, /)")]
     fn set_sd_client_config(&self, sd_client_config: &SdConfig) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_client_config(&sd_client_config.clone().into())
             .map_err(abstraction_err_to_pyerr)
@@ -333,6 +401,8 @@ impl ConsumedServiceInstanceV1 {
     }
 }
 
+identifiable_pymethods!(ConsumedServiceInstanceV1);
+
 //##################################################################
 
 iterator_wrapper!(ConsumedServiceInstanceV1Iterator, ConsumedServiceInstanceV1);
@@ -368,14 +438,28 @@ impl ConsumedEventGroupV1 {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -393,6 +477,10 @@ impl ConsumedEventGroupV1 {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// list all `EventHandlerV1`s that reference this `ConsumedEventGroupV1`
     fn event_handlers(&self) -> Vec<EventHandlerV1> {
         self.0
@@ -406,6 +494,7 @@ impl ConsumedEventGroupV1 {
     /// This may be a different `SocketAddress` than the one that is used to send requests.
     #[setter]
     fn set_application_endpoint(&self, socket_address: &SocketAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_application_endpoint(&socket_address.0)
             .map_err(abstraction_err_to_pyerr)
@@ -421,6 +510,7 @@ impl ConsumedEventGroupV1 {
     /// set the event group identifier of this `ConsumedEventGroup`
     #[setter]
     fn set_event_group_identifier(&self, event_group_identifier: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_event_group_identifier(event_group_identifier)
             .map_err(abstraction_err_to_pyerr)
@@ -436,6 +526,7 @@ impl ConsumedEventGroupV1 {
     #[pyo3(signature = (routing_group, /))]
     #[pyo3(text_signature = "(self, routing_group: SoAdRoutingGroup, /)")]
     fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_routing_group(&routing_group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -449,6 +540,7 @@ impl ConsumedEventGroupV1 {
     /// set the SD client configuration for this `ConsumedEventGroup`
     #[setter]
     fn set_sd_client_config(&self, sd_client_config: &SdEventConfig) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_sd_client_config(&sd_client_config.into())
             .map_err(abstraction_err_to_pyerr)
@@ -461,6 +553,8 @@ impl ConsumedEventGroupV1 {
     }
 }
 
+identifiable_pymethods!(ConsumedEventGroupV1);
+
 //##################################################################
 
 iterator_wrapper!(ConsumedEventGroupV1Iterator, ConsumedEventGroupV1);
@@ -34,14 +34,28 @@ impl FlexrayPhysicalChannel {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -59,6 +73,10 @@ impl FlexrayPhysicalChannel {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the channel name of a `FlexrayPhysicalChannel`
     #[getter]
     fn channel_name(&self) -> Option<FlexrayChannelName> {
@@ -107,6 +125,8 @@ impl FlexrayPhysicalChannel {
     }
 }
 
+identifiable_pymethods!(FlexrayPhysicalChannel);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayFrameTriggeringsIterator, FlexrayFrameTriggering);
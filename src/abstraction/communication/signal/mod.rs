@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -43,14 +44,28 @@ impl ISignal {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -68,9 +83,14 @@ impl ISignal {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the data type for this signal
     #[setter]
     fn set_datatype(&self, datatype: &SwBaseType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_datatype(&datatype.0)
             .map_err(abstraction_err_to_pyerr)
@@ -85,6 +105,7 @@ impl ISignal {
     /// set the length of this signal in bits
     #[setter]
     fn set_length(&self, bit_length: u64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_length(bit_length)
             .map_err(abstraction_err_to_pyerr)
@@ -99,6 +120,7 @@ impl ISignal {
     /// set the init value for this signal
     #[setter]
     fn set_init_value(&self, init_value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let init_value = init_value
             .map(|val| pyany_to_value_specification(val))
             .transpose()?;
@@ -118,6 +140,7 @@ impl ISignal {
     /// set the system signal that corresponds to this isignal
     #[setter]
     fn set_system_signal(&self, system_signal: &SystemSignal) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_system_signal(&system_signal.0)
             .map_err(abstraction_err_to_pyerr)
@@ -160,6 +183,7 @@ impl ISignal {
     #[pyo3(signature = (data_transformation, /))]
     #[pyo3(text_signature = "(self, data_transformation: DataTransformation, /)")]
     fn add_data_transformation(&self, data_transformation: &DataTransformation) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_data_transformation(&data_transformation.0)
             .map_err(abstraction_err_to_pyerr)
@@ -177,6 +201,7 @@ impl ISignal {
         &self,
         transformer: &TransformationTechnology,
     ) -> PyResult<EndToEndTransformationISignalProps> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_e2e_transformation_isignal_props(&transformer.0)
@@ -193,6 +218,7 @@ impl ISignal {
         &self,
         transformer: &TransformationTechnology,
     ) -> PyResult<SomeIpTransformationISignalProps> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_someip_transformation_isignal_props(&transformer.0)
@@ -225,6 +251,8 @@ impl ISignal {
     }
 }
 
+identifiable_pymethods!(ISignal);
+
 //##################################################################
 
 iterator_wrapper!(ISignalIterator, ISignal);
@@ -257,14 +285,28 @@ impl SystemSignal {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -282,6 +324,10 @@ impl SystemSignal {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the signal group that contains this signal
     #[getter]
     fn signal_group(&self) -> Option<SystemSignalGroup> {
@@ -291,6 +337,7 @@ impl SystemSignal {
     /// set the unit for this signal
     #[setter]
     fn set_unit(&self, unit: &Unit) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_unit(&unit.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -303,6 +350,7 @@ impl SystemSignal {
     /// set the compu method for this signal
     #[setter]
     fn set_compu_method(&self, compu_method: &CompuMethod) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_compu_method(&compu_method.0)
             .map_err(abstraction_err_to_pyerr)
@@ -317,6 +365,7 @@ impl SystemSignal {
     /// set the data constraint for this signal
     #[setter]
     fn set_data_constr(&self, data_constr: &DataConstr) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_data_constr(&data_constr.0)
             .map_err(abstraction_err_to_pyerr)
@@ -329,6 +378,8 @@ impl SystemSignal {
     }
 }
 
+identifiable_pymethods!(SystemSignal);
+
 //##################################################################
 
 iterator_wrapper!(SystemSignalIterator, SystemSignal);
@@ -358,14 +409,28 @@ impl ISignalGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -383,8 +448,13 @@ impl ISignalGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add a signal to the signal group
     fn add_signal(&self, signal: &ISignal) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_signal(&signal.0)
             .map_err(abstraction_err_to_pyerr)
@@ -407,6 +477,7 @@ impl ISignalGroup {
     #[pyo3(signature = (data_transformation, /))]
     #[pyo3(text_signature = "(self, data_transformation: DataTransformation, /)")]
     fn add_data_transformation(&self, data_transformation: &DataTransformation) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_data_transformation(&data_transformation.0)
             .map_err(abstraction_err_to_pyerr)
@@ -424,6 +495,7 @@ impl ISignalGroup {
         &self,
         transformer: &TransformationTechnology,
     ) -> PyResult<EndToEndTransformationISignalProps> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_e2e_transformation_isignal_props(&transformer.0)
@@ -440,6 +512,7 @@ impl ISignalGroup {
         &self,
         transformer: &TransformationTechnology,
     ) -> PyResult<SomeIpTransformationISignalProps> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_someip_transformation_isignal_props(&transformer.0)
@@ -472,6 +545,8 @@ impl ISignalGroup {
     }
 }
 
+identifiable_pymethods!(ISignalGroup);
+
 //##################################################################
 
 /// A signal group refers to a set of signals that shall always be kept together. A signal group is used to
@@ -504,14 +579,28 @@ impl SystemSignalGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -529,10 +618,15 @@ impl SystemSignalGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add a signal to the signal group
     #[pyo3(signature = (signal, /))]
     #[pyo3(text_signature = "(self, signal: ISignal, /)")]
     fn add_signal(&self, signal: &SystemSignal) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_signal(&signal.0)
             .map_err(abstraction_err_to_pyerr)
@@ -544,6 +638,8 @@ impl SystemSignalGroup {
     }
 }
 
+identifiable_pymethods!(SystemSignalGroup);
+
 /// an `ISignalTriggering` triggers a signal in a PDU
 #[pyclass(
     skip_from_py_object,
@@ -571,14 +667,28 @@ impl ISignalTriggering {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -596,6 +706,10 @@ impl ISignalTriggering {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the physical channel that contains this signal triggering
     #[getter]
     fn physical_channel(&self, py: Python) -> PyResult<Py<PyAny>> {
@@ -626,6 +740,7 @@ impl ISignalTriggering {
         ecu: &EcuInstance,
         direction: CommunicationDirection,
     ) -> PyResult<ISignalPort> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.connect_to_ecu(&ecu.0, direction.into()) {
             Ok(signal_port) => Ok(ISignalPort(signal_port)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -638,6 +753,8 @@ impl ISignalTriggering {
     }
 }
 
+identifiable_pymethods!(ISignalTriggering);
+
 //##################################################################
 
 iterator_wrapper!(ISignalPortIterator, ISignalPort);
@@ -667,14 +784,28 @@ impl ISignalPort {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -692,6 +823,10 @@ impl ISignalPort {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the ECU that is connected to this signal port
     #[getter]
     fn ecu(&self) -> PyResult<EcuInstance> {
@@ -704,6 +839,7 @@ impl ISignalPort {
     /// set the communication direction of this port
     #[setter]
     fn set_communication_direction(&self, direction: CommunicationDirection) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_communication_direction(direction.into())
             .map_err(abstraction_err_to_pyerr)
@@ -718,6 +854,8 @@ impl ISignalPort {
     }
 }
 
+identifiable_pymethods!(ISignalPort);
+
 //##################################################################
 
 /// The `TransferProperty` defines if or how the signal influences the transfer of the PDU
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -38,14 +39,28 @@ impl CanTpConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,9 +78,14 @@ impl CanTpConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `CanCluster` associated with this configuration
     #[setter]
     fn set_cluster(&self, can_cluster: &CanCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cluster(&can_cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -87,6 +107,7 @@ impl CanTpConfig {
         ecu_instance: &EcuInstance,
         cycle_time_main_function: Option<f64>,
     ) -> PyResult<CanTpEcu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_can_tp_ecu(&ecu_instance.0, cycle_time_main_function)
@@ -105,6 +126,7 @@ impl CanTpConfig {
     #[pyo3(signature = (name, address, /))]
     #[pyo3(text_signature = "(self, name: str, address: int)")]
     fn create_can_tp_address(&self, name: &str, address: u32) -> PyResult<CanTpAddress> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_tp_address(name, address) {
             Ok(address) => Ok(CanTpAddress(address)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -125,6 +147,7 @@ impl CanTpConfig {
         channel_id: u32,
         mode: CanTpChannelMode,
     ) -> PyResult<CanTpChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_tp_channel(name, channel_id, mode.into()) {
             Ok(channel) => Ok(CanTpChannel(channel)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -150,6 +173,7 @@ impl CanTpConfig {
         tp_sdu: &Bound<'_, PyAny>,
         padding_activation: bool,
     ) -> PyResult<CanTpConnection> {
+        crate::frozen::check_element(self.0.element())?;
         let tp_sdu = pyany_to_ipdu(tp_sdu)?;
 
         match self.0.create_can_tp_connection(
@@ -174,6 +198,7 @@ impl CanTpConfig {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_can_tp_node(&self, name: &str) -> PyResult<CanTpNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_tp_node(name) {
             Ok(node) => Ok(CanTpNode(node)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -186,6 +211,8 @@ impl CanTpConfig {
     }
 }
 
+identifiable_pymethods!(CanTpConfig);
+
 //#########################################################
 
 /// A `CanTpEcu` represents an ECU that is using the `CanTp` module
@@ -211,12 +238,25 @@ impl CanTpEcu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -226,9 +266,14 @@ impl CanTpEcu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the ECU instance of the `CanTpEcu`
     #[setter]
     fn set_ecu_instance(&self, ecu_instance: &EcuInstance) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_ecu_instance(&ecu_instance.0)
             .map_err(abstraction_err_to_pyerr)
@@ -243,6 +288,7 @@ impl CanTpEcu {
     /// set the cycle time of the `CanTp` main function of the ECU
     #[setter]
     fn set_cycle_time_main_function(&self, cycle_time: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cycle_time_main_function(cycle_time)
             .map_err(abstraction_err_to_pyerr)
@@ -284,14 +330,28 @@ impl CanTpAddress {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -309,9 +369,14 @@ impl CanTpAddress {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the address value of the `CanTpAddress`
     #[setter]
     fn set_tp_address(&self, address: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_address(address)
             .map_err(abstraction_err_to_pyerr)
@@ -324,6 +389,8 @@ impl CanTpAddress {
     }
 }
 
+identifiable_pymethods!(CanTpAddress);
+
 //#########################################################
 
 iterator_wrapper!(CanTpAddressIterator, CanTpAddress);
@@ -353,14 +420,28 @@ impl CanTpChannel {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -378,9 +459,14 @@ impl CanTpChannel {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the channel id of the channel
     #[setter]
     fn set_channel_id(&self, channel_id: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel_id(channel_id)
             .map_err(abstraction_err_to_pyerr)
@@ -395,6 +481,7 @@ impl CanTpChannel {
     /// set the channel mode of the channel
     #[setter]
     fn set_channel_mode(&self, mode: CanTpChannelMode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel_mode(mode.into())
             .map_err(abstraction_err_to_pyerr)
@@ -407,6 +494,8 @@ impl CanTpChannel {
     }
 }
 
+identifiable_pymethods!(CanTpChannel);
+
 //#########################################################
 
 iterator_wrapper!(CanTpChannelIterator, CanTpChannel);
@@ -484,14 +573,28 @@ impl CanTpConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -509,9 +612,14 @@ impl CanTpConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `CanTpChannel` associated with this connection
     #[setter]
     fn set_channel(&self, channel: &CanTpChannel) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_channel(&channel.0)
             .map_err(abstraction_err_to_pyerr)
@@ -526,6 +634,7 @@ impl CanTpConnection {
     /// set the `NPdu` associated with this connection
     #[setter]
     fn set_data_pdu(&self, data_pdu: &NPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_data_pdu(&data_pdu.0)
             .map_err(abstraction_err_to_pyerr)
@@ -542,6 +651,7 @@ impl CanTpConnection {
     /// set the `IPdu` associated with this connection
     #[setter]
     fn set_tp_sdu(&self, tp_sdu: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let tp_sdu = pyany_to_ipdu(tp_sdu)?;
         self.0.set_tp_sdu(&tp_sdu).map_err(abstraction_err_to_pyerr)
     }
@@ -557,6 +667,7 @@ impl CanTpConnection {
     /// set the addressing format of the connection
     #[setter]
     fn set_addressing_format(&self, addressing_format: CanTpAddressingFormat) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_addressing_format(addressing_format.into())
             .map_err(abstraction_err_to_pyerr)
@@ -571,6 +682,7 @@ impl CanTpConnection {
     /// set the padding activation of the connection
     #[setter]
     fn set_padding_activation(&self, padding_activation: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_padding_activation(padding_activation)
             .map_err(abstraction_err_to_pyerr)
@@ -587,6 +699,7 @@ impl CanTpConnection {
     /// This is a `CanTpNode` representing an ECU that will send the data
     #[setter]
     fn set_transmitter(&self, transmitter: &CanTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transmitter(&transmitter.0)
             .map_err(abstraction_err_to_pyerr)
@@ -604,6 +717,7 @@ impl CanTpConnection {
     #[pyo3(signature = (receiver, /))]
     #[pyo3(text_signature = "(self, receiver: CanTpNode, /)")]
     fn add_receiver(&self, receiver: &CanTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_receiver(&receiver.0)
             .map_err(abstraction_err_to_pyerr)
@@ -615,6 +729,8 @@ impl CanTpConnection {
     }
 }
 
+identifiable_pymethods!(CanTpConnection);
+
 //#########################################################
 
 iterator_wrapper!(CanTpConnectionIterator, CanTpConnection);
@@ -716,14 +832,28 @@ impl CanTpNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -741,9 +871,14 @@ impl CanTpNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `CanTpAddress` of this Node
     #[setter]
     fn set_address(&self, address: &CanTpAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_address(&address.0)
             .map_err(abstraction_err_to_pyerr)
@@ -761,6 +896,7 @@ impl CanTpNode {
     /// ECU is also connected to the `CanTpNode`
     #[setter]
     fn set_connector(&self, connector: &CanCommunicationConnector) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_connector(&connector.0)
             .map_err(abstraction_err_to_pyerr)
@@ -773,6 +909,8 @@ impl CanTpNode {
     }
 }
 
+identifiable_pymethods!(CanTpNode);
+
 //#########################################################
 
 iterator_wrapper!(CanTpNodeIterator, CanTpNode);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -32,14 +33,28 @@ impl DoIpTpConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -57,9 +72,14 @@ impl DoIpTpConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the reference to the `EthernetCluster` for this `DoIpTpConfig`
     #[setter]
     fn set_cluster(&self, cluster: &EthernetCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -75,6 +95,7 @@ impl DoIpTpConfig {
     #[pyo3(signature = (name, address, /))]
     #[pyo3(text_signature = "(self, name: str, address: int, /)")]
     fn create_doip_logic_address(&self, name: &str, address: u32) -> PyResult<DoIpLogicAddress> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_doip_logic_address(name, address) {
             Ok(value) => Ok(DoIpLogicAddress(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -98,6 +119,7 @@ impl DoIpTpConfig {
         target: &DoIpLogicAddress,
         tp_sdu_triggering: &PduTriggering,
     ) -> PyResult<DoIpTpConnection> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_doip_tp_connection(name, &source.0, &target.0, &tp_sdu_triggering.0)
@@ -113,6 +135,8 @@ impl DoIpTpConfig {
     }
 }
 
+identifiable_pymethods!(DoIpTpConfig);
+
 //##################################################################
 
 /// This element defines the logical address of a `DoIp` connection
@@ -141,14 +165,28 @@ impl DoIpLogicAddress {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -166,9 +204,14 @@ impl DoIpLogicAddress {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the address of this `DoIpLogicAddress`
     #[setter]
     fn set_address(&self, address: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_address(address)
             .map_err(abstraction_err_to_pyerr)
@@ -181,6 +224,8 @@ impl DoIpLogicAddress {
     }
 }
 
+identifiable_pymethods!(DoIpLogicAddress);
+
 //##################################################################
 
 iterator_wrapper!(DoIpLogicAddressIterator, DoIpLogicAddress);
@@ -213,14 +258,28 @@ impl DoIpTpConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -238,9 +297,14 @@ impl DoIpTpConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the source `DoIpLogicAddress`
     #[setter]
     fn set_source(&self, source: &DoIpLogicAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_source(&source.0)
             .map_err(abstraction_err_to_pyerr)
@@ -255,6 +319,7 @@ impl DoIpTpConnection {
     /// set the target `DoIpLogicAddress`
     #[setter]
     fn set_target(&self, target: &DoIpLogicAddress) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_target(&target.0)
             .map_err(abstraction_err_to_pyerr)
@@ -269,6 +334,7 @@ impl DoIpTpConnection {
     /// set the `PduTriggering` for this connection
     #[setter]
     fn set_tp_sdu_triggering(&self, tp_sdu_triggering: &PduTriggering) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_sdu_triggering(&tp_sdu_triggering.0)
             .map_err(abstraction_err_to_pyerr)
@@ -281,6 +347,8 @@ impl DoIpTpConnection {
     }
 }
 
+identifiable_pymethods!(DoIpTpConnection);
+
 //##################################################################
 
 iterator_wrapper!(DoIpTpConnectionIterator, DoIpTpConnection);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -41,14 +42,28 @@ impl FlexrayArTpConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,9 +81,14 @@ impl FlexrayArTpConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the Flexray cluster for the configuration
     #[setter]
     fn set_cluster(&self, cluster: &FlexrayCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -84,6 +104,7 @@ impl FlexrayArTpConfig {
     #[pyo3(signature = (name, address, /))]
     #[pyo3(text_signature = "(self, name: str, address: int, /)")]
     fn create_tp_address(&self, name: &str, address: u32) -> PyResult<TpAddress> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_tp_address(name, address) {
             Ok(value) => Ok(TpAddress(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -108,6 +129,7 @@ impl FlexrayArTpConfig {
         minimum_separation_time: f32,
         multicast_segmentation: bool,
     ) -> PyResult<FlexrayArTpChannel> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_ar_tp_channel(
             ack_type.into(),
             extended_addressing,
@@ -129,6 +151,7 @@ impl FlexrayArTpConfig {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_flexray_ar_tp_node(&self, name: &str) -> PyResult<FlexrayArTpNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_ar_tp_node(name) {
             Ok(value) => Ok(FlexrayArTpNode(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -141,6 +164,8 @@ impl FlexrayArTpConfig {
     }
 }
 
+identifiable_pymethods!(FlexrayArTpConfig);
+
 //##################################################################
 
 /// The `FlexrayArTpChannel` represents a channel in the Flexray Autosar Transport Protocol
@@ -170,12 +195,25 @@ impl FlexrayArTpChannel {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -185,9 +223,14 @@ impl FlexrayArTpChannel {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the ack type of the channel
     #[setter]
     fn set_ack_type(&self, ack_type: FrArTpAckType) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_ack_type(ack_type.into())
             .map_err(abstraction_err_to_pyerr)
@@ -202,6 +245,7 @@ impl FlexrayArTpChannel {
     /// set the extended addressing attribute
     #[setter]
     fn set_extended_addressing(&self, extended_addressing: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_extended_addressing(extended_addressing)
             .map_err(abstraction_err_to_pyerr)
@@ -219,6 +263,7 @@ impl FlexrayArTpChannel {
         &self,
         maximum_message_length: MaximumMessageLengthType,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_maximum_message_length(maximum_message_length.into())
             .map_err(abstraction_err_to_pyerr)
@@ -235,6 +280,7 @@ impl FlexrayArTpChannel {
     /// set the minimum separation time
     #[setter]
     fn set_minimum_separation_time(&self, minimum_separation_time: f32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_minimum_separation_time(minimum_separation_time)
             .map_err(abstraction_err_to_pyerr)
@@ -249,6 +295,7 @@ impl FlexrayArTpChannel {
     /// set the multicast segmentation
     #[setter]
     fn set_multicast_segmentation(&self, multicast_segmentation: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_multicast_segmentation(multicast_segmentation)
             .map_err(abstraction_err_to_pyerr)
@@ -272,6 +319,7 @@ impl FlexrayArTpChannel {
         source: &FlexrayArTpNode,
         target: &FlexrayArTpNode,
     ) -> PyResult<FlexrayArTpConnection> {
+        crate::frozen::check_element(self.0.element())?;
         let direct_tp_sdu = pyany_to_ipdu(direct_tp_sdu)?;
         match self
             .0
@@ -298,6 +346,7 @@ impl FlexrayArTpChannel {
     #[pyo3(signature = (n_pdu, /))]
     #[pyo3(text_signature = "(self, n_pdu: NPdu, /)")]
     fn add_n_pdu(&self, n_pdu: &NPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.add_n_pdu(&n_pdu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -447,14 +496,28 @@ impl FlexrayArTpConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -472,9 +535,14 @@ impl FlexrayArTpConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the direct TP SDU
     #[setter]
     fn set_direct_tp_sdu(&self, direct_tp_sdu: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let direct_tp_sdu = pyany_to_ipdu(direct_tp_sdu)?;
         self.0
             .set_direct_tp_sdu(&direct_tp_sdu)
@@ -492,6 +560,7 @@ impl FlexrayArTpConnection {
     /// set the source of the connection
     #[setter]
     fn set_source(&self, source: &FlexrayArTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_source(&source.0)
             .map_err(abstraction_err_to_pyerr)
@@ -509,6 +578,7 @@ impl FlexrayArTpConnection {
     #[pyo3(signature = (target, /))]
     #[pyo3(text_signature = "(self, target: FlexrayArTpNode, /)")]
     fn add_target(&self, target: &FlexrayArTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_target(&target.0)
             .map_err(abstraction_err_to_pyerr)
@@ -525,6 +595,7 @@ impl FlexrayArTpConnection {
     /// if Some(value) is passed, the reversed TP SDU is set to the given value, otherwise it is removed.
     #[setter]
     fn set_reversed_tp_sdu(&self, reversed_tp_sdu: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let reversed_tp_sdu = reversed_tp_sdu.map(pyany_to_ipdu).transpose()?;
         self.0
             .set_reversed_tp_sdu(reversed_tp_sdu.as_ref())
@@ -540,6 +611,8 @@ impl FlexrayArTpConnection {
     }
 }
 
+identifiable_pymethods!(FlexrayArTpConnection);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayArTpConnectionIterator, FlexrayArTpConnection);
@@ -574,14 +647,28 @@ impl FlexrayArTpNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -599,11 +686,16 @@ impl FlexrayArTpNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set or remove the TP address
     ///
     /// if Some(value) is passed, the TP address is set to the given value, otherwise it is removed.
     #[setter]
     fn set_tp_address(&self, tp_address: Option<&TpAddress>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_address(tp_address.map(|tp_address| &tp_address.0))
             .map_err(abstraction_err_to_pyerr)
@@ -626,6 +718,7 @@ impl FlexrayArTpNode {
         &self,
         connector: &FlexrayCommunicationConnector,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_communication_connector(&connector.0)
             .map_err(abstraction_err_to_pyerr)
@@ -641,6 +734,8 @@ impl FlexrayArTpNode {
     }
 }
 
+identifiable_pymethods!(FlexrayArTpNode);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayArTpNodeIterator, FlexrayArTpNode);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -40,14 +41,28 @@ impl FlexrayTpConfig {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,9 +80,14 @@ impl FlexrayTpConfig {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the `FlexrayCluster` of the `FlexrayTpConfig`
     #[setter]
     fn set_cluster(&self, cluster: &FlexrayCluster) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cluster(&cluster.0)
             .map_err(abstraction_err_to_pyerr)
@@ -83,6 +103,7 @@ impl FlexrayTpConfig {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_flexray_tp_pdu_pool(&self, name: &str) -> PyResult<FlexrayTpPduPool> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_tp_pdu_pool(name) {
             Ok(value) => Ok(FlexrayTpPduPool(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -98,6 +119,7 @@ impl FlexrayTpConfig {
     #[pyo3(signature = (name, address, /))]
     #[pyo3(text_signature = "(self, name: str, address: int, /)")]
     fn create_tp_address(&self, name: &str, address: u32) -> PyResult<TpAddress> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_tp_address(name, address) {
             Ok(value) => Ok(TpAddress(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -121,6 +143,7 @@ impl FlexrayTpConfig {
         direct_tp_sdu: &Bound<'_, PyAny>,
         connection_control: &FlexrayTpConnectionControl,
     ) -> PyResult<FlexrayTpConnection> {
+        crate::frozen::check_element(self.0.element())?;
         let direct_tp_sdu = pyany_to_ipdu(direct_tp_sdu)?;
 
         match self.0.create_flexray_tp_connection(
@@ -146,6 +169,7 @@ impl FlexrayTpConfig {
         &self,
         name: &str,
     ) -> PyResult<FlexrayTpConnectionControl> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_tp_connection_control(name) {
             Ok(value) => Ok(FlexrayTpConnectionControl(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -169,6 +193,7 @@ impl FlexrayTpConfig {
         ecu_instance: &EcuInstance,
         full_duplex_enabled: bool,
     ) -> PyResult<FlexrayTpEcu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_tp_ecu(&ecu_instance.0, full_duplex_enabled)
@@ -187,6 +212,7 @@ impl FlexrayTpConfig {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_flexray_tp_node(&self, name: &str) -> PyResult<FlexrayTpNode> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_tp_node(name) {
             Ok(value) => Ok(FlexrayTpNode(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -199,6 +225,8 @@ impl FlexrayTpConfig {
     }
 }
 
+identifiable_pymethods!(FlexrayTpConfig);
+
 //##################################################################
 
 /// A `FlexrayTpPduPool` contains a set of `NPdus` that can be used for sending and receiving
@@ -227,14 +255,28 @@ impl FlexrayTpPduPool {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -252,10 +294,15 @@ impl FlexrayTpPduPool {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// add an `NPdu` to the `PduPool`
     #[pyo3(signature = (n_pdu, /))]
     #[pyo3(text_signature = "(self, n_pdu: NPdu, /)")]
     fn add_n_pdu(&self, n_pdu: &NPdu) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.add_n_pdu(&n_pdu.0).map_err(abstraction_err_to_pyerr)
     }
 
@@ -265,6 +312,8 @@ impl FlexrayTpPduPool {
     }
 }
 
+identifiable_pymethods!(FlexrayTpPduPool);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayTpPduPoolIterator, FlexrayTpPduPool);
@@ -298,14 +347,28 @@ impl FlexrayTpConnection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -323,9 +386,14 @@ impl FlexrayTpConnection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the transmitter of the connection
     #[setter]
     fn set_transmitter(&self, transmitter: &FlexrayTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_transmitter(&transmitter.0)
             .map_err(abstraction_err_to_pyerr)
@@ -340,6 +408,7 @@ impl FlexrayTpConnection {
     /// set the direct TP SDU of the connection
     #[setter]
     fn set_direct_tp_sdu(&self, direct_tp_sdu: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let direct_tp_sdu = pyany_to_ipdu(direct_tp_sdu)?;
 
         self.0
@@ -361,6 +430,7 @@ impl FlexrayTpConnection {
         &self,
         connection_control: &FlexrayTpConnectionControl,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_connection_control(&connection_control.0)
             .map_err(abstraction_err_to_pyerr)
@@ -376,6 +446,7 @@ impl FlexrayTpConnection {
     #[pyo3(signature = (receiver, /))]
     #[pyo3(text_signature = "(self, receiver: FlexrayTpNode, /)")]
     fn add_receiver(&self, receiver: &FlexrayTpNode) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_receiver(&receiver.0)
             .map_err(abstraction_err_to_pyerr)
@@ -390,6 +461,7 @@ impl FlexrayTpConnection {
     /// This is used if the connection supports both sending and receiving
     #[setter]
     fn set_reversed_tp_sdu(&self, reversed_tp_sdu: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let reversed_tp_sdu = pyany_to_ipdu(reversed_tp_sdu)?;
 
         self.0
@@ -408,6 +480,7 @@ impl FlexrayTpConnection {
     /// set the TX `FlexrayTpPduPool` of the connection
     #[setter]
     fn set_tx_pdu_pool(&self, tx_pdu_pool: &FlexrayTpPduPool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tx_pdu_pool(&tx_pdu_pool.0)
             .map_err(abstraction_err_to_pyerr)
@@ -422,6 +495,7 @@ impl FlexrayTpConnection {
     /// set the RX `FlexrayTpPduPool` of the connection
     #[setter]
     fn set_rx_pdu_pool(&self, rx_pdu_pool: &FlexrayTpPduPool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_rx_pdu_pool(&rx_pdu_pool.0)
             .map_err(abstraction_err_to_pyerr)
@@ -436,6 +510,7 @@ impl FlexrayTpConnection {
     /// set the multicast `TpAddress` of the connection
     #[setter]
     fn set_multicast_address(&self, multicast_address: Option<&TpAddress>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_multicast_address(multicast_address.map(|tp_address| &tp_address.0))
             .map_err(abstraction_err_to_pyerr)
@@ -448,6 +523,8 @@ impl FlexrayTpConnection {
     }
 }
 
+identifiable_pymethods!(FlexrayTpConnection);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayTpConnectionIterator, FlexrayTpConnection);
@@ -481,14 +558,28 @@ impl FlexrayTpConnectionControl {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -506,9 +597,14 @@ impl FlexrayTpConnectionControl {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the maxFcWait value
     #[setter]
     fn set_max_fc_wait(&self, max_fc_wait: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_fc_wait(max_fc_wait)
             .map_err(abstraction_err_to_pyerr)
@@ -523,6 +619,7 @@ impl FlexrayTpConnectionControl {
     /// set the maxNumberOfNpduPerCycle value
     #[setter]
     fn set_max_number_of_npdu_per_cycle(&self, max_number_of_npdu_per_cycle: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_number_of_npdu_per_cycle(max_number_of_npdu_per_cycle)
             .map_err(abstraction_err_to_pyerr)
@@ -537,6 +634,7 @@ impl FlexrayTpConnectionControl {
     /// set the maxRetries value
     #[setter]
     fn set_max_retries(&self, max_retries: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_retries(max_retries)
             .map_err(abstraction_err_to_pyerr)
@@ -551,6 +649,7 @@ impl FlexrayTpConnectionControl {
     /// set the separationCycleExponent value
     #[setter]
     fn set_separation_cycle_exponent(&self, separation_cycle_exponent: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_separation_cycle_exponent(separation_cycle_exponent)
             .map_err(abstraction_err_to_pyerr)
@@ -563,6 +662,8 @@ impl FlexrayTpConnectionControl {
     }
 }
 
+identifiable_pymethods!(FlexrayTpConnectionControl);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -595,12 +696,25 @@ impl FlexrayTpEcu {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -610,9 +724,14 @@ impl FlexrayTpEcu {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the ECU instance of the `FlexrayTpEcu`
     #[setter]
     fn set_ecu_instance(&self, ecu_instance: &EcuInstance) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_ecu_instance(&ecu_instance.0)
             .map_err(abstraction_err_to_pyerr)
@@ -627,6 +746,7 @@ impl FlexrayTpEcu {
     /// set the full duplex enabled flag of the `FlexrayTpEcu`
     #[setter]
     fn set_full_duplex_enabled(&self, full_duplex_enabled: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_full_duplex_enabled(full_duplex_enabled)
             .map_err(abstraction_err_to_pyerr)
@@ -641,6 +761,7 @@ impl FlexrayTpEcu {
     /// set the cycle time of the TP main function in seconds
     #[setter]
     fn set_cycle_time_main_function(&self, cycle_time_main_function: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cycle_time_main_function(cycle_time_main_function)
             .map_err(abstraction_err_to_pyerr)
@@ -655,6 +776,7 @@ impl FlexrayTpEcu {
     /// set the cancellation status of the `FlexrayTpEcu`
     #[setter]
     fn set_cancellation(&self, cancellation: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_cancellation(cancellation)
             .map_err(abstraction_err_to_pyerr)
@@ -696,14 +818,28 @@ impl FlexrayTpNode {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -721,11 +857,16 @@ impl FlexrayTpNode {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set or remove `FlexrayTpAddress` of the node
     /// A TP address is mandatory for unicast nodes, but optional for multicast nodes
     /// Setting None will remove the element
     #[setter]
     fn set_tp_address(&self, tp_address: Option<&TpAddress>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_tp_address(tp_address.map(|tp_address| &tp_address.0))
             .map_err(abstraction_err_to_pyerr)
@@ -746,6 +887,7 @@ impl FlexrayTpNode {
         &self,
         connector: &FlexrayCommunicationConnector,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_communication_connector(&connector.0)
             .map_err(abstraction_err_to_pyerr)
@@ -761,6 +903,8 @@ impl FlexrayTpNode {
     }
 }
 
+identifiable_pymethods!(FlexrayTpNode);
+
 //##################################################################
 
 iterator_wrapper!(FlexrayTpNodeIterator, FlexrayTpNode);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -40,14 +41,28 @@ impl ApplicationArrayDataType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -65,6 +80,10 @@ impl ApplicationArrayDataType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the array element of the array data type
     #[getter]
     fn array_element(&self) -> Option<ApplicationArrayElement> {
@@ -75,6 +94,7 @@ impl ApplicationArrayDataType {
     #[pyo3(signature = (size, /))]
     #[pyo3(text_signature = "(self, size: ApplicationArraySize, /)")]
     fn set_size(&self, size: ApplicationArraySize) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_size(size.into())
             .map_err(abstraction_err_to_pyerr)
@@ -86,6 +106,8 @@ impl ApplicationArrayDataType {
     }
 }
 
+identifiable_pymethods!(ApplicationArrayDataType);
+
 //#########################################################
 
 /// definition of the size type of an application array data type
@@ -191,14 +213,28 @@ impl ApplicationArrayElement {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -216,9 +252,14 @@ impl ApplicationArrayElement {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the data type of the array element
     #[setter]
     fn set_data_type(&self, data_type: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_application_data_type(data_type)?;
         self.0
             .set_data_type(&data_type)
@@ -234,6 +275,8 @@ impl ApplicationArrayElement {
     }
 }
 
+identifiable_pymethods!(ApplicationArrayElement);
+
 //#########################################################
 
 /// An application record data type
@@ -265,14 +308,28 @@ impl ApplicationRecordDataType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -290,6 +347,10 @@ impl ApplicationRecordDataType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new element in the record data type
     #[pyo3(signature = (name, data_type, /))]
     #[pyo3(text_signature = "(self, name: str, data_type: ApplicationDataType, /)")]
@@ -298,6 +359,7 @@ impl ApplicationRecordDataType {
         name: &str,
         data_type: &Bound<'_, PyAny>,
     ) -> PyResult<ApplicationRecordElement> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_application_data_type(data_type)?;
         match self.0.create_record_element(name, &data_type) {
             Ok(element) => Ok(ApplicationRecordElement(element)),
@@ -313,6 +375,8 @@ impl ApplicationRecordDataType {
     }
 }
 
+identifiable_pymethods!(ApplicationRecordDataType);
+
 //#########################################################
 
 /// An element in an application record data type
@@ -342,14 +406,28 @@ impl ApplicationRecordElement {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -367,9 +445,14 @@ impl ApplicationRecordElement {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the data type of the record element
     #[setter]
     fn set_data_type(&self, data_type: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_application_data_type(data_type)?;
         self.0
             .set_data_type(&data_type)
@@ -385,6 +468,8 @@ impl ApplicationRecordElement {
     }
 }
 
+identifiable_pymethods!(ApplicationRecordElement);
+
 //#########################################################
 
 iterator_wrapper!(ApplicationRecordElementIterator, ApplicationRecordElement);
@@ -420,14 +505,28 @@ impl ApplicationPrimitiveDataType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -445,9 +544,14 @@ impl ApplicationPrimitiveDataType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the category of the primitive data type
     #[setter]
     fn set_category(&self, category: ApplicationPrimitiveCategory) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.into())
             .map_err(abstraction_err_to_pyerr)
@@ -462,6 +566,7 @@ impl ApplicationPrimitiveDataType {
     /// set the compu method of the primitive data type
     #[setter]
     fn set_compu_method(&self, compu_method: Option<&CompuMethod>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_compu_method(compu_method.map(|cm| &cm.0))
             .map_err(abstraction_err_to_pyerr)
@@ -476,6 +581,7 @@ impl ApplicationPrimitiveDataType {
     /// set the unit of the primitive data type
     #[setter]
     fn set_unit(&self, unit: Option<&Unit>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_unit(unit.map(|u| &u.0))
             .map_err(abstraction_err_to_pyerr)
@@ -490,6 +596,7 @@ impl ApplicationPrimitiveDataType {
     /// set the data constraint of the primitive data type
     #[setter]
     fn set_data_constraint(&self, data_constraint: Option<&DataConstr>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_data_constraint(data_constraint.map(|dc| &dc.0))
             .map_err(abstraction_err_to_pyerr)
@@ -502,6 +609,8 @@ impl ApplicationPrimitiveDataType {
     }
 }
 
+identifiable_pymethods!(ApplicationPrimitiveDataType);
+
 //#########################################################
 
 /// The category of an application primitive data type
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{AutosarAbstractionError, ByteOrder, abstraction_err_to_pyerr},
@@ -32,14 +33,28 @@ impl SwBaseType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -57,9 +72,14 @@ impl SwBaseType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the base type size (in bits) of the `SwBaseType`
     #[setter]
     fn set_bit_length(&self, bit_length: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_bit_length(bit_length)
             .map_err(abstraction_err_to_pyerr)
@@ -74,6 +94,7 @@ impl SwBaseType {
     /// set the base type encoding of the `SwBaseType`
     #[setter]
     fn set_base_type_encoding(&self, base_type_encoding: BaseTypeEncoding) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_base_type_encoding(base_type_encoding.into())
             .map_err(abstraction_err_to_pyerr)
@@ -90,6 +111,7 @@ impl SwBaseType {
     /// The byte order is platform specific and should only be set when it is really needed.
     #[setter]
     fn set_byte_order(&self, byte_order: ByteOrder) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_byte_order(byte_order.into())
             .map_err(abstraction_err_to_pyerr)
@@ -107,6 +129,7 @@ impl SwBaseType {
     /// Since the memory alignment is platform specific, it should only be set when it is really needed.
     #[setter]
     fn set_mem_alignment(&self, mem_alignment: u32) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_mem_alignment(mem_alignment)
             .map_err(abstraction_err_to_pyerr)
@@ -123,6 +146,7 @@ impl SwBaseType {
     /// The native declaration is a string that represents the type in the native programming language.
     #[setter]
     fn set_native_declaration(&self, native_declaration: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_native_declaration(native_declaration)
             .map_err(abstraction_err_to_pyerr)
@@ -135,6 +159,8 @@ impl SwBaseType {
     }
 }
 
+identifiable_pymethods!(SwBaseType);
+
 //##################################################################
 
 /// `BaseTypeEncoding` describes the encoding of a basic data type.
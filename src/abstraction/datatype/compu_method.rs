@@ -31,14 +31,28 @@ impl CompuMethod {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -56,6 +70,10 @@ impl CompuMethod {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the category of the `CompuMethod`
     #[getter]
     fn category(&self) -> Option<CompuMethodCategory> {
@@ -65,6 +83,7 @@ impl CompuMethod {
     /// Set the Unit of the `CompuMethod`
     #[setter]
     fn set_unit(&self, unit: Option<&Unit>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let unit = unit.map(|u| &u.0);
         self.0.set_unit(unit).map_err(abstraction_err_to_pyerr)
     }
@@ -81,6 +100,7 @@ impl CompuMethod {
     #[pyo3(signature = (content, /))]
     #[pyo3(text_signature = "(self, content: CompuMethodContent, /)")]
     fn set_content(&self, content: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let content = pyany_to_compu_method_content(content)?;
         self.0
             .set_content(content)
@@ -105,6 +125,7 @@ impl CompuMethod {
         lower_limit: Option<f64>,
         upper_limit: Option<f64>,
     ) -> PyResult<CompuScale> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_compu_scale(direction.into(), lower_limit, upper_limit)
@@ -125,6 +146,8 @@ impl CompuMethod {
     }
 }
 
+identifiable_pymethods!(CompuMethod);
+
 //#########################################################
 
 iterator_wrapper!(IntToPhysCompuScaleIterator, CompuScale);
@@ -218,12 +241,25 @@ impl CompuScale {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -233,6 +269,10 @@ impl CompuScale {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the lower limit of the `CompuScale`
     #[getter]
     fn lower_limit(&self) -> Option<f64> {
@@ -248,6 +288,7 @@ impl CompuScale {
     /// Set the mask of the `CompuScale`, applicable for `BitfieldTextTable`
     #[setter]
     fn set_mask(&self, mask: u64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_mask(mask).map_err(abstraction_err_to_pyerr)
     }
 
@@ -260,6 +301,7 @@ impl CompuScale {
     /// Set the content of the `CompuScale`
     #[setter]
     fn set_content(&self, content: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let content = if let Ok(content) = content.extract::<String>() {
             autosar_data_abstraction::datatype::CompuScaleContent::TextConstant(content)
         } else if let Ok(content) = content.extract::<f64>() {
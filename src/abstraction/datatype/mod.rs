@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{AutosarAbstractionError, abstraction_err_to_pyerr},
@@ -47,14 +48,28 @@ impl Unit {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,6 +81,7 @@ impl Unit {
     /// set the display name of the unit
     #[setter]
     fn set_display_name(&self, display_name: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_display_name(display_name)
             .map_err(abstraction_err_to_pyerr)
@@ -85,8 +101,14 @@ impl Unit {
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
 }
 
+identifiable_pymethods!(Unit);
+
 //##################################################################
 
 /// `DataConstr` represents a data constraint.
@@ -112,14 +134,28 @@ impl DataConstr {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -137,6 +173,10 @@ impl DataConstr {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a data constraint rule
     #[pyo3(signature = (rule_type, /, *, lower_limit=None, upper_limit=None))]
     #[pyo3(
@@ -148,6 +188,7 @@ impl DataConstr {
         lower_limit: Option<f64>,
         upper_limit: Option<f64>,
     ) -> PyResult<DataConstrRule> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_data_constr_rule(rule_type.into(), lower_limit, upper_limit)
@@ -163,6 +204,8 @@ impl DataConstr {
     }
 }
 
+identifiable_pymethods!(DataConstr);
+
 //##################################################################
 
 /// `DataConstrRule` represents a data constraint rule.
@@ -188,12 +231,25 @@ impl DataConstrRule {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -203,6 +259,10 @@ impl DataConstrRule {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the constraint type
     #[getter]
     fn rule_type(&self) -> DataConstrType {
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -50,14 +51,28 @@ impl EcucChoiceContainerDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -75,10 +90,15 @@ impl EcucChoiceContainerDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `EcucParamConfContainerDef` as one of the choices in this choice container
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_param_conf_container_def(&self, name: &str) -> PyResult<EcucParamConfContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_param_conf_container_def(name) {
             Ok(value) => Ok(EcucParamConfContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -95,6 +115,7 @@ impl EcucChoiceContainerDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -109,6 +130,7 @@ impl EcucChoiceContainerDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -127,6 +149,7 @@ impl EcucChoiceContainerDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -139,6 +162,8 @@ impl EcucChoiceContainerDef {
     }
 }
 
+identifiable_pymethods!(EcucChoiceContainerDef);
+
 //##################################################################
 
 /// The `EcucParamConfContainerDef` is used to define configuration containers
@@ -168,14 +193,28 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -193,10 +232,15 @@ impl EcucParamConfContainerDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `EcucChoiceContainerDef` as a sub-container
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_choice_container_def(&self, name: &str) -> PyResult<EcucChoiceContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_choice_container_def(name) {
             Ok(value) => Ok(EcucChoiceContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -207,6 +251,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_param_conf_container_def(&self, name: &str) -> PyResult<EcucParamConfContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_param_conf_container_def(name) {
             Ok(value) => Ok(EcucParamConfContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -226,6 +271,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_add_info_param_def(&self, name: &str, origin: &str) -> PyResult<EcucAddInfoParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_add_info_param_def(name, origin) {
             Ok(value) => Ok(EcucAddInfoParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -236,6 +282,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_boolean_param_def(&self, name: &str, origin: &str) -> PyResult<EcucBooleanParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_boolean_param_def(name, origin) {
             Ok(value) => Ok(EcucBooleanParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -250,6 +297,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucEnumerationParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_enumeration_param_def(name, origin) {
             Ok(value) => Ok(EcucEnumerationParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -260,6 +308,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_float_param_def(&self, name: &str, origin: &str) -> PyResult<EcucFloatParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_float_param_def(name, origin) {
             Ok(value) => Ok(EcucFloatParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -270,6 +319,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_integer_param_def(&self, name: &str, origin: &str) -> PyResult<EcucIntegerParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_integer_param_def(name, origin) {
             Ok(value) => Ok(EcucIntegerParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -284,6 +334,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucFunctionNameDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_function_name_param_def(name, origin) {
             Ok(value) => Ok(EcucFunctionNameDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -298,6 +349,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucLinkerSymbolDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_linker_symbol_param_def(name, origin) {
             Ok(value) => Ok(EcucLinkerSymbolDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -312,6 +364,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucMultilineStringParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_multiline_string_param_def(name, origin) {
             Ok(value) => Ok(EcucMultilineStringParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -322,6 +375,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_string_param_def(&self, name: &str, origin: &str) -> PyResult<EcucStringParamDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_string_param_def(name, origin) {
             Ok(value) => Ok(EcucStringParamDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -345,6 +399,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucForeignReferenceDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_foreign_reference_def(name, origin) {
             Ok(value) => Ok(EcucForeignReferenceDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -359,6 +414,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucInstanceReferenceDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_instance_reference_def(name, origin) {
             Ok(value) => Ok(EcucInstanceReferenceDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -373,6 +429,7 @@ impl EcucParamConfContainerDef {
         name: &str,
         origin: &str,
     ) -> PyResult<EcucChoiceReferenceDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_choice_reference_def(name, origin) {
             Ok(value) => Ok(EcucChoiceReferenceDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -383,6 +440,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_reference_def(&self, name: &str, origin: &str) -> PyResult<EcucReferenceDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_reference_def(name, origin) {
             Ok(value) => Ok(EcucReferenceDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -393,6 +451,7 @@ impl EcucParamConfContainerDef {
     #[pyo3(signature = (name, origin, /))]
     #[pyo3(text_signature = "(self, name: str, origin: str, /)")]
     fn create_uri_reference_def(&self, name: &str, origin: &str) -> PyResult<EcucUriReferenceDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_uri_reference_def(name, origin) {
             Ok(value) => Ok(EcucUriReferenceDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -413,6 +472,7 @@ impl EcucParamConfContainerDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -427,6 +487,7 @@ impl EcucParamConfContainerDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -445,6 +506,7 @@ impl EcucParamConfContainerDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -457,6 +519,8 @@ impl EcucParamConfContainerDef {
     }
 }
 
+identifiable_pymethods!(EcucParamConfContainerDef);
+
 //##################################################################
 
 iterator_wrapper!(EcucParamConfContainerDefIterator, EcucParamConfContainerDef);
@@ -41,14 +41,28 @@ impl EcucDefinitionCollection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,10 +80,15 @@ impl EcucDefinitionCollection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// add a reference to a module definition to the collection
     #[pyo3(signature = (module_def, /))]
     #[pyo3(text_signature = "(self, module_def: EcucModuleDef, /)")]
     fn add_module_def(&self, module_def: &EcucModuleDef) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_module_def(&module_def.0)
             .map_err(abstraction_err_to_pyerr)
@@ -81,6 +100,8 @@ impl EcucDefinitionCollection {
     }
 }
 
+identifiable_pymethods!(EcucDefinitionCollection);
+
 //##################################################################
 
 /// The `EcucModuleDef` is a container for the definition of a single base software module
@@ -110,14 +131,28 @@ impl EcucModuleDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -135,10 +170,15 @@ impl EcucModuleDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new EcucChoiceContainerDef in the module
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_choice_container_def(&self, name: &str) -> PyResult<EcucChoiceContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_choice_container_def(name) {
             Ok(value) => Ok(EcucChoiceContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -149,6 +189,7 @@ impl EcucModuleDef {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_param_conf_container_def(&self, name: &str) -> PyResult<EcucParamConfContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_param_conf_container_def(name) {
             Ok(value) => Ok(EcucParamConfContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -170,6 +211,7 @@ impl EcucModuleDef {
     /// this attribute is needed to define the prefix for the API services
     #[setter]
     fn set_api_service_prefix(&self, prefix: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_api_service_prefix(prefix)
             .map_err(abstraction_err_to_pyerr)
@@ -190,6 +232,7 @@ impl EcucModuleDef {
         &self,
         variants: Vec<EcucConfigurationVariant>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let variants = variants
             .iter()
             .map(|variant| (*variant).into())
@@ -212,6 +255,7 @@ impl EcucModuleDef {
     /// set or remove the post build variant support attribute
     #[setter]
     fn set_post_build_variant_support(&self, support: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_support(support)
             .map_err(abstraction_err_to_pyerr)
@@ -226,6 +270,7 @@ impl EcucModuleDef {
     /// set or remove the category of the module definition
     #[setter]
     fn set_category(&self, category: Option<EcucModuleDefCategory>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.map(std::convert::Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -242,6 +287,7 @@ impl EcucModuleDef {
     /// This reference is only used if the category is `VendorSpecificModuleDefinition`
     #[setter]
     fn set_refined_module_def(&self, refined_module_def: Option<&EcucModuleDef>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_refined_module_def(refined_module_def.as_ref().map(|def| &def.0))
             .map_err(abstraction_err_to_pyerr)
@@ -260,6 +306,7 @@ impl EcucModuleDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -274,6 +321,7 @@ impl EcucModuleDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -292,6 +340,7 @@ impl EcucModuleDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -304,6 +353,8 @@ impl EcucModuleDef {
     }
 }
 
+identifiable_pymethods!(EcucModuleDef);
+
 //##################################################################
 
 iterator_wrapper!(EcucModuleDefIterator, EcucModuleDef);
@@ -501,14 +552,28 @@ impl EcucDestinationUriDefSet {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -526,6 +591,10 @@ impl EcucDestinationUriDefSet {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new `EcucDestinationUriDef`
     #[pyo3(signature = (name, contract, /))]
     #[pyo3(text_signature = "(self, name: str, contract: EcucDestinationUriNestingContract, /)")]
@@ -534,6 +603,7 @@ impl EcucDestinationUriDefSet {
         name: &str,
         contract: EcucDestinationUriNestingContract,
     ) -> PyResult<EcucDestinationUriDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_destination_uri_def(name, contract.into()) {
             Ok(value) => Ok(EcucDestinationUriDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -546,6 +616,8 @@ impl EcucDestinationUriDefSet {
     }
 }
 
+identifiable_pymethods!(EcucDestinationUriDefSet);
+
 //##################################################################
 
 /// A `EcucDestinationUriDef` defines a target for an `EcucUriReferenceDef`
@@ -574,6 +646,7 @@ impl EcucDestinationUriDef {
 
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -591,9 +664,14 @@ impl EcucDestinationUriDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the nesting contract for the destination uri
     #[setter]
     fn set_nesting_contract(&self, contract: EcucDestinationUriNestingContract) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_nesting_contract(contract.into())
             .map_err(abstraction_err_to_pyerr)
@@ -611,6 +689,7 @@ impl EcucDestinationUriDef {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_param_conf_container_def(&self, name: &str) -> PyResult<EcucParamConfContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_param_conf_container_def(name) {
             Ok(value) => Ok(EcucParamConfContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -621,6 +700,7 @@ impl EcucDestinationUriDef {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_choice_container_def(&self, name: &str) -> PyResult<EcucChoiceContainerDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_choice_container_def(name) {
             Ok(value) => Ok(EcucChoiceContainerDef(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -637,6 +717,8 @@ impl EcucDestinationUriDef {
     }
 }
 
+identifiable_pymethods!(EcucDestinationUriDef);
+
 //#########################################################
 
 iterator_wrapper!(EcucDestinationUriDefIterator, EcucDestinationUriDef);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -41,14 +42,28 @@ impl EcucAddInfoParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,6 +81,10 @@ impl EcucAddInfoParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------- EcucCommonAttributes -------
 
     /// set the multiplicity config classes of the parameter definition.
@@ -77,6 +96,7 @@ impl EcucAddInfoParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -109,6 +129,7 @@ impl EcucAddInfoParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -130,6 +151,7 @@ impl EcucAddInfoParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -150,6 +172,7 @@ impl EcucAddInfoParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -167,6 +190,7 @@ impl EcucAddInfoParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -188,6 +212,7 @@ impl EcucAddInfoParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -220,6 +245,7 @@ impl EcucAddInfoParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -235,6 +261,7 @@ impl EcucAddInfoParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -249,6 +276,7 @@ impl EcucAddInfoParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -267,6 +295,7 @@ impl EcucAddInfoParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -279,6 +308,8 @@ impl EcucAddInfoParamDef {
     }
 }
 
+identifiable_pymethods!(EcucAddInfoParamDef);
+
 //##################################################################
 
 /// `EcucBooleanParamDef` is used to specify a boolean parameter in the ECU configuration
@@ -308,14 +339,28 @@ impl EcucBooleanParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -333,9 +378,14 @@ impl EcucBooleanParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the default value of the boolean parameter
     #[setter]
     fn set_default_value(&self, default_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -358,6 +408,7 @@ impl EcucBooleanParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -390,6 +441,7 @@ impl EcucBooleanParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -411,6 +463,7 @@ impl EcucBooleanParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -431,6 +484,7 @@ impl EcucBooleanParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -448,6 +502,7 @@ impl EcucBooleanParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -469,6 +524,7 @@ impl EcucBooleanParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -501,6 +557,7 @@ impl EcucBooleanParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -516,6 +573,7 @@ impl EcucBooleanParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -530,6 +588,7 @@ impl EcucBooleanParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -548,6 +607,7 @@ impl EcucBooleanParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -560,6 +620,8 @@ impl EcucBooleanParamDef {
     }
 }
 
+identifiable_pymethods!(EcucBooleanParamDef);
+
 //##################################################################
 
 /// `EcucEnumerationParamDef` is used to specify an enumeration parameter in the ECU configuration
@@ -589,14 +651,28 @@ impl EcucEnumerationParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -614,8 +690,13 @@ impl EcucEnumerationParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// create a new enumeration literal
     fn create_enumeration_literal(&self, name: &str) -> PyResult<EcucEnumerationLiteralDef> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_enumeration_literal(name) {
             Ok(value) => Ok(EcucEnumerationLiteralDef(value)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -634,6 +715,7 @@ impl EcucEnumerationParamDef {
     /// Note: enumeration literals must be created first, since the default value must match one of the literals
     #[setter]
     fn set_default_value(&self, default_value: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -656,6 +738,7 @@ impl EcucEnumerationParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -688,6 +771,7 @@ impl EcucEnumerationParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -709,6 +793,7 @@ impl EcucEnumerationParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -729,6 +814,7 @@ impl EcucEnumerationParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -746,6 +832,7 @@ impl EcucEnumerationParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -767,6 +854,7 @@ impl EcucEnumerationParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -799,6 +887,7 @@ impl EcucEnumerationParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -814,6 +903,7 @@ impl EcucEnumerationParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -828,6 +918,7 @@ impl EcucEnumerationParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -846,6 +937,7 @@ impl EcucEnumerationParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -858,6 +950,8 @@ impl EcucEnumerationParamDef {
     }
 }
 
+identifiable_pymethods!(EcucEnumerationParamDef);
+
 //##################################################################
 
 /// `EcucEnumerationLiteralDef` is used to specify an enumeration literal in the ECU configuration
@@ -887,14 +981,28 @@ impl EcucEnumerationLiteralDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -911,8 +1019,14 @@ impl EcucEnumerationLiteralDef {
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
 }
 
+identifiable_pymethods!(EcucEnumerationLiteralDef);
+
 //##################################################################
 
 iterator_wrapper!(EcucEnumerationLiteralDefIterator, EcucEnumerationLiteralDef);
@@ -946,14 +1060,28 @@ impl EcucFloatParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -971,9 +1099,14 @@ impl EcucFloatParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the default value of the float parameter
     #[setter]
     fn set_default_value(&self, default_value: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -988,6 +1121,7 @@ impl EcucFloatParamDef {
     /// set the min value of the float parameter
     #[setter]
     fn set_min(&self, min: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_min(min).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1000,6 +1134,7 @@ impl EcucFloatParamDef {
     /// set the max value of the float parameter
     #[setter]
     fn set_max(&self, max: Option<f64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_max(max).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1020,6 +1155,7 @@ impl EcucFloatParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1052,6 +1188,7 @@ impl EcucFloatParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1073,6 +1210,7 @@ impl EcucFloatParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1093,6 +1231,7 @@ impl EcucFloatParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1110,6 +1249,7 @@ impl EcucFloatParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -1131,6 +1271,7 @@ impl EcucFloatParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1163,6 +1304,7 @@ impl EcucFloatParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -1178,6 +1320,7 @@ impl EcucFloatParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1192,6 +1335,7 @@ impl EcucFloatParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1210,6 +1354,7 @@ impl EcucFloatParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -1222,6 +1367,8 @@ impl EcucFloatParamDef {
     }
 }
 
+identifiable_pymethods!(EcucFloatParamDef);
+
 //##################################################################
 
 /// `EcucIntegerParamDef` is used to specify an integer parameter in the ECU configuration
@@ -1251,14 +1398,28 @@ impl EcucIntegerParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1276,9 +1437,14 @@ impl EcucIntegerParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the default value of the integer parameter
     #[setter]
     fn set_default_value(&self, default_value: Option<i64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1293,6 +1459,7 @@ impl EcucIntegerParamDef {
     /// set the min value of the integer parameter
     #[setter]
     fn set_min(&self, min: Option<i64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_min(min).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1305,6 +1472,7 @@ impl EcucIntegerParamDef {
     /// set the max value of the integer parameter
     #[setter]
     fn set_max(&self, max: Option<i64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_max(max).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1325,6 +1493,7 @@ impl EcucIntegerParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1357,6 +1526,7 @@ impl EcucIntegerParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1378,6 +1548,7 @@ impl EcucIntegerParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1398,6 +1569,7 @@ impl EcucIntegerParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1415,6 +1587,7 @@ impl EcucIntegerParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -1436,6 +1609,7 @@ impl EcucIntegerParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1468,6 +1642,7 @@ impl EcucIntegerParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -1483,6 +1658,7 @@ impl EcucIntegerParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1497,6 +1673,7 @@ impl EcucIntegerParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1515,6 +1692,7 @@ impl EcucIntegerParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -1527,6 +1705,8 @@ impl EcucIntegerParamDef {
     }
 }
 
+identifiable_pymethods!(EcucIntegerParamDef);
+
 //##################################################################
 
 /// `EcucFunctionNameDef` is used to specify a function name parameter in the ECU configuration
@@ -1556,14 +1736,28 @@ impl EcucFunctionNameDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1581,11 +1775,16 @@ impl EcucFunctionNameDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------- EcucAbstractStringParamDef -------
 
     /// set or remove the max length attribute
     #[setter]
     fn set_max_length(&self, max_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_length(max_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1600,6 +1799,7 @@ impl EcucFunctionNameDef {
     /// set or remove the min length attribute
     #[setter]
     fn set_min_length(&self, min_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_min_length(min_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1615,6 +1815,7 @@ impl EcucFunctionNameDef {
     /// The regular expression is a string that is used to validate the string parameter
     #[setter]
     fn set_regular_expression(&self, regular_expression: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_regular_expression(regular_expression)
             .map_err(abstraction_err_to_pyerr)
@@ -1630,6 +1831,7 @@ impl EcucFunctionNameDef {
     /// set or remove the default value attribute
     #[setter]
     fn set_default_value(&self, default_value: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1652,6 +1854,7 @@ impl EcucFunctionNameDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1684,6 +1887,7 @@ impl EcucFunctionNameDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1705,6 +1909,7 @@ impl EcucFunctionNameDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1725,6 +1930,7 @@ impl EcucFunctionNameDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1742,6 +1948,7 @@ impl EcucFunctionNameDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -1763,6 +1970,7 @@ impl EcucFunctionNameDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1795,6 +2003,7 @@ impl EcucFunctionNameDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -1810,6 +2019,7 @@ impl EcucFunctionNameDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1824,6 +2034,7 @@ impl EcucFunctionNameDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1842,6 +2053,7 @@ impl EcucFunctionNameDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -1854,6 +2066,8 @@ impl EcucFunctionNameDef {
     }
 }
 
+identifiable_pymethods!(EcucFunctionNameDef);
+
 //##################################################################
 
 /// `EcucLinkerSymbolDef` is used to specify a linker symbol parameter in the ECU configuration
@@ -1883,14 +2097,28 @@ impl EcucLinkerSymbolDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1908,11 +2136,16 @@ impl EcucLinkerSymbolDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------- EcucAbstractStringParamDef -------
 
     /// set or remove the max length attribute
     #[setter]
     fn set_max_length(&self, max_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_length(max_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1927,6 +2160,7 @@ impl EcucLinkerSymbolDef {
     /// set or remove the min length attribute
     #[setter]
     fn set_min_length(&self, min_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_min_length(min_length)
             .map_err(abstraction_err_to_pyerr)
@@ -1942,6 +2176,7 @@ impl EcucLinkerSymbolDef {
     /// The regular expression is a string that is used to validate the string parameter
     #[setter]
     fn set_regular_expression(&self, regular_expression: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_regular_expression(regular_expression)
             .map_err(abstraction_err_to_pyerr)
@@ -1957,6 +2192,7 @@ impl EcucLinkerSymbolDef {
     /// set or remove the default value attribute
     #[setter]
     fn set_default_value(&self, default_value: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1979,6 +2215,7 @@ impl EcucLinkerSymbolDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2011,6 +2248,7 @@ impl EcucLinkerSymbolDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -2032,6 +2270,7 @@ impl EcucLinkerSymbolDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2052,6 +2291,7 @@ impl EcucLinkerSymbolDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -2069,6 +2309,7 @@ impl EcucLinkerSymbolDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -2090,6 +2331,7 @@ impl EcucLinkerSymbolDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2122,6 +2364,7 @@ impl EcucLinkerSymbolDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -2137,6 +2380,7 @@ impl EcucLinkerSymbolDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2151,6 +2395,7 @@ impl EcucLinkerSymbolDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2169,6 +2414,7 @@ impl EcucLinkerSymbolDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -2181,6 +2427,8 @@ impl EcucLinkerSymbolDef {
     }
 }
 
+identifiable_pymethods!(EcucLinkerSymbolDef);
+
 //##################################################################
 
 /// `EcucMultilineStringParamDef` is used to specify a multiline string parameter in the ECU configuration
@@ -2210,14 +2458,28 @@ impl EcucMultilineStringParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -2235,11 +2497,16 @@ impl EcucMultilineStringParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------- EcucAbstractStringParamDef -------
 
     /// set or remove the max length attribute
     #[setter]
     fn set_max_length(&self, max_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_length(max_length)
             .map_err(abstraction_err_to_pyerr)
@@ -2254,6 +2521,7 @@ impl EcucMultilineStringParamDef {
     /// set or remove the min length attribute
     #[setter]
     fn set_min_length(&self, min_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_min_length(min_length)
             .map_err(abstraction_err_to_pyerr)
@@ -2269,6 +2537,7 @@ impl EcucMultilineStringParamDef {
     /// The regular expression is a string that is used to validate the string parameter
     #[setter]
     fn set_regular_expression(&self, regular_expression: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_regular_expression(regular_expression)
             .map_err(abstraction_err_to_pyerr)
@@ -2284,6 +2553,7 @@ impl EcucMultilineStringParamDef {
     /// set or remove the default value attribute
     #[setter]
     fn set_default_value(&self, default_value: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -2306,6 +2576,7 @@ impl EcucMultilineStringParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2338,6 +2609,7 @@ impl EcucMultilineStringParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -2359,6 +2631,7 @@ impl EcucMultilineStringParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2379,6 +2652,7 @@ impl EcucMultilineStringParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -2396,6 +2670,7 @@ impl EcucMultilineStringParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -2417,6 +2692,7 @@ impl EcucMultilineStringParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2449,6 +2725,7 @@ impl EcucMultilineStringParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -2464,6 +2741,7 @@ impl EcucMultilineStringParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2478,6 +2756,7 @@ impl EcucMultilineStringParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2496,6 +2775,7 @@ impl EcucMultilineStringParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -2508,6 +2788,8 @@ impl EcucMultilineStringParamDef {
     }
 }
 
+identifiable_pymethods!(EcucMultilineStringParamDef);
+
 //##################################################################
 
 /// `EcucStringParamDef` is used to specify a string parameter in the ECU configuration
@@ -2537,14 +2819,28 @@ impl EcucStringParamDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -2562,11 +2858,16 @@ impl EcucStringParamDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------- EcucAbstractStringParamDef -------
 
     /// set or remove the max length attribute
     #[setter]
     fn set_max_length(&self, max_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_max_length(max_length)
             .map_err(abstraction_err_to_pyerr)
@@ -2581,6 +2882,7 @@ impl EcucStringParamDef {
     /// set or remove the min length attribute
     #[setter]
     fn set_min_length(&self, min_length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_min_length(min_length)
             .map_err(abstraction_err_to_pyerr)
@@ -2596,6 +2898,7 @@ impl EcucStringParamDef {
     /// The regular expression is a string that is used to validate the string parameter
     #[setter]
     fn set_regular_expression(&self, regular_expression: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_regular_expression(regular_expression)
             .map_err(abstraction_err_to_pyerr)
@@ -2611,6 +2914,7 @@ impl EcucStringParamDef {
     /// set or remove the default value attribute
     #[setter]
     fn set_default_value(&self, default_value: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_default_value(default_value)
             .map_err(abstraction_err_to_pyerr)
@@ -2633,6 +2937,7 @@ impl EcucStringParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2665,6 +2970,7 @@ impl EcucStringParamDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -2686,6 +2992,7 @@ impl EcucStringParamDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2706,6 +3013,7 @@ impl EcucStringParamDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -2723,6 +3031,7 @@ impl EcucStringParamDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -2744,6 +3053,7 @@ impl EcucStringParamDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -2776,6 +3086,7 @@ impl EcucStringParamDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -2791,6 +3102,7 @@ impl EcucStringParamDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2805,6 +3117,7 @@ impl EcucStringParamDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -2823,6 +3136,7 @@ impl EcucStringParamDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -2835,6 +3149,8 @@ impl EcucStringParamDef {
     }
 }
 
+identifiable_pymethods!(EcucStringParamDef);
+
 //##################################################################
 
 iterator_wrapper!(EcucParameterDefIterator, Py<PyAny>, "EcucParameterDef");
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -46,14 +47,28 @@ impl EcucForeignReferenceDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -71,9 +86,14 @@ impl EcucForeignReferenceDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the destination type of the reference definition
     #[setter]
     fn set_destination_type(&self, destination_type: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_destination_type(destination_type)
             .map_err(abstraction_err_to_pyerr)
@@ -96,6 +116,7 @@ impl EcucForeignReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -128,6 +149,7 @@ impl EcucForeignReferenceDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -149,6 +171,7 @@ impl EcucForeignReferenceDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -169,6 +192,7 @@ impl EcucForeignReferenceDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -186,6 +210,7 @@ impl EcucForeignReferenceDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -207,6 +232,7 @@ impl EcucForeignReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -239,6 +265,7 @@ impl EcucForeignReferenceDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -254,6 +281,7 @@ impl EcucForeignReferenceDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -268,6 +296,7 @@ impl EcucForeignReferenceDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -286,6 +315,7 @@ impl EcucForeignReferenceDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -298,6 +328,8 @@ impl EcucForeignReferenceDef {
     }
 }
 
+identifiable_pymethods!(EcucForeignReferenceDef);
+
 //##################################################################
 
 /// The `EcucInstanceReferenceDef` specifies a reference to an XML description of an entity
@@ -328,14 +360,28 @@ impl EcucInstanceReferenceDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -353,9 +399,14 @@ impl EcucInstanceReferenceDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the destination type of the reference definition
     #[setter]
     fn set_destination_type(&self, destination_type: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_destination_type(destination_type)
             .map_err(abstraction_err_to_pyerr)
@@ -374,6 +425,7 @@ impl EcucInstanceReferenceDef {
     /// E.g. "SW-COMPONENT-PROTOTYPE* R-PORT-PROTOTYPE"
     #[setter]
     fn set_destination_context(&self, destination_context: Option<&str>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_destination_context(destination_context)
             .map_err(abstraction_err_to_pyerr)
@@ -398,6 +450,7 @@ impl EcucInstanceReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -430,6 +483,7 @@ impl EcucInstanceReferenceDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -451,6 +505,7 @@ impl EcucInstanceReferenceDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -471,6 +526,7 @@ impl EcucInstanceReferenceDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -488,6 +544,7 @@ impl EcucInstanceReferenceDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -509,6 +566,7 @@ impl EcucInstanceReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -541,6 +599,7 @@ impl EcucInstanceReferenceDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -556,6 +615,7 @@ impl EcucInstanceReferenceDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -570,6 +630,7 @@ impl EcucInstanceReferenceDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -588,6 +649,7 @@ impl EcucInstanceReferenceDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -600,6 +662,8 @@ impl EcucInstanceReferenceDef {
     }
 }
 
+identifiable_pymethods!(EcucInstanceReferenceDef);
+
 //##################################################################
 
 /// The `EcucChoiceReferenceDef` specifies alternative references where only one of the specified
@@ -630,14 +694,28 @@ impl EcucChoiceReferenceDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -655,8 +733,13 @@ impl EcucChoiceReferenceDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// add a reference to a destination container
     fn add_destination(&self, destination: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let destination = ecuc_container_def_from_pyany(destination)?;
         self.0
             .add_destination(&destination)
@@ -683,6 +766,7 @@ impl EcucChoiceReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -715,6 +799,7 @@ impl EcucChoiceReferenceDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -736,6 +821,7 @@ impl EcucChoiceReferenceDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -756,6 +842,7 @@ impl EcucChoiceReferenceDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -773,6 +860,7 @@ impl EcucChoiceReferenceDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -794,6 +882,7 @@ impl EcucChoiceReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -826,6 +915,7 @@ impl EcucChoiceReferenceDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -841,6 +931,7 @@ impl EcucChoiceReferenceDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -855,6 +946,7 @@ impl EcucChoiceReferenceDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -873,6 +965,7 @@ impl EcucChoiceReferenceDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -885,6 +978,8 @@ impl EcucChoiceReferenceDef {
     }
 }
 
+identifiable_pymethods!(EcucChoiceReferenceDef);
+
 //##################################################################
 
 /// The `EcuReferenceDef` specifies references between parameters in the ECU configuration.
@@ -914,14 +1009,28 @@ impl EcucReferenceDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -939,9 +1048,14 @@ impl EcucReferenceDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the destination container of the reference
     #[setter]
     fn set_destination(&self, destination: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let destination = destination.and_then(|d| ecuc_container_def_from_pyany(d).ok());
         self.0
             .set_destination(destination.as_ref())
@@ -967,6 +1081,7 @@ impl EcucReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -999,6 +1114,7 @@ impl EcucReferenceDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1020,6 +1136,7 @@ impl EcucReferenceDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1040,6 +1157,7 @@ impl EcucReferenceDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1057,6 +1175,7 @@ impl EcucReferenceDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -1078,6 +1197,7 @@ impl EcucReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1110,6 +1230,7 @@ impl EcucReferenceDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -1125,6 +1246,7 @@ impl EcucReferenceDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1139,6 +1261,7 @@ impl EcucReferenceDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1157,6 +1280,7 @@ impl EcucReferenceDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -1169,6 +1293,8 @@ impl EcucReferenceDef {
     }
 }
 
+identifiable_pymethods!(EcucReferenceDef);
+
 //##################################################################
 
 /// The `EcucUriReferenceDef` defines a reference with a destination that is specified via a destinationUri
@@ -1198,14 +1324,28 @@ impl EcucUriReferenceDef {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1223,9 +1363,14 @@ impl EcucUriReferenceDef {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the destination uri of the reference definition
     #[setter]
     fn set_destination_uri(&self, destination_uri: Option<&EcucDestinationUriDef>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let destination_uri = destination_uri.as_ref().map(|d| &d.0);
         self.0
             .set_destination_uri(destination_uri)
@@ -1249,6 +1394,7 @@ impl EcucUriReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1281,6 +1427,7 @@ impl EcucUriReferenceDef {
     /// Standardized parameters use the origin "AUTOSAR_ECUC", while vendors are supposed to use string like "VendorXyz_v1.3"
     #[setter]
     fn set_origin(&self, origin: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_origin(origin).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1302,6 +1449,7 @@ impl EcucUriReferenceDef {
         &self,
         post_build_variant_multiplicity: Option<bool>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_multiplicity(post_build_variant_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1322,6 +1470,7 @@ impl EcucUriReferenceDef {
     /// may have different values in different post-build variants.
     #[setter]
     fn set_post_build_variant_value(&self, post_build_variant_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_post_build_variant_value(post_build_variant_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1339,6 +1488,7 @@ impl EcucUriReferenceDef {
     /// set or remove the requiresIndex attribute
     #[setter]
     fn set_requires_index(&self, requires_index: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_requires_index(requires_index)
             .map_err(abstraction_err_to_pyerr)
@@ -1360,6 +1510,7 @@ impl EcucUriReferenceDef {
         &self,
         config: Vec<(EcucConfigurationClass, EcucConfigurationVariant)>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let config: Vec<_> = config
             .iter()
             .map(|(class, variant)| ((*class).into(), (*variant).into()))
@@ -1392,6 +1543,7 @@ impl EcucUriReferenceDef {
     /// If withAuto is true, then the parameter or reference is allowed to set its isAutoValue attribute to true.
     #[setter]
     fn set_with_auto(&self, with_auto: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_with_auto(with_auto)
             .map_err(abstraction_err_to_pyerr)
@@ -1407,6 +1559,7 @@ impl EcucUriReferenceDef {
     /// set or remove the lower multiplicity attribute
     #[setter]
     fn set_lower_multiplicity(&self, lower_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_lower_multiplicity(lower_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1421,6 +1574,7 @@ impl EcucUriReferenceDef {
     /// set or remove the upper multiplicity attribute
     #[setter]
     fn set_upper_multiplicity(&self, upper_multiplicity: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity(upper_multiplicity)
             .map_err(abstraction_err_to_pyerr)
@@ -1439,6 +1593,7 @@ impl EcucUriReferenceDef {
     /// When this attribute is true, the upper multiplicity attribute may not be used.
     #[setter]
     fn set_upper_multiplicity_infinite(&self, infinite: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_upper_multiplicity_infinite(infinite)
             .map_err(abstraction_err_to_pyerr)
@@ -1451,6 +1606,8 @@ impl EcucUriReferenceDef {
     }
 }
 
+identifiable_pymethods!(EcucUriReferenceDef);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -48,14 +49,28 @@ impl EcucValueCollection {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -73,6 +88,10 @@ impl EcucValueCollection {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add a reference to a module configuration to the collection
     #[pyo3(signature = (module_configuration, /))]
     #[pyo3(text_signature = "(self, module_configuration: EcucModuleConfigurationValues)")]
@@ -80,6 +99,7 @@ impl EcucValueCollection {
         &self,
         module_configuration: &EcucModuleConfigurationValues,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_module_configuration(&module_configuration.0)
             .map_err(abstraction_err_to_pyerr)
@@ -97,6 +117,7 @@ impl EcucValueCollection {
     /// Set the ecu extract reference, which links a `System` to the ECU configuration
     #[setter]
     fn set_ecu_extract_reference(&self, system: &System) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_ecu_extract_reference(&system.0)
             .map_err(abstraction_err_to_pyerr)
@@ -109,6 +130,8 @@ impl EcucValueCollection {
     }
 }
 
+identifiable_pymethods!(EcucValueCollection);
+
 //##################################################################
 
 /// The `EcucModuleConfigurationValues` is a container for the configuration of a single base software module
@@ -139,14 +162,28 @@ impl EcucModuleConfigurationValues {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -164,9 +201,14 @@ impl EcucModuleConfigurationValues {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the module definition reference
     #[setter]
     fn set_definition(&self, module_definition: &EcucModuleDef) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_definition(&module_definition.0)
             .map_err(abstraction_err_to_pyerr)
@@ -198,6 +240,7 @@ impl EcucModuleConfigurationValues {
         name: &str,
         definition: &Bound<'_, PyAny>,
     ) -> PyResult<EcucContainerValue> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = ecuc_container_def_from_pyany(definition)?;
         match self.0.create_container_value(name, &definition) {
             Ok(value) => Ok(EcucContainerValue(value)),
@@ -211,6 +254,8 @@ impl EcucModuleConfigurationValues {
     }
 }
 
+identifiable_pymethods!(EcucModuleConfigurationValues);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -247,14 +292,28 @@ impl EcucContainerValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -272,9 +331,14 @@ impl EcucContainerValue {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the container definition reference
     #[setter]
     fn set_definition(&self, definition: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = ecuc_container_def_from_pyany(definition)?;
         self.0
             .set_definition(&definition)
@@ -309,6 +373,7 @@ impl EcucContainerValue {
         name: &str,
         definition: &Bound<'_, PyAny>,
     ) -> PyResult<EcucContainerValue> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = ecuc_container_def_from_pyany(definition)?;
         match self.0.create_sub_container(name, &definition) {
             Ok(value) => Ok(EcucContainerValue(value)),
@@ -327,6 +392,7 @@ impl EcucContainerValue {
     /// must have an index. Otherwise the index is meaningless.
     #[setter]
     fn set_index(&self, index: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_index(index).map_err(abstraction_err_to_pyerr)
     }
 
@@ -347,6 +413,7 @@ impl EcucContainerValue {
         definition: &Bound<'_, PyAny>,
         value: &str,
     ) -> PyResult<EcucNumericalParamValue> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_parameter_def(definition)?;
         match self.0.create_numerical_param_value(&definition, value) {
             Ok(value) => Ok(EcucNumericalParamValue(value)),
@@ -362,6 +429,7 @@ impl EcucContainerValue {
         definition: &Bound<'_, PyAny>,
         value: &str,
     ) -> PyResult<EcucTextualParamValue> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_parameter_def(definition)?;
         match self.0.create_textual_param_value(&definition, value) {
             Ok(value) => Ok(EcucTextualParamValue(value)),
@@ -376,6 +444,7 @@ impl EcucContainerValue {
         &self,
         definition: &EcucAddInfoParamDef,
     ) -> PyResult<EcucAddInfoParamValue> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_add_info_param_value(&definition.0) {
             Ok(value) => Ok(EcucAddInfoParamValue(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -402,6 +471,7 @@ impl EcucContainerValue {
         target_context: Vec<Element>,
         target: &Element,
     ) -> PyResult<EcucInstanceReferenceValue> {
+        crate::frozen::check_element(self.0.element())?;
         let target_context = target_context.iter().map(|e| &e.0).collect::<Vec<_>>();
         match self
             .0
@@ -420,6 +490,7 @@ impl EcucContainerValue {
         definition: &Bound<'_, PyAny>,
         target: &Element,
     ) -> PyResult<EcucReferenceValue> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_reference_def(definition)?;
         match self.0.create_reference_value(&definition, &target.0) {
             Ok(value) => Ok(EcucReferenceValue(value)),
@@ -437,6 +508,8 @@ impl EcucContainerValue {
     }
 }
 
+identifiable_pymethods!(EcucContainerValue);
+
 //##################################################################
 
 iterator_wrapper!(EcucContainerValueIterator, EcucContainerValue);
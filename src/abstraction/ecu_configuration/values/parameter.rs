@@ -36,12 +36,25 @@ impl EcucAddInfoParamValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -50,6 +63,10 @@ impl EcucAddInfoParamValue {
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
 }
 
 //##################################################################
@@ -84,12 +101,25 @@ impl EcucNumericalParamValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -99,9 +129,14 @@ impl EcucNumericalParamValue {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the parameter definition reference
     #[setter]
     fn set_definition(&self, definition: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_parameter_def(definition)?;
         self.0
             .set_definition(&definition)
@@ -132,6 +167,7 @@ impl EcucNumericalParamValue {
     /// set the numerical value as a string
     #[setter]
     fn set_value(&self, value: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_value(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -165,6 +201,7 @@ impl EcucNumericalParamValue {
     /// must have an index. Otherwise the index is meaningless.
     #[setter]
     fn set_index(&self, index: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_index(index).map_err(abstraction_err_to_pyerr)
     }
 
@@ -182,6 +219,7 @@ impl EcucNumericalParamValue {
     /// If the parameter definition has `withAuto` set to `true`, then the parameter is allowed to have an auto value.
     #[setter]
     fn set_is_auto_value(&self, is_auto_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_auto_value(is_auto_value)
             .map_err(abstraction_err_to_pyerr)
@@ -224,12 +262,25 @@ impl EcucTextualParamValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -239,9 +290,14 @@ impl EcucTextualParamValue {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the parameter definition reference
     #[setter]
     fn set_definition(&self, definition: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_parameter_def(definition)?;
         self.0
             .set_definition(&definition)
@@ -273,6 +329,7 @@ impl EcucTextualParamValue {
     /// set the textual value
     #[setter]
     fn set_value(&self, value: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_value(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -288,6 +345,7 @@ impl EcucTextualParamValue {
     /// must have an index. Otherwise the index is meaningless.
     #[setter]
     fn set_index(&self, index: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_index(index).map_err(abstraction_err_to_pyerr)
     }
 
@@ -305,6 +363,7 @@ impl EcucTextualParamValue {
     /// If the parameter definition has `withAuto` set to `true`, then the parameter is allowed to have an auto value.
     #[setter]
     fn set_is_auto_value(&self, is_auto_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_auto_value(is_auto_value)
             .map_err(abstraction_err_to_pyerr)
@@ -38,12 +38,25 @@ impl EcucInstanceReferenceValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -53,9 +66,14 @@ impl EcucInstanceReferenceValue {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the parameter definition reference
     #[setter]
     fn set_definition(&self, definition: &EcucInstanceReferenceDef) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_definition(&definition.0)
             .map_err(abstraction_err_to_pyerr)
@@ -87,6 +105,7 @@ impl EcucInstanceReferenceValue {
     /// target element. The instance reference definition specifies which context elements are required.
     #[setter]
     fn set_target(&self, spec: (Vec<Element>, Element)) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let target = spec.1;
         let target_context: Vec<_> = spec.0.iter().map(|e| &e.0).collect();
         self.0
@@ -115,6 +134,7 @@ impl EcucInstanceReferenceValue {
     /// must have an index. Otherwise the index is meaningless.
     #[setter]
     fn set_index(&self, index: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_index(index).map_err(abstraction_err_to_pyerr)
     }
 
@@ -132,6 +152,7 @@ impl EcucInstanceReferenceValue {
     /// If the reference definition has `withAuto` set to `true`, then the reference is allowed to have an auto value.
     #[setter]
     fn set_is_auto_value(&self, is_auto_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_auto_value(is_auto_value)
             .map_err(abstraction_err_to_pyerr)
@@ -173,12 +194,25 @@ impl EcucReferenceValue {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[getter]
     fn element(&self) -> Element {
         Element(self.0.element().clone())
@@ -188,9 +222,14 @@ impl EcucReferenceValue {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the parameter definition reference
     #[setter]
     fn set_definition(&self, definition: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let definition = pyany_to_ecuc_reference_def(definition)?;
         self.0
             .set_definition(&definition)
@@ -221,6 +260,7 @@ impl EcucReferenceValue {
     /// Set the target of the reference
     #[setter]
     fn set_target(&self, target: &Element) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_target(&target.0)
             .map_err(abstraction_err_to_pyerr)
@@ -238,6 +278,7 @@ impl EcucReferenceValue {
     /// must have an index. Otherwise the index is meaningless.
     #[setter]
     fn set_index(&self, index: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_index(index).map_err(abstraction_err_to_pyerr)
     }
 
@@ -255,6 +296,7 @@ impl EcucReferenceValue {
     /// If the reference definition has `withAuto` set to `true`, then the reference is allowed to have an auto value.
     #[setter]
     fn set_is_auto_value(&self, is_auto_value: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_auto_value(is_auto_value)
             .map_err(abstraction_err_to_pyerr)
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::abstraction::{
     AutosarAbstractionError, abstraction_err_to_pyerr,
     communication::{
@@ -34,14 +35,28 @@ impl EcuInstance {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -59,6 +74,10 @@ impl EcuInstance {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a CAN-COMMUNICATION-CONTROLLER for this ECU-INSTANCE
     ///
     /// The ECU must have one controller per bus it communicates on.
@@ -69,6 +88,7 @@ impl EcuInstance {
         &self,
         name: &str,
     ) -> PyResult<CanCommunicationController> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_communication_controller(name) {
             Ok(value) => Ok(CanCommunicationController(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -86,6 +106,7 @@ impl EcuInstance {
         name: &str,
         mac_address: Option<String>,
     ) -> PyResult<EthernetCommunicationController> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_ethernet_communication_controller(name, mac_address)
@@ -105,6 +126,7 @@ impl EcuInstance {
         &self,
         name: &str,
     ) -> PyResult<FlexrayCommunicationController> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_communication_controller(name) {
             Ok(value) => Ok(FlexrayCommunicationController(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -143,6 +165,7 @@ impl EcuInstance {
     #[pyo3(signature = (group, /))]
     #[pyo3(text_signature = "(self, group: ISignalIPduGroup, /)")]
     fn add_associated_com_ipdu_group(&self, group: &ISignalIPduGroup) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_associated_com_ipdu_group(&group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -154,6 +177,8 @@ impl EcuInstance {
     }
 }
 
+identifiable_pymethods!(EcuInstance);
+
 //##################################################################
 
 iterator_wrapper!(
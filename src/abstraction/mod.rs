@@ -1,7 +1,11 @@
 use crate::{ArxmlFile, AutosarModel, AutosarVersion, Element, iterator_wrapper};
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::ElementName;
 use pyo3::PyTypeInfo;
 use pyo3::create_exception;
 use pyo3::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 mod communication;
 mod datatype;
@@ -10,11 +14,22 @@ mod software_component;
 
 mod arpackage;
 mod ecuinstance;
+mod name_index;
+mod remove_preview;
+mod sdg;
 mod system;
+mod variant;
+mod wrap;
 
 pub(crate) use arpackage::ArPackage;
 pub(crate) use ecuinstance::EcuInstance;
+pub(crate) use remove_preview::{RemovePreview, preview_remove};
+pub(crate) use sdg::Sdg;
 pub(crate) use system::System;
+pub(crate) use variant::PredefinedVariant;
+pub(crate) use wrap::wrap_or_element;
+
+use name_index::identifiables_by_name;
 
 create_exception!(
     module.abstraction,
@@ -22,6 +37,124 @@ create_exception!(
     pyo3::exceptions::PyException
 );
 
+// Every identifiable element can have SDGs in its ADMIN-DATA and the documentation fields LONG-NAME,
+// DESC and INTRODUCTION. The methods to access them are the same for all wrappers, so they are
+// generated by this macro in an additional #[pymethods] block (enabled by the pyo3 feature
+// multiple-pymethods). The wrapped type must implement AbstractionElement.
+macro_rules! identifiable_pymethods {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// get all SDGs in the ADMIN-DATA of this element
+            fn sdgs(&self) -> Vec<crate::abstraction::Sdg> {
+                crate::abstraction::sdg::sdgs(self.0.element())
+            }
+
+            /// get the SDG with the given GID from the ADMIN-DATA of this element
+            #[pyo3(signature = (gid, /))]
+            #[pyo3(text_signature = "(self, gid: str, /)")]
+            fn get_sdg(&self, gid: &str) -> Option<crate::abstraction::Sdg> {
+                crate::abstraction::sdg::get_sdg(self.0.element(), gid)
+            }
+
+            /// create or replace an SDG in the ADMIN-DATA of this element
+            ///
+            /// The SDG is given either as an `Sdg` object, or as a GID and a list of (gid, value) pairs.
+            /// String values become SDs and list values become nested SDGs; the order is kept.
+            #[pyo3(signature = (gid, values=None, /))]
+            #[pyo3(text_signature = "(self, gid: Union[str, Sdg], values: Optional[SdgValues] = None, /)")]
+            fn set_sdg(
+                &self,
+                gid: &Bound<'_, PyAny>,
+                values: Option<&Bound<'_, PyAny>>,
+            ) -> PyResult<crate::abstraction::Sdg> {
+                crate::abstraction::sdg::set_sdg(self.0.element(), gid, values)
+            }
+
+            /// get the LONG-NAME of this element as a dict of language -> text, optionally only in one language
+            #[pyo3(signature = (language=None))]
+            #[pyo3(text_signature = "(self, language: Optional[str] = None)")]
+            fn long_name(
+                &self,
+                language: Option<&str>,
+            ) -> std::collections::HashMap<String, String> {
+                crate::documentation::get_text(
+                    self.0.element(),
+                    crate::documentation::DocField::LongName,
+                    language,
+                )
+            }
+
+            /// set the LONG-NAME of this element in one language. If the text is None, this language is removed
+            #[pyo3(signature = (text, /, language="EN"))]
+            #[pyo3(text_signature = "(self, text: Optional[str], /, language: str = \"EN\")")]
+            fn set_long_name(&self, text: Option<&str>, language: &str) -> PyResult<()> {
+                crate::documentation::set_text(
+                    self.0.element(),
+                    crate::documentation::DocField::LongName,
+                    text,
+                    language,
+                )
+            }
+
+            /// get the DESC of this element as a dict of language -> text, optionally only in one language
+            #[pyo3(signature = (language=None))]
+            #[pyo3(text_signature = "(self, language: Optional[str] = None)")]
+            fn desc(&self, language: Option<&str>) -> std::collections::HashMap<String, String> {
+                crate::documentation::get_text(
+                    self.0.element(),
+                    crate::documentation::DocField::Desc,
+                    language,
+                )
+            }
+
+            /// set the DESC of this element in one language. If the text is None, this language is removed
+            #[pyo3(signature = (text, /, language="EN"))]
+            #[pyo3(text_signature = "(self, text: Optional[str], /, language: str = \"EN\")")]
+            fn set_desc(&self, text: Option<&str>, language: &str) -> PyResult<()> {
+                crate::documentation::set_text(
+                    self.0.element(),
+                    crate::documentation::DocField::Desc,
+                    text,
+                    language,
+                )
+            }
+
+            /// get the INTRODUCTION of this element as a dict of language -> text, optionally only in one language
+            ///
+            /// Paragraphs are separated by newlines.
+            #[pyo3(signature = (language=None))]
+            #[pyo3(text_signature = "(self, language: Optional[str] = None)")]
+            fn introduction(
+                &self,
+                language: Option<&str>,
+            ) -> std::collections::HashMap<String, String> {
+                crate::documentation::get_text(
+                    self.0.element(),
+                    crate::documentation::DocField::Introduction,
+                    language,
+                )
+            }
+
+            /// set the INTRODUCTION of this element in one language. If the text is None, this language is removed
+            ///
+            /// Each line becomes a separate paragraph.
+            #[pyo3(signature = (text, /, language="EN"))]
+            #[pyo3(text_signature = "(self, text: Optional[str], /, language: str = \"EN\")")]
+            fn set_introduction(&self, text: Option<&str>, language: &str) -> PyResult<()> {
+                crate::documentation::set_text(
+                    self.0.element(),
+                    crate::documentation::DocField::Introduction,
+                    text,
+                    language,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use identifiable_pymethods;
+
 //##################################################################
 
 #[pyclass(
@@ -61,8 +194,11 @@ impl AutosarModelAbstraction {
     #[pyo3(signature = (filename, /))]
     #[pyo3(text_signature = "(cls, filename: str, /)")]
     fn from_file(filename: &str) -> PyResult<Self> {
-        match autosar_data_abstraction::AutosarModelAbstraction::from_file(filename) {
-            Ok(model_abstraction) => Ok(Self(model_abstraction)),
+        let model = autosar_data_rs::AutosarModel::new();
+        match crate::model::load_file(&model, filename, false) {
+            Ok(_) => Ok(Self(
+                autosar_data_abstraction::AutosarModelAbstraction::new(model),
+            )),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
@@ -88,6 +224,7 @@ impl AutosarModelAbstraction {
     #[pyo3(signature = (path, /))]
     #[pyo3(text_signature = "(path: str, /)")]
     fn get_or_create_package(&self, path: &str) -> PyResult<ArPackage> {
+        crate::frozen::check_model(self.0.model())?;
         match self.0.get_or_create_package(path) {
             Ok(package) => Ok(ArPackage(package)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -98,6 +235,7 @@ impl AutosarModelAbstraction {
     #[pyo3(signature = (filename, /, *, version=None))]
     #[pyo3(text_signature = "(filename: str, /, *, version: Optional[AutosarVersion] = None)")]
     fn create_file(&self, filename: &str, version: Option<AutosarVersion>) -> PyResult<ArxmlFile> {
+        crate::frozen::check_model(self.0.model())?;
         let version = version.unwrap_or(AutosarVersion::Latest);
         match self.0.create_file(filename, version.into()) {
             Ok(file) => Ok(ArxmlFile(file)),
@@ -109,7 +247,8 @@ impl AutosarModelAbstraction {
     #[pyo3(signature = (filename, /, *, strict=false))]
     #[pyo3(text_signature = "(filename: str, /, * strict: bool = False)")]
     fn load_file(&self, filename: &str, strict: bool) -> PyResult<(ArxmlFile, Vec<String>)> {
-        match self.0.load_file(filename, strict) {
+        crate::frozen::check_model(self.0.model())?;
+        match crate::model::load_file(self.0.model(), filename, strict) {
             Ok((file, warn)) => {
                 let warnstrings: Vec<String> =
                     warn.iter().map(std::string::ToString::to_string).collect();
@@ -126,7 +265,7 @@ impl AutosarModelAbstraction {
 
     /// write the model to disk, creating or updating all files in the model
     fn write(&self) -> PyResult<()> {
-        self.0.write().map_err(abstraction_err_to_pyerr)
+        crate::model::write_model(self.0.model()).map_err(AutosarAbstractionError::new_err)
     }
 
     /// Get an element by its path
@@ -136,17 +275,262 @@ impl AutosarModelAbstraction {
         self.0.get_element_by_path(path).map(Element)
     }
 
+    /// Get an element by its path, wrapped in the matching abstraction class
+    ///
+    /// If there is no abstraction class for the element, then the plain `Element` is returned.
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_typed_element_by_path(&self, py: Python<'_>, path: &str) -> PyResult<Option<Py<PyAny>>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| wrap_or_element(py, element))
+            .transpose()
+    }
+
     /// find an existing SYSTEM in the model, if it exists
     #[pyo3(text_signature = "(self, /)")]
     fn find_system(&self) -> Option<System> {
         self.0.find_system().map(System)
     }
 
+    /// find all SYSTEMs in the model, optionally only those with the given category
+    #[pyo3(signature = (/, *, category=None))]
+    #[pyo3(text_signature = "(self, /, *, category: Optional[SystemCategory] = None)")]
+    fn systems(&self, category: Option<system::SystemCategory>) -> Vec<System> {
+        identifiables_by_name(self.0.model(), &[ElementName::System])
+            .into_iter()
+            .filter_map(|element| autosar_data_abstraction::System::try_from(element).ok())
+            .filter(|system| {
+                category.is_none()
+                    || system.category().map(system::SystemCategory::from) == category
+            })
+            .map(System)
+            .collect()
+    }
+
+    /// get the I-SIGNAL with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not an I-SIGNAL
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_isignal(&self, path: &str) -> PyResult<Option<communication::ISignal>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| {
+                autosar_data_abstraction::communication::ISignal::try_from(element)
+                    .map(communication::ISignal)
+                    .map_err(abstraction_err_to_pyerr)
+            })
+            .transpose()
+    }
+
+    /// get the ECU-INSTANCE with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not an ECU-INSTANCE
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_ecu_instance(&self, path: &str) -> PyResult<Option<EcuInstance>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| {
+                autosar_data_abstraction::EcuInstance::try_from(element)
+                    .map(EcuInstance)
+                    .map_err(abstraction_err_to_pyerr)
+            })
+            .transpose()
+    }
+
+    /// get the SYSTEM with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not a SYSTEM
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_system(&self, path: &str) -> PyResult<Option<System>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| {
+                autosar_data_abstraction::System::try_from(element)
+                    .map(System)
+                    .map_err(abstraction_err_to_pyerr)
+            })
+            .transpose()
+    }
+
+    /// get the SYSTEM-SIGNAL with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not a SYSTEM-SIGNAL
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_system_signal(&self, path: &str) -> PyResult<Option<communication::SystemSignal>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| {
+                autosar_data_abstraction::communication::SystemSignal::try_from(element)
+                    .map(communication::SystemSignal)
+                    .map_err(abstraction_err_to_pyerr)
+            })
+            .transpose()
+    }
+
+    /// get the COMPU-METHOD with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not a COMPU-METHOD
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_compu_method(&self, path: &str) -> PyResult<Option<datatype::CompuMethod>> {
+        self.0
+            .get_element_by_path(path)
+            .map(|element| {
+                autosar_data_abstraction::datatype::CompuMethod::try_from(element)
+                    .map(datatype::CompuMethod)
+                    .map_err(abstraction_err_to_pyerr)
+            })
+            .transpose()
+    }
+
+    /// get the software component type with the given path
+    ///
+    /// Returns None if the path does not exist, and raises an error if the element is not a software component type
+    #[pyo3(signature = (path, /))]
+    #[pyo3(text_signature = "(self, path: str, /)")]
+    fn get_sw_component_type(&self, py: Python<'_>, path: &str) -> PyResult<Option<Py<PyAny>>> {
+        let Some(element) = self.0.get_element_by_path(path) else {
+            return Ok(None);
+        };
+        if !SW_COMPONENT_TYPES.contains(&element.element_name()) {
+            return Err(AutosarAbstractionError::new_err(format!(
+                "The element at {path} is a {} and not a software component type",
+                element.element_name()
+            )));
+        }
+        wrap_or_element(py, element).map(Some)
+    }
+
+    /// get all I-SIGNALs in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_isignals(&self) -> Vec<communication::ISignal> {
+        identifiables_by_name(self.0.model(), &[ElementName::ISignal])
+            .into_iter()
+            .filter_map(|element| {
+                autosar_data_abstraction::communication::ISignal::try_from(element).ok()
+            })
+            .map(communication::ISignal)
+            .collect()
+    }
+
+    /// get all ECU-INSTANCEs in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_ecu_instances(&self) -> Vec<EcuInstance> {
+        identifiables_by_name(self.0.model(), &[ElementName::EcuInstance])
+            .into_iter()
+            .filter_map(|element| autosar_data_abstraction::EcuInstance::try_from(element).ok())
+            .map(EcuInstance)
+            .collect()
+    }
+
+    /// get all software component types in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_sw_component_types(&self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
+        identifiables_by_name(self.0.model(), &SW_COMPONENT_TYPES)
+            .into_iter()
+            .map(|element| wrap_or_element(py, element))
+            .collect()
+    }
+
+    /// get all SYSTEM-SIGNALs in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_system_signals(&self) -> Vec<communication::SystemSignal> {
+        identifiables_by_name(self.0.model(), &[ElementName::SystemSignal])
+            .into_iter()
+            .filter_map(|element| {
+                autosar_data_abstraction::communication::SystemSignal::try_from(element).ok()
+            })
+            .map(communication::SystemSignal)
+            .collect()
+    }
+
+    /// get all frames in the model, sorted by path
+    ///
+    /// Frames without an abstraction class are returned as plain `Element`s.
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_frames(&self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
+        identifiables_by_name(self.0.model(), &FRAMES)
+            .into_iter()
+            .map(|element| wrap_or_element(py, element))
+            .collect()
+    }
+
+    /// get all PDUs in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_pdus(&self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
+        identifiables_by_name(self.0.model(), &PDUS)
+            .into_iter()
+            .map(|element| wrap_or_element(py, element))
+            .collect()
+    }
+
+    /// get all COMPU-METHODs in the model, sorted by path
+    #[pyo3(text_signature = "(self, /)")]
+    fn all_compu_methods(&self) -> Vec<datatype::CompuMethod> {
+        identifiables_by_name(self.0.model(), &[ElementName::CompuMethod])
+            .into_iter()
+            .filter_map(|element| {
+                autosar_data_abstraction::datatype::CompuMethod::try_from(element).ok()
+            })
+            .map(datatype::CompuMethod)
+            .collect()
+    }
+
+    /// get all PREDEFINED-VARIANTs in the model
+    #[pyo3(text_signature = "(self, /)")]
+    fn predefined_variants(&self) -> Vec<PredefinedVariant> {
+        variant::predefined_variants(self.0.model())
+    }
+
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.0.model().hash(&mut hasher);
+        hasher.finish() as isize
+    }
 }
 
+/// element names of all software component types
+const SW_COMPONENT_TYPES: [ElementName; 6] = [
+    ElementName::ApplicationSwComponentType,
+    ElementName::ComplexDeviceDriverSwComponentType,
+    ElementName::CompositionSwComponentType,
+    ElementName::EcuAbstractionSwComponentType,
+    ElementName::SensorActuatorSwComponentType,
+    ElementName::ServiceSwComponentType,
+];
+
+/// element names of all frames
+const FRAMES: [ElementName; 5] = [
+    ElementName::CanFrame,
+    ElementName::FlexrayFrame,
+    ElementName::LinEventTriggeredFrame,
+    ElementName::LinSporadicFrame,
+    ElementName::LinUnconditionalFrame,
+];
+
+/// element names of all PDUs
+const PDUS: [ElementName; 10] = [
+    ElementName::ContainerIPdu,
+    ElementName::DcmIPdu,
+    ElementName::GeneralPurposeIPdu,
+    ElementName::GeneralPurposePdu,
+    ElementName::ISignalIPdu,
+    ElementName::MultiplexedIPdu,
+    ElementName::NPdu,
+    ElementName::NmPdu,
+    ElementName::SecuredIPdu,
+    ElementName::UserDefinedPdu,
+];
+
 //##################################################################
 
 iterator_wrapper!(ArPackageIterator, ArPackage);
@@ -208,11 +592,16 @@ pub(crate) fn add_submodules(py: Python<'_>, parent: &Bound<'_, PyModule>) -> Py
     abstraction.add_class::<ByteOrder>()?;
     abstraction.add_class::<arpackage::ArPackage>()?;
     abstraction.add_class::<ecuinstance::EcuInstance>()?;
+    abstraction.add_class::<variant::PredefinedVariant>()?;
+    abstraction.add_class::<remove_preview::RemovePreview>()?;
+    abstraction.add_class::<sdg::Sd>()?;
+    abstraction.add_class::<sdg::Sdg>()?;
     abstraction.add_class::<system::SenderReceiverToSignalMapping>()?;
     abstraction.add_class::<system::SwcToEcuMapping>()?;
     abstraction.add_class::<system::System>()?;
     abstraction.add_class::<system::SystemCategory>()?;
     abstraction.add_class::<system::SystemMapping>()?;
+    abstraction.add_function(wrap_pyfunction!(wrap::wrap, &abstraction)?)?;
 
     let communication = PyModule::new(py, "_communication")?;
     abstraction.add_submodule(&communication)?;
@@ -691,3 +1080,40 @@ pub(crate) fn abstraction_err_to_pyerr(
 ) -> PyErr {
     AutosarAbstractionError::new_err(err.to_string())
 }
+
+/// find an existing element for one of the `get_or_create_*` methods
+///
+/// The element is searched by its name inside the `container_name` sub element of `parent`.
+/// If an element with this name exists, but it does not have the expected element name, then an error is returned.
+pub(crate) fn find_existing_element(
+    parent: &autosar_data_rs::Element,
+    container_name: ElementName,
+    name: &str,
+    expected: ElementName,
+) -> PyResult<Option<autosar_data_rs::Element>> {
+    let existing = parent
+        .get_sub_element(container_name)
+        .and_then(|container| {
+            container
+                .sub_elements()
+                .find(|element| element.item_name().as_deref() == Some(name))
+        });
+    match existing {
+        Some(existing) if existing.element_name() != expected => Err(incompatible_element_err(
+            &existing,
+            &format!(
+                "it is a {} instead of a {expected}",
+                existing.element_name()
+            ),
+        )),
+        existing => Ok(existing),
+    }
+}
+
+/// create the error of a `get_or_create_*` method, if the existing element is not compatible with the requested one
+pub(crate) fn incompatible_element_err(existing: &autosar_data_rs::Element, reason: &str) -> PyErr {
+    let path = existing
+        .path()
+        .unwrap_or_else(|_| existing.item_name().unwrap_or_default());
+    AutosarAbstractionError::new_err(format!("{path} already exists, but {reason}"))
+}
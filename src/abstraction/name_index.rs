@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{AutosarModel, Element, ElementName, WeakElement};
+
+use crate::{is_model_key, model_key};
+
+// The typed lookups of the abstraction model need all identifiable elements of a given type. Instead of
+// scanning the identifiable index of the model on each call, the elements are grouped by element name
+// here. The element name of an element never changes, so the index stays valid as long as the same
+// elements are identifiable under the same paths. The path index of the model is maintained by the
+// autosar_data crate for every modification, so a fingerprint of it reliably detects outdated entries.
+static NAME_INDEX: Mutex<Vec<NameIndex>> = Mutex::new(Vec::new());
+
+struct NameIndex {
+    /// identifies the model, see `model_key`
+    model: WeakElement,
+    /// the fingerprint of the path index of the model at the time when the index was built
+    fingerprint: u64,
+    /// the identifiable elements of each element name, sorted by path
+    entries: HashMap<ElementName, Vec<WeakElement>>,
+}
+
+/// get all identifiable elements with one of the given element names, sorted by path
+pub(crate) fn identifiables_by_name(
+    model: &AutosarModel,
+    element_names: &[ElementName],
+) -> Vec<Element> {
+    let mut index = NAME_INDEX
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // drop the entries of models that no longer exist
+    index.retain(|entry| {
+        entry
+            .model
+            .upgrade()
+            .is_some_and(|root| root.model().is_ok())
+    });
+
+    let fingerprint = identifiables_fingerprint(model);
+    let position = index
+        .iter()
+        .position(|entry| is_model_key(&entry.model, model));
+    let model_index = match position {
+        Some(position) if index[position].fingerprint == fingerprint => &index[position],
+        _ => {
+            // the index is missing or outdated
+            let entry = NameIndex {
+                model: model_key(model),
+                fingerprint,
+                entries: build_index(model),
+            };
+            if let Some(position) = position {
+                index.remove(position);
+            }
+            index.push(entry);
+            &index[index.len() - 1]
+        }
+    };
+
+    if let [element_name] = element_names {
+        // the entries of a single element name are already sorted
+        return model_index
+            .entries
+            .get(element_name)
+            .into_iter()
+            .flatten()
+            .filter_map(WeakElement::upgrade)
+            .collect();
+    }
+    let mut elements: Vec<(String, Element)> = element_names
+        .iter()
+        .filter_map(|element_name| model_index.entries.get(element_name))
+        .flatten()
+        .filter_map(WeakElement::upgrade)
+        .filter_map(|element| Some((element.path().ok()?, element)))
+        .collect();
+    elements.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+    elements.into_iter().map(|(_, element)| element).collect()
+}
+
+/// hash the paths of all identifiable elements in the model together with the elements themselves
+fn identifiables_fingerprint(model: &AutosarModel) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for (path, element) in model.identifiable_elements() {
+        path.hash(&mut hasher);
+        element.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn build_index(model: &AutosarModel) -> HashMap<ElementName, Vec<WeakElement>> {
+    let mut elements: Vec<(String, Element)> = model
+        .identifiable_elements()
+        .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+        .collect();
+    elements.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+    let mut entries: HashMap<ElementName, Vec<WeakElement>> = HashMap::new();
+    for (_, element) in elements {
+        entries
+            .entry(element.element_name())
+            .or_default()
+            .push(element.downgrade());
+    }
+    entries
+}
@@ -0,0 +1,164 @@
+use crate::{ArxmlFile, AutosarDataError, Element};
+use ::autosar_data as autosar_data_rs;
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+//##################################################################
+
+/// The effect that `remove` would have on the model, as returned by `preview_remove`
+#[pyclass(
+    skip_from_py_object,
+    frozen,
+    module = "autosar_data._autosar_data._abstraction"
+)]
+pub(crate) struct RemovePreview {
+    elements: Vec<autosar_data_rs::Element>,
+    dangling_references: Vec<autosar_data_rs::Element>,
+    files: Vec<autosar_data_rs::ArxmlFile>,
+}
+
+#[pymethods]
+impl RemovePreview {
+    /// the elements that would be removed; elements inside of removed elements are not listed separately
+    #[getter]
+    fn elements(&self) -> Vec<Element> {
+        self.elements.iter().cloned().map(Element).collect()
+    }
+
+    /// the reference elements that would point to missing elements after the removal
+    #[getter]
+    fn dangling_references(&self) -> Vec<Element> {
+        self.dangling_references
+            .iter()
+            .cloned()
+            .map(Element)
+            .collect()
+    }
+
+    /// the files that contain removed elements or dangling references
+    #[getter]
+    fn files(&self) -> Vec<ArxmlFile> {
+        self.files.iter().cloned().map(ArxmlFile).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        let elements: Vec<String> = self.elements.iter().map(describe_element).collect();
+        let dangling_references: Vec<String> = self
+            .dangling_references
+            .iter()
+            .map(describe_element)
+            .collect();
+        let files: Vec<String> = self
+            .files
+            .iter()
+            .map(|file| file.filename().display().to_string())
+            .collect();
+        format!(
+            "RemovePreview {{ elements: {elements:?}, dangling_references: {dangling_references:?}, files: {files:?} }}"
+        )
+    }
+}
+
+//##################################################################
+
+/// preview the effect of removing an element, without modifying the model
+///
+/// The removal is performed on a copy of the model. The copy has the same element order as the original,
+/// so the depth-first position of each element is used to map the results back to the original model.
+/// The references are checked before and after the removal, so that only newly broken references are reported.
+// elements are hashed by their identity, so the interior mutability of the key type does not matter
+#[allow(clippy::mutable_key_type)]
+pub(crate) fn preview_remove(
+    element: &autosar_data_rs::Element,
+    remove: impl FnOnce(&Element) -> PyResult<()>,
+) -> PyResult<RemovePreview> {
+    let model = element
+        .model()
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+    let copy = model
+        .duplicate()
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+
+    let originals: Vec<autosar_data_rs::Element> =
+        model.elements_dfs().map(|(_, elem)| elem).collect();
+    let copies: Vec<autosar_data_rs::Element> = copy.elements_dfs().map(|(_, elem)| elem).collect();
+    let position = originals
+        .iter()
+        .position(|original| original == element)
+        .ok_or_else(|| AutosarDataError::new_err("The element is not part of the model"))?;
+    let copy_to_original: HashMap<autosar_data_rs::Element, autosar_data_rs::Element> = copies
+        .iter()
+        .cloned()
+        .zip(originals.iter().cloned())
+        .collect();
+    let broken_before: HashSet<autosar_data_rs::Element> = copy
+        .check_references()
+        .iter()
+        .filter_map(|weak| weak.upgrade())
+        .collect();
+
+    remove(&Element(copies[position].clone()))?;
+
+    let remaining: HashSet<autosar_data_rs::Element> =
+        copy.elements_dfs().map(|(_, elem)| elem).collect();
+    let removed: HashSet<&autosar_data_rs::Element> = copies
+        .iter()
+        .zip(originals.iter())
+        .filter(|(elem, _)| !remaining.contains(*elem))
+        .map(|(_, original)| original)
+        .collect();
+    // only report the top-most removed elements; their content is removed along with them
+    let elements: Vec<autosar_data_rs::Element> = originals
+        .iter()
+        .filter(|original| removed.contains(original))
+        .filter(|original| {
+            !original
+                .parent()
+                .ok()
+                .flatten()
+                .is_some_and(|parent| removed.contains(&parent))
+        })
+        .cloned()
+        .collect();
+    let dangling_references: Vec<autosar_data_rs::Element> = copy
+        .check_references()
+        .iter()
+        .filter_map(|weak| weak.upgrade())
+        .filter(|reference| !broken_before.contains(reference))
+        .filter_map(|reference| copy_to_original.get(&reference).cloned())
+        .collect();
+
+    let mut files: Vec<autosar_data_rs::ArxmlFile> = Vec::new();
+    for elem in elements.iter().chain(dangling_references.iter()) {
+        for file in element_files(&model, elem) {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+    }
+    files.sort_by_key(|file| file.filename());
+
+    Ok(RemovePreview {
+        elements,
+        dangling_references,
+        files,
+    })
+}
+
+/// get the files that contain an element; an empty file membership means that the element is in all files
+fn element_files(
+    model: &autosar_data_rs::AutosarModel,
+    element: &autosar_data_rs::Element,
+) -> Vec<autosar_data_rs::ArxmlFile> {
+    match element.file_membership() {
+        Ok((_, file_set)) if !file_set.is_empty() => {
+            file_set.iter().filter_map(|weak| weak.upgrade()).collect()
+        }
+        _ => model.files().collect(),
+    }
+}
+
+/// describe an element by its Autosar path, or by its xml path if it is not identifiable
+fn describe_element(element: &autosar_data_rs::Element) -> String {
+    element.path().unwrap_or_else(|_| element.xml_path())
+}
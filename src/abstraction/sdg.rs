@@ -0,0 +1,278 @@
+use crate::abstraction::AutosarAbstractionError;
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{AttributeName, CharacterData, ElementName};
+use pyo3::IntoPyObject;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+//##################################################################
+
+/// A special data group (SDG) in the ADMIN-DATA of an element
+///
+/// SDGs store data that is not covered by the Autosar standard, e.g. vendor or OEM specific attributes.
+#[pyclass(
+    skip_from_py_object,
+    frozen,
+    eq,
+    module = "autosar_data._autosar_data._abstraction"
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Sdg {
+    gid: String,
+    content: Vec<SdgContent>,
+}
+
+/// an item inside of an SDG: either an SD or a nested SDG
+#[derive(Debug, Clone, PartialEq, Eq, IntoPyObject)]
+enum SdgContent {
+    Sd(Sd),
+    Sdg(Sdg),
+}
+
+#[pymethods]
+impl Sdg {
+    /// the GID of the SDG
+    #[getter]
+    fn gid(&self) -> &str {
+        &self.gid
+    }
+
+    /// the SDs and nested SDGs of the SDG in their original order
+    #[getter]
+    fn content(&self) -> Vec<SdgContent> {
+        self.content.clone()
+    }
+
+    /// the special data (SD) items of the SDG
+    #[getter]
+    fn sds(&self) -> Vec<Sd> {
+        self.iter_sds().cloned().collect()
+    }
+
+    /// the nested SDGs
+    #[getter]
+    fn sdgs(&self) -> Vec<Sdg> {
+        self.iter_sdgs().cloned().collect()
+    }
+
+    /// get the value of the first SD with the given GID
+    #[pyo3(signature = (gid, /))]
+    #[pyo3(text_signature = "(self, gid: str, /)")]
+    fn get_sd(&self, gid: &str) -> Option<String> {
+        self.iter_sds()
+            .find(|sd| sd.gid == gid)
+            .map(|sd| sd.value.clone())
+    }
+
+    /// get the first nested SDG with the given GID
+    #[pyo3(signature = (gid, /))]
+    #[pyo3(text_signature = "(self, gid: str, /)")]
+    fn get_sdg(&self, gid: &str) -> Option<Sdg> {
+        self.iter_sdgs().find(|sdg| sdg.gid == gid).cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:#?}")
+    }
+}
+
+impl Sdg {
+    fn iter_sds(&self) -> impl Iterator<Item = &Sd> {
+        self.content.iter().filter_map(|item| match item {
+            SdgContent::Sd(sd) => Some(sd),
+            SdgContent::Sdg(_) => None,
+        })
+    }
+
+    fn iter_sdgs(&self) -> impl Iterator<Item = &Sdg> {
+        self.content.iter().filter_map(|item| match item {
+            SdgContent::Sd(_) => None,
+            SdgContent::Sdg(sdg) => Some(sdg),
+        })
+    }
+}
+
+/// A special data (SD) item inside of an SDG
+#[pyclass(
+    skip_from_py_object,
+    frozen,
+    get_all,
+    eq,
+    module = "autosar_data._autosar_data._abstraction"
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Sd {
+    /// the GID of the SD
+    gid: String,
+    /// the value of the SD
+    value: String,
+}
+
+#[pymethods]
+impl Sd {
+    fn __repr__(&self) -> String {
+        format!("{self:#?}")
+    }
+}
+
+//##################################################################
+
+/// get all SDGs in the ADMIN-DATA of an element
+pub(crate) fn sdgs(element: &autosar_data_rs::Element) -> Vec<Sdg> {
+    sdg_elements(element).map(|sdg| read_sdg(&sdg)).collect()
+}
+
+/// get the first SDG with the given GID in the ADMIN-DATA of an element
+pub(crate) fn get_sdg(element: &autosar_data_rs::Element, gid: &str) -> Option<Sdg> {
+    sdg_elements(element)
+        .find(|sdg| gid_of(sdg) == gid)
+        .map(|sdg| read_sdg(&sdg))
+}
+
+/// create or replace an SDG in the ADMIN-DATA of an element
+///
+/// The SDG is either given as an `Sdg` object, or as a GID together with its values.
+/// The values are a list of (gid, value) pairs or a dict; string values become SDs, and
+/// list or dict values become nested SDGs. The items are written in the given order.
+/// An existing SDG with the same GID is replaced in place.
+pub(crate) fn set_sdg(
+    element: &autosar_data_rs::Element,
+    gid: &Bound<'_, PyAny>,
+    values: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Sdg> {
+    crate::frozen::check_element(element)?;
+    // convert the values first, so that the model is not modified if they are invalid
+    let sdg = match (gid.cast::<Sdg>(), values) {
+        (Ok(sdg), None) => sdg.get().clone(),
+        (Err(_), Some(values)) => Sdg {
+            gid: gid.extract()?,
+            content: values_to_content(values)?,
+        },
+        _ => {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "set_sdg expects either an Sdg, or a gid and the values of the SDG",
+            ));
+        }
+    };
+
+    let sdgs_element = element
+        .get_or_create_sub_element(ElementName::AdminData)
+        .and_then(|admin_data| admin_data.get_or_create_sub_element(ElementName::Sdgs))
+        .map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+    let existing = sdgs_element
+        .sub_elements()
+        .find(|sdg_element| gid_of(sdg_element) == sdg.gid);
+    let result = match existing {
+        Some(existing) => {
+            let position = existing.position().unwrap_or_default();
+            sdgs_element
+                .remove_sub_element(existing)
+                .and_then(|()| sdgs_element.create_sub_element_at(ElementName::Sdg, position))
+        }
+        None => sdgs_element.create_sub_element(ElementName::Sdg),
+    }
+    .and_then(|sdg_element| write_sdg(&sdg_element, &sdg));
+    result.map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+
+    Ok(sdg)
+}
+
+fn sdg_elements(
+    element: &autosar_data_rs::Element,
+) -> impl Iterator<Item = autosar_data_rs::Element> {
+    element
+        .get_sub_element(ElementName::AdminData)
+        .and_then(|admin_data| admin_data.get_sub_element(ElementName::Sdgs))
+        .into_iter()
+        .flat_map(|sdgs| sdgs.sub_elements())
+        .filter(|sdg| sdg.element_name() == ElementName::Sdg)
+}
+
+fn gid_of(element: &autosar_data_rs::Element) -> String {
+    element
+        .attribute_value(AttributeName::Gid)
+        .map(|gid| gid.to_string())
+        .unwrap_or_default()
+}
+
+fn read_sdg(sdg_element: &autosar_data_rs::Element) -> Sdg {
+    let content = sdg_element
+        .sub_elements()
+        .filter_map(|sub_element| match sub_element.element_name() {
+            ElementName::Sd => Some(SdgContent::Sd(Sd {
+                gid: gid_of(&sub_element),
+                value: sub_element
+                    .character_data()
+                    .map(|value| value.to_string())
+                    .unwrap_or_default(),
+            })),
+            ElementName::Sdg => Some(SdgContent::Sdg(read_sdg(&sub_element))),
+            _ => None,
+        })
+        .collect();
+    Sdg {
+        gid: gid_of(sdg_element),
+        content,
+    }
+}
+
+fn write_sdg(
+    sdg_element: &autosar_data_rs::Element,
+    sdg: &Sdg,
+) -> Result<(), autosar_data_rs::AutosarDataError> {
+    sdg_element.set_attribute(AttributeName::Gid, CharacterData::String(sdg.gid.clone()))?;
+    for item in &sdg.content {
+        match item {
+            SdgContent::Sd(sd) => {
+                let sd_element = sdg_element.create_sub_element(ElementName::Sd)?;
+                sd_element
+                    .set_attribute(AttributeName::Gid, CharacterData::String(sd.gid.clone()))?;
+                sd_element.set_character_data(CharacterData::String(sd.value.clone()))?;
+            }
+            SdgContent::Sdg(nested_sdg) => {
+                let nested_element = sdg_element.create_sub_element(ElementName::Sdg)?;
+                write_sdg(&nested_element, nested_sdg)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// convert a list of (gid, value) pairs or a dict into the content of an SDG
+fn values_to_content(values: &Bound<'_, PyAny>) -> PyResult<Vec<SdgContent>> {
+    if let Ok(dict) = values.cast::<PyDict>() {
+        dict.iter()
+            .map(|(gid, value)| item_to_content(&gid, &value))
+            .collect()
+    } else if let Ok(list) = values.cast::<PyList>() {
+        list.iter()
+            .map(|item| {
+                let (gid, value): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item.extract()?;
+                item_to_content(&gid, &value)
+            })
+            .collect()
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "the values of an SDG must be a list of (gid, value) pairs or a dict, not '{}'",
+            values.get_type()
+        )))
+    }
+}
+
+fn item_to_content(gid: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<SdgContent> {
+    let gid: String = gid.extract()?;
+    if let Ok(text) = value.cast::<PyString>() {
+        Ok(SdgContent::Sd(Sd {
+            gid,
+            value: text.to_str()?.to_string(),
+        }))
+    } else if value.is_instance_of::<PyDict>() || value.is_instance_of::<PyList>() {
+        let content = values_to_content(value)?;
+        Ok(SdgContent::Sdg(Sdg { gid, content }))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "the value of \"{gid}\" must be a string, a list or a dict, not '{}'",
+            value.get_type()
+        )))
+    }
+}
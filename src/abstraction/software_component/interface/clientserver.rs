@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::abstraction::{
     datatype::{autosar_data_type_to_pyany, pyany_to_autosar_data_type},
     software_component::*,
@@ -38,14 +39,28 @@ impl ClientServerInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -63,10 +78,15 @@ impl ClientServerInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add a possible error to the client server interface
     #[pyo3(signature = (name, error_code, /))]
     #[pyo3(text_signature = "(self, name: str, error_code: int, /)")]
     fn create_possible_error(&self, name: &str, error_code: u64) -> PyResult<ApplicationError> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_possible_error(name, error_code) {
             Ok(value) => Ok(ApplicationError(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -82,6 +102,7 @@ impl ClientServerInterface {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_operation(&self, name: &str) -> PyResult<ClientServerOperation> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_operation(name) {
             Ok(value) => Ok(ClientServerOperation(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -96,6 +117,7 @@ impl ClientServerInterface {
     /// Set the is_service flag for this `ClientServerInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -108,6 +130,8 @@ impl ClientServerInterface {
     }
 }
 
+identifiable_pymethods!(ClientServerInterface);
+
 //##################################################################
 
 /// An `ApplicationError` represents an error that can be returned by a client server operation
@@ -137,14 +161,28 @@ impl ApplicationError {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -162,9 +200,14 @@ impl ApplicationError {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the error code of the application error
     #[setter]
     fn set_error_code(&self, error_code: u64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_error_code(error_code)
             .map_err(abstraction_err_to_pyerr)
@@ -177,6 +220,8 @@ impl ApplicationError {
     }
 }
 
+identifiable_pymethods!(ApplicationError);
+
 //##################################################################
 
 iterator_wrapper!(ApplicationErrorIterator, ApplicationError);
@@ -210,14 +255,28 @@ impl ClientServerOperation {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -235,6 +294,10 @@ impl ClientServerOperation {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add an argument to the operation
     #[pyo3(signature = (name, data_type, direction, /))]
     #[pyo3(
@@ -246,6 +309,7 @@ impl ClientServerOperation {
         data_type: &Bound<'_, PyAny>,
         direction: ArgumentDirection,
     ) -> PyResult<ArgumentDataPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         match self.0.create_argument(name, &data_type, direction.into()) {
             Ok(value) => Ok(ArgumentDataPrototype(value)),
@@ -262,6 +326,7 @@ impl ClientServerOperation {
     #[pyo3(signature = (error, /))]
     #[pyo3(text_signature = "(self, error: ApplicationError, /)")]
     fn add_possible_error(&self, error: &ApplicationError) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_possible_error(&error.0)
             .map_err(abstraction_err_to_pyerr)
@@ -273,6 +338,8 @@ impl ClientServerOperation {
     }
 }
 
+identifiable_pymethods!(ClientServerOperation);
+
 //##################################################################
 
 iterator_wrapper!(ClientServerOperationIterator, ClientServerOperation);
@@ -350,14 +417,28 @@ impl ArgumentDataPrototype {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -375,9 +456,14 @@ impl ArgumentDataPrototype {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the data type of the argument
     #[setter]
     fn set_data_type(&self, data_type: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         self.0
             .set_data_type(&data_type)
@@ -395,6 +481,7 @@ impl ArgumentDataPrototype {
     /// Set the direction of the argument
     #[setter]
     fn set_direction(&self, direction: ArgumentDirection) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_direction(direction.into())
             .map_err(abstraction_err_to_pyerr)
@@ -407,6 +494,8 @@ impl ArgumentDataPrototype {
     }
 }
 
+identifiable_pymethods!(ArgumentDataPrototype);
+
 //##################################################################
 
 iterator_wrapper!(ArgumentDataPrototypeIterator, ArgumentDataPrototype);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     abstraction::{
         datatype::{
@@ -51,14 +52,28 @@ impl ModeSwitchInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -76,6 +91,10 @@ impl ModeSwitchInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a new `ModeGroup` in this `ModeSwitchInterface`
     ///
     /// The `ModeSwitchInterface` can only contain one mode group
@@ -84,6 +103,7 @@ impl ModeSwitchInterface {
         name: &str,
         mode_declaration_group: &ModeDeclarationGroup,
     ) -> PyResult<ModeGroup> {
+        crate::frozen::check_element(self.0.element())?;
         let mode_group = self
             .0
             .create_mode_group(name, &mode_declaration_group.0)
@@ -100,6 +120,7 @@ impl ModeSwitchInterface {
     /// Set the is_service flag for this `ModeSwitchInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -112,6 +133,8 @@ impl ModeSwitchInterface {
     }
 }
 
+identifiable_pymethods!(ModeSwitchInterface);
+
 //###################################################################
 
 /// A `ModeGroup` represents a mode group in a `ModeSwitchInterface`
@@ -137,14 +160,28 @@ impl ModeGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -162,12 +199,17 @@ impl ModeGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the mode declaration group for this `ModeGroup`
     #[setter]
     fn set_mode_declaration_group(
         &self,
         mode_declaration_group: &ModeDeclarationGroup,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_mode_declaration_group(&mode_declaration_group.0)
             .map_err(abstraction_err_to_pyerr)
@@ -180,6 +222,8 @@ impl ModeGroup {
     }
 }
 
+identifiable_pymethods!(ModeGroup);
+
 //###################################################################
 
 /// A `ParameterInterface` defines a set of parameters that can be accessed
@@ -211,14 +255,28 @@ impl ParameterInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -236,12 +294,17 @@ impl ParameterInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Create a new `ParameterDataPrototype` in this `ParameterInterface`
     fn create_parameter(
         &self,
         name: &str,
         data_type: &Bound<'_, PyAny>,
     ) -> PyResult<ParameterDataPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         let parameter = self
             .0
@@ -258,6 +321,7 @@ impl ParameterInterface {
     /// Set the is_service flag for this `ParameterInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -270,6 +334,8 @@ impl ParameterInterface {
     }
 }
 
+identifiable_pymethods!(ParameterInterface);
+
 //##################################################################
 
 /// A `ParameterDataPrototype` defines a read-only parameter.
@@ -301,14 +367,28 @@ impl ParameterDataPrototype {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -326,9 +406,14 @@ impl ParameterDataPrototype {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the data type of the parameter
     #[setter]
     fn set_data_type(&self, data_type: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         self.0
             .set_data_type(&data_type)
@@ -355,6 +440,7 @@ impl ParameterDataPrototype {
     /// set the init value for the parameter
     #[setter]
     fn set_init_value(&self, init_value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let init_value = init_value
             .map(|val| pyany_to_value_specification(val))
             .transpose()?;
@@ -372,6 +458,8 @@ impl ParameterDataPrototype {
     }
 }
 
+identifiable_pymethods!(ParameterDataPrototype);
+
 //##################################################################
 
 iterator_wrapper!(ParameterDataPrototypeIterator, ParameterDataPrototype);
@@ -407,14 +495,28 @@ impl NvDataInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -432,9 +534,14 @@ impl NvDataInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the is_service flag for this `NvDataInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -447,6 +554,8 @@ impl NvDataInterface {
     }
 }
 
+identifiable_pymethods!(NvDataInterface);
+
 //##################################################################
 
 /// A `TriggerInterface` declares a number of triggers that can be sent by an trigger source
@@ -478,14 +587,28 @@ impl TriggerInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -503,9 +626,14 @@ impl TriggerInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the is_service flag for this `TriggerInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -518,6 +646,8 @@ impl TriggerInterface {
     }
 }
 
+identifiable_pymethods!(TriggerInterface);
+
 //##################################################################
 
 pub(crate) fn port_interface_to_pyany(
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     abstraction::{
         datatype::{
@@ -44,14 +45,28 @@ impl SenderReceiverInterface {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -69,6 +84,10 @@ impl SenderReceiverInterface {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Add a new data element to the sender receiver interface
     #[pyo3(signature = (name, data_type, /))]
     #[pyo3(text_signature = "(self, name: str, data_type: AutosarDataType, /)")]
@@ -77,6 +96,7 @@ impl SenderReceiverInterface {
         name: &str,
         data_type: &Bound<'_, PyAny>,
     ) -> PyResult<VariableDataPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         match self.0.create_data_element(name, &data_type) {
             Ok(value) => Ok(VariableDataPrototype(value)),
@@ -92,6 +112,7 @@ impl SenderReceiverInterface {
     /// Set the is_service flag for this `SenderReceiverInterface`
     #[setter]
     fn set_is_service(&self, is_service: Option<bool>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_is_service(is_service)
             .map_err(abstraction_err_to_pyerr)
@@ -104,6 +125,8 @@ impl SenderReceiverInterface {
     }
 }
 
+identifiable_pymethods!(SenderReceiverInterface);
+
 //##################################################################
 
 /// A `VariableDataPrototype` represents a data element in a `SenderReceiverInterface`
@@ -133,14 +156,28 @@ impl VariableDataPrototype {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -158,9 +195,14 @@ impl VariableDataPrototype {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the data type of the data element
     #[setter]
     fn set_data_type(&self, data_type: &Bound<'_, PyAny>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let data_type = pyany_to_autosar_data_type(data_type)?;
         self.0
             .set_data_type(&data_type)
@@ -187,6 +229,7 @@ impl VariableDataPrototype {
     /// set the init value for the data element
     #[setter]
     fn set_init_value(&self, init_value: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let init_value = init_value
             .map(|val| pyany_to_value_specification(val))
             .transpose()?;
@@ -204,6 +247,8 @@ impl VariableDataPrototype {
     }
 }
 
+identifiable_pymethods!(VariableDataPrototype);
+
 //##################################################################
 
 iterator_wrapper!(VariableDataPrototypeIterator, VariableDataPrototype);
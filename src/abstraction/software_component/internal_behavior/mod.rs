@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -48,14 +49,28 @@ impl SwcInternalBehavior {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -73,6 +88,10 @@ impl SwcInternalBehavior {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the software component type that contains the `SwcInternalBehavior`
     #[getter]
     fn sw_component_type(&self) -> Option<Py<PyAny>> {
@@ -85,6 +104,7 @@ impl SwcInternalBehavior {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_runnable_entity(&self, name: &str) -> PyResult<RunnableEntity> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_runnable_entity(name) {
             Ok(value) => Ok(RunnableEntity(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -103,6 +123,7 @@ impl SwcInternalBehavior {
         &self,
         data_type_mapping_set: &DataTypeMappingSet,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .add_data_type_mapping_set(&data_type_mapping_set.0)
             .map_err(abstraction_err_to_pyerr)
@@ -117,6 +138,7 @@ impl SwcInternalBehavior {
     #[pyo3(signature = (name, runnable, /))]
     #[pyo3(text_signature = "(self, name: str, runnable: RunnableEntity, /)")]
     fn create_init_event(&self, name: &str, runnable: &RunnableEntity) -> PyResult<InitEvent> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_init_event(name, &runnable.0) {
             Ok(value) => Ok(InitEvent(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -136,6 +158,7 @@ impl SwcInternalBehavior {
         client_server_operation: &ClientServerOperation,
         context_p_port: &PPortPrototype,
     ) -> PyResult<OperationInvokedEvent> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_operation_invoked_event(
             name,
             &runnable.0,
@@ -156,6 +179,7 @@ impl SwcInternalBehavior {
         runnable: &RunnableEntity,
         period: f64,
     ) -> PyResult<TimingEvent> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_timing_event(name, &runnable.0, period) {
             Ok(value) => Ok(TimingEvent(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -170,6 +194,7 @@ impl SwcInternalBehavior {
         name: &str,
         runnable: &RunnableEntity,
     ) -> PyResult<BackgroundEvent> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_background_event(name, &runnable.0) {
             Ok(value) => Ok(BackgroundEvent(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -188,6 +213,7 @@ impl SwcInternalBehavior {
         variable_data_prototype: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<DataReceivedEvent> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self.0.create_data_received_event(
             name,
@@ -208,6 +234,7 @@ impl SwcInternalBehavior {
         name: &str,
         runnable: &RunnableEntity,
     ) -> PyResult<OsTaskExecutionEvent> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_os_task_execution_event(name, &runnable.0) {
             Ok(value) => Ok(OsTaskExecutionEvent(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -228,6 +255,7 @@ impl SwcInternalBehavior {
         mode_declaration: &ModeDeclaration,
         second_mode_declaration: Option<&ModeDeclaration>,
     ) -> PyResult<SwcModeSwitchEvent> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self.0.create_mode_switch_event(
             name,
@@ -252,6 +280,8 @@ impl SwcInternalBehavior {
     }
 }
 
+identifiable_pymethods!(SwcInternalBehavior);
+
 //##################################################################
 
 iterator_wrapper!(SwcInternalBehaviorIterator, SwcInternalBehavior);
@@ -285,14 +315,28 @@ impl RunnableEntity {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -310,6 +354,10 @@ impl RunnableEntity {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `SwcInternalBehavior` that contains the `RunnableEntity`
     #[getter]
     fn swc_internal_behavior(&self) -> Option<SwcInternalBehavior> {
@@ -338,6 +386,7 @@ impl RunnableEntity {
         data_element: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<VariableAccess> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -366,6 +415,7 @@ impl RunnableEntity {
         data_element: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<VariableAccess> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -392,6 +442,7 @@ impl RunnableEntity {
         data_element: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<VariableAccess> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -420,6 +471,7 @@ impl RunnableEntity {
         data_element: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<VariableAccess> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -446,6 +498,7 @@ impl RunnableEntity {
         data_element: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<VariableAccess> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -468,6 +521,7 @@ impl RunnableEntity {
         client_server_operation: &ClientServerOperation,
         context_r_port: &RPortPrototype,
     ) -> PyResult<SynchronousServerCallPoint> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_synchronous_server_call_point(
             name,
             &client_server_operation.0,
@@ -494,6 +548,7 @@ impl RunnableEntity {
         mode_group: &ModeGroup,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<ModeAccessPoint> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -516,6 +571,7 @@ impl RunnableEntity {
         mode_group: &ModeGroup,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<ModeSwitchPoint> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         match self
             .0
@@ -532,6 +588,8 @@ impl RunnableEntity {
     }
 }
 
+identifiable_pymethods!(RunnableEntity);
+
 //##################################################################
 
 iterator_wrapper!(RunnableEntityIterator, RunnableEntity);
@@ -565,14 +623,28 @@ impl VariableAccess {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -590,6 +662,10 @@ impl VariableAccess {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the accessed variable
     #[pyo3(signature = (variable, context_port, /))]
     #[pyo3(
@@ -600,6 +676,7 @@ impl VariableAccess {
         variable: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         self.0
             .set_accessed_variable(&variable.0, &context_port)
@@ -622,6 +699,8 @@ impl VariableAccess {
     }
 }
 
+identifiable_pymethods!(VariableAccess);
+
 //##################################################################
 
 iterator_wrapper!(VariableAccessIterator, VariableAccess);
@@ -655,14 +734,28 @@ impl SynchronousServerCallPoint {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -680,6 +773,10 @@ impl SynchronousServerCallPoint {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the client server operation
     #[pyo3(signature = (client_server_operation, context_r_port, /))]
     #[pyo3(
@@ -690,6 +787,7 @@ impl SynchronousServerCallPoint {
         client_server_operation: &ClientServerOperation,
         context_r_port: &RPortPrototype,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_client_server_operation(&client_server_operation.0, &context_r_port.0)
             .map_err(abstraction_err_to_pyerr)
@@ -711,6 +809,8 @@ impl SynchronousServerCallPoint {
     }
 }
 
+identifiable_pymethods!(SynchronousServerCallPoint);
+
 //##################################################################
 
 iterator_wrapper!(
@@ -747,14 +847,28 @@ impl ModeAccessPoint {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -772,6 +886,10 @@ impl ModeAccessPoint {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the mode group and context port of the `ModeAccessPoint`
     #[pyo3(signature = (mode_group, context_port, /))]
     #[pyo3(text_signature = "(self, mode_group: ModeGroup, context_port: PortPrototype, /)")]
@@ -780,6 +898,7 @@ impl ModeAccessPoint {
         mode_group: &ModeGroup,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         self.0
             .set_mode_group(&mode_group.0, &context_port)
@@ -802,6 +921,8 @@ impl ModeAccessPoint {
     }
 }
 
+identifiable_pymethods!(ModeAccessPoint);
+
 //##################################################################
 
 iterator_wrapper!(ModeAccessPointIterator, ModeAccessPoint);
@@ -835,14 +956,28 @@ impl ModeSwitchPoint {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -860,6 +995,10 @@ impl ModeSwitchPoint {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the mode group and context port of the `ModeSwitchPoint`
     #[pyo3(signature = (mode_group, context_port, /))]
     #[pyo3(text_signature = "(self, mode_group: ModeGroup, context_port: PortPrototype, /)")]
@@ -868,6 +1007,7 @@ impl ModeSwitchPoint {
         mode_group: &ModeGroup,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         self.0
             .set_mode_group(&mode_group.0, &context_port)
@@ -890,6 +1030,8 @@ impl ModeSwitchPoint {
     }
 }
 
+identifiable_pymethods!(ModeSwitchPoint);
+
 //##################################################################
 
 iterator_wrapper!(ModeSwitchPointIterator, ModeSwitchPoint);
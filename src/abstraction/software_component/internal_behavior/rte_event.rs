@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -41,14 +42,28 @@ impl TimingEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -66,9 +81,14 @@ impl TimingEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `TimingEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -89,6 +109,7 @@ impl TimingEvent {
     /// Set the period of the `TimingEvent`
     #[setter]
     fn set_period(&self, period: f64) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_period(period).map_err(abstraction_err_to_pyerr)
     }
 
@@ -99,6 +120,8 @@ impl TimingEvent {
     }
 }
 
+identifiable_pymethods!(TimingEvent);
+
 //##################################################################
 
 /// an asynchronous server call completed
@@ -128,14 +151,28 @@ impl AsynchronousServerCallReturnsEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -153,6 +190,10 @@ impl AsynchronousServerCallReturnsEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `RunnableEntity` that is triggered by the `AsynchronousServerCallCompleted`
     #[getter]
     fn runnable_entity(&self) -> Option<RunnableEntity> {
@@ -162,6 +203,7 @@ impl AsynchronousServerCallReturnsEvent {
     /// Set the `RunnableEntity` that is triggered by the `AsynchronousServerCallCompleted`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -174,6 +216,8 @@ impl AsynchronousServerCallReturnsEvent {
     }
 }
 
+identifiable_pymethods!(AsynchronousServerCallReturnsEvent);
+
 //##################################################################
 
 /// starts a runnable for background processing at low priority
@@ -203,14 +247,28 @@ impl BackgroundEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -228,6 +286,10 @@ impl BackgroundEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Get the `RunnableEntity` that is triggered by the `BackgroundEvent`
     #[getter]
     fn runnable_entity(&self) -> Option<RunnableEntity> {
@@ -237,6 +299,7 @@ impl BackgroundEvent {
     /// Set the `RunnableEntity` that is triggered by the `BackgroundEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -249,6 +312,8 @@ impl BackgroundEvent {
     }
 }
 
+identifiable_pymethods!(BackgroundEvent);
+
 //##################################################################
 
 /// A `DataReceivedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is received
@@ -278,14 +343,28 @@ impl DataReceivedEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -303,9 +382,14 @@ impl DataReceivedEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `DataReceivedEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -333,6 +417,7 @@ impl DataReceivedEvent {
         variable_data_prototype: &VariableDataPrototype,
         context_port: &Bound<'_, PyAny>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         self.0
             .set_variable_data_prototype(&variable_data_prototype.0, &context_port)
@@ -348,6 +433,8 @@ impl DataReceivedEvent {
     }
 }
 
+identifiable_pymethods!(DataReceivedEvent);
+
 //##################################################################
 
 /// A `DataSendCompletedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is sent
@@ -377,14 +464,28 @@ impl DataSendCompletedEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -402,9 +503,14 @@ impl DataSendCompletedEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `DataSendCompletedEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -423,6 +529,8 @@ impl DataSendCompletedEvent {
     }
 }
 
+identifiable_pymethods!(DataSendCompletedEvent);
+
 //##################################################################
 
 /// A `DataReceiveErrorEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a data receive error occurs
@@ -452,14 +560,28 @@ impl DataReceiveErrorEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -477,9 +599,14 @@ impl DataReceiveErrorEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `DataReceiveErrorEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -498,6 +625,8 @@ impl DataReceiveErrorEvent {
     }
 }
 
+identifiable_pymethods!(DataReceiveErrorEvent);
+
 //##################################################################
 
 /// A `DataWriteCompletedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when data is written
@@ -527,14 +656,28 @@ impl DataWriteCompletedEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -552,9 +695,14 @@ impl DataWriteCompletedEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `DataWriteCompletedEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -573,6 +721,8 @@ impl DataWriteCompletedEvent {
     }
 }
 
+identifiable_pymethods!(DataWriteCompletedEvent);
+
 //##################################################################
 
 /// A `ExternalTriggerOccurredEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an external trigger occurs
@@ -602,14 +752,28 @@ impl ExternalTriggerOccurredEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -627,9 +791,14 @@ impl ExternalTriggerOccurredEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `ExternalTriggerOccurredEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -648,6 +817,8 @@ impl ExternalTriggerOccurredEvent {
     }
 }
 
+identifiable_pymethods!(ExternalTriggerOccurredEvent);
+
 //##################################################################
 
 /// A `InitEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when the software component is initialized
@@ -673,14 +844,28 @@ impl InitEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -698,9 +883,14 @@ impl InitEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `InitEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -719,6 +909,8 @@ impl InitEvent {
     }
 }
 
+identifiable_pymethods!(InitEvent);
+
 //##################################################################
 
 /// A `InternalTriggerOccurredEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an internal trigger occurs
@@ -748,14 +940,28 @@ impl InternalTriggerOccurredEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -773,9 +979,14 @@ impl InternalTriggerOccurredEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `InternalTriggerOccurredEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -794,6 +1005,8 @@ impl InternalTriggerOccurredEvent {
     }
 }
 
+identifiable_pymethods!(InternalTriggerOccurredEvent);
+
 //##################################################################
 
 /// A `ModeSwitchedAckEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode switch is acknowledged
@@ -823,14 +1036,28 @@ impl ModeSwitchedAckEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -848,9 +1075,14 @@ impl ModeSwitchedAckEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `ModeSwitchedAckEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -869,6 +1101,8 @@ impl ModeSwitchedAckEvent {
     }
 }
 
+identifiable_pymethods!(ModeSwitchedAckEvent);
+
 //##################################################################
 
 /// A `OperationInvokedEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an operation is invoked
@@ -898,14 +1132,28 @@ impl OperationInvokedEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -923,6 +1171,10 @@ impl OperationInvokedEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `ClientServerOperation` that is triggers the `OperationInvokedEvent`
     #[pyo3(signature = (client_server_operation, context_p_port, /))]
     #[pyo3(
@@ -933,6 +1185,7 @@ impl OperationInvokedEvent {
         client_server_operation: &ClientServerOperation,
         context_p_port: &PPortPrototype,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_client_server_operation(&client_server_operation.0, &context_p_port.0)
             .map_err(abstraction_err_to_pyerr)
@@ -954,6 +1207,7 @@ impl OperationInvokedEvent {
     /// Set the `RunnableEntity` that is triggered by the `OperationInvokedEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -972,6 +1226,8 @@ impl OperationInvokedEvent {
     }
 }
 
+identifiable_pymethods!(OperationInvokedEvent);
+
 //##################################################################
 
 /// A `OsTaskExecutionEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when an OS task is executed
@@ -1001,14 +1257,28 @@ impl OsTaskExecutionEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1026,9 +1296,14 @@ impl OsTaskExecutionEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `OsTaskExecutionEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1047,6 +1322,8 @@ impl OsTaskExecutionEvent {
     }
 }
 
+identifiable_pymethods!(OsTaskExecutionEvent);
+
 //##################################################################
 
 /// A `SwcModeManagerErrorEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode manager error occurs
@@ -1076,14 +1353,28 @@ impl SwcModeManagerErrorEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1101,9 +1392,14 @@ impl SwcModeManagerErrorEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `SwcModeManagerErrorEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1122,6 +1418,8 @@ impl SwcModeManagerErrorEvent {
     }
 }
 
+identifiable_pymethods!(SwcModeManagerErrorEvent);
+
 //##################################################################
 
 /// A `SwcModeSwitchEvent` is a subclass of `RTEEvent` which triggers a `RunnableEntity` when a mode switch occurs
@@ -1151,14 +1449,28 @@ impl SwcModeSwitchEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1176,9 +1488,14 @@ impl SwcModeSwitchEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `SwcModeSwitchEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1199,6 +1516,7 @@ impl SwcModeSwitchEvent {
     /// Set the `ModeActivationKind` for the `SwcModeSwitchEvent`
     #[setter]
     fn set_mode_activation_kind(&self, kind: &ModeActivationKind) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_mode_activation_kind((*kind).into())
             .map_err(abstraction_err_to_pyerr)
@@ -1224,6 +1542,7 @@ impl SwcModeSwitchEvent {
         mode_declaration: &ModeDeclaration,
         second_mode_declaration: Option<&ModeDeclaration>,
     ) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         let context_port = pyany_to_port_prototype(context_port)?;
         self.0
             .set_mode_declaration(
@@ -1245,6 +1564,8 @@ impl SwcModeSwitchEvent {
     }
 }
 
+identifiable_pymethods!(SwcModeSwitchEvent);
+
 //##################################################################
 
 /// Kind of mode switch condition used for activation of an event
@@ -1327,15 +1648,29 @@ impl TransformerHardErrorEvent {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     /// Set the name of the `TransformerHardErrorEvent`
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1356,9 +1691,14 @@ impl TransformerHardErrorEvent {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// Set the `RunnableEntity` that is triggered by the `TransformerHardErrorEvent`
     #[setter]
     fn set_runnable_entity(&self, runnable_entity: &RunnableEntity) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_runnable_entity(&runnable_entity.0)
             .map_err(abstraction_err_to_pyerr)
@@ -1377,6 +1717,8 @@ impl TransformerHardErrorEvent {
     }
 }
 
+identifiable_pymethods!(TransformerHardErrorEvent);
+
 //##################################################################
 
 iterator_wrapper!(RteEventIterator, Py<PyAny>, "RTEEvent");
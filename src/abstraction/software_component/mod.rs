@@ -1,4 +1,5 @@
 use crate::{abstraction::*, *};
+use ::autosar_data::ElementName;
 use autosar_data_abstraction::{
     self, AbstractionElement, IdentifiableAbstractionElement,
     software_component::{AbstractSwComponentType, AtomicSwComponentType},
@@ -47,14 +48,28 @@ impl CompositionSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -72,6 +87,10 @@ impl CompositionSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// check if the composition is a parent (or grand-parent, etc.) of the component
     #[pyo3(signature = (other, /))]
     #[pyo3(text_signature = "(self, other: SwComponentType, /)")]
@@ -90,6 +109,7 @@ impl CompositionSwComponentType {
         name: &str,
         component_type: &Bound<'_, PyAny>,
     ) -> PyResult<SwComponentPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let component_type = pyany_to_sw_component_type(component_type)?;
         match self.0.create_component(name, &component_type) {
             Ok(value) => Ok(SwComponentPrototype(value)),
@@ -116,6 +136,7 @@ impl CompositionSwComponentType {
         inner_sw_prototype: &SwComponentPrototype,
         outer_port: &Bound<'_, PyAny>,
     ) -> PyResult<DelegationSwConnector> {
+        crate::frozen::check_element(self.0.element())?;
         let inner_port = pyany_to_port_prototype(inner_port)?;
         let outer_port = pyany_to_port_prototype(outer_port)?;
         match self.0.create_delegation_connector(
@@ -146,6 +167,7 @@ impl CompositionSwComponentType {
         port_2: &Bound<'_, PyAny>,
         sw_prototype_2: &SwComponentPrototype,
     ) -> PyResult<AssemblySwConnector> {
+        crate::frozen::check_element(self.0.element())?;
         let port_1 = pyany_to_port_prototype(port_1)?;
         let port_2 = pyany_to_port_prototype(port_2)?;
         match self.0.create_assembly_connector(
@@ -171,6 +193,7 @@ impl CompositionSwComponentType {
         port_1: &Bound<'_, PyAny>,
         port_2: &Bound<'_, PyAny>,
     ) -> PyResult<PassThroughSwConnector> {
+        crate::frozen::check_element(self.0.element())?;
         let port_1 = pyany_to_port_prototype(port_1)?;
         let port_2 = pyany_to_port_prototype(port_2)?;
         match self.0.create_pass_through_connector(name, &port_1, &port_2) {
@@ -216,6 +239,7 @@ impl CompositionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -231,6 +255,7 @@ impl CompositionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -246,6 +271,7 @@ impl CompositionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -253,6 +279,81 @@ impl CompositionSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -266,6 +367,7 @@ impl CompositionSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -273,6 +375,8 @@ impl CompositionSwComponentType {
     }
 }
 
+identifiable_pymethods!(CompositionSwComponentType);
+
 //##################################################################
 
 /// An `ApplicationSwComponentType` is a software component that provides application functionality
@@ -304,14 +408,28 @@ impl ApplicationSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -329,6 +447,10 @@ impl ApplicationSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------ AbstractSwComponentType ------
 
     /// list all instances of the component type
@@ -357,6 +479,7 @@ impl ApplicationSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -372,6 +495,7 @@ impl ApplicationSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -387,6 +511,7 @@ impl ApplicationSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -394,6 +519,81 @@ impl ApplicationSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -407,6 +607,7 @@ impl ApplicationSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -421,6 +622,7 @@ impl ApplicationSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_swc_internal_behavior(&self, name: &str) -> PyResult<SwcInternalBehavior> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_swc_internal_behavior(name) {
             Ok(value) => Ok(SwcInternalBehavior(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -433,6 +635,8 @@ impl ApplicationSwComponentType {
     }
 }
 
+identifiable_pymethods!(ApplicationSwComponentType);
+
 //##################################################################
 
 /// A `ComplexDeviceDriverSwComponentType` is a software component that provides complex device driver functionality
@@ -464,14 +668,28 @@ impl ComplexDeviceDriverSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -489,6 +707,10 @@ impl ComplexDeviceDriverSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------ AbstractSwComponentType ------
 
     /// list of all instances of the component type
@@ -517,6 +739,7 @@ impl ComplexDeviceDriverSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -532,6 +755,7 @@ impl ComplexDeviceDriverSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -547,6 +771,7 @@ impl ComplexDeviceDriverSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -554,6 +779,81 @@ impl ComplexDeviceDriverSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -567,6 +867,7 @@ impl ComplexDeviceDriverSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -581,6 +882,7 @@ impl ComplexDeviceDriverSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_swc_internal_behavior(&self, name: &str) -> PyResult<SwcInternalBehavior> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_swc_internal_behavior(name) {
             Ok(value) => Ok(SwcInternalBehavior(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -593,6 +895,8 @@ impl ComplexDeviceDriverSwComponentType {
     }
 }
 
+identifiable_pymethods!(ComplexDeviceDriverSwComponentType);
+
 //##################################################################
 
 /// `ServiceSwComponentType` is used for configuring services for a given ECU. Instances of this class should only
@@ -625,14 +929,28 @@ impl ServiceSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -650,6 +968,10 @@ impl ServiceSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------ AbstractSwComponentType ------
 
     /// list all the instances of the component type
@@ -678,6 +1000,7 @@ impl ServiceSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -693,6 +1016,7 @@ impl ServiceSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -708,6 +1032,7 @@ impl ServiceSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -715,6 +1040,81 @@ impl ServiceSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -728,6 +1128,7 @@ impl ServiceSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -742,6 +1143,7 @@ impl ServiceSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_swc_internal_behavior(&self, name: &str) -> PyResult<SwcInternalBehavior> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_swc_internal_behavior(name) {
             Ok(value) => Ok(SwcInternalBehavior(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -754,6 +1156,8 @@ impl ServiceSwComponentType {
     }
 }
 
+identifiable_pymethods!(ServiceSwComponentType);
+
 //##################################################################
 
 /// `SensorActuatorSwComponentType` is used to connect sensor/acutator devices to the ECU configuration
@@ -785,14 +1189,28 @@ impl SensorActuatorSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -810,6 +1228,10 @@ impl SensorActuatorSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------ AbstractSwComponentType ------
 
     /// list all instances of the component type
@@ -838,6 +1260,7 @@ impl SensorActuatorSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -853,6 +1276,7 @@ impl SensorActuatorSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -868,6 +1292,7 @@ impl SensorActuatorSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -875,6 +1300,81 @@ impl SensorActuatorSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -888,6 +1388,7 @@ impl SensorActuatorSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -902,6 +1403,7 @@ impl SensorActuatorSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_swc_internal_behavior(&self, name: &str) -> PyResult<SwcInternalBehavior> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_swc_internal_behavior(name) {
             Ok(value) => Ok(SwcInternalBehavior(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -914,6 +1416,8 @@ impl SensorActuatorSwComponentType {
     }
 }
 
+identifiable_pymethods!(SensorActuatorSwComponentType);
+
 //##################################################################
 
 /// The `ECUAbstraction` is a special `AtomicSwComponentType` that resides between a software-component
@@ -946,14 +1450,28 @@ impl EcuAbstractionSwComponentType {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -971,6 +1489,10 @@ impl EcuAbstractionSwComponentType {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     // ------ AbstractSwComponentType ------
 
     /// iterator over the instances of the component type
@@ -999,6 +1521,7 @@ impl EcuAbstractionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<RPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_r_port(name, &port_interface) {
             Ok(value) => Ok(RPortPrototype(value)),
@@ -1014,6 +1537,7 @@ impl EcuAbstractionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_p_port(name, &port_interface) {
             Ok(value) => Ok(PPortPrototype(value)),
@@ -1029,6 +1553,7 @@ impl EcuAbstractionSwComponentType {
         name: &str,
         port_interface: &Bound<'_, PyAny>,
     ) -> PyResult<PRPortPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         let port_interface = pyany_to_port_interface(port_interface)?;
         match self.0.create_pr_port(name, &port_interface) {
             Ok(value) => Ok(PRPortPrototype(value)),
@@ -1036,6 +1561,81 @@ impl EcuAbstractionSwComponentType {
         }
     }
 
+    /// get the required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not an R-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_r_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<RPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::RPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::RPortPrototype::try_from(existing)
+                    .map(RPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_r_port(name, port_interface),
+        }
+    }
+
+    /// get the provided port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a P-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_p_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PPortPrototype::try_from(existing)
+                    .map(PPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_p_port(name, port_interface),
+        }
+    }
+
+    /// get the provided required port with the given name, or create it if it does not exist
+    ///
+    /// Raises an error if the existing port is not a PR-PORT-PROTOTYPE, or if it uses a different port interface
+    #[pyo3(signature = (name, port_interface, /))]
+    #[pyo3(text_signature = "(self, name: str, port_interface: PortInterface, /)")]
+    fn get_or_create_pr_port(
+        &self,
+        name: &str,
+        port_interface: &Bound<'_, PyAny>,
+    ) -> PyResult<PRPortPrototype> {
+        match find_existing_port(
+            self.0.element(),
+            name,
+            ElementName::PrPortPrototype,
+            port_interface,
+        )? {
+            Some(existing) => {
+                autosar_data_abstraction::software_component::PRPortPrototype::try_from(existing)
+                    .map(PRPortPrototype)
+                    .map_err(abstraction_err_to_pyerr)
+            }
+            None => self.create_pr_port(name, port_interface),
+        }
+    }
+
     /// get an iterator over the ports of the component
     fn ports(&self) -> PortPrototypeIterator {
         PortPrototypeIterator::new(
@@ -1049,6 +1649,7 @@ impl EcuAbstractionSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_port_group(&self, name: &str) -> PyResult<PortGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_port_group(name) {
             Ok(value) => Ok(PortGroup(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -1063,6 +1664,7 @@ impl EcuAbstractionSwComponentType {
     #[pyo3(signature = (name, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn create_swc_internal_behavior(&self, name: &str) -> PyResult<SwcInternalBehavior> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_swc_internal_behavior(name) {
             Ok(value) => Ok(SwcInternalBehavior(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -1075,6 +1677,8 @@ impl EcuAbstractionSwComponentType {
     }
 }
 
+identifiable_pymethods!(EcuAbstractionSwComponentType);
+
 //##################################################################
 
 pub(crate) fn pyany_to_sw_component_type(
@@ -1103,6 +1707,40 @@ pub(crate) fn pyany_to_sw_component_type(
     }
 }
 
+/// find an existing port of a component type for one of the `get_or_create_*_port` methods
+///
+/// An error is returned if the existing port has a different kind or uses a different port interface.
+fn find_existing_port(
+    component_type: &autosar_data_rs::Element,
+    name: &str,
+    expected: ElementName,
+    port_interface: &Bound<'_, PyAny>,
+) -> PyResult<Option<autosar_data_rs::Element>> {
+    let Some(existing) = find_existing_element(component_type, ElementName::Ports, name, expected)?
+    else {
+        return Ok(None);
+    };
+    let interface_element: Element = port_interface.getattr("element")?.extract()?;
+    let existing_interface = existing
+        .sub_elements()
+        .find(|sub_element| {
+            matches!(
+                sub_element.element_name(),
+                ElementName::RequiredInterfaceTref
+                    | ElementName::ProvidedInterfaceTref
+                    | ElementName::ProvidedRequiredInterfaceTref
+            )
+        })
+        .and_then(|interface_ref| interface_ref.get_reference_target().ok());
+    if existing_interface.as_ref() != Some(&interface_element.0) {
+        return Err(incompatible_element_err(
+            &existing,
+            "it uses a different port interface",
+        ));
+    }
+    Ok(Some(existing))
+}
+
 pub(crate) fn sw_component_type_to_pyany(
     component_type: autosar_data_abstraction::software_component::SwComponentType,
 ) -> PyResult<Py<PyAny>> {
@@ -1157,14 +1795,28 @@ impl SwComponentPrototype {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1181,8 +1833,14 @@ impl SwComponentPrototype {
     fn __repr__(&self) -> String {
         format!("{:#?}", self.0)
     }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
 }
 
+identifiable_pymethods!(SwComponentPrototype);
+
 //##################################################################
 
 iterator_wrapper!(SwComponentPrototypeIterator, SwComponentPrototype);
@@ -1231,14 +1889,28 @@ impl RootSwCompositionPrototype {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -1256,9 +1928,15 @@ impl RootSwCompositionPrototype {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the composition that this root component is based on
     #[getter]
     fn composition(&self) -> Option<CompositionSwComponentType> {
         self.0.composition().map(CompositionSwComponentType)
     }
 }
+
+identifiable_pymethods!(RootSwCompositionPrototype);
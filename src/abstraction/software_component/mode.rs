@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     abstraction::{AutosarAbstractionError, Element, abstraction_err_to_pyerr},
     iterator_wrapper,
@@ -34,14 +35,28 @@ impl ModeDeclarationGroup {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -59,9 +74,14 @@ impl ModeDeclarationGroup {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the category of the mode declaration group
     #[setter]
     fn set_category(&self, category: Option<ModeDeclarationGroupCategory>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.map(Into::into))
             .map_err(abstraction_err_to_pyerr)
@@ -75,6 +95,7 @@ impl ModeDeclarationGroup {
 
     /// Create a new mode declaration in the mode declaration group
     fn create_mode_declaration(&self, name: &str) -> PyResult<ModeDeclaration> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_mode_declaration(name) {
             Ok(value) => Ok(ModeDeclaration(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -92,6 +113,7 @@ impl ModeDeclarationGroup {
     /// This setting is required to be present and the referenced mode must be part of the mode declaration group.
     #[setter]
     fn set_initial_mode(&self, mode: &ModeDeclaration) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_initial_mode(&mode.0)
             .map_err(abstraction_err_to_pyerr)
@@ -106,6 +128,7 @@ impl ModeDeclarationGroup {
     /// set the onTransitionValue attribute of the mode declaration group
     #[setter]
     fn set_on_transition_value(&self, value: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_on_transition_value(value)
             .map_err(abstraction_err_to_pyerr)
@@ -118,6 +141,8 @@ impl ModeDeclarationGroup {
     }
 }
 
+identifiable_pymethods!(ModeDeclarationGroup);
+
 //##################################################################
 
 /// Category of mode declaration group, which defines the ordering of the modes in the group
@@ -198,14 +223,28 @@ impl ModeDeclaration {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -223,9 +262,14 @@ impl ModeDeclaration {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the value of the mode declaration
     #[setter]
     fn set_value(&self, value: Option<u64>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_value(value).map_err(abstraction_err_to_pyerr)
     }
 
@@ -236,6 +280,8 @@ impl ModeDeclaration {
     }
 }
 
+identifiable_pymethods!(ModeDeclaration);
+
 //##################################################################
 
 iterator_wrapper!(ModeDeclarationIterator, ModeDeclaration);
@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -39,14 +40,28 @@ impl SystemMapping {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -64,6 +79,10 @@ impl SystemMapping {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the system that contains this mapping
     #[getter]
     fn system(&self) -> PyResult<System> {
@@ -84,6 +103,7 @@ impl SystemMapping {
         component_prototype: &SwComponentPrototype,
         ecu: &EcuInstance,
     ) -> PyResult<SwcToEcuMapping> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.map_swc_to_ecu(name, &component_prototype.0, &ecu.0) {
             Ok(value) => Ok(SwcToEcuMapping(value)),
             Err(e) => Err(AutosarAbstractionError::new_err(e.to_string())),
@@ -115,6 +135,7 @@ impl SystemMapping {
         context_components: Vec<SwComponentPrototype>,
         root_composition_prototype: Option<&RootSwCompositionPrototype>,
     ) -> PyResult<SenderReceiverToSignalMapping> {
+        crate::frozen::check_element(self.0.element())?;
         let port_prototype = pyany_to_port_prototype(port_prototype)?;
         let context_components: Vec<_> = context_components.iter().map(|c| &c.0).collect();
         self.0
@@ -130,6 +151,8 @@ impl SystemMapping {
     }
 }
 
+identifiable_pymethods!(SystemMapping);
+
 //##################################################################
 
 /// A `SwcToEcuMapping` contains a mapping between a `SwComponentPrototype` and an `EcuInstance`
@@ -155,14 +178,28 @@ impl SwcToEcuMapping {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -180,6 +217,10 @@ impl SwcToEcuMapping {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// get the component prototype that is mapped here
     #[getter]
     fn target_component(&self) -> Option<SwComponentPrototype> {
@@ -193,6 +234,8 @@ impl SwcToEcuMapping {
     }
 }
 
+identifiable_pymethods!(SwcToEcuMapping);
+
 //##################################################################
 
 /// A `SenderReceiverToSignalMapping` contains a mapping between a sender/receiver port and a system signal
@@ -220,12 +263,29 @@ impl SenderReceiverToSignalMapping {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     #[getter]
     fn system_signal(&self) -> Option<SystemSignal> {
         self.0.system_signal().map(SystemSignal)
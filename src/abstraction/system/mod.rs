@@ -1,3 +1,4 @@
+use crate::abstraction::identifiable_pymethods;
 use crate::{
     Element,
     abstraction::{
@@ -15,10 +16,13 @@ use crate::{
             UserDefinedPdu,
         },
         datatype::SwBaseType,
+        find_existing_element, incompatible_element_err,
         software_component::{CompositionSwComponentType, RootSwCompositionPrototype},
     },
     iterator_wrapper,
 };
+use ::autosar_data::ElementName;
+use autosar_data_abstraction::communication::{AbstractFrame, AbstractPdu};
 use autosar_data_abstraction::{self, AbstractionElement, IdentifiableAbstractionElement};
 use pyo3::{IntoPyObjectExt, exceptions::PyTypeError, prelude::*};
 
@@ -28,6 +32,22 @@ pub(crate) use mapping::*;
 
 //##################################################################
 
+/// check that an existing frame or PDU has the requested length, for the `get_or_create_*` methods
+fn check_length(
+    existing: &::autosar_data::Element,
+    length: Option<u32>,
+    expected: u64,
+) -> PyResult<()> {
+    if length.map(u64::from) == Some(expected) {
+        Ok(())
+    } else {
+        Err(incompatible_element_err(
+            existing,
+            &format!("its length is {length:?} instead of {expected}"),
+        ))
+    }
+}
+
 /// The System is the top level of a system template
 ///
 /// It defines how ECUs communicate with each other over various networks.
@@ -54,14 +74,28 @@ impl System {
     #[pyo3(signature = (/, *, deep = false))]
     #[pyo3(text_signature = "(self, /, *, deep: bool = false)")]
     fn remove(&self, deep: bool) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.clone()
             .0
             .remove(deep)
             .map_err(abstraction_err_to_pyerr)
     }
 
+    /// preview the effect of `remove` without modifying the model
+    ///
+    /// The removal is performed on a copy of the model. Each call duplicates the whole model
+    /// and checks all references twice, so this is expensive on large models.
+    #[pyo3(signature = (/, *, deep = true))]
+    #[pyo3(text_signature = "(self, /, *, deep: bool = True)")]
+    fn preview_remove(&self, deep: bool) -> PyResult<crate::abstraction::RemovePreview> {
+        crate::abstraction::preview_remove(self.0.element(), |element| {
+            Self::new(element)?.remove(deep)
+        })
+    }
+
     #[setter]
     fn set_name(&self, name: &str) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0.set_name(name).map_err(abstraction_err_to_pyerr)
     }
 
@@ -79,9 +113,14 @@ impl System {
         format!("{:#?}", self.0)
     }
 
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(self.0.element())
+    }
+
     /// set the category of the system
     #[setter]
     fn set_category(&self, category: SystemCategory) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_category(category.into())
             .map_err(abstraction_err_to_pyerr)
@@ -96,6 +135,7 @@ impl System {
     /// set the pncVectorLength of the system
     #[setter]
     fn set_pnc_vector_length(&self, length: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_vector_length(length)
             .map_err(abstraction_err_to_pyerr)
@@ -110,6 +150,7 @@ impl System {
     /// set the pncVectorOffset of the system
     #[setter]
     fn set_pnc_vector_offset(&self, offset: Option<u32>) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .set_pnc_vector_offset(offset)
             .map_err(abstraction_err_to_pyerr)
@@ -125,12 +166,32 @@ impl System {
     #[pyo3(signature = (name, package, /))]
     #[pyo3(text_signature = "(self, name: str, package: ArPackage, /)")]
     fn create_ecu_instance(&self, name: &str, package: &ArPackage) -> PyResult<EcuInstance> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ecu_instance(name, &package.0) {
             Ok(ecu) => Ok(EcuInstance(ecu)),
             Err(error) => PyResult::Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `EcuInstance` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an ECU-INSTANCE
+    #[pyo3(signature = (name, package, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, /)")]
+    fn get_or_create_ecu_instance(&self, name: &str, package: &ArPackage) -> PyResult<EcuInstance> {
+        match find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::EcuInstance,
+        )? {
+            Some(existing) => autosar_data_abstraction::EcuInstance::try_from(existing)
+                .map(EcuInstance)
+                .map_err(abstraction_err_to_pyerr),
+            None => self.create_ecu_instance(name, package),
+        }
+    }
+
     /// get an iterator over all ECU-INSTANCEs in this SYSTEM
     fn ecu_instances(&self) -> EcuInstanceIterator {
         EcuInstanceIterator::new(self.0.ecu_instances().map(EcuInstance))
@@ -150,6 +211,7 @@ impl System {
         package: &ArPackage,
         can_baudrate: Option<u32>,
     ) -> PyResult<CanCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_can_cluster(cluster_name, &package.0, can_baudrate)
@@ -170,6 +232,7 @@ impl System {
         cluster_name: &str,
         package: &ArPackage,
     ) -> PyResult<EthernetCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_ethernet_cluster(cluster_name, &package.0) {
             Ok(cluster) => Ok(EthernetCluster(cluster)),
             Err(error) => PyResult::Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -192,6 +255,7 @@ impl System {
         package: &ArPackage,
         settings: &FlexrayClusterSettings,
     ) -> PyResult<FlexrayCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_cluster(cluster_name, &package.0, &settings.0)
@@ -208,6 +272,7 @@ impl System {
     #[pyo3(signature = (cluster_name, package, /))]
     #[pyo3(text_signature = "(self, cluster_name: str, package: ArPackage, /)")]
     fn create_lin_cluster(&self, cluster_name: &str, package: &ArPackage) -> PyResult<LinCluster> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_lin_cluster(cluster_name, &package.0) {
             Ok(cluster) => Ok(LinCluster(cluster)),
             Err(error) => PyResult::Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -244,12 +309,39 @@ impl System {
         package: &ArPackage,
         byte_length: u64,
     ) -> PyResult<CanFrame> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_can_frame(name, &package.0, byte_length) {
             Ok(frame) => Ok(CanFrame(frame)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `CanFrame` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a CAN-FRAME, or if its length differs
+    #[pyo3(signature = (name, package, byte_length, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, byte_length: int, /)")]
+    fn get_or_create_can_frame(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        byte_length: u64,
+    ) -> PyResult<CanFrame> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::CanFrame,
+        )?
+        else {
+            return self.create_can_frame(name, package, byte_length);
+        };
+        let value = autosar_data_abstraction::communication::CanFrame::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        check_length(&existing, value.length(), byte_length)?;
+        Ok(CanFrame(value))
+    }
+
     /// create a new [`FlexrayFrame`]
     ///
     /// This new frame needs to be linked to a `FlexrayPhysicalChannel`
@@ -261,12 +353,40 @@ impl System {
         package: &ArPackage,
         byte_length: u64,
     ) -> PyResult<FlexrayFrame> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_flexray_frame(name, &package.0, byte_length) {
             Ok(frame) => Ok(FlexrayFrame(frame)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `FlexrayFrame` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not a FLEXRAY-FRAME, or if its length differs
+    #[pyo3(signature = (name, package, byte_length, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, byte_length: int, /)")]
+    fn get_or_create_flexray_frame(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        byte_length: u64,
+    ) -> PyResult<FlexrayFrame> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::FlexrayFrame,
+        )?
+        else {
+            return self.create_flexray_frame(name, package, byte_length);
+        };
+        let value =
+            autosar_data_abstraction::communication::FlexrayFrame::try_from(existing.clone())
+                .map_err(abstraction_err_to_pyerr)?;
+        check_length(&existing, value.length(), byte_length)?;
+        Ok(FlexrayFrame(value))
+    }
+
     /// create a new [`LinEventTriggeredFrame`]
     ///
     /// This new frame needs to be linked to a `LinPhysicalChannel`
@@ -278,6 +398,7 @@ impl System {
         package: &ArPackage,
         byte_length: u64,
     ) -> PyResult<LinEventTriggeredFrame> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_lin_event_triggered_frame(name, &package.0, byte_length)
@@ -298,6 +419,7 @@ impl System {
         package: &ArPackage,
         byte_length: u64,
     ) -> PyResult<LinSporadicFrame> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_lin_sporadic_frame(name, &package.0, byte_length)
@@ -318,6 +440,7 @@ impl System {
         package: &ArPackage,
         byte_length: u64,
     ) -> PyResult<LinUnconditionalFrame> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_lin_unconditional_frame(name, &package.0, byte_length)
@@ -370,6 +493,7 @@ impl System {
         syssignal: &SystemSignal,
         datatype: Option<&SwBaseType>,
     ) -> PyResult<ISignal> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_isignal(
             name,
             &package.0,
@@ -382,6 +506,59 @@ impl System {
         }
     }
 
+    /// get the isignal with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an I-SIGNAL, or if
+    /// its length, system signal or datatype differ from the requested ones
+    #[pyo3(signature = (name, package, bit_length, syssignal, /, *, datatype=None))]
+    #[pyo3(
+        text_signature = "(self, name: str, package: ArPackage, bit_length: int, syssignal: SystemSignal, /, *, datatype: Optional[SwBaseType] = None)"
+    )]
+    fn get_or_create_isignal(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        bit_length: u64,
+        syssignal: &SystemSignal,
+        datatype: Option<&SwBaseType>,
+    ) -> PyResult<ISignal> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ISignal,
+        )?
+        else {
+            return self.create_isignal(name, package, bit_length, syssignal, datatype);
+        };
+        let isignal = autosar_data_abstraction::communication::ISignal::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        if isignal.length() != Some(bit_length) {
+            return Err(incompatible_element_err(
+                &existing,
+                &format!(
+                    "its length is {:?} instead of {bit_length}",
+                    isignal.length()
+                ),
+            ));
+        }
+        if isignal.system_signal().as_ref() != Some(&syssignal.0) {
+            return Err(incompatible_element_err(
+                &existing,
+                "it refers to a different system signal",
+            ));
+        }
+        if let Some(datatype) = datatype
+            && isignal.datatype().as_ref() != Some(&datatype.0)
+        {
+            return Err(incompatible_element_err(
+                &existing,
+                "it uses a different datatype",
+            ));
+        }
+        Ok(ISignal(isignal))
+    }
+
     /// iterate over all ISignals in the System
     ///
     /// This iterator returns all ISignals that are connected to the System using a FibexElementRef.
@@ -403,6 +580,7 @@ impl System {
         package: &ArPackage,
         system_signal_group: &SystemSignalGroup,
     ) -> PyResult<ISignalGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_isignal_group(name, &package.0, &system_signal_group.0)
@@ -426,32 +604,109 @@ impl System {
         package: &ArPackage,
         length: u32,
     ) -> PyResult<ISignalIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_isignal_ipdu(name, &package.0, length) {
             Ok(ipdu) => Ok(ISignalIPdu(ipdu)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `ISignalIPdu` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an I-SIGNAL-I-PDU, or if its length differs
+    #[pyo3(signature = (name, package, length, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, length: int, /)")]
+    fn get_or_create_isignal_ipdu(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        length: u32,
+    ) -> PyResult<ISignalIPdu> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::ISignalIPdu,
+        )?
+        else {
+            return self.create_isignal_ipdu(name, package, length);
+        };
+        let value =
+            autosar_data_abstraction::communication::ISignalIPdu::try_from(existing.clone())
+                .map_err(abstraction_err_to_pyerr)?;
+        check_length(&existing, value.length(), u64::from(length))?;
+        Ok(ISignalIPdu(value))
+    }
+
     /// create an [`NmPdu`] in the [`System`]
     #[pyo3(signature = (name, package, length, /))]
     #[pyo3(text_signature = "(self, name: str, package: ArPackage, length: int, /)")]
     fn create_nm_pdu(&self, name: &str, package: &ArPackage, length: u32) -> PyResult<NmPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_nm_pdu(name, &package.0, length) {
             Ok(ipdu) => Ok(NmPdu(ipdu)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `NmPdu` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an NM-PDU, or if its length differs
+    #[pyo3(signature = (name, package, length, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, length: int, /)")]
+    fn get_or_create_nm_pdu(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        length: u32,
+    ) -> PyResult<NmPdu> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::NmPdu,
+        )?
+        else {
+            return self.create_nm_pdu(name, package, length);
+        };
+        let value = autosar_data_abstraction::communication::NmPdu::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        check_length(&existing, value.length(), u64::from(length))?;
+        Ok(NmPdu(value))
+    }
+
     /// create an [`NPdu`] in the [`System`]
     #[pyo3(signature = (name, package, length, /))]
     #[pyo3(text_signature = "(self, name: str, package: ArPackage, length: int, /)")]
     fn create_n_pdu(&self, name: &str, package: &ArPackage, length: u32) -> PyResult<NPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_n_pdu(name, &package.0, length) {
             Ok(ipdu) => Ok(NPdu(ipdu)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
         }
     }
 
+    /// get the `NPdu` with the given name in the package, or create it if it does not exist
+    ///
+    /// Raises an error if an element with this name exists, but it is not an N-PDU, or if its length differs
+    #[pyo3(signature = (name, package, length, /))]
+    #[pyo3(text_signature = "(self, name: str, package: ArPackage, length: int, /)")]
+    fn get_or_create_n_pdu(&self, name: &str, package: &ArPackage, length: u32) -> PyResult<NPdu> {
+        let Some(existing) = find_existing_element(
+            package.0.element(),
+            ElementName::Elements,
+            name,
+            ElementName::NPdu,
+        )?
+        else {
+            return self.create_n_pdu(name, package, length);
+        };
+        let value = autosar_data_abstraction::communication::NPdu::try_from(existing.clone())
+            .map_err(abstraction_err_to_pyerr)?;
+        check_length(&existing, value.length(), u64::from(length))?;
+        Ok(NPdu(value))
+    }
+
     /// create a [`DcmIPdu`] in the [`System`]
     #[pyo3(signature = (name, package, length, diag_pdu_type, /))]
     #[pyo3(
@@ -464,6 +719,7 @@ impl System {
         length: u32,
         diag_pdu_type: DiagPduType,
     ) -> PyResult<DcmIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_dcm_ipdu(name, &package.0, length, diag_pdu_type.into())
@@ -485,6 +741,7 @@ impl System {
         length: u32,
         category: GeneralPurposePduCategory,
     ) -> PyResult<GeneralPurposePdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_general_purpose_pdu(name, &package.0, length, category.into())
@@ -506,6 +763,7 @@ impl System {
         length: u32,
         category: GeneralPurposeIPduCategory,
     ) -> PyResult<GeneralPurposeIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_general_purpose_ipdu(name, &package.0, length, category.into())
@@ -528,6 +786,7 @@ impl System {
         header_type: ContainerIPduHeaderType,
         rx_accept: RxAcceptContainedIPdu,
     ) -> PyResult<ContainerIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_container_ipdu(
             name,
             &package.0,
@@ -552,6 +811,7 @@ impl System {
         length: u32,
         secure_props: &SecureCommunicationProps,
     ) -> PyResult<SecuredIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_secured_ipdu(name, &package.0, length, &secure_props.into())
@@ -570,6 +830,7 @@ impl System {
         package: &ArPackage,
         length: u32,
     ) -> PyResult<MultiplexedIPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_multiplexed_ipdu(name, &package.0, length) {
             Ok(ipdu) => Ok(MultiplexedIPdu(ipdu)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -585,6 +846,7 @@ impl System {
         package: &ArPackage,
         length: u32,
     ) -> PyResult<UserDefinedPdu> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_user_defined_pdu(name, &package.0, length) {
             Ok(ipdu) => Ok(UserDefinedPdu(ipdu)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -636,6 +898,7 @@ impl System {
         package: &ArPackage,
         communication_direction: CommunicationDirection,
     ) -> PyResult<ISignalIPduGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_isignal_ipdu_group(name, &package.0, communication_direction.into())
@@ -660,6 +923,7 @@ impl System {
         name: &str,
         package: &ArPackage,
     ) -> PyResult<SocketConnectionIpduIdentifierSet> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_socket_connection_ipdu_identifier_set(name, &package.0)
@@ -684,6 +948,7 @@ impl System {
         package: &ArPackage,
         control_type: Option<EventGroupControlType>,
     ) -> PyResult<SoAdRoutingGroup> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_so_ad_routing_group(
             name,
             &package.0,
@@ -704,6 +969,7 @@ impl System {
         name: &str,
         package: &ArPackage,
     ) -> PyResult<ServiceInstanceCollectionSet> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_service_instance_collection_set(name, &package.0)
@@ -724,6 +990,7 @@ impl System {
         package: &ArPackage,
         cluster: &Bound<'_, PyAny>,
     ) -> PyResult<SomeipTpConfig> {
+        crate::frozen::check_element(self.0.element())?;
         let cluster = if let Ok(can_cluster) = cluster.extract::<CanCluster>() {
             autosar_data_abstraction::communication::Cluster::Can(can_cluster.0)
         } else if let Ok(ethernet_cluster) = cluster.extract::<EthernetCluster>() {
@@ -756,6 +1023,7 @@ impl System {
         package: &ArPackage,
         can_cluster: &CanCluster,
     ) -> PyResult<CanTpConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_can_tp_config(name, &package.0, &can_cluster.0)
@@ -778,6 +1046,7 @@ impl System {
         package: &ArPackage,
         eth_cluster: &EthernetCluster,
     ) -> PyResult<DoIpTpConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_doip_tp_config(name, &package.0, &eth_cluster.0)
@@ -801,6 +1070,7 @@ impl System {
         package: &ArPackage,
         flexray_cluster: &FlexrayCluster,
     ) -> PyResult<FlexrayTpConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_tp_config(name, &package.0, &flexray_cluster.0)
@@ -824,6 +1094,7 @@ impl System {
         package: &ArPackage,
         flexray_cluster: &FlexrayCluster,
     ) -> PyResult<FlexrayArTpConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self
             .0
             .create_flexray_ar_tp_config(name, &package.0, &flexray_cluster.0)
@@ -840,6 +1111,7 @@ impl System {
     #[pyo3(signature = (name, package, /))]
     #[pyo3(text_signature = "(self, name: str, package: ArPackage, /)")]
     fn create_nm_config(&self, name: &str, package: &ArPackage) -> PyResult<NmConfig> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.create_nm_config(name, &package.0) {
             Ok(config) => Ok(NmConfig(config)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -859,6 +1131,7 @@ impl System {
     #[pyo3(signature = (elem, /))]
     #[pyo3(text_signature = "(self, elem: Element, /)")]
     fn create_fibex_element_ref(&self, elem: &Element) -> PyResult<()> {
+        crate::frozen::check_element(self.0.element())?;
         self.0
             .create_fibex_element_ref(&elem.0)
             .map_err(abstraction_err_to_pyerr)
@@ -874,6 +1147,7 @@ impl System {
         name: &str,
         composition_type: &CompositionSwComponentType,
     ) -> PyResult<RootSwCompositionPrototype> {
+        crate::frozen::check_element(self.0.element())?;
         match self.0.set_root_sw_composition(name, &composition_type.0) {
             Ok(composition) => Ok(RootSwCompositionPrototype(composition)),
             Err(error) => Err(AutosarAbstractionError::new_err(error.to_string())),
@@ -900,6 +1174,8 @@ impl System {
     }
 }
 
+identifiable_pymethods!(System);
+
 iterator_wrapper!(EcuInstanceIterator, EcuInstance);
 iterator_wrapper!(
     ClusterIterator,
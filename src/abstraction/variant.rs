@@ -0,0 +1,480 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Element;
+use crate::abstraction::{AutosarAbstractionError, AutosarModelAbstraction};
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{ElementContent, ElementName};
+use pyo3::prelude::*;
+
+//##################################################################
+
+/// A `PredefinedVariant` selects one variant of a model with variation points
+///
+/// It assigns values to the system constants (SW-SYSTEMCONST) through the referenced
+/// SW-SYSTEMCONSTANT-VALUE-SETs. These values are used to evaluate the conditions of the VARIATION-POINTs.
+///
+/// Only the conditions based on system constants (SW-SYSCOND) are evaluated; post-build variant conditions are ignored.
+#[pyclass(
+    from_py_object,
+    frozen,
+    eq,
+    module = "autosar_data._autosar_data._abstraction"
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PredefinedVariant(pub(crate) autosar_data_rs::Element);
+
+#[pymethods]
+impl PredefinedVariant {
+    #[new]
+    fn new(element: &Element) -> PyResult<Self> {
+        if element.0.element_name() == ElementName::PredefinedVariant {
+            Ok(Self(element.0.clone()))
+        } else {
+            Err(AutosarAbstractionError::new_err(format!(
+                "Conversion of {} to PredefinedVariant failed",
+                element.0.element_name()
+            )))
+        }
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.0.item_name()
+    }
+
+    #[getter]
+    fn element(&self) -> Element {
+        Element(self.0.clone())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:#?}", self.0)
+    }
+
+    fn __hash__(&self) -> isize {
+        crate::element::element_hash(&self.0)
+    }
+
+    /// get the values of all system constants in this variant, keyed by the path of the SW-SYSTEMCONST
+    ///
+    /// The values of included variants are part of the result, unless they are overridden by this variant.
+    fn system_constant_values(&self) -> PyResult<HashMap<String, f64>> {
+        system_constant_values(&self.0).map_err(AutosarAbstractionError::new_err)
+    }
+
+    /// evaluate the condition of a VARIATION-POINT in this variant
+    ///
+    /// The element can either be a VARIATION-POINT or an element that contains one.
+    /// Elements without a variation point or without a SW-SYSCOND are always active.
+    #[pyo3(signature = (element, /))]
+    #[pyo3(text_signature = "(self, element: Element, /)")]
+    fn evaluate(&self, element: &Element) -> PyResult<bool> {
+        let constants =
+            system_constant_values(&self.0).map_err(AutosarAbstractionError::new_err)?;
+        let variation_point = if element.0.element_name() == ElementName::VariationPoint {
+            Some(element.0.clone())
+        } else {
+            element.0.get_sub_element(ElementName::VariationPoint)
+        };
+        match variation_point {
+            Some(variation_point) => is_active(&variation_point, &constants),
+            None => Ok(true),
+        }
+        .map_err(AutosarAbstractionError::new_err)
+    }
+
+    /// evaluate all VARIATION-POINTs in the model
+    ///
+    /// Returns a list of (element, active), where element is the element containing the VARIATION-POINT
+    fn variation_points(&self) -> PyResult<Vec<(Element, bool)>> {
+        let constants =
+            system_constant_values(&self.0).map_err(AutosarAbstractionError::new_err)?;
+        let model = self
+            .0
+            .model()
+            .map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+        evaluate_variation_points(&model, &constants)
+            .map(|results| {
+                results
+                    .into_iter()
+                    .filter_map(|(variation_point, active)| {
+                        variation_point
+                            .parent()
+                            .ok()
+                            .flatten()
+                            .map(|owner| (Element(owner), active))
+                    })
+                    .collect()
+            })
+            .map_err(AutosarAbstractionError::new_err)
+    }
+
+    /// create a copy of the model that is bound to this variant
+    ///
+    /// In the copy, all elements whose variation point is inactive are removed, and the
+    /// VARIATION-POINTs of the remaining elements are removed. The original model is not modified.
+    ///
+    /// The header settings of the files are carried over to the copy, and the copy is frozen if the
+    /// original model is frozen.
+    fn resolve(&self) -> PyResult<AutosarModelAbstraction> {
+        let constants =
+            system_constant_values(&self.0).map_err(AutosarAbstractionError::new_err)?;
+        let model = self
+            .0
+            .model()
+            .map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+        let resolved_model = copy_model(&model)
+            .map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+
+        let variation_points = evaluate_variation_points(&resolved_model, &constants)
+            .map_err(AutosarAbstractionError::new_err)?;
+        for (variation_point, active) in variation_points {
+            // the variation point may already be gone, if an enclosing element was removed
+            if variation_point.model().is_err() {
+                continue;
+            }
+            let Ok(Some(owner)) = variation_point.parent() else {
+                continue;
+            };
+            let result = if active {
+                owner.remove_sub_element(variation_point)
+            } else if let Ok(Some(parent)) = owner.parent() {
+                parent.remove_sub_element(owner)
+            } else {
+                Ok(())
+            };
+            result.map_err(|error| AutosarAbstractionError::new_err(error.to_string()))?;
+        }
+
+        // the copy can only be frozen after the inactive elements have been removed
+        if crate::frozen::is_frozen(&model) {
+            crate::frozen::freeze(&resolved_model);
+        }
+        Ok(AutosarModelAbstraction(
+            autosar_data_abstraction::AutosarModelAbstraction::new(resolved_model),
+        ))
+    }
+}
+
+//##################################################################
+
+/// get all PREDEFINED-VARIANTs in the model, sorted by path
+pub(crate) fn predefined_variants(model: &autosar_data_rs::AutosarModel) -> Vec<PredefinedVariant> {
+    super::identifiables_by_name(model, &[ElementName::PredefinedVariant])
+        .into_iter()
+        .map(PredefinedVariant)
+        .collect()
+}
+
+/// create a copy of the model, including the header settings of its files
+fn copy_model(
+    model: &autosar_data_rs::AutosarModel,
+) -> Result<autosar_data_rs::AutosarModel, autosar_data_rs::AutosarDataError> {
+    let copy = model.duplicate()?;
+    crate::file_header::copy_headers(model, &copy);
+    Ok(copy)
+}
+
+/// evaluate the conditions of all VARIATION-POINTs in the model
+fn evaluate_variation_points(
+    model: &autosar_data_rs::AutosarModel,
+    constants: &HashMap<String, f64>,
+) -> Result<Vec<(autosar_data_rs::Element, bool)>, String> {
+    model
+        .elements_dfs()
+        .filter(|(_, element)| element.element_name() == ElementName::VariationPoint)
+        .map(|(_, variation_point)| {
+            let active = is_active(&variation_point, constants)?;
+            Ok((variation_point, active))
+        })
+        .collect()
+}
+
+/// evaluate the SW-SYSCOND of a VARIATION-POINT; a variation point without a condition is always active
+fn is_active(
+    variation_point: &autosar_data_rs::Element,
+    constants: &HashMap<String, f64>,
+) -> Result<bool, String> {
+    match variation_point.get_sub_element(ElementName::SwSyscond) {
+        Some(condition) => Ok(evaluate_formula(&condition, constants)? != 0.0),
+        None => Ok(true),
+    }
+}
+
+/// collect the values of the system constants of a PREDEFINED-VARIANT, including all included variants
+fn system_constant_values(
+    variant: &autosar_data_rs::Element,
+) -> Result<HashMap<String, f64>, String> {
+    let mut values = HashMap::new();
+    let mut visited = HashSet::new();
+    collect_system_constant_values(variant, &mut values, &mut visited)?;
+    Ok(values)
+}
+
+fn collect_system_constant_values(
+    variant: &autosar_data_rs::Element,
+    values: &mut HashMap<String, f64>,
+    visited: &mut HashSet<autosar_data_rs::WeakElement>,
+) -> Result<(), String> {
+    if !visited.insert(variant.downgrade()) {
+        return Ok(());
+    }
+    // the values of the included variants are collected first, so that they can be overridden
+    if let Some(included_refs) = variant.get_sub_element(ElementName::IncludedVariantRefs) {
+        for included_ref in included_refs.sub_elements() {
+            let included = included_ref
+                .get_reference_target()
+                .map_err(|error| error.to_string())?;
+            collect_system_constant_values(&included, values, visited)?;
+        }
+    }
+
+    let Some(set_refs) = variant.get_sub_element(ElementName::SwSystemconstantValueSetRefs) else {
+        return Ok(());
+    };
+    for set_ref in set_refs.sub_elements() {
+        let value_set = set_ref
+            .get_reference_target()
+            .map_err(|error| error.to_string())?;
+        let Some(constant_values) = value_set.get_sub_element(ElementName::SwSystemconstantValues)
+        else {
+            continue;
+        };
+        for constant_value in constant_values.sub_elements() {
+            let Some(constant_path) = constant_value
+                .get_sub_element(ElementName::SwSystemconstRef)
+                .and_then(|constant_ref| constant_ref.character_data())
+                .and_then(|cdata| cdata.string_value())
+            else {
+                continue;
+            };
+            let Some(value_element) = constant_value.get_sub_element(ElementName::Value) else {
+                continue;
+            };
+            let value = evaluate_formula(&value_element, values)
+                .map_err(|error| format!("invalid value of {constant_path}: {error}"))?;
+            values.insert(constant_path, value);
+        }
+    }
+    Ok(())
+}
+
+/// a token of a formula in the AUTOSAR formula language
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Number(f64),
+    Operator(&'static str),
+}
+
+/// all operators, longer operators first so that "<=" is not tokenized as "<" followed by "="
+const OPERATORS: [&str; 18] = [
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "+", "-", "*", "/", "%", "!", "(", ")", "?", ":",
+];
+
+/// evaluate the formula contained in an element, e.g. a SW-SYSCOND
+///
+/// The formula consists of text and references to system constants (SYSC-REF). Logical
+/// expressions evaluate to 1.0 (true) or 0.0 (false).
+fn evaluate_formula(
+    element: &autosar_data_rs::Element,
+    constants: &HashMap<String, f64>,
+) -> Result<f64, String> {
+    let mut tokens = Vec::new();
+    for content in element.content() {
+        match content {
+            ElementContent::CharacterData(cdata) => tokenize(&cdata.to_string(), &mut tokens)?,
+            ElementContent::Element(sub_element)
+                if sub_element.element_name() == ElementName::SyscRef =>
+            {
+                let path = sub_element
+                    .character_data()
+                    .and_then(|cdata| cdata.string_value())
+                    .unwrap_or_default();
+                let value = constants
+                    .get(&path)
+                    .ok_or_else(|| format!("the system constant {path} has no value"))?;
+                tokens.push(Token::Number(*value));
+            }
+            ElementContent::Element(sub_element) => {
+                return Err(format!(
+                    "{} is not supported in formulas",
+                    sub_element.element_name()
+                ));
+            }
+        }
+    }
+    let mut parser = FormulaParser { tokens, pos: 0 };
+    let value = parser.conditional()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(format!(
+            "unexpected {:?} in formula",
+            parser.tokens[parser.pos]
+        ));
+    }
+    Ok(value)
+}
+
+fn tokenize(text: &str, tokens: &mut Vec<Token>) -> Result<(), String> {
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        if let Some(operator) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Operator(operator));
+            rest = &rest[operator.len()..];
+        } else if rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+                .unwrap_or(rest.len());
+            let number = &rest[..len];
+            let value = if let Some(hex) = number
+                .strip_prefix("0x")
+                .or_else(|| number.strip_prefix("0X"))
+            {
+                u64::from_str_radix(hex, 16).map(|value| value as f64).ok()
+            } else {
+                number.parse::<f64>().ok()
+            };
+            let value = value.ok_or_else(|| format!("invalid number \"{number}\" in formula"))?;
+            tokens.push(Token::Number(value));
+            rest = &rest[len..];
+        } else {
+            return Err(format!("unsupported formula \"{}\"", text.trim()));
+        }
+        rest = rest.trim_start();
+    }
+    Ok(())
+}
+
+/// recursive descent parser for formulas, which evaluates the formula while parsing it
+///
+/// The operator precedence follows the C language.
+struct FormulaParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl FormulaParser {
+    fn next_operator(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Operator(op)) if operators.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, operator: &'static str) -> Result<(), String> {
+        self.next_operator(&[operator])
+            .map(|_| ())
+            .ok_or_else(|| format!("expected \"{operator}\" in formula"))
+    }
+
+    fn conditional(&mut self) -> Result<f64, String> {
+        let condition = self.logical_or()?;
+        if self.next_operator(&["?"]).is_some() {
+            let if_true = self.conditional()?;
+            self.expect(":")?;
+            let if_false = self.conditional()?;
+            Ok(if condition != 0.0 { if_true } else { if_false })
+        } else {
+            Ok(condition)
+        }
+    }
+
+    fn logical_or(&mut self) -> Result<f64, String> {
+        let mut value = self.logical_and()?;
+        while self.next_operator(&["||"]).is_some() {
+            let rhs = self.logical_and()?;
+            value = bool_value(value != 0.0 || rhs != 0.0);
+        }
+        Ok(value)
+    }
+
+    fn logical_and(&mut self) -> Result<f64, String> {
+        let mut value = self.equality()?;
+        while self.next_operator(&["&&"]).is_some() {
+            let rhs = self.equality()?;
+            value = bool_value(value != 0.0 && rhs != 0.0);
+        }
+        Ok(value)
+    }
+
+    fn equality(&mut self) -> Result<f64, String> {
+        let mut value = self.relational()?;
+        while let Some(op) = self.next_operator(&["==", "!="]) {
+            let rhs = self.relational()?;
+            value = bool_value((value == rhs) == (op == "=="));
+        }
+        Ok(value)
+    }
+
+    fn relational(&mut self) -> Result<f64, String> {
+        let mut value = self.additive()?;
+        while let Some(op) = self.next_operator(&["<", "<=", ">", ">="]) {
+            let rhs = self.additive()?;
+            value = bool_value(match op {
+                "<" => value < rhs,
+                "<=" => value <= rhs,
+                ">" => value > rhs,
+                _ => value >= rhs,
+            });
+        }
+        Ok(value)
+    }
+
+    fn additive(&mut self) -> Result<f64, String> {
+        let mut value = self.multiplicative()?;
+        while let Some(op) = self.next_operator(&["+", "-"]) {
+            let rhs = self.multiplicative()?;
+            value = if op == "+" { value + rhs } else { value - rhs };
+        }
+        Ok(value)
+    }
+
+    fn multiplicative(&mut self) -> Result<f64, String> {
+        let mut value = self.unary()?;
+        while let Some(op) = self.next_operator(&["*", "/", "%"]) {
+            let rhs = self.unary()?;
+            if op != "*" && rhs == 0.0 {
+                return Err("division by zero in formula".to_string());
+            }
+            value = match op {
+                "*" => value * rhs,
+                "/" => value / rhs,
+                _ => value % rhs,
+            };
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        match self.next_operator(&["!", "-", "+"]) {
+            Some("!") => Ok(bool_value(self.unary()? == 0.0)),
+            Some("-") => Ok(-self.unary()?),
+            Some(_) => self.unary(),
+            None => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<f64, String> {
+        match self.tokens.get(self.pos).copied() {
+            Some(Token::Number(value)) => {
+                self.pos += 1;
+                Ok(value)
+            }
+            Some(Token::Operator("(")) => {
+                self.pos += 1;
+                let value = self.conditional()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Some(token) => Err(format!("unexpected {token:?} in formula")),
+            None => Err("unexpected end of formula".to_string()),
+        }
+    }
+}
+
+fn bool_value(value: bool) -> f64 {
+    if value { 1.0 } else { 0.0 }
+}
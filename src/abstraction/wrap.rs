@@ -0,0 +1,462 @@
+use super::{
+    arpackage, communication, datatype, ecu_configuration, ecuinstance, software_component, system,
+    variant,
+};
+use crate::Element;
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::ElementName;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use pyo3::{IntoPyObjectExt, PyTypeInfo};
+
+//##################################################################
+
+/// Wrap an `Element` in the matching abstraction class
+///
+/// The class is selected based on the element name, e.g. an I-SIGNAL becomes an `ISignal` and
+/// a CAN-FRAME becomes a `CanFrame`. Returns `None` if there is no abstraction class for the element.
+#[pyfunction]
+#[pyo3(signature = (element, /))]
+#[pyo3(text_signature = "(element: Element, /)")]
+pub(crate) fn wrap(py: Python<'_>, element: &Element) -> PyResult<Option<Py<PyAny>>> {
+    match wrapper_type(py, &element.0) {
+        Some(wrapper_type) => Ok(Some(wrapper_type.call1((element.clone(),))?.unbind())),
+        None => Ok(None),
+    }
+}
+
+/// wrap an element in the matching abstraction class, or return the plain `Element` if there is no such class
+///
+/// Errors from the constructor of the abstraction class are returned to the caller.
+pub(crate) fn wrap_or_element(
+    py: Python<'_>,
+    element: autosar_data_rs::Element,
+) -> PyResult<Py<PyAny>> {
+    let element = Element(element);
+    match wrap(py, &element)? {
+        Some(wrapped) => Ok(wrapped),
+        None => element.into_py_any(py),
+    }
+}
+
+/// get the type of the abstraction class that wraps the given element
+fn wrapper_type<'py>(
+    py: Python<'py>,
+    element: &autosar_data_rs::Element,
+) -> Option<Bound<'py, PyType>> {
+    let type_object = match element.element_name() {
+        ElementName::ApplicationArrayDataType => {
+            datatype::ApplicationArrayDataType::type_object(py)
+        }
+        ElementName::ApplicationError => software_component::ApplicationError::type_object(py),
+        ElementName::ApplicationPrimitiveDataType => {
+            datatype::ApplicationPrimitiveDataType::type_object(py)
+        }
+        ElementName::ApplicationRecordDataType => {
+            datatype::ApplicationRecordDataType::type_object(py)
+        }
+        ElementName::ApplicationRecordElement => {
+            datatype::ApplicationRecordElement::type_object(py)
+        }
+        ElementName::ApplicationSwComponentType => {
+            software_component::ApplicationSwComponentType::type_object(py)
+        }
+        ElementName::ArPackage => arpackage::ArPackage::type_object(py),
+        ElementName::ArgumentDataPrototype => {
+            software_component::ArgumentDataPrototype::type_object(py)
+        }
+        ElementName::AssemblySwConnector => {
+            software_component::AssemblySwConnector::type_object(py)
+        }
+        ElementName::AsynchronousServerCallReturnsEvent => {
+            software_component::AsynchronousServerCallReturnsEvent::type_object(py)
+        }
+        ElementName::BackgroundEvent => software_component::BackgroundEvent::type_object(py),
+        ElementName::CanCluster => communication::CanCluster::type_object(py),
+        ElementName::CanCommunicationConnector => {
+            communication::CanCommunicationConnector::type_object(py)
+        }
+        ElementName::CanCommunicationController => {
+            communication::CanCommunicationController::type_object(py)
+        }
+        ElementName::CanFrame => communication::CanFrame::type_object(py),
+        ElementName::CanFrameTriggering => communication::CanFrameTriggering::type_object(py),
+        ElementName::CanNmCluster => communication::CanNmCluster::type_object(py),
+        ElementName::CanNmClusterCoupling => communication::CanNmClusterCoupling::type_object(py),
+        ElementName::CanNmNode => communication::CanNmNode::type_object(py),
+        ElementName::CanPhysicalChannel => communication::CanPhysicalChannel::type_object(py),
+        ElementName::CanTpAddress => communication::CanTpAddress::type_object(py),
+        ElementName::CanTpChannel => communication::CanTpChannel::type_object(py),
+        ElementName::CanTpConfig => communication::CanTpConfig::type_object(py),
+        ElementName::CanTpConnection => communication::CanTpConnection::type_object(py),
+        ElementName::CanTpEcu => communication::CanTpEcu::type_object(py),
+        ElementName::CanTpNode => communication::CanTpNode::type_object(py),
+        ElementName::ClientServerInterface => {
+            software_component::ClientServerInterface::type_object(py)
+        }
+        ElementName::ClientServerOperation => {
+            software_component::ClientServerOperation::type_object(py)
+        }
+        ElementName::ComplexDeviceDriverSwComponentType => {
+            software_component::ComplexDeviceDriverSwComponentType::type_object(py)
+        }
+        ElementName::CompositionSwComponentType => {
+            software_component::CompositionSwComponentType::type_object(py)
+        }
+        ElementName::CompuMethod => datatype::CompuMethod::type_object(py),
+        ElementName::CompuScale => datatype::CompuScale::type_object(py),
+        ElementName::ConstantSpecification => datatype::ConstantSpecification::type_object(py),
+        ElementName::ConsumedEventGroup => {
+            if in_service_instance_collection(element) {
+                communication::ConsumedEventGroup::type_object(py)
+            } else {
+                communication::ConsumedEventGroupV1::type_object(py)
+            }
+        }
+        ElementName::ConsumedServiceInstance => {
+            if in_service_instance_collection(element) {
+                communication::ConsumedServiceInstance::type_object(py)
+            } else {
+                communication::ConsumedServiceInstanceV1::type_object(py)
+            }
+        }
+        ElementName::ContainerIPdu => communication::ContainerIPdu::type_object(py),
+        ElementName::DataConstr => datatype::DataConstr::type_object(py),
+        ElementName::DataConstrRule => datatype::DataConstrRule::type_object(py),
+        ElementName::DataReceiveErrorEvent => {
+            software_component::DataReceiveErrorEvent::type_object(py)
+        }
+        ElementName::DataReceivedEvent => software_component::DataReceivedEvent::type_object(py),
+        ElementName::DataSendCompletedEvent => {
+            software_component::DataSendCompletedEvent::type_object(py)
+        }
+        ElementName::DataTransformation => communication::DataTransformation::type_object(py),
+        ElementName::DataTransformationSet => communication::DataTransformationSet::type_object(py),
+        ElementName::DataTypeMap => datatype::DataTypeMap::type_object(py),
+        ElementName::DataTypeMappingSet => datatype::DataTypeMappingSet::type_object(py),
+        ElementName::DataWriteCompletedEvent => {
+            software_component::DataWriteCompletedEvent::type_object(py)
+        }
+        ElementName::DcmIPdu => communication::DcmIPdu::type_object(py),
+        ElementName::DelegationSwConnector => {
+            software_component::DelegationSwConnector::type_object(py)
+        }
+        ElementName::DoIpLogicAddress => communication::DoIpLogicAddress::type_object(py),
+        ElementName::DoIpTpConfig => communication::DoIpTpConfig::type_object(py),
+        ElementName::DoIpTpConnection => communication::DoIpTpConnection::type_object(py),
+        ElementName::EcuAbstractionSwComponentType => {
+            software_component::EcuAbstractionSwComponentType::type_object(py)
+        }
+        ElementName::EcuInstance => ecuinstance::EcuInstance::type_object(py),
+        ElementName::EcucAddInfoParamDef => ecu_configuration::EcucAddInfoParamDef::type_object(py),
+        ElementName::EcucAddInfoParamValue => {
+            ecu_configuration::EcucAddInfoParamValue::type_object(py)
+        }
+        ElementName::EcucBooleanParamDef => ecu_configuration::EcucBooleanParamDef::type_object(py),
+        ElementName::EcucChoiceContainerDef => {
+            ecu_configuration::EcucChoiceContainerDef::type_object(py)
+        }
+        ElementName::EcucChoiceReferenceDef => {
+            ecu_configuration::EcucChoiceReferenceDef::type_object(py)
+        }
+        ElementName::EcucContainerValue => ecu_configuration::EcucContainerValue::type_object(py),
+        ElementName::EcucDefinitionCollection => {
+            ecu_configuration::EcucDefinitionCollection::type_object(py)
+        }
+        ElementName::EcucDestinationUriDef => {
+            ecu_configuration::EcucDestinationUriDef::type_object(py)
+        }
+        ElementName::EcucDestinationUriDefSet => {
+            ecu_configuration::EcucDestinationUriDefSet::type_object(py)
+        }
+        ElementName::EcucEnumerationLiteralDef => {
+            ecu_configuration::EcucEnumerationLiteralDef::type_object(py)
+        }
+        ElementName::EcucEnumerationParamDef => {
+            ecu_configuration::EcucEnumerationParamDef::type_object(py)
+        }
+        ElementName::EcucFloatParamDef => ecu_configuration::EcucFloatParamDef::type_object(py),
+        ElementName::EcucForeignReferenceDef => {
+            ecu_configuration::EcucForeignReferenceDef::type_object(py)
+        }
+        ElementName::EcucFunctionNameDef => ecu_configuration::EcucFunctionNameDef::type_object(py),
+        ElementName::EcucInstanceReferenceDef => {
+            ecu_configuration::EcucInstanceReferenceDef::type_object(py)
+        }
+        ElementName::EcucInstanceReferenceValue => {
+            ecu_configuration::EcucInstanceReferenceValue::type_object(py)
+        }
+        ElementName::EcucIntegerParamDef => ecu_configuration::EcucIntegerParamDef::type_object(py),
+        ElementName::EcucLinkerSymbolDef => ecu_configuration::EcucLinkerSymbolDef::type_object(py),
+        ElementName::EcucModuleConfigurationValues => {
+            ecu_configuration::EcucModuleConfigurationValues::type_object(py)
+        }
+        ElementName::EcucModuleDef => ecu_configuration::EcucModuleDef::type_object(py),
+        ElementName::EcucMultilineStringParamDef => {
+            ecu_configuration::EcucMultilineStringParamDef::type_object(py)
+        }
+        ElementName::EcucNumericalParamValue => {
+            ecu_configuration::EcucNumericalParamValue::type_object(py)
+        }
+        ElementName::EcucParamConfContainerDef => {
+            ecu_configuration::EcucParamConfContainerDef::type_object(py)
+        }
+        ElementName::EcucReferenceDef => ecu_configuration::EcucReferenceDef::type_object(py),
+        ElementName::EcucReferenceValue => ecu_configuration::EcucReferenceValue::type_object(py),
+        ElementName::EcucStringParamDef => ecu_configuration::EcucStringParamDef::type_object(py),
+        ElementName::EcucTextualParamValue => {
+            ecu_configuration::EcucTextualParamValue::type_object(py)
+        }
+        ElementName::EcucUriReferenceDef => ecu_configuration::EcucUriReferenceDef::type_object(py),
+        ElementName::EcucValueCollection => ecu_configuration::EcucValueCollection::type_object(py),
+        ElementName::Element
+            if element.parent().ok().flatten().is_some_and(|parent| {
+                parent.element_name() == ElementName::ApplicationArrayDataType
+            }) =>
+        {
+            datatype::ApplicationArrayElement::type_object(py)
+        }
+        ElementName::EndToEndTransformationISignalProps => {
+            communication::EndToEndTransformationISignalProps::type_object(py)
+        }
+        ElementName::EthernetCluster => communication::EthernetCluster::type_object(py),
+        ElementName::EthernetCommunicationConnector => {
+            communication::EthernetCommunicationConnector::type_object(py)
+        }
+        ElementName::EthernetCommunicationController => {
+            communication::EthernetCommunicationController::type_object(py)
+        }
+        ElementName::EthernetPhysicalChannel => {
+            communication::EthernetPhysicalChannel::type_object(py)
+        }
+        ElementName::EventControlledTiming => communication::EventControlledTiming::type_object(py),
+        ElementName::EventHandler => {
+            if in_service_instance_collection(element) {
+                communication::EventHandler::type_object(py)
+            } else {
+                communication::EventHandlerV1::type_object(py)
+            }
+        }
+        ElementName::ExternalTriggerOccurredEvent => {
+            software_component::ExternalTriggerOccurredEvent::type_object(py)
+        }
+        ElementName::FlexrayArTpChannel => communication::FlexrayArTpChannel::type_object(py),
+        ElementName::FlexrayArTpConfig => communication::FlexrayArTpConfig::type_object(py),
+        ElementName::FlexrayArTpConnection => communication::FlexrayArTpConnection::type_object(py),
+        ElementName::FlexrayArTpNode => communication::FlexrayArTpNode::type_object(py),
+        ElementName::FlexrayCluster => communication::FlexrayCluster::type_object(py),
+        ElementName::FlexrayCommunicationConnector => {
+            communication::FlexrayCommunicationConnector::type_object(py)
+        }
+        ElementName::FlexrayCommunicationController => {
+            communication::FlexrayCommunicationController::type_object(py)
+        }
+        ElementName::FlexrayFrame => communication::FlexrayFrame::type_object(py),
+        ElementName::FlexrayFrameTriggering => {
+            communication::FlexrayFrameTriggering::type_object(py)
+        }
+        ElementName::FlexrayNmCluster => communication::FlexrayNmCluster::type_object(py),
+        ElementName::FlexrayNmClusterCoupling => {
+            communication::FlexrayNmClusterCoupling::type_object(py)
+        }
+        ElementName::FlexrayNmNode => communication::FlexrayNmNode::type_object(py),
+        ElementName::FlexrayPhysicalChannel => {
+            communication::FlexrayPhysicalChannel::type_object(py)
+        }
+        ElementName::FlexrayTpConfig => communication::FlexrayTpConfig::type_object(py),
+        ElementName::FlexrayTpConnection => communication::FlexrayTpConnection::type_object(py),
+        ElementName::FlexrayTpConnectionControl => {
+            communication::FlexrayTpConnectionControl::type_object(py)
+        }
+        ElementName::FlexrayTpEcu => communication::FlexrayTpEcu::type_object(py),
+        ElementName::FlexrayTpNode => communication::FlexrayTpNode::type_object(py),
+        ElementName::FlexrayTpPduPool => communication::FlexrayTpPduPool::type_object(py),
+        ElementName::FramePort => communication::FramePort::type_object(py),
+        ElementName::GeneralPurposeIPdu => communication::GeneralPurposeIPdu::type_object(py),
+        ElementName::GeneralPurposePdu => communication::GeneralPurposePdu::type_object(py),
+        ElementName::IPduPort => communication::IPduPort::type_object(py),
+        ElementName::ISignal => communication::ISignal::type_object(py),
+        ElementName::ISignalGroup => communication::ISignalGroup::type_object(py),
+        ElementName::ISignalIPdu => communication::ISignalIPdu::type_object(py),
+        ElementName::ISignalIPduGroup => communication::ISignalIPduGroup::type_object(py),
+        ElementName::ISignalPort => communication::ISignalPort::type_object(py),
+        ElementName::ISignalToIPduMapping => communication::ISignalToIPduMapping::type_object(py),
+        ElementName::ISignalTriggering => communication::ISignalTriggering::type_object(py),
+        ElementName::ImplementationDataType => datatype::ImplementationDataType::type_object(py),
+        ElementName::ImplementationDataTypeElement => {
+            datatype::ImplementationDataTypeElement::type_object(py)
+        }
+        ElementName::InitEvent => software_component::InitEvent::type_object(py),
+        ElementName::InternalTriggerOccurredEvent => {
+            software_component::InternalTriggerOccurredEvent::type_object(py)
+        }
+        ElementName::LinCluster => communication::LinCluster::type_object(py),
+        ElementName::LinCommunicationConnector => {
+            communication::LinCommunicationConnector::type_object(py)
+        }
+        ElementName::LinEventTriggeredFrame => {
+            communication::LinEventTriggeredFrame::type_object(py)
+        }
+        ElementName::LinFrameTriggering => communication::LinFrameTriggering::type_object(py),
+        ElementName::LinMaster => communication::LinMaster::type_object(py),
+        ElementName::LinPhysicalChannel => communication::LinPhysicalChannel::type_object(py),
+        ElementName::LinSlave => communication::LinSlave::type_object(py),
+        ElementName::ModeAccessPoint => software_component::ModeAccessPoint::type_object(py),
+        ElementName::ModeDeclaration => software_component::ModeDeclaration::type_object(py),
+        ElementName::ModeDeclarationGroup => {
+            software_component::ModeDeclarationGroup::type_object(py)
+        }
+        ElementName::ModeGroup => software_component::ModeGroup::type_object(py),
+        ElementName::ModeSwitchInterface => {
+            software_component::ModeSwitchInterface::type_object(py)
+        }
+        ElementName::ModeSwitchPoint => software_component::ModeSwitchPoint::type_object(py),
+        ElementName::ModeSwitchedAckEvent => {
+            software_component::ModeSwitchedAckEvent::type_object(py)
+        }
+        ElementName::MultiplexedIPdu => communication::MultiplexedIPdu::type_object(py),
+        ElementName::NPdu => communication::NPdu::type_object(py),
+        ElementName::NetworkEndpoint => communication::NetworkEndpoint::type_object(py),
+        ElementName::NmConfig => communication::NmConfig::type_object(py),
+        ElementName::NmEcu => communication::NmEcu::type_object(py),
+        ElementName::NmPdu => communication::NmPdu::type_object(py),
+        ElementName::NvDataInterface => software_component::NvDataInterface::type_object(py),
+        ElementName::OperationInvokedEvent => {
+            software_component::OperationInvokedEvent::type_object(py)
+        }
+        ElementName::OsTaskExecutionEvent => {
+            software_component::OsTaskExecutionEvent::type_object(py)
+        }
+        ElementName::PPortPrototype => software_component::PPortPrototype::type_object(py),
+        ElementName::ParameterDataPrototype => {
+            software_component::ParameterDataPrototype::type_object(py)
+        }
+        ElementName::ParameterInterface => software_component::ParameterInterface::type_object(py),
+        ElementName::PassThroughSwConnector => {
+            software_component::PassThroughSwConnector::type_object(py)
+        }
+        ElementName::PduActivationRoutingGroup => {
+            communication::PduActivationRoutingGroup::type_object(py)
+        }
+        ElementName::PduToFrameMapping => communication::PduToFrameMapping::type_object(py),
+        ElementName::PduTriggering => communication::PduTriggering::type_object(py),
+        ElementName::PortGroup => software_component::PortGroup::type_object(py),
+        ElementName::PrPortPrototype => software_component::PRPortPrototype::type_object(py),
+        ElementName::PredefinedVariant => variant::PredefinedVariant::type_object(py),
+        ElementName::ProvidedServiceInstance => {
+            if in_service_instance_collection(element) {
+                communication::ProvidedServiceInstance::type_object(py)
+            } else {
+                communication::ProvidedServiceInstanceV1::type_object(py)
+            }
+        }
+        ElementName::RPortPrototype => software_component::RPortPrototype::type_object(py),
+        ElementName::RootSwCompositionPrototype => {
+            software_component::RootSwCompositionPrototype::type_object(py)
+        }
+        ElementName::RunnableEntity => software_component::RunnableEntity::type_object(py),
+        ElementName::SecuredIPdu => communication::SecuredIPdu::type_object(py),
+        ElementName::SenderReceiverInterface => {
+            software_component::SenderReceiverInterface::type_object(py)
+        }
+        ElementName::SenderReceiverToSignalMapping => {
+            system::SenderReceiverToSignalMapping::type_object(py)
+        }
+        ElementName::SensorActuatorSwComponentType => {
+            software_component::SensorActuatorSwComponentType::type_object(py)
+        }
+        ElementName::ServiceInstanceCollectionSet => {
+            communication::ServiceInstanceCollectionSet::type_object(py)
+        }
+        ElementName::ServiceSwComponentType => {
+            software_component::ServiceSwComponentType::type_object(py)
+        }
+        ElementName::SoAdRoutingGroup => communication::SoAdRoutingGroup::type_object(py),
+        ElementName::SoConIPduIdentifier => communication::SoConIPduIdentifier::type_object(py),
+        ElementName::SocketAddress => communication::SocketAddress::type_object(py),
+        ElementName::SocketConnection => communication::SocketConnection::type_object(py),
+        ElementName::SocketConnectionBundle => {
+            communication::SocketConnectionBundle::type_object(py)
+        }
+        ElementName::SocketConnectionIpduIdentifier => {
+            communication::SocketConnectionIpduIdentifier::type_object(py)
+        }
+        ElementName::SocketConnectionIpduIdentifierSet => {
+            communication::SocketConnectionIpduIdentifierSet::type_object(py)
+        }
+        ElementName::SomeipSdClientEventGroupTimingConfig => {
+            communication::SomeipSdClientEventGroupTimingConfig::type_object(py)
+        }
+        ElementName::SomeipSdClientServiceInstanceConfig => {
+            communication::SomeipSdClientServiceInstanceConfig::type_object(py)
+        }
+        ElementName::SomeipSdServerEventGroupTimingConfig => {
+            communication::SomeipSdServerEventGroupTimingConfig::type_object(py)
+        }
+        ElementName::SomeipSdServerServiceInstanceConfig => {
+            communication::SomeipSdServerServiceInstanceConfig::type_object(py)
+        }
+        ElementName::SomeipTpChannel => communication::SomeipTpChannel::type_object(py),
+        ElementName::SomeipTpConfig => communication::SomeipTpConfig::type_object(py),
+        ElementName::SomeipTpConnection => communication::SomeipTpConnection::type_object(py),
+        ElementName::SomeipTransformationISignalProps => {
+            communication::SomeIpTransformationISignalProps::type_object(py)
+        }
+        ElementName::StaticSocketConnection => {
+            communication::StaticSocketConnection::type_object(py)
+        }
+        ElementName::SwBaseType => datatype::SwBaseType::type_object(py),
+        ElementName::SwComponentPrototype => {
+            software_component::SwComponentPrototype::type_object(py)
+        }
+        ElementName::SwcInternalBehavior => {
+            software_component::SwcInternalBehavior::type_object(py)
+        }
+        ElementName::SwcModeManagerErrorEvent => {
+            software_component::SwcModeManagerErrorEvent::type_object(py)
+        }
+        ElementName::SwcModeSwitchEvent => software_component::SwcModeSwitchEvent::type_object(py),
+        ElementName::SwcToEcuMapping => system::SwcToEcuMapping::type_object(py),
+        ElementName::SynchronousServerCallPoint => {
+            software_component::SynchronousServerCallPoint::type_object(py)
+        }
+        ElementName::System => system::System::type_object(py),
+        ElementName::SystemMapping => system::SystemMapping::type_object(py),
+        ElementName::SystemSignal => communication::SystemSignal::type_object(py),
+        ElementName::SystemSignalGroup => communication::SystemSignalGroup::type_object(py),
+        ElementName::TimingEvent => software_component::TimingEvent::type_object(py),
+        ElementName::TpAddress => communication::TpAddress::type_object(py),
+        ElementName::TransformationTechnology => {
+            communication::TransformationTechnology::type_object(py)
+        }
+        ElementName::TransformerHardErrorEvent => {
+            software_component::TransformerHardErrorEvent::type_object(py)
+        }
+        ElementName::TriggerInterface => software_component::TriggerInterface::type_object(py),
+        ElementName::UdpNmCluster => communication::UdpNmCluster::type_object(py),
+        ElementName::UdpNmClusterCoupling => communication::UdpNmClusterCoupling::type_object(py),
+        ElementName::UdpNmNode => communication::UdpNmNode::type_object(py),
+        ElementName::Unit => datatype::Unit::type_object(py),
+        ElementName::UserDefinedPdu => communication::UserDefinedPdu::type_object(py),
+        ElementName::VariableAccess => software_component::VariableAccess::type_object(py),
+        ElementName::VariableDataPrototype => {
+            software_component::VariableDataPrototype::type_object(py)
+        }
+        _ => return None,
+    };
+    Some(type_object)
+}
+
+/// service instances, event handlers and consumed event groups exist in two variants:
+/// the current ones are located in a SERVICE-INSTANCE-COLLECTION-SET,
+/// while the old (V1) ones are located in the APPLICATION-ENDPOINT of a SOCKET-ADDRESS
+fn in_service_instance_collection(element: &autosar_data_rs::Element) -> bool {
+    let mut current = element.parent().ok().flatten();
+    while let Some(parent) = current {
+        if parent.element_name() == ElementName::ServiceInstanceCollectionSet {
+            return true;
+        }
+        current = parent.parent().ok().flatten();
+    }
+    false
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
@@ -50,6 +51,7 @@ impl ArxmlFile {
 
     #[setter]
     fn set_filename(&self, filename: &str) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
         self.0
             .set_filename(filename)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -62,6 +64,7 @@ impl ArxmlFile {
 
     #[setter]
     fn set_version(&self, version: AutosarVersion) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
         self.0
             .set_version(version.into())
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -156,15 +159,107 @@ impl ArxmlFile {
 
     /// Serialize the ARXML file to a string
     fn serialize(&self) -> PyResult<String> {
-        match self.0.serialize() {
+        match file_header::serialize_file(&self.0) {
             Ok(text) => Ok(text),
             Err(error) => Err(AutosarDataError::new_err(error.to_string())),
         }
     }
 
-    /// get the "xml_standalone" attribute from the header of the ARXML file
+    /// get the "standalone" attribute from the xml declaration of the ARXML file
     #[getter]
     fn xml_standalone(&self) -> Option<bool> {
-        self.0.xml_standalone()
+        file_header::get_header(&self.0)
+            .standalone
+            .unwrap_or_else(|| self.0.xml_standalone())
+    }
+
+    /// set the "standalone" attribute in the xml declaration of the ARXML file
+    #[setter]
+    fn set_xml_standalone(&self, standalone: Option<bool>) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
+        let file_standalone = self.0.xml_standalone();
+        file_header::update_header(&self.0, |header| {
+            header.standalone = (standalone != file_standalone).then_some(standalone);
+        });
+        Ok(())
+    }
+
+    /// get the encoding from the xml declaration of the ARXML file
+    ///
+    /// The file is always written as UTF-8, so the encoding is read-only. The spelling from the
+    /// loaded file, e.g. "UTF-8", is kept when the file is written. Files which declare a different
+    /// encoding are rejected when they are loaded.
+    #[getter]
+    fn encoding(&self) -> String {
+        file_header::get_header(&self.0)
+            .encoding
+            .unwrap_or_else(|| "utf-8".to_string())
+    }
+
+    /// get the comments that are placed before the root element of the ARXML file
+    #[getter]
+    fn header_comments(&self) -> Vec<String> {
+        file_header::get_header(&self.0).comments
+    }
+
+    /// set the comments that are placed before the root element of the ARXML file
+    #[setter]
+    fn set_header_comments(&self, comments: Vec<String>) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
+        if let Some(comment) = comments
+            .iter()
+            .find(|comment| comment.contains("--") || comment.ends_with('-'))
+        {
+            return Err(PyValueError::new_err(format!(
+                "invalid comment '{comment}': comments may not contain '--' or end with '-'"
+            )));
+        }
+        file_header::update_header(&self.0, |header| header.comments = comments);
+        Ok(())
+    }
+
+    /// get the value of the xsi:schemaLocation attribute of the root element of the ARXML file
+    #[getter]
+    fn schema_location(&self) -> String {
+        file_header::get_header(&self.0)
+            .schema_location
+            .unwrap_or_else(|| file_header::default_schema_location(&self.0))
+    }
+
+    /// set the value of the xsi:schemaLocation attribute of the root element of the ARXML file
+    ///
+    /// Setting the value to None restores the default, which is derived from the version of the file.
+    #[setter]
+    fn set_schema_location(&self, schema_location: Option<String>) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
+        let default_schema_location = file_header::default_schema_location(&self.0);
+        file_header::update_header(&self.0, |header| {
+            header.schema_location =
+                schema_location.filter(|value| *value != default_schema_location);
+        });
+        Ok(())
+    }
+
+    /// get the additional attributes of the root element of the ARXML file, e.g. T, S or xml:space
+    #[getter]
+    fn root_attributes(&self) -> BTreeMap<String, String> {
+        file_header::get_header(&self.0).root_attributes
+    }
+
+    /// set the additional attributes of the root element of the ARXML file, e.g. T, S or xml:space
+    #[setter]
+    fn set_root_attributes(&self, root_attributes: BTreeMap<String, String>) -> PyResult<()> {
+        frozen::check_file(&self.0)?;
+        if let Some(name) = root_attributes.keys().find(|name| {
+            matches!(name.as_str(), "xmlns" | "xmlns:xsi" | "xsi:schemaLocation")
+                || name.is_empty()
+                || name.contains(|c: char| c.is_whitespace() || "=<>\"'/".contains(c))
+        }) {
+            return Err(PyValueError::new_err(format!(
+                "'{name}' cannot be used as an additional attribute of the root element"
+            )));
+        }
+        file_header::update_header(&self.0, |header| header.root_attributes = root_attributes);
+        Ok(())
     }
 }
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{AttributeName, Element, ElementContent, ElementName};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// Build a canonical text representation of an element and all of its content
+///
+/// The text only depends on the content of the element: comments are dropped, attributes are
+/// written in a fixed order, and the listed attributes are left out entirely.
+/// If `skip_short_name` is true, the SHORT-NAME of the element itself is omitted, so that
+/// two identifiable elements with identical content but different names produce the same text.
+pub(crate) fn canonical_text(
+    element: &Element,
+    ignored_attributes: &HashSet<AttributeName>,
+    skip_short_name: bool,
+) -> String {
+    let mut output = String::new();
+    write_canonical(element, ignored_attributes, skip_short_name, &mut output);
+    output
+}
+
+fn write_canonical(
+    element: &Element,
+    ignored_attributes: &HashSet<AttributeName>,
+    skip_short_name: bool,
+    output: &mut String,
+) {
+    let mut attributes: Vec<_> = element
+        .attributes()
+        .filter(|attr| !ignored_attributes.contains(&attr.attrname))
+        .map(|attr| (attr.attrname.to_string(), attr.content.to_string()))
+        .collect();
+    attributes.sort();
+
+    let _ = write!(output, "<{}", element.element_name());
+    for (name, value) in attributes {
+        let _ = write!(output, " {name}=\"{}\"", escape(&value));
+    }
+    output.push('>');
+    for content in element.content() {
+        match content {
+            ElementContent::Element(sub_element) => {
+                if skip_short_name && sub_element.element_name() == ElementName::ShortName {
+                    continue;
+                }
+                write_canonical(&sub_element, ignored_attributes, false, output);
+            }
+            ElementContent::CharacterData(cdata) => {
+                output.push_str(&escape(&cdata.to_string()));
+            }
+        }
+    }
+    let _ = write!(output, "</{}>", element.element_name());
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Hash the given text with the named algorithm and return the digest as a hex string
+///
+/// Supported algorithms are "sha256", "sha384" and "sha512". Returns None for any other algorithm.
+pub(crate) fn hash_text(text: &str, algorithm: &str) -> Option<String> {
+    let digest = match algorithm {
+        "sha256" => Sha256::digest(text.as_bytes()).to_vec(),
+        "sha384" => Sha384::digest(text.as_bytes()).to_vec(),
+        "sha512" => Sha512::digest(text.as_bytes()).to_vec(),
+        _ => return None,
+    };
+    let mut output = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        let _ = write!(output, "{byte:02x}");
+    }
+    Some(output)
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{
+    AttributeName, CharacterData, Element, ElementContent, ElementName, EnumItem,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// The multilingual documentation fields of an identifiable element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DocField {
+    /// LONG-NAME, containing one L-4 per language
+    LongName,
+    /// DESC, containing one L-2 per language
+    Desc,
+    /// INTRODUCTION, containing paragraphs (P) with one L-1 per language
+    Introduction,
+}
+
+impl DocField {
+    pub(crate) const ALL: [DocField; 3] =
+        [DocField::LongName, DocField::Desc, DocField::Introduction];
+
+    pub(crate) fn element_name(self) -> ElementName {
+        match self {
+            DocField::LongName => ElementName::LongName,
+            DocField::Desc => ElementName::Desc,
+            DocField::Introduction => ElementName::Introduction,
+        }
+    }
+
+    fn text_element_name(self) -> ElementName {
+        match self {
+            DocField::LongName => ElementName::L4,
+            DocField::Desc => ElementName::L2,
+            DocField::Introduction => ElementName::L1,
+        }
+    }
+}
+
+impl FromStr for DocField {
+    type Err = PyErr;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        DocField::ALL
+            .into_iter()
+            .find(|field| field.element_name().to_string() == name)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "\"{name}\" is not a documentation field; valid fields are LONG-NAME, DESC and INTRODUCTION"
+                ))
+            })
+    }
+}
+
+/// get the text of a documentation field, as a map of language -> text
+///
+/// The paragraphs of an INTRODUCTION are separated by newlines. If a language is given, then
+/// only the text in this language is returned.
+pub(crate) fn get_text(
+    element: &Element,
+    field: DocField,
+    language: Option<&str>,
+) -> HashMap<String, String> {
+    let mut texts = HashMap::<String, String>::new();
+    let Some(container) = element.get_sub_element(field.element_name()) else {
+        return texts;
+    };
+    let paragraphs: Vec<Element> = if field == DocField::Introduction {
+        container
+            .sub_elements()
+            .filter(|sub_element| sub_element.element_name() == ElementName::P)
+            .collect()
+    } else {
+        vec![container]
+    };
+    for paragraph in paragraphs {
+        for text_element in paragraph
+            .sub_elements()
+            .filter(|sub_element| sub_element.element_name() == field.text_element_name())
+        {
+            let text_language = language_of(&text_element);
+            if language.is_some_and(|language| language != text_language) {
+                continue;
+            }
+            let text = text_content(&text_element);
+            texts
+                .entry(text_language)
+                .and_modify(|existing| {
+                    existing.push('\n');
+                    existing.push_str(&text);
+                })
+                .or_insert(text);
+        }
+    }
+    texts
+}
+
+/// set the text of a documentation field in one language; if the text is None, the language is removed
+///
+/// For an INTRODUCTION, each line of the text becomes a separate paragraph.
+pub(crate) fn set_text(
+    element: &Element,
+    field: DocField,
+    text: Option<&str>,
+    language: &str,
+) -> PyResult<()> {
+    crate::frozen::check_element(element)?;
+    let language_item = EnumItem::from_str(language)
+        .map_err(|_| PyValueError::new_err(format!("\"{language}\" is not a valid language")))?;
+    set_text_inner(element, field, text, language_item)
+        .map_err(|error| crate::AutosarDataError::new_err(error.to_string()))
+}
+
+fn set_text_inner(
+    element: &Element,
+    field: DocField,
+    text: Option<&str>,
+    language: EnumItem,
+) -> Result<(), autosar_data_rs::AutosarDataError> {
+    let container = match element.get_sub_element(field.element_name()) {
+        Some(container) => container,
+        None if text.is_none() => return Ok(()),
+        None => element.create_sub_element(field.element_name())?,
+    };
+    let mut paragraphs: Vec<Element> = if field == DocField::Introduction {
+        container
+            .sub_elements()
+            .filter(|sub_element| sub_element.element_name() == ElementName::P)
+            .collect()
+    } else {
+        vec![container.clone()]
+    };
+
+    // remove the existing text in this language
+    for paragraph in &paragraphs {
+        let existing: Vec<Element> = paragraph
+            .sub_elements()
+            .filter(|sub_element| {
+                sub_element.element_name() == field.text_element_name()
+                    && language_of(sub_element) == language.to_str()
+            })
+            .collect();
+        for text_element in existing {
+            paragraph.remove_sub_element(text_element)?;
+        }
+    }
+
+    if let Some(text) = text {
+        let lines: Vec<&str> = if field == DocField::Introduction {
+            text.lines().collect()
+        } else {
+            vec![text]
+        };
+        for (idx, line) in lines.into_iter().enumerate() {
+            let paragraph = match paragraphs.get(idx) {
+                Some(paragraph) => paragraph.clone(),
+                None => {
+                    let paragraph = container.create_sub_element(ElementName::P)?;
+                    paragraphs.push(paragraph.clone());
+                    paragraph
+                }
+            };
+            let text_element = paragraph.create_sub_element(field.text_element_name())?;
+            text_element.set_attribute(AttributeName::L, CharacterData::Enum(language))?;
+            text_element.insert_character_content_item(line, 0)?;
+        }
+    }
+
+    // remove paragraphs and containers that have become empty
+    if field == DocField::Introduction {
+        for paragraph in paragraphs {
+            if paragraph.sub_elements().next().is_none() {
+                container.remove_sub_element(paragraph)?;
+            }
+        }
+    }
+    if container.sub_elements().next().is_none() {
+        element.remove_sub_element(container)?;
+    }
+    Ok(())
+}
+
+/// get the languages in which a documentation field has text
+pub(crate) fn languages(element: &Element, field: DocField) -> Vec<String> {
+    get_text(element, field, None)
+        .into_iter()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(language, _)| language)
+        .collect()
+}
+
+fn language_of(element: &Element) -> String {
+    element
+        .attribute_value(AttributeName::L)
+        .map(|language| language.to_string())
+        .unwrap_or_default()
+}
+
+/// get all text inside of an element with mixed content, including the text of nested elements
+fn text_content(element: &Element) -> String {
+    let mut text = String::new();
+    for content in element.content() {
+        match content {
+            ElementContent::CharacterData(cdata) => text.push_str(&cdata.to_string()),
+            ElementContent::Element(sub_element) => text.push_str(&text_content(&sub_element)),
+        }
+    }
+    text
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
@@ -35,9 +36,7 @@ impl Element {
     }
 
     fn __hash__(&self) -> isize {
-        let mut hasher = DefaultHasher::new();
-        self.0.hash(&mut hasher);
-        hasher.finish() as isize
+        element_hash(&self.0)
     }
 
     /// Serialize the element to a string in XML format
@@ -45,6 +44,19 @@ impl Element {
         self.0.serialize()
     }
 
+    /// Calculate a hash of the content of this element and all of its sub elements
+    ///
+    /// The hash is calculated over a canonical serialization of the element, so it is stable across
+    /// runs and platforms. Comments and the ignored attributes are not included.
+    #[pyo3(signature = (algorithm="sha256", ignore=None))]
+    #[pyo3(
+        text_signature = "(self, algorithm: str = \"sha256\", ignore: Optional[List[AttributeName]] = None)"
+    )]
+    fn content_hash(&self, algorithm: &str, ignore: Option<Vec<String>>) -> PyResult<String> {
+        let ignored_attributes = ignored_attribute_set(ignore)?;
+        element_content_hash(&self.0, algorithm, &ignored_attributes)
+    }
+
     #[getter]
     fn parent(&self) -> PyResult<Option<Element>> {
         match self.0.parent() {
@@ -80,6 +92,7 @@ impl Element {
 
     #[setter]
     fn set_item_name(&self, new_name: &str) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         match self.0.set_item_name(new_name) {
             Ok(()) => Ok(()),
             Err(error) => Err(AutosarDataError::new_err(error.to_string())),
@@ -125,6 +138,7 @@ impl Element {
     #[pyo3(signature = (name_str, /, position = None))]
     #[pyo3(text_signature = "(self, name: str, /, position: Optional[int] = None)")]
     fn create_sub_element(&self, name_str: &str, position: Option<usize>) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
         let element_name = get_element_name(name_str)?;
         if let Some(position) = position {
             match self.0.create_sub_element_at(element_name, position) {
@@ -148,6 +162,7 @@ impl Element {
         item_name: &str,
         position: Option<usize>,
     ) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
         let element_name = get_element_name(name_str)?;
         if let Some(position) = position {
             match self
@@ -171,6 +186,7 @@ impl Element {
     #[pyo3(signature = (name_str, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn get_or_create_sub_element(&self, name_str: &str) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
         let element_name = get_element_name(name_str)?;
         match self.0.get_or_create_sub_element(element_name) {
             Ok(element) => Ok(Element(element)),
@@ -186,6 +202,7 @@ impl Element {
         name_str: &str,
         item_name: &str,
     ) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
         let element_name = get_element_name(name_str)?;
         match self
             .0
@@ -206,6 +223,7 @@ impl Element {
         other: &Element,
         position: Option<usize>,
     ) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
         if let Some(position) = position {
             match self.0.create_copied_sub_element_at(&other.0, position) {
                 Ok(element) => Ok(Element(element)),
@@ -219,6 +237,120 @@ impl Element {
         }
     }
 
+    /// Import a copy of an element from another model as a sub-element of this element
+    ///
+    /// References in the copy are rewritten using the rename_map, which maps path prefixes in the source
+    /// model to path prefixes in this model. The longest matching prefix is used. References to the
+    /// imported element itself or to its sub elements are always redirected to the copy.
+    /// If this element already has a sub-element with the same name, on_conflict decides what happens:
+    /// "error" raises an exception, "skip" returns the existing element, "replace" removes the existing
+    /// element before importing, and "rename" imports the copy with a new unique name.
+    #[pyo3(signature = (other, /, rename_map = None, on_conflict = "error"))]
+    #[pyo3(
+        text_signature = "(self, other: Element, /, rename_map: Optional[Dict[str, str]] = None, on_conflict: str = \"error\")"
+    )]
+    fn import_from(
+        &self,
+        other: &Element,
+        rename_map: Option<HashMap<String, String>>,
+        on_conflict: &str,
+    ) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
+        if !matches!(on_conflict, "error" | "skip" | "replace" | "rename") {
+            return Err(PyValueError::new_err(format!(
+                "Invalid value '{on_conflict}' for on_conflict. Valid values are 'error', 'skip', 'replace' and 'rename'"
+            )));
+        }
+        let mut prefixes: Vec<(String, String)> =
+            rename_map.unwrap_or_default().into_iter().collect();
+        prefixes.sort_by(|(prefix_a, _), (prefix_b, _)| {
+            prefix_b
+                .len()
+                .cmp(&prefix_a.len())
+                .then_with(|| prefix_a.cmp(prefix_b))
+        });
+        let source_path = other.0.path().ok();
+
+        let existing = other.0.item_name().and_then(|name| {
+            self.0
+                .sub_elements()
+                .find(|sub_element| sub_element.item_name().as_ref() == Some(&name))
+        });
+        let mut position = None;
+        let mut new_name = None;
+        if let Some(existing) = existing {
+            match on_conflict {
+                "skip" => return Ok(Element(existing)),
+                "replace" => {
+                    position = existing.position();
+                    self.0
+                        .remove_sub_element(existing)
+                        .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+                }
+                "rename" => {
+                    let name = other.0.item_name().unwrap_or_default();
+                    new_name = (1..).map(|idx| format!("{name}_{idx}")).find(|candidate| {
+                        !self
+                            .0
+                            .sub_elements()
+                            .any(|se| se.item_name().as_ref() == Some(candidate))
+                    });
+                }
+                _ => {
+                    return Err(AutosarDataError::new_err(format!(
+                        "An element named {} already exists in {}",
+                        existing.item_name().unwrap_or_default(),
+                        self.0.xml_path()
+                    )));
+                }
+            }
+        }
+
+        let copy = match position {
+            Some(position) => self.0.create_copied_sub_element_at(&other.0, position),
+            None => self.0.create_copied_sub_element(&other.0),
+        }
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        if let Some(new_name) = new_name
+            && copy.item_name().as_ref() != Some(&new_name)
+        {
+            copy.set_item_name(&new_name)
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        }
+
+        let copy_path = copy.path().ok();
+        for (_, sub_element) in copy.elements_dfs() {
+            if !sub_element.element_type().is_ref() {
+                continue;
+            }
+            let Some(target) = sub_element
+                .character_data()
+                .and_then(|cdata| cdata.string_value())
+            else {
+                continue;
+            };
+            let new_target = match (&source_path, &copy_path) {
+                (Some(source_path), Some(copy_path))
+                    if target == *source_path || target.starts_with(&format!("{source_path}/")) =>
+                {
+                    Some(format!("{copy_path}{}", &target[source_path.len()..]))
+                }
+                _ => prefixes.iter().find_map(|(old_prefix, new_prefix)| {
+                    target
+                        .strip_prefix(old_prefix.as_str())
+                        .map(|rest| format!("{new_prefix}{rest}"))
+                }),
+            };
+            if let Some(new_target) = new_target.filter(|new_target| *new_target != target) {
+                sub_element
+                    .set_character_data(autosar_data_rs::CharacterData::String(new_target))
+                    .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            }
+        }
+
+        Ok(Element(copy))
+    }
+
     /// Move the given element to become a sub-element of this element
     #[pyo3(signature = (move_element, /, position = None))]
     #[pyo3(text_signature = "(self, move_element: Element, /, position: Optional[int] = None)")]
@@ -227,6 +359,8 @@ impl Element {
         move_element: &Element,
         position: Option<usize>,
     ) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
+        frozen::check_element(&move_element.0)?;
         if let Some(position) = position {
             match self.0.move_element_here_at(&move_element.0, position) {
                 Ok(element) => Ok(Element(element)),
@@ -240,12 +374,174 @@ impl Element {
         }
     }
 
+    /// Move this element so that it is located directly before the given sibling
+    ///
+    /// If the parent has the content mode Sequence, then the element can only be placed next to elements with the same name.
+    #[pyo3(signature = (sibling, /))]
+    #[pyo3(text_signature = "(self, sibling: Element, /)")]
+    fn insert_before(&self, sibling: &Element) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
+        frozen::check_element(&sibling.0)?;
+        move_next_to(&self.0, &sibling.0, false).map(Element)
+    }
+
+    /// Move this element so that it is located directly after the given sibling
+    ///
+    /// If the parent has the content mode Sequence, then the element can only be placed next to elements with the same name.
+    #[pyo3(signature = (sibling, /))]
+    #[pyo3(text_signature = "(self, sibling: Element, /)")]
+    fn insert_after(&self, sibling: &Element) -> PyResult<Element> {
+        frozen::check_element(&self.0)?;
+        frozen::check_element(&sibling.0)?;
+        move_next_to(&self.0, &sibling.0, true).map(Element)
+    }
+
+    /// Move this element to a new position within its parent
+    ///
+    /// The position is the index of the element in the content of the parent after the move.
+    /// If the parent has the content mode Sequence, then the element can only be moved among elements with the same name.
+    #[pyo3(signature = (position, /))]
+    #[pyo3(text_signature = "(self, position: int, /)")]
+    fn move_to(&self, position: usize) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
+        let parent = moveable_parent(&self.0)?;
+        if position >= parent.content_item_count() {
+            return Err(AutosarDataError::new_err(format!(
+                "Position {position} is out of range for the content of {}",
+                parent.xml_path()
+            )));
+        }
+        if self.0.position() == Some(position) {
+            return Ok(());
+        }
+        if parent.element_type().content_mode() == autosar_data_specification::ContentMode::Sequence
+        {
+            // the element must end up inside or at either end of the run of same-named siblings
+            let siblings: Vec<Option<autosar_data_rs::Element>> = parent
+                .content()
+                .filter_map(|item| match item {
+                    autosar_data_rs::ElementContent::Element(element) if element == self.0 => None,
+                    autosar_data_rs::ElementContent::Element(element) => Some(Some(element)),
+                    autosar_data_rs::ElementContent::CharacterData(_) => Some(None),
+                })
+                .collect();
+            let has_same_name = |sibling: Option<&Option<autosar_data_rs::Element>>| {
+                sibling
+                    .and_then(Option::as_ref)
+                    .is_some_and(|sibling| sibling.element_name() == self.0.element_name())
+            };
+            let before = position
+                .checked_sub(1)
+                .and_then(|index| siblings.get(index));
+            if !has_same_name(before) && !has_same_name(siblings.get(position)) {
+                return Err(AutosarDataError::new_err(format!(
+                    "{} can not be moved to position {position} in {}, because the content mode of {} is Sequence",
+                    self.0.element_name(),
+                    parent.xml_path(),
+                    parent.element_name()
+                )));
+            }
+        }
+        parent
+            .move_element_here_at(&self.0, position)
+            .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        Ok(())
+    }
+
+    /// Swap the positions of this element and the other element
+    ///
+    /// Both elements must be part of the same model, and neither may be a sub element of the other. If they have
+    /// the same parent and its content mode is Sequence, then both elements must have the same name.
+    /// If the swap fails, then neither element is moved.
+    #[pyo3(signature = (other, /))]
+    #[pyo3(text_signature = "(self, other: Element, /)")]
+    fn swap_with(&self, other: &Element) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
+        frozen::check_element(&other.0)?;
+        if self.0 == other.0 {
+            return Ok(());
+        }
+        if self.0.model().ok() != other.0.model().ok() {
+            return Err(AutosarDataError::new_err(
+                "Only elements in the same model can be swapped",
+            ));
+        }
+        if is_ancestor(&self.0, &other.0) || is_ancestor(&other.0, &self.0) {
+            return Err(AutosarDataError::new_err(
+                "An element can not be swapped with one of its own sub elements",
+            ));
+        }
+        let parent_a = moveable_parent(&self.0)?;
+        let parent_b = moveable_parent(&other.0)?;
+        let (Some(position_a), Some(position_b)) = (self.0.position(), other.0.position()) else {
+            return Err(AutosarDataError::new_err(
+                "The position of the elements could not be determined",
+            ));
+        };
+        let move_result = if parent_a == parent_b {
+            check_sequence_order(&parent_a, &self.0, &other.0)?;
+            let (first, first_position, second, second_position) = if position_a < position_b {
+                (&self.0, position_a, &other.0, position_b)
+            } else {
+                (&other.0, position_b, &self.0, position_a)
+            };
+            parent_a
+                .move_element_here_at(second, first_position)
+                .and_then(|_| parent_a.move_element_here_at(first, second_position))
+        } else {
+            let moved = parent_b
+                .move_element_here_at(&self.0, position_b)
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            // undo the first move if the second one fails, so that the swap is never half-applied
+            parent_a
+                .move_element_here_at(&other.0, position_a)
+                .inspect_err(|_| {
+                    let _ = parent_a.move_element_here_at(&moved, position_a);
+                })
+        };
+        move_result.map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        Ok(())
+    }
+
+    /// Sort the sub elements of this element using a key function, like the Python builtin sorted()
+    ///
+    /// This is only possible if the content mode of this element is Bag, since the order of the sub elements is
+    /// not significant in this case. The sort is stable.
+    #[pyo3(signature = (key, /))]
+    #[pyo3(text_signature = "(self, key: Callable[[Element], Any], /)")]
+    fn sort_by(&self, py: Python<'_>, key: Py<PyAny>) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
+        if self.0.element_type().content_mode() != autosar_data_specification::ContentMode::Bag {
+            return Err(AutosarDataError::new_err(format!(
+                "The sub elements of {} can not be sorted, because its content mode is not Bag",
+                self.0.element_name()
+            )));
+        }
+        let sub_elements: Vec<Element> = self.0.sub_elements().map(Element).collect();
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "key"), key)?;
+        let sorted: Vec<Element> = py
+            .import(intern!(py, "builtins"))?
+            .getattr(intern!(py, "sorted"))?
+            .call((sub_elements,), Some(&kwargs))?
+            .extract()?;
+        for (position, element) in sorted.iter().enumerate() {
+            if element.0.position() != Some(position) {
+                self.0
+                    .move_element_here_at(&element.0, position)
+                    .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Remove the given sub-element from this element
     ///
     /// Removing the element invalidates it, and causes all of the removed elements children to be removed as well.
     #[pyo3(signature = (sub_element, /))]
     #[pyo3(text_signature = "(self, sub_element: Element, /)")]
     fn remove_sub_element(&self, sub_element: Element) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .remove_sub_element(sub_element.0)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -257,6 +553,7 @@ impl Element {
     #[pyo3(signature = (name_str, /))]
     #[pyo3(text_signature = "(self, name: str, /)")]
     fn remove_sub_element_kind(&self, name_str: &str) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         let element_name = get_element_name(name_str)?;
         self.0
             .remove_sub_element_kind(element_name)
@@ -268,6 +565,7 @@ impl Element {
     /// This is only valid for elements with a reference content type.
     #[setter]
     fn set_reference_target(&self, target: Element) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .set_reference_target(&target.0)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -341,6 +639,19 @@ impl Element {
         ElementsIterator::new(self.0.sub_elements().map(Element))
     }
 
+    /// iterate over the sub elements with the given element name
+    #[pyo3(signature = (name_str, /))]
+    #[pyo3(text_signature = "(self, name: str, /)")]
+    fn sub_elements_named(&self, name_str: &str) -> PyResult<ElementsIterator> {
+        let element_name = get_element_name(name_str)?;
+        Ok(ElementsIterator::new(
+            self.0
+                .sub_elements()
+                .filter(move |sub_element| sub_element.element_name() == element_name)
+                .map(Element),
+        ))
+    }
+
     #[getter]
     fn elements_dfs(&self) -> ElementsDfsIterator {
         ElementsDfsIterator::new(self.0.elements_dfs().filter_map(|(depth, elem)| {
@@ -355,8 +666,27 @@ impl Element {
         ))
     }
 
+    /// depth first search iterator for this element and all of its sub elements, limited to the given element names
+    #[pyo3(signature = (element_names, /))]
+    #[pyo3(text_signature = "(self, element_names: List[ElementName], /)")]
+    fn elements_dfs_named(&self, element_names: Vec<String>) -> PyResult<ElementsDfsIterator> {
+        let element_names = element_names
+            .iter()
+            .map(|name| get_element_name(name))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(ElementsDfsIterator::new(
+            self.0
+                .elements_dfs()
+                .filter(move |(_, elem)| element_names.contains(&elem.element_name()))
+                .filter_map(|(depth, elem)| {
+                    Python::attach(|py| (depth, Element(elem)).into_py_any(py).ok())
+                }),
+        ))
+    }
+
     #[setter]
     fn set_character_data(&self, chardata: Py<PyAny>) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         let Some(spec) = self.0.element_type().chardata_spec() else {
             return Err(AutosarDataError::new_err(
                 autosar_data_rs::AutosarDataError::IncorrectContentType {
@@ -373,6 +703,7 @@ impl Element {
 
     /// Remove the character data from the element
     fn remove_character_data(&self) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .remove_character_data()
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -389,6 +720,7 @@ impl Element {
     #[pyo3(signature = (chardata, position, /))]
     #[pyo3(text_signature = "(self, chardata: str, position: int, /)")]
     fn insert_character_content_item(&self, chardata: &str, position: usize) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .insert_character_content_item(chardata, position)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -397,6 +729,7 @@ impl Element {
     #[pyo3(signature = (position, /))]
     #[pyo3(text_signature = "(self, position: int, /)")]
     fn remove_character_content_item(&self, position: usize) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .remove_character_content_item(position)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -438,6 +771,7 @@ impl Element {
     }
 
     pub(crate) fn set_attribute(&self, attrname_str: &str, value: Py<PyAny>) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         let attrname = get_attribute_name(attrname_str)?;
         let attrspec = self.0.element_type().find_attribute_spec(attrname).ok_or(
             AutosarDataError::new_err(
@@ -453,12 +787,15 @@ impl Element {
     #[pyo3(signature = (attrname_str, /))]
     #[pyo3(text_signature = "(self, attrname: str, /)")]
     fn remove_attribute(&self, attrname_str: &str) -> PyResult<bool> {
+        frozen::check_element(&self.0)?;
         let attrname = get_attribute_name(attrname_str)?;
         Ok(self.0.remove_attribute(attrname))
     }
 
-    fn sort(&self) {
+    fn sort(&self) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0.sort();
+        Ok(())
     }
 
     fn list_valid_sub_elements(&self) -> Vec<ValidSubElementInfo> {
@@ -496,12 +833,14 @@ impl Element {
     }
 
     fn add_to_file(&self, file: &ArxmlFile) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .add_to_file(&file.0)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
     }
 
     fn remove_from_file(&self, file: &ArxmlFile) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0
             .remove_from_file(&file.0)
             .map_err(|error| AutosarDataError::new_err(error.to_string()))
@@ -520,13 +859,112 @@ impl Element {
         }
     }
 
+    /// The position in the source file where this element was loaded from: (filename, line, column)
+    ///
+    /// The value is None for elements that were created programmatically.
+    #[getter]
+    fn source_location(&self) -> Option<(String, usize, usize)> {
+        source_location::source_location(&self.0)
+    }
+
     #[getter]
     fn comment(&self) -> Option<String> {
         self.0.comment()
     }
 
     #[setter]
-    fn set_comment(&self, opt_comment: Option<String>) {
+    fn set_comment(&self, opt_comment: Option<String>) -> PyResult<()> {
+        frozen::check_element(&self.0)?;
         self.0.set_comment(opt_comment);
+        Ok(())
+    }
+}
+
+/// hash an element based on its identity
+///
+/// The abstraction wrappers use the same hash as the element they wrap, so that they can be mixed with elements in sets and dicts.
+pub(crate) fn element_hash(element: &autosar_data_rs::Element) -> isize {
+    let mut hasher = DefaultHasher::new();
+    element.hash(&mut hasher);
+    hasher.finish() as isize
+}
+
+/// get the parent of an element that should be moved
+fn moveable_parent(element: &autosar_data_rs::Element) -> PyResult<autosar_data_rs::Element> {
+    element
+        .parent()
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))?
+        .ok_or_else(|| AutosarDataError::new_err("The root element can not be moved"))
+}
+
+/// check if the element is a direct or indirect parent of the other element
+fn is_ancestor(element: &autosar_data_rs::Element, other: &autosar_data_rs::Element) -> bool {
+    let mut current = other.parent().ok().flatten();
+    while let Some(parent) = current {
+        if &parent == element {
+            return true;
+        }
+        current = parent.parent().ok().flatten();
     }
+    false
+}
+
+/// In a Sequence, the order of differently named elements is determined by the specification,
+/// so an element can only be placed next to elements with the same name
+fn check_sequence_order(
+    parent: &autosar_data_rs::Element,
+    element: &autosar_data_rs::Element,
+    neighbor: &autosar_data_rs::Element,
+) -> PyResult<()> {
+    if parent.element_type().content_mode() == autosar_data_specification::ContentMode::Sequence
+        && element.element_name() != neighbor.element_name()
+    {
+        return Err(AutosarDataError::new_err(format!(
+            "The order of {} and {} in {} is fixed, because the content mode of {} is Sequence",
+            element.element_name(),
+            neighbor.element_name(),
+            parent.xml_path(),
+            parent.element_name()
+        )));
+    }
+    Ok(())
+}
+
+/// move an element so that it is located directly before or after a sibling
+fn move_next_to(
+    element: &autosar_data_rs::Element,
+    sibling: &autosar_data_rs::Element,
+    after: bool,
+) -> PyResult<autosar_data_rs::Element> {
+    if element == sibling {
+        return Ok(element.clone());
+    }
+    let parent = moveable_parent(sibling)?;
+    check_sequence_order(&parent, element, sibling)?;
+    let Some(sibling_position) = sibling.position() else {
+        return Err(AutosarDataError::new_err(
+            "The position of the sibling could not be determined",
+        ));
+    };
+    let mut position = if after {
+        sibling_position + 1
+    } else {
+        sibling_position
+    };
+    // when the element is moved within its parent, the positions after it shift down by one
+    if element.parent().ok().flatten().as_ref() == Some(&parent)
+        && element
+            .position()
+            .is_some_and(|old_position| old_position < sibling_position)
+    {
+        position -= 1;
+    }
+    if element.position() == Some(position)
+        && element.parent().ok().flatten().as_ref() == Some(&parent)
+    {
+        return Ok(element.clone());
+    }
+    parent
+        .move_element_here_at(element, position)
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))
 }
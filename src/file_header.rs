@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{ArxmlFile, AutosarDataError, AutosarModel, WeakArxmlFile};
+
+use crate::source_location::find_tag_end;
+
+// The autosar_data crate always generates the same file header, with only the version-specific
+// schema location and the standalone flag taken from the file. Any settings that deviate from this
+// are stored here, and are applied to the serialized text of the file.
+// The settings can't be stored in the ArxmlFile pyclass, since a new wrapper object is created every
+// time a file is returned to Python. Copies of a model get the settings through `copy_headers`.
+static FILE_HEADERS: Mutex<Vec<(WeakArxmlFile, FileHeader)>> = Mutex::new(Vec::new());
+
+const SCHEMA_NAMESPACE: &str = "http://autosar.org/schema/r4.0";
+
+/// Settings of the file header which differ from the defaults of the autosar_data crate
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FileHeader {
+    /// encoding in the xml declaration
+    pub(crate) encoding: Option<String>,
+    /// standalone value in the xml declaration; None means that the value of the file is used
+    pub(crate) standalone: Option<Option<bool>>,
+    /// comments before the root element
+    pub(crate) comments: Vec<String>,
+    /// custom value of the xsi:schemaLocation attribute
+    pub(crate) schema_location: Option<String>,
+    /// additional attributes of the root element, e.g. T, S or xml:space
+    pub(crate) root_attributes: BTreeMap<String, String>,
+}
+
+impl FileHeader {
+    fn is_default(&self) -> bool {
+        self.encoding.is_none()
+            && self.standalone.is_none()
+            && self.comments.is_empty()
+            && self.schema_location.is_none()
+            && self.root_attributes.is_empty()
+    }
+}
+
+/// get the header settings of a file
+pub(crate) fn get_header(file: &ArxmlFile) -> FileHeader {
+    let headers = FILE_HEADERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    headers
+        .iter()
+        .find(|(weak_file, _)| weak_file.upgrade().as_ref() == Some(file))
+        .map(|(_, header)| header.clone())
+        .unwrap_or_default()
+}
+
+/// modify the header settings of a file
+pub(crate) fn update_header(file: &ArxmlFile, update: impl FnOnce(&mut FileHeader)) {
+    let mut headers = FILE_HEADERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    headers.retain(|(weak_file, _)| weak_file.upgrade().is_some());
+    if let Some((_, header)) = headers
+        .iter_mut()
+        .find(|(weak_file, _)| weak_file.upgrade().as_ref() == Some(file))
+    {
+        update(header);
+    } else {
+        let mut header = FileHeader::default();
+        update(&mut header);
+        headers.push((file.downgrade(), header));
+    }
+    headers.retain(|(_, header)| !header.is_default());
+}
+
+/// copy the header settings of all files of a model to the files with the same names in a copy of the model
+pub(crate) fn copy_headers(source: &AutosarModel, destination: &AutosarModel) {
+    for source_file in source.files() {
+        let header = get_header(&source_file);
+        if header.is_default() {
+            continue;
+        }
+        if let Some(destination_file) = destination
+            .files()
+            .find(|file| file.filename() == source_file.filename())
+        {
+            update_header(&destination_file, |destination_header| {
+                *destination_header = header;
+            });
+        }
+    }
+}
+
+/// the value of xsi:schemaLocation that the autosar_data crate writes for this file
+pub(crate) fn default_schema_location(file: &ArxmlFile) -> String {
+    format!("{SCHEMA_NAMESPACE} {}", file.version().filename())
+}
+
+/// store the header settings of a file that was loaded from the given text
+///
+/// Only settings that the autosar_data crate would not reproduce on its own are kept.
+pub(crate) fn record_header(file: &ArxmlFile, text: &str) {
+    let mut encoding = None;
+    let mut comments = Vec::new();
+    let mut root_attributes = Vec::new();
+
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find('<') {
+        let rest = &text[pos + offset..];
+        if let Some(declaration) = rest.strip_prefix("<?xml") {
+            let Some(end) = declaration.find("?>") else {
+                return;
+            };
+            encoding = parse_attributes(&declaration[..end])
+                .into_iter()
+                .find(|(name, _)| name == "encoding")
+                .map(|(_, value)| value);
+            pos += offset + 5 + end + 2;
+        } else if let Some(comment) = rest.strip_prefix("<!--") {
+            let Some(end) = comment.find("-->") else {
+                return;
+            };
+            comments.push(comment[..end].to_string());
+            pos += offset + 4 + end + 3;
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            let Some(end) = rest.find('>') else {
+                return;
+            };
+            pos += offset + end + 1;
+        } else {
+            let Some(end) = find_tag_end(rest) else {
+                return;
+            };
+            let tag = rest[..end].trim_end_matches('/');
+            let attr_start = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            root_attributes = parse_attributes(&tag[attr_start..])
+                .into_iter()
+                .map(|(name, value)| (name, unescape_attribute(&value)))
+                .collect();
+            break;
+        }
+    }
+
+    let default_schema_location = default_schema_location(file);
+    update_header(file, |header| {
+        // the content is always written as UTF-8. The parser rejects other encodings, but the
+        // declaration must never claim anything else, so they are replaced by the default
+        header.encoding =
+            encoding.filter(|encoding| encoding != "utf-8" && is_utf8_label(encoding));
+        header.comments = comments;
+        header.schema_location = None;
+        header.root_attributes.clear();
+        for (name, value) in root_attributes {
+            match name.as_str() {
+                "xmlns" | "xmlns:xsi" => {}
+                "xsi:schemaLocation" => {
+                    if value != default_schema_location {
+                        header.schema_location = Some(value);
+                    }
+                }
+                _ => {
+                    header.root_attributes.insert(name, value);
+                }
+            }
+        }
+    });
+}
+
+/// check if an encoding name from the xml declaration refers to UTF-8
+fn is_utf8_label(encoding: &str) -> bool {
+    encoding.eq_ignore_ascii_case("utf-8") || encoding.eq_ignore_ascii_case("utf8")
+}
+
+/// serialize the file and apply the stored header settings to the result
+pub(crate) fn serialize_file(file: &ArxmlFile) -> Result<String, AutosarDataError> {
+    let text = file.serialize()?;
+    let header = get_header(file);
+    if header.is_default() {
+        return Ok(text);
+    }
+
+    // split the generated text into the root start tag and the remainder
+    let Some(root_start) = text.find("<AUTOSAR") else {
+        return Ok(text);
+    };
+    let Some(root_len) = find_tag_end(&text[root_start..]) else {
+        return Ok(text);
+    };
+    let root_tag = &text[root_start..root_start + root_len];
+    let remainder = &text[root_start + root_len..];
+    let (root_tag, remainder) = match root_tag.strip_suffix('/') {
+        Some(root_tag) => (root_tag, &text[root_start + root_len - 1..]),
+        None => (root_tag, remainder),
+    };
+
+    let encoding = header.encoding.as_deref().unwrap_or("utf-8");
+    let standalone = header.standalone.unwrap_or_else(|| file.xml_standalone());
+    let mut output = String::with_capacity(text.len() + 256);
+    output.push_str(&format!("<?xml version=\"1.0\" encoding=\"{encoding}\""));
+    if let Some(standalone) = standalone {
+        let value = if standalone { "yes" } else { "no" };
+        output.push_str(&format!(" standalone=\"{value}\""));
+    }
+    output.push_str("?>\n");
+    for comment in &header.comments {
+        output.push_str(&format!("<!--{comment}-->\n"));
+    }
+
+    let attr_start = root_tag.find(char::is_whitespace).unwrap_or(root_tag.len());
+    let mut attributes = parse_attributes(&root_tag[attr_start..]);
+    if let Some(schema_location) = &header.schema_location {
+        let escaped = escape_attribute(schema_location);
+        match attributes
+            .iter_mut()
+            .find(|(name, _)| name == "xsi:schemaLocation")
+        {
+            Some((_, value)) => *value = escaped,
+            None => attributes.push(("xsi:schemaLocation".to_string(), escaped)),
+        }
+    }
+    for (name, value) in &header.root_attributes {
+        let escaped = escape_attribute(value);
+        match attributes
+            .iter_mut()
+            .find(|(attr_name, _)| attr_name == name)
+        {
+            Some((_, attr_value)) => *attr_value = escaped,
+            None => attributes.push((name.clone(), escaped)),
+        }
+    }
+    output.push_str("<AUTOSAR");
+    for (name, value) in attributes {
+        output.push_str(&format!(" {name}=\"{value}\""));
+    }
+    output.push_str(remainder);
+
+    Ok(output)
+}
+
+/// parse the attributes in the text of a tag. The values are returned without unescaping.
+fn parse_attributes(text: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = text;
+    while let Some(eq_pos) = rest.find('=') {
+        let name = rest[..eq_pos].trim().to_string();
+        let after_eq = rest[eq_pos + 1..].trim_start();
+        let Some(quote) = after_eq.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(value_len) = after_eq[1..].find(quote) else {
+            break;
+        };
+        attributes.push((name, after_eq[1..1 + value_len].to_string()));
+        rest = &after_eq[value_len + 2..];
+    }
+    attributes
+}
+
+fn escape_attribute(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape_attribute(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,73 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{ArxmlFile, AutosarModel, Element, WeakElement};
+use pyo3::prelude::*;
+
+use crate::{ModelFrozenError, is_model_key, model_key};
+
+// The autosar_data crate has no concept of a read-only model. Instead, the frozen models are tracked
+// here, and every mutating method of the bindings checks this list before modifying anything.
+static FROZEN_MODELS: Mutex<Vec<WeakElement>> = Mutex::new(Vec::new());
+// number of entries in FROZEN_MODELS, so that the checks are cheap as long as no model is frozen
+static FROZEN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn freeze(model: &AutosarModel) {
+    let mut frozen = FROZEN_MODELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    frozen.retain(|key| key.upgrade().is_some_and(|root| root.model().is_ok()));
+    if !frozen.iter().any(|key| is_model_key(key, model)) {
+        frozen.push(model_key(model));
+    }
+    FROZEN_COUNT.store(frozen.len(), Ordering::Release);
+}
+
+pub(crate) fn unfreeze(model: &AutosarModel) {
+    let mut frozen = FROZEN_MODELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    frozen.retain(|key| {
+        key.upgrade().is_some_and(|root| root.model().is_ok()) && !is_model_key(key, model)
+    });
+    FROZEN_COUNT.store(frozen.len(), Ordering::Release);
+}
+
+pub(crate) fn is_frozen(model: &AutosarModel) -> bool {
+    if FROZEN_COUNT.load(Ordering::Acquire) == 0 {
+        return false;
+    }
+    let frozen = FROZEN_MODELS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    frozen.iter().any(|key| is_model_key(key, model))
+}
+
+/// raise a `ModelFrozenError` if the model is frozen
+pub(crate) fn check_model(model: &AutosarModel) -> PyResult<()> {
+    if is_frozen(model) {
+        Err(ModelFrozenError::new_err(
+            "The model is frozen and can not be modified",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// raise a `ModelFrozenError` if the element belongs to a frozen model
+pub(crate) fn check_element(element: &Element) -> PyResult<()> {
+    match element.model() {
+        Ok(model) => check_model(&model),
+        // deleted elements are not part of any model; modifying them fails anyway
+        Err(_) => Ok(()),
+    }
+}
+
+/// raise a `ModelFrozenError` if the file belongs to a frozen model
+pub(crate) fn check_file(file: &ArxmlFile) -> PyResult<()> {
+    match file.model() {
+        Ok(model) => check_model(&model),
+        Err(_) => Ok(()),
+    }
+}
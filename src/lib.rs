@@ -15,10 +15,19 @@ mod abstraction;
 
 // These modules are not part of the api
 mod arxmlfile;
+mod canonical;
+mod documentation;
 mod element;
+mod file_header;
+mod frozen;
+mod lint;
 mod model;
 mod pyutils;
+mod reference_repair;
+mod source_location;
 mod specification;
+mod text_search;
+mod uuid_index;
 mod version;
 
 use pyo3::IntoPyObjectExt;
@@ -30,6 +39,12 @@ create_exception!(
     pyo3::exceptions::PyException
 );
 
+create_exception!(
+    autosar_data.autosar_data,
+    ModelFrozenError,
+    AutosarDataError
+);
+
 #[pyclass(frozen, module = "autosar_data._autosar_data")]
 /// Autosar data model. It contains all elements.
 struct AutosarModel(autosar_data_rs::AutosarModel);
@@ -108,6 +123,45 @@ struct ValidSubElementInfo {
     is_allowed: bool,
 }
 
+#[pyclass(frozen, module = "autosar_data._autosar_data")]
+#[derive(Debug)]
+/// A reference whose target does not exist, together with possible replacement targets
+struct BrokenReference {
+    #[pyo3(get)]
+    /// the reference element
+    element: Element,
+    #[pyo3(get)]
+    /// the path of the missing target
+    target: String,
+    #[pyo3(get)]
+    /// the value of the DEST attribute of the reference
+    dest: Option<String>,
+    #[pyo3(get)]
+    /// candidate targets and their similarity scores, best candidates first
+    candidates: Vec<(Element, f64)>,
+}
+
+#[pyclass(frozen, module = "autosar_data._autosar_data")]
+#[derive(Debug)]
+/// A piece of text in the model that matches a search pattern
+struct TextMatch {
+    #[pyo3(get)]
+    /// the element containing the text
+    element: Element,
+    #[pyo3(get)]
+    /// the name of the attribute, if the text is an attribute value
+    attribute: Option<String>,
+    #[pyo3(get)]
+    /// the index of the content item, if the element has mixed content
+    content_index: Option<usize>,
+    #[pyo3(get)]
+    /// the complete text
+    text: String,
+    #[pyo3(get)]
+    /// the (start, end) character offsets of all matches in the text
+    spans: Vec<(usize, usize)>,
+}
+
 #[pyclass(skip_from_py_object, eq, eq_int, module = "autosar_data._autosar_data")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// The content type of an element
@@ -213,6 +267,11 @@ struct CharacterDataTypeFloat(());
 // The autosar_data_abstraction crate returns iterators that are not directly usable in Python.
 // Every one of these iterators follows the same pattern, returning "impl Iterator<Item = T>", so
 // they can all be wrapped using the same method.
+//
+// The wrapped iterators produce their items lazily. Items that are requested ahead of the
+// iteration, e.g. by len() or indexing, are converted to Python objects and buffered until the
+// iteration reaches them. Length, indexing and slicing always refer to the items that have not
+// been iterated over yet.
 macro_rules! iterator_wrapper {
     ($iter_name:ident, $item_name:ty) => {
         iterator_wrapper!($iter_name, $item_name, stringify!($item_name));
@@ -221,6 +280,9 @@ macro_rules! iterator_wrapper {
         #[pyclass(module = "autosar_data._autosar_data._iterators")]
         pub(crate) struct $iter_name {
             iter: Box<dyn Iterator<Item = $item_name> + Sync + Send + 'static>,
+            buffer: std::collections::VecDeque<Py<PyAny>>,
+            /// only items with this element name are returned, see `filter`
+            element_name: Option<::autosar_data::ElementName>,
         }
 
         impl $iter_name {
@@ -229,7 +291,36 @@ macro_rules! iterator_wrapper {
             ) -> Self {
                 Self {
                     iter: Box::new(iter),
+                    buffer: std::collections::VecDeque::new(),
+                    element_name: None,
+                }
+            }
+
+            /// buffer items until the buffer contains at least `count` items, or the iterator is exhausted
+            ///
+            /// If an item cannot be converted to a Python object, the error is returned; the items
+            /// that were buffered before it remain available.
+            fn fill_buffer(&mut self, count: Option<usize>) -> PyResult<()> {
+                while count.is_none_or(|count| self.buffer.len() < count) {
+                    let Some(item) = self.iter.next() else {
+                        break;
+                    };
+                    let item = Python::attach(|py| pyo3::IntoPyObjectExt::into_py_any(item, py))?;
+                    if self.matches_filter(&item) {
+                        self.buffer.push_back(item);
+                    }
                 }
+                Ok(())
+            }
+
+            /// check if a converted item has the element name that was passed to `filter`
+            fn matches_filter(&self, item: &Py<PyAny>) -> bool {
+                self.element_name.is_none_or(|element_name| {
+                    Python::attach(|py| {
+                        $crate::iterator_item_element(item.bind(py))
+                            .is_some_and(|element| element.element_name() == element_name)
+                    })
+                })
             }
         }
 
@@ -239,17 +330,186 @@ macro_rules! iterator_wrapper {
                 slf
             }
 
-            fn __next__(&mut self) -> Option<$item_name> {
-                self.iter.next()
+            fn __next__(&mut self) -> PyResult<Option<$crate::IteratorItem<$item_name>>> {
+                // without buffered items or a filter, the item is passed on unchanged
+                if self.buffer.is_empty() && self.element_name.is_none() {
+                    return Ok(self.iter.next().map($crate::IteratorItem::Item));
+                }
+                self.fill_buffer(Some(1))?;
+                Ok(self.buffer.pop_front().map($crate::IteratorItem::Buffered))
             }
 
             fn __repr__(&self) -> String {
                 concat!("Iterator[", $desc, "]").to_string()
             }
+
+            /// get the number of remaining items; all of them are buffered in order to count them
+            ///
+            /// This is not the total number of items: items that were already returned by `next()` are not counted.
+            fn __len__(&mut self) -> PyResult<usize> {
+                self.fill_buffer(None)?;
+                Ok(self.buffer.len())
+            }
+
+            /// estimate the number of remaining items without buffering them
+            fn __length_hint__(&self) -> usize {
+                if self.element_name.is_some() {
+                    self.buffer.len()
+                } else {
+                    self.buffer.len() + self.iter.size_hint().0
+                }
+            }
+
+            fn __getitem__(
+                &mut self,
+                py: Python<'_>,
+                index: &Bound<'_, PyAny>,
+            ) -> PyResult<Py<PyAny>> {
+                if let Ok(slice) = index.cast::<pyo3::types::PySlice>() {
+                    self.fill_buffer(None)?;
+                    let indices = slice.indices(self.buffer.len() as isize)?;
+                    let mut items = Vec::with_capacity(indices.slicelength);
+                    let mut position = indices.start;
+                    for _ in 0..indices.slicelength {
+                        items.push(self.buffer[position as usize].clone_ref(py));
+                        position += indices.step;
+                    }
+                    return pyo3::IntoPyObjectExt::into_py_any(items, py);
+                }
+                let index: isize = index.extract()?;
+                let position = if index < 0 {
+                    self.fill_buffer(None)?;
+                    self.buffer.len() as isize + index
+                } else {
+                    self.fill_buffer(Some(index as usize + 1))?;
+                    index
+                };
+                if position < 0 || position as usize >= self.buffer.len() {
+                    return Err(pyo3::exceptions::PyIndexError::new_err(
+                        "iterator index out of range",
+                    ));
+                }
+                Ok(self.buffer[position as usize].clone_ref(py))
+            }
+
+            /// get the first remaining item with the given name
+            ///
+            /// The items before it remain available for the iteration.
+            #[pyo3(signature = (name, /))]
+            #[pyo3(text_signature = "(self, name: str, /)")]
+            fn by_name(&mut self, py: Python<'_>, name: &str) -> PyResult<Option<Py<PyAny>>> {
+                let mut position = 0;
+                loop {
+                    self.fill_buffer(Some(position + 1))?;
+                    let Some(item) = self.buffer.get(position) else {
+                        return Ok(None);
+                    };
+                    if $crate::iterator_item_name(item.bind(py)).as_deref() == Some(name) {
+                        return Ok(Some(item.clone_ref(py)));
+                    }
+                    position += 1;
+                }
+            }
+
+            /// filter the remaining items by their element name
+            ///
+            /// This consumes the iterator and returns a new iterator containing only the matching items.
+            #[pyo3(signature = (*, element_name))]
+            #[pyo3(text_signature = "(self, *, element_name: ElementName)")]
+            fn filter(&mut self, element_name: &str) -> PyResult<Self> {
+                let element_name = $crate::get_element_name(element_name)?;
+                let buffer = std::mem::take(&mut self.buffer);
+                let mut iter: Box<dyn Iterator<Item = $item_name> + Sync + Send + 'static> =
+                    std::mem::replace(&mut self.iter, Box::new(std::iter::empty()));
+                // the remaining items were already filtered by a different element name
+                if self
+                    .element_name
+                    .is_some_and(|current| current != element_name)
+                {
+                    iter = Box::new(std::iter::empty());
+                }
+                let mut filtered = Self {
+                    iter,
+                    buffer: std::collections::VecDeque::new(),
+                    element_name: Some(element_name),
+                };
+                let buffer = buffer
+                    .into_iter()
+                    .filter(|item| filtered.matches_filter(item))
+                    .collect();
+                filtered.buffer = buffer;
+                Ok(filtered)
+            }
         }
     };
 }
 
+/// an item returned by one of the wrapped iterators
+///
+/// Items are passed on unchanged, unless they were already converted to Python objects for the buffer.
+pub(crate) enum IteratorItem<T> {
+    Item(T),
+    Buffered(Py<PyAny>),
+}
+
+impl<'py, T> IntoPyObject<'py> for IteratorItem<T>
+where
+    T: IntoPyObject<'py>,
+{
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self {
+            IteratorItem::Item(item) => pyo3::IntoPyObjectExt::into_bound_py_any(item, py),
+            IteratorItem::Buffered(item) => Ok(item.into_bound(py)),
+        }
+    }
+}
+
+/// get the element that an iterator item refers to
+///
+/// Items can be elements, abstraction wrappers with an `element` attribute, or tuples whose last entry is an element.
+pub(crate) fn iterator_item_element(item: &Bound<'_, PyAny>) -> Option<autosar_data_rs::Element> {
+    if let Ok(element) = item.cast::<Element>() {
+        return Some(element.get().0.clone());
+    }
+    if let Ok(tuple) = item.cast::<PyTuple>() {
+        let last = tuple.get_item(tuple.len().checked_sub(1)?).ok()?;
+        return iterator_item_element(&last);
+    }
+    let element = item.getattr(intern!(item.py(), "element")).ok()?;
+    element
+        .cast::<Element>()
+        .ok()
+        .map(|element| element.get().0.clone())
+}
+
+/// get the name of an iterator item: the name of an attribute, or the item name of an element
+pub(crate) fn iterator_item_name(item: &Bound<'_, PyAny>) -> Option<String> {
+    if let Ok(attribute) = item.cast::<Attribute>() {
+        return Some(attribute.get().attrname.clone());
+    }
+    iterator_item_element(item)?.item_name()
+}
+
+/// get a weak handle that identifies a model in the side tables of the bindings
+///
+/// The root element is used, because it is created together with the model and is never replaced.
+pub(crate) fn model_key(model: &autosar_data_rs::AutosarModel) -> autosar_data_rs::WeakElement {
+    model.root_element().downgrade()
+}
+
+/// check if a handle created by `model_key` belongs to the given model
+pub(crate) fn is_model_key(
+    key: &autosar_data_rs::WeakElement,
+    model: &autosar_data_rs::AutosarModel,
+) -> bool {
+    key.upgrade()
+        .is_some_and(|root| root == model.root_element())
+}
+
 pub(crate) use iterator_wrapper;
 
 //##################################################################
@@ -366,6 +626,20 @@ impl ValidSubElementInfo {
     }
 }
 
+#[pymethods]
+impl BrokenReference {
+    fn __repr__(&self) -> String {
+        format!("{self:#?}")
+    }
+}
+
+#[pymethods]
+impl TextMatch {
+    fn __repr__(&self) -> String {
+        format!("{self:#?}")
+    }
+}
+
 #[pyfunction]
 fn check_file(filename: &str) -> bool {
     autosar_data_rs::check_file(filename)
@@ -400,6 +674,7 @@ fn check_buffer(input: Py<PyAny>) -> PyResult<bool> {
 /// Submodules:
 ///
 /// - abstraction
+/// - lint
 ///
 /// Classes:
 ///
@@ -407,10 +682,12 @@ fn check_buffer(input: Py<PyAny>) -> PyResult<bool> {
 /// - ArxmlFile
 /// - AutosarModel
 /// - AutosarVersion
+/// - BrokenReference
 /// - ContentMode
 /// - Element
 /// - ElementType
 /// - SubElementSpec
+/// - TextMatch
 /// - ValidSubElementInfo
 ///
 /// Functions:
@@ -442,6 +719,8 @@ fn _autosar_data(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SubElementSpec>()?;
     m.add_class::<ContentMode>()?;
     m.add_class::<ValidSubElementInfo>()?;
+    m.add_class::<BrokenReference>()?;
+    m.add_class::<TextMatch>()?;
     m.add_class::<CharacterDataTypeEnum>()?;
     m.add_class::<CharacterDataTypeFloat>()?;
     m.add_class::<CharacterDataTypeRestrictedString>()?;
@@ -450,9 +729,11 @@ fn _autosar_data(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(check_file, m)?)?;
     m.add_function(wrap_pyfunction!(check_buffer, m)?)?;
     m.add("AutosarDataError", py.get_type::<AutosarDataError>())?;
+    m.add("ModelFrozenError", py.get_type::<ModelFrozenError>())?;
     m.add("__version__", intern!(m.py(), env!("CARGO_PKG_VERSION")))?;
 
     abstraction::add_submodules(py, m)?;
+    lint::add_submodule(py, m)?;
 
     Ok(())
 }
@@ -567,6 +848,29 @@ fn get_attribute_name(name_str: &str) -> PyResult<autosar_data_rs::AttributeName
     })
 }
 
+/// convert an optional list of attribute names to a set; if no list is given, only UUID is included
+fn ignored_attribute_set(
+    ignore: Option<Vec<String>>,
+) -> PyResult<std::collections::HashSet<autosar_data_rs::AttributeName>> {
+    match ignore {
+        Some(names) => names.iter().map(|name| get_attribute_name(name)).collect(),
+        None => Ok(std::collections::HashSet::from([
+            autosar_data_rs::AttributeName::Uuid,
+        ])),
+    }
+}
+
+/// calculate the content hash of an element, see `Element.content_hash`
+fn element_content_hash(
+    element: &autosar_data_rs::Element,
+    algorithm: &str,
+    ignored_attributes: &std::collections::HashSet<autosar_data_rs::AttributeName>,
+) -> PyResult<String> {
+    let text = canonical::canonical_text(element, ignored_attributes, false);
+    canonical::hash_text(&text, algorithm)
+        .ok_or_else(|| PyValueError::new_err(format!("unsupported hash algorithm '{algorithm}'")))
+}
+
 fn version_mask_from_any(version_obj: &Py<PyAny>) -> PyResult<u32> {
     Python::attach(|py| {
         if let Ok(list) = version_obj.cast_bound::<PyList>(py) {
@@ -0,0 +1,613 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::*;
+use autosar_data_rs::{AttributeName, ElementName};
+use regex::Regex;
+
+/// the built-in rules: (id, default severity, description)
+const BUILTIN_RULES: [(&str, Severity, &str); 7] = [
+    (
+        "broken-reference",
+        Severity::Error,
+        "The target of a reference does not exist",
+    ),
+    (
+        "duplicate-can-id",
+        Severity::Error,
+        "Several frame triggerings on the same CAN channel use the same CAN ID",
+    ),
+    (
+        "empty-package",
+        Severity::Warning,
+        "An AR-PACKAGE contains neither elements nor sub-packages",
+    ),
+    (
+        "isignal-without-system-signal",
+        Severity::Error,
+        "An I-SIGNAL does not reference a SYSTEM-SIGNAL",
+    ),
+    (
+        "missing-desc",
+        Severity::Info,
+        "An element in a package has no DESC",
+    ),
+    (
+        "short-name-convention",
+        Severity::Warning,
+        "A SHORT-NAME does not match the naming convention for its element type",
+    ),
+    (
+        "uuid-missing",
+        Severity::Info,
+        "An identifiable element in a package has no UUID",
+    ),
+];
+
+/// The severity of a lint finding
+#[pyclass(
+    from_py_object,
+    frozen,
+    eq,
+    eq_int,
+    module = "autosar_data._autosar_data._lint"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Severity {
+    /// The model violates a rule that must be fixed
+    Error,
+    /// The model violates a rule that should be fixed
+    Warning,
+    /// The finding is for information only
+    Info,
+}
+
+impl Severity {
+    fn sarif_level(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "note",
+        }
+    }
+}
+
+/// A single violation of a lint rule
+#[pyclass(frozen, module = "autosar_data._autosar_data._lint")]
+#[derive(Debug)]
+pub(crate) struct LintFinding {
+    #[pyo3(get)]
+    /// the id of the rule that produced the finding
+    rule: String,
+    #[pyo3(get)]
+    /// the severity of the finding
+    severity: Severity,
+    #[pyo3(get)]
+    /// the element that violates the rule
+    element: Element,
+    #[pyo3(get)]
+    /// a description of the problem
+    message: String,
+}
+
+#[pymethods]
+impl LintFinding {
+    fn __repr__(&self) -> String {
+        format!("{self:#?}")
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "{:?} [{}] {}: {}",
+            self.severity,
+            self.rule,
+            element_location(&self.element.0),
+            self.message
+        )
+    }
+}
+
+/// A rule implemented in Python
+struct PythonRule {
+    name: String,
+    element_names: Option<HashSet<ElementName>>,
+    check: Py<PyAny>,
+}
+
+/// Checks a model against a configurable set of rules
+///
+/// All built-in rules are enabled when a `Linter` is created. Additional rules can be
+/// implemented in Python and added with `add_rule`.
+#[pyclass(module = "autosar_data._autosar_data._lint")]
+pub(crate) struct Linter {
+    severities: HashMap<String, Option<Severity>>,
+    naming_conventions: HashMap<ElementName, Regex>,
+    python_rules: Vec<PythonRule>,
+}
+
+#[pymethods]
+impl Linter {
+    #[new]
+    fn new() -> Self {
+        let severities = BUILTIN_RULES
+            .iter()
+            .map(|(name, severity, _)| (name.to_string(), Some(*severity)))
+            .collect();
+        Self {
+            severities,
+            naming_conventions: HashMap::new(),
+            python_rules: Vec::new(),
+        }
+    }
+
+    /// the names of all rules, both built-in and user defined
+    #[getter]
+    fn rules(&self) -> Vec<String> {
+        BUILTIN_RULES
+            .iter()
+            .map(|(name, _, _)| name.to_string())
+            .chain(self.python_rules.iter().map(|rule| rule.name.clone()))
+            .collect()
+    }
+
+    /// get a description of a built-in rule
+    #[staticmethod]
+    #[pyo3(signature = (rule, /))]
+    #[pyo3(text_signature = "(rule: str, /)")]
+    fn describe_rule(rule: &str) -> Option<&'static str> {
+        BUILTIN_RULES
+            .iter()
+            .find(|(name, _, _)| *name == rule)
+            .map(|(_, _, description)| *description)
+    }
+
+    /// get the severity of a rule. Returns None if the rule is disabled
+    #[pyo3(signature = (rule, /))]
+    #[pyo3(text_signature = "(self, rule: str, /)")]
+    fn get_severity(&self, rule: &str) -> PyResult<Option<Severity>> {
+        self.severities
+            .get(rule)
+            .copied()
+            .ok_or_else(|| PyValueError::new_err(format!("unknown lint rule \"{rule}\"")))
+    }
+
+    /// set the severity of a rule. Setting the severity to None disables the rule
+    #[pyo3(signature = (rule, severity, /))]
+    #[pyo3(text_signature = "(self, rule: str, severity: Optional[Severity], /)")]
+    fn set_severity(&mut self, rule: &str, severity: Option<Severity>) -> PyResult<()> {
+        match self.severities.get_mut(rule) {
+            Some(value) => {
+                *value = severity;
+                Ok(())
+            }
+            None => Err(PyValueError::new_err(format!(
+                "unknown lint rule \"{rule}\""
+            ))),
+        }
+    }
+
+    /// set the naming convention for the SHORT-NAMEs of one element type
+    ///
+    /// The regular expression must match the complete name. If the pattern is None, then the
+    /// naming convention for the element type is removed.
+    #[pyo3(signature = (element_name, pattern, /))]
+    #[pyo3(text_signature = "(self, element_name: ElementName, pattern: Optional[str], /)")]
+    fn set_naming_convention(&mut self, element_name: &str, pattern: Option<&str>) -> PyResult<()> {
+        let element_name = get_element_name(element_name)?;
+        if let Some(pattern) = pattern {
+            let regex = Regex::new(&format!("^(?:{pattern})$")).map_err(|error| {
+                PyValueError::new_err(format!("invalid pattern \"{pattern}\": {error}"))
+            })?;
+            self.naming_conventions.insert(element_name, regex);
+        } else {
+            self.naming_conventions.remove(&element_name);
+        }
+        Ok(())
+    }
+
+    /// add a rule that is implemented in Python
+    ///
+    /// The check function is called for each element in the model whose name is contained in
+    /// element_names, or for all elements if element_names is None. It can return None if the element
+    /// is fine, or a message string or a list of message strings describing the problems.
+    #[pyo3(signature = (name, check, /, element_names=None, severity=Severity::Warning))]
+    #[pyo3(
+        text_signature = "(self, name: str, check: Callable[[Element], Union[None, str, List[str]]], /, element_names: Optional[List[ElementName]] = None, severity: Severity = Severity.Warning)"
+    )]
+    fn add_rule(
+        &mut self,
+        name: &str,
+        check: Py<PyAny>,
+        element_names: Option<Vec<String>>,
+        severity: Severity,
+    ) -> PyResult<()> {
+        if self.severities.contains_key(name) {
+            return Err(PyValueError::new_err(format!(
+                "a lint rule named \"{name}\" already exists"
+            )));
+        }
+        let element_names = element_names
+            .map(|names| {
+                names
+                    .iter()
+                    .map(|name| get_element_name(name))
+                    .collect::<PyResult<HashSet<_>>>()
+            })
+            .transpose()?;
+        self.severities.insert(name.to_string(), Some(severity));
+        self.python_rules.push(PythonRule {
+            name: name.to_string(),
+            element_names,
+            check,
+        });
+        Ok(())
+    }
+
+    /// check the model and return all findings of the enabled rules
+    #[pyo3(signature = (model, /))]
+    #[pyo3(text_signature = "(self, model: AutosarModel, /)")]
+    fn run(&self, py: Python, model: &AutosarModel) -> PyResult<Vec<LintFinding>> {
+        let mut findings = Vec::new();
+        let broken_references: HashSet<autosar_data_rs::WeakElement> =
+            if self.severity("broken-reference").is_some() {
+                model.0.check_references().into_iter().collect()
+            } else {
+                HashSet::new()
+            };
+        // (channel, addressing mode, identifier) -> first frame triggering using the identifier
+        let mut can_ids = HashMap::new();
+
+        for (_, element) in model.0.elements_dfs() {
+            let mut report = |rule: &str, message: String| {
+                if let Some(severity) = self.severity(rule) {
+                    findings.push(LintFinding {
+                        rule: rule.to_string(),
+                        severity,
+                        element: Element(element.clone()),
+                        message,
+                    });
+                }
+            };
+
+            if broken_references.contains(&element.downgrade()) {
+                let target = element
+                    .character_data()
+                    .map(|cdata| cdata.to_string())
+                    .unwrap_or_default();
+                report(
+                    "broken-reference",
+                    format!("the reference target \"{target}\" does not exist"),
+                );
+            }
+            if let Some(name) = element.item_name()
+                && let Some(regex) = self.naming_conventions.get(&element.element_name())
+                && !regex.is_match(&name)
+            {
+                report(
+                    "short-name-convention",
+                    format!(
+                        "the name \"{name}\" does not match the naming convention \"{}\"",
+                        regex.as_str()
+                    ),
+                );
+            }
+            if is_packaged(&element) {
+                if element.get_sub_element(ElementName::Desc).is_none() {
+                    report("missing-desc", "the element has no DESC".to_string());
+                }
+                if element.attribute_value(AttributeName::Uuid).is_none() {
+                    report("uuid-missing", "the element has no UUID".to_string());
+                }
+            }
+
+            match element.element_name() {
+                ElementName::ArPackage => {
+                    let has_content = [ElementName::Elements, ElementName::ArPackages]
+                        .into_iter()
+                        .filter_map(|name| element.get_sub_element(name))
+                        .any(|sub_element| sub_element.sub_elements().next().is_some());
+                    if !has_content {
+                        report(
+                            "empty-package",
+                            "the package contains neither elements nor sub-packages".to_string(),
+                        );
+                    }
+                }
+                ElementName::ISignal
+                    if element
+                        .get_sub_element(ElementName::SystemSignalRef)
+                        .is_none() =>
+                {
+                    report(
+                        "isignal-without-system-signal",
+                        "the I-SIGNAL does not reference a SYSTEM-SIGNAL".to_string(),
+                    );
+                }
+                ElementName::CanFrameTriggering => {
+                    if let Some(key) = can_id_key(&element) {
+                        match can_ids.get(&key) {
+                            Some(first) => report(
+                                "duplicate-can-id",
+                                format!(
+                                    "the CAN ID {} is also used by {}",
+                                    key.2,
+                                    element_location(first)
+                                ),
+                            ),
+                            None => {
+                                can_ids.insert(key, element.clone());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            for rule in &self.python_rules {
+                let Some(severity) = self.severity(&rule.name) else {
+                    continue;
+                };
+                if rule
+                    .element_names
+                    .as_ref()
+                    .is_some_and(|names| !names.contains(&element.element_name()))
+                {
+                    continue;
+                }
+                let result = rule.check.call1(py, (Element(element.clone()),))?;
+                let result = result.bind(py);
+                let messages = if result.is_none() {
+                    Vec::new()
+                } else if let Ok(message) = result.extract::<String>() {
+                    vec![message]
+                } else {
+                    result.extract::<Vec<String>>().map_err(|_| {
+                        PyTypeError::new_err(format!(
+                            "the lint rule \"{}\" must return None, a string or a list of strings",
+                            rule.name
+                        ))
+                    })?
+                };
+                findings.extend(messages.into_iter().map(|message| LintFinding {
+                    rule: rule.name.clone(),
+                    severity,
+                    element: Element(element.clone()),
+                    message,
+                }));
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+impl Linter {
+    fn severity(&self, rule: &str) -> Option<Severity> {
+        self.severities.get(rule).copied().flatten()
+    }
+}
+
+/// check if the element is located directly inside AR-PACKAGE/ELEMENTS
+fn is_packaged(element: &autosar_data_rs::Element) -> bool {
+    element.parent().ok().flatten().is_some_and(|parent| {
+        parent.element_name() == ElementName::Elements
+            && parent
+                .parent()
+                .ok()
+                .flatten()
+                .is_some_and(|grandparent| grandparent.element_name() == ElementName::ArPackage)
+    })
+}
+
+/// get the key that identifies the CAN ID of a frame triggering: (channel, addressing mode, identifier)
+fn can_id_key(
+    frame_triggering: &autosar_data_rs::Element,
+) -> Option<(autosar_data_rs::WeakElement, String, u64)> {
+    let channel = frame_triggering.parent().ok()??.parent().ok()??.downgrade();
+    let identifier = frame_triggering
+        .get_sub_element(ElementName::Identifier)?
+        .character_data()?
+        .parse_integer::<u64>()?;
+    let addressing_mode = frame_triggering
+        .get_sub_element(ElementName::CanAddressingMode)
+        .and_then(|mode| mode.character_data())
+        .map(|mode| mode.to_string())
+        .unwrap_or_else(|| "STANDARD".to_string());
+    Some((channel, addressing_mode, identifier))
+}
+
+/// a human readable location of the element: its path if it is identifiable, otherwise its xml path
+fn element_location(element: &autosar_data_rs::Element) -> String {
+    element.path().unwrap_or_else(|_| element.xml_path())
+}
+
+/// get the file containing the element, together with the line and column if they are known
+fn file_location(element: &autosar_data_rs::Element) -> Option<(String, Option<(usize, usize)>)> {
+    if let Some((filename, line, column)) = crate::source_location::source_location(element) {
+        return Some((filename, Some((line, column))));
+    }
+    let (_, files) = element.file_membership().ok()?;
+    files
+        .iter()
+        .filter_map(|weak| weak.upgrade())
+        .map(|file| file.filename().to_string_lossy().to_string())
+        .min()
+        .map(|filename| (filename, None))
+}
+
+/// convert a list of findings to a SARIF 2.1.0 log
+#[pyfunction]
+#[pyo3(signature = (findings, /))]
+#[pyo3(text_signature = "(findings: List[LintFinding], /)")]
+pub(crate) fn to_sarif(findings: Vec<Bound<'_, LintFinding>>) -> String {
+    let mut rule_ids: Vec<&str> = Vec::new();
+    for finding in &findings {
+        let rule = finding.get().rule.as_str();
+        if !rule_ids.contains(&rule) {
+            rule_ids.push(rule);
+        }
+    }
+
+    let mut output = String::new();
+    output.push_str("{\n");
+    output.push_str(
+        "  \"$schema\": \"https://json.schemastore.org/sarif-2.1.0.json\",\n  \"version\": \"2.1.0\",\n",
+    );
+    output.push_str("  \"runs\": [\n    {\n      \"tool\": {\n        \"driver\": {\n");
+    let _ = writeln!(
+        output,
+        "          \"name\": \"autosar_data.lint\",\n          \"version\": \"{}\",",
+        env!("CARGO_PKG_VERSION")
+    );
+    output.push_str("          \"rules\": [");
+    for (idx, rule) in rule_ids.iter().enumerate() {
+        if idx > 0 {
+            output.push(',');
+        }
+        let _ = write!(output, "\n            {{\"id\": {}", json_string(rule));
+        if let Some(description) = Linter::describe_rule(rule) {
+            let _ = write!(
+                output,
+                ", \"shortDescription\": {{\"text\": {}}}",
+                json_string(description)
+            );
+        }
+        output.push('}');
+    }
+    output.push_str("\n          ]\n        }\n      },\n      \"results\": [");
+    for (idx, finding) in findings.iter().enumerate() {
+        let finding = finding.get();
+        if idx > 0 {
+            output.push(',');
+        }
+        let rule_index = rule_ids
+            .iter()
+            .position(|rule| *rule == finding.rule)
+            .unwrap_or_default();
+        let _ = write!(
+            output,
+            "\n        {{\n          \"ruleId\": {},\n          \"ruleIndex\": {rule_index},\n          \"level\": \"{}\",\n          \"message\": {{\"text\": {}}},\n          \"locations\": [{{",
+            json_string(&finding.rule),
+            finding.severity.sarif_level(),
+            json_string(&finding.message)
+        );
+        if let Some((filename, position)) = file_location(&finding.element.0) {
+            let _ = write!(
+                output,
+                "\"physicalLocation\": {{\"artifactLocation\": {{\"uri\": {}}}",
+                json_string(&filename)
+            );
+            if let Some((line, column)) = position {
+                let _ = write!(
+                    output,
+                    ", \"region\": {{\"startLine\": {line}, \"startColumn\": {column}}}"
+                );
+            }
+            output.push_str("}, ");
+        }
+        let _ = write!(
+            output,
+            "\"logicalLocations\": [{{\"fullyQualifiedName\": {}}}]}}]\n        }}",
+            json_string(&element_location(&finding.element.0))
+        );
+    }
+    output.push_str("\n      ]\n    }\n  ]\n}\n");
+    output
+}
+
+/// convert a list of findings to a JUnit XML report
+///
+/// Each rule with findings becomes a test suite, and each finding becomes a failed test case.
+#[pyfunction]
+#[pyo3(signature = (findings, /))]
+#[pyo3(text_signature = "(findings: List[LintFinding], /)")]
+pub(crate) fn to_junit(findings: Vec<Bound<'_, LintFinding>>) -> String {
+    let mut suites: Vec<(&str, Vec<&LintFinding>)> = Vec::new();
+    for finding in &findings {
+        let finding = finding.get();
+        match suites.iter_mut().find(|(rule, _)| *rule == finding.rule) {
+            Some((_, suite_findings)) => suite_findings.push(finding),
+            None => suites.push((finding.rule.as_str(), vec![finding])),
+        }
+    }
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        output,
+        "<testsuites name=\"autosar_data.lint\" tests=\"{0}\" failures=\"{0}\">",
+        findings.len()
+    );
+    for (rule, suite_findings) in suites {
+        let _ = writeln!(
+            output,
+            "  <testsuite name=\"{}\" tests=\"{1}\" failures=\"{1}\" errors=\"0\" skipped=\"0\">",
+            xml_escape(rule),
+            suite_findings.len()
+        );
+        for finding in suite_findings {
+            let location = element_location(&finding.element.0);
+            let _ = writeln!(
+                output,
+                "    <testcase classname=\"{}\" name=\"{}\">",
+                xml_escape(rule),
+                xml_escape(&location)
+            );
+            let _ = writeln!(
+                output,
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>",
+                finding.severity.sarif_level(),
+                xml_escape(&finding.message),
+                xml_escape(&format!("{location}: {}", finding.message))
+            );
+            output.push_str("    </testcase>\n");
+        }
+        output.push_str("  </testsuite>\n");
+    }
+    output.push_str("</testsuites>\n");
+    output
+}
+
+fn json_string(text: &str) -> String {
+    let mut output = String::with_capacity(text.len() + 2);
+    output.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub(crate) fn add_submodule(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    let lint = PyModule::new(py, "_lint")?;
+    parent.add_submodule(&lint)?;
+    lint.add_class::<Severity>()?;
+    lint.add_class::<LintFinding>()?;
+    lint.add_class::<Linter>()?;
+    lint.add_function(wrap_pyfunction!(to_sarif, &lint)?)?;
+    lint.add_function(wrap_pyfunction!(to_junit, &lint)?)?;
+
+    // Workaround for Pyo3 issue #759, see abstraction::add_submodules
+    let sys_modules = py.import("sys")?.getattr("modules")?;
+    sys_modules.set_item("autosar_data._autosar_data._lint", &lint)?;
+    lint.setattr("__module__", "autosar_data._autosar_data._lint")?;
+    lint.setattr("__name__", "autosar_data._autosar_data._lint")?;
+
+    Ok(())
+}
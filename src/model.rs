@@ -1,11 +1,17 @@
-use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::hash::Hasher;
 
+use crate::canonical::canonical_text;
+use crate::documentation::DocField;
+use crate::uuid_index::element_uuid;
 use crate::*;
 use ::autosar_data as autosar_data_rs;
 
+/// a change made by `replace_text`: (element, attribute name, old text, new text)
+type TextChange = (Element, Option<String>, String, String);
+
 #[pymethods]
 impl AutosarModel {
     #[new]
@@ -49,6 +55,7 @@ impl AutosarModel {
     /// create a new file in the model
     #[pyo3(signature = (filename, /, version=AutosarVersion::Latest))]
     fn create_file(&self, filename: &str, version: AutosarVersion) -> PyResult<ArxmlFile> {
+        frozen::check_model(&self.0)?;
         match self.0.create_file(filename, version.into()) {
             Ok(file) => Ok(ArxmlFile(file)),
             Err(error) => PyResult::Err(AutosarDataError::new_err(error.to_string())),
@@ -63,7 +70,8 @@ impl AutosarModel {
         filename: &str,
         strict: bool,
     ) -> PyResult<(ArxmlFile, Vec<String>)> {
-        match self.0.load_buffer(buffer.as_bytes(), filename, strict) {
+        frozen::check_model(&self.0)?;
+        match load_text(&self.0, buffer.as_bytes(), filename, strict) {
             Ok((file, warn)) => {
                 let warnstrings: Vec<String> =
                     warn.iter().map(std::string::ToString::to_string).collect();
@@ -74,12 +82,23 @@ impl AutosarModel {
     }
 
     /// load a file as arxml
-    #[pyo3(signature = (filename, /, strict=false))]
-    fn load_file(&self, filename: &str, strict: bool) -> PyResult<(ArxmlFile, Vec<String>)> {
-        match self.0.load_file(filename, strict) {
+    ///
+    /// If read_only is true, then the model is frozen after loading the file.
+    #[pyo3(signature = (filename, /, strict=false, read_only=false))]
+    fn load_file(
+        &self,
+        filename: &str,
+        strict: bool,
+        read_only: bool,
+    ) -> PyResult<(ArxmlFile, Vec<String>)> {
+        frozen::check_model(&self.0)?;
+        match load_file(&self.0, filename, strict) {
             Ok((file, warn)) => {
                 let warnstrings: Vec<String> =
                     warn.iter().map(std::string::ToString::to_string).collect();
+                if read_only {
+                    frozen::freeze(&self.0);
+                }
                 Ok((ArxmlFile(file), warnstrings))
             }
             Err(error) => PyResult::Err(AutosarDataError::new_err(error.to_string())),
@@ -87,25 +106,26 @@ impl AutosarModel {
     }
 
     /// remove a file from the model. Any elements belonging exclusively to that file will also be removed.
-    fn remove_file(&self, file: &ArxmlFile) {
+    fn remove_file(&self, file: &ArxmlFile) -> PyResult<()> {
+        frozen::check_model(&self.0)?;
         self.0.remove_file(&file.0);
+        Ok(())
     }
 
     /// serialize all files individually, to generate a dict(filename, serialized content),
     fn serialize_files(&self) -> HashMap<String, String> {
-        let hm_orig: HashMap<std::path::PathBuf, String> = self.0.serialize_files();
         let mut hm_out = HashMap::<String, String>::new();
-        for (k, v) in hm_orig {
-            hm_out.insert(String::from(k.to_string_lossy()), v);
+        for file in self.0.files() {
+            if let Ok(text) = file_header::serialize_file(&file) {
+                hm_out.insert(String::from(file.filename().to_string_lossy()), text);
+            }
         }
         hm_out
     }
 
     /// write all files in the model to disk
     fn write(&self) -> PyResult<()> {
-        self.0
-            .write()
-            .map_err(|error| AutosarDataError::new_err(error.to_string()))
+        write_model(&self.0).map_err(AutosarDataError::new_err)
     }
 
     #[getter]
@@ -125,6 +145,37 @@ impl AutosarModel {
         self.0.get_element_by_path(path).map(Element)
     }
 
+    /// get an element in the model by its xml path, as returned by `Element.xml_path`
+    ///
+    /// This also works for elements that are not identifiable. The xml path is not necessarily unique,
+    /// since siblings with the same element name have the same path. A segment can be given a 1-based
+    /// index among the siblings that match it, e.g. `<FIBEX-ELEMENT-REF-CONDITIONAL>[2]`.
+    /// Returns None if no element matches, and raises an error if several elements match.
+    #[pyo3(signature = (xml_path, /))]
+    #[pyo3(text_signature = "(self, xml_path: str, /)")]
+    fn get_element_by_xml_path(&self, xml_path: &str) -> PyResult<Option<Element>> {
+        let Some(path) = xml_path.strip_prefix('/') else {
+            return Ok(None);
+        };
+        let segments: Vec<(&str, Option<usize>)> =
+            path.split('/').map(parse_xml_path_segment).collect();
+        let root = self.0.root_element();
+        let mut matches = Vec::new();
+        if let Some(((first, index), rest)) = segments.split_first()
+            && xml_path_segment(&root) == *first
+            && index.is_none_or(|index| index == 1)
+        {
+            find_by_xml_path(&root, rest, &mut matches);
+        }
+        match matches.len() {
+            0 => Ok(None),
+            1 => Ok(matches.pop().map(Element)),
+            count => Err(AutosarDataError::new_err(format!(
+                "The xml path {xml_path} is ambiguous, it matches {count} elements. Add an index such as [2] to the ambiguous segment"
+            ))),
+        }
+    }
+
     #[getter]
     /// depth first dearch iterator over all elements in the model, regardless of their association with a file
     fn elements_dfs(&self) -> ElementsDfsIterator {
@@ -133,9 +184,29 @@ impl AutosarModel {
         }))
     }
 
+    /// depth first search iterator over the elements in the model which have one of the given element names
+    #[pyo3(signature = (element_names, /))]
+    #[pyo3(text_signature = "(self, element_names: List[ElementName], /)")]
+    fn elements_dfs_named(&self, element_names: Vec<String>) -> PyResult<ElementsDfsIterator> {
+        let element_names = element_names
+            .iter()
+            .map(|name| get_element_name(name))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(ElementsDfsIterator::new(
+            self.0
+                .elements_dfs()
+                .filter(move |(_, elem)| element_names.contains(&elem.element_name()))
+                .filter_map(|(depth, elem)| {
+                    Python::attach(|py| (depth, Element(elem)).into_py_any(py).ok())
+                }),
+        ))
+    }
+
     ///sort the entire model in place. Takes all ordering constraints into account.
-    fn sort(&self) {
+    fn sort(&self) -> PyResult<()> {
+        frozen::check_model(&self.0)?;
         self.0.sort();
+        Ok(())
     }
 
     #[getter]
@@ -167,8 +238,1144 @@ impl AutosarModel {
     /// duplicate the model, creating a new independent copy
     fn duplicate(&self) -> PyResult<AutosarModel> {
         match self.0.duplicate() {
-            Ok(model) => Ok(AutosarModel(model)),
+            Ok(model) => {
+                file_header::copy_headers(&self.0, &model);
+                Ok(AutosarModel(model))
+            }
             Err(error) => Err(AutosarDataError::new_err(error.to_string())),
         }
     }
+
+    /// make the model read-only
+    ///
+    /// Any attempt to modify a frozen model, its files or its elements raises a ModelFrozenError.
+    fn freeze(&self) {
+        frozen::freeze(&self.0);
+    }
+
+    /// make a frozen model modifiable again
+    fn unfreeze(&self) {
+        frozen::unfreeze(&self.0);
+    }
+
+    #[getter]
+    /// true if the model is frozen, i.e. read-only
+    fn is_frozen(&self) -> bool {
+        frozen::is_frozen(&self.0)
+    }
+
+    /// find all elements in AR-PACKAGEs that are not referenced by any other element
+    ///
+    /// A reference to a sub element also counts as a reference to the containing element.
+    /// If roots are given, then all elements that can't be reached from the roots by following references
+    /// are reported, including groups of elements that only reference each other. Elements that are not
+    /// among the element_types are kept, so they count as roots too. If a root is an AR-PACKAGE, then everything
+    /// inside of it is kept. The result is sorted by path.
+    #[pyo3(signature = (element_types=None, roots=None))]
+    #[pyo3(
+        text_signature = "(self, element_types: Optional[List[ElementName]] = None, roots: Optional[List[Element]] = None)"
+    )]
+    fn find_unreferenced(
+        &self,
+        element_types: Option<Vec<String>>,
+        roots: Option<Vec<Element>>,
+    ) -> PyResult<Vec<Element>> {
+        let element_types = element_name_set(element_types)?;
+        let unreferenced = match roots {
+            Some(roots) => {
+                let roots: Vec<_> = roots.into_iter().map(|e| e.0).collect();
+                find_unreachable_elements(&self.0, element_types.as_ref(), &roots)
+            }
+            None => find_unreferenced_elements(&self.0, element_types.as_ref(), &HashSet::new()),
+        };
+        Ok(unreferenced.into_iter().map(Element).collect())
+    }
+
+    /// remove all elements in AR-PACKAGEs that are not referenced by any other element
+    ///
+    /// Without roots, removing an element can cause further elements to become unreferenced, so the search
+    /// is repeated until no more unreferenced elements are found. With roots, all elements that can't be
+    /// reached from the roots are removed, as in find_unreferenced.
+    /// Returns the paths of all removed elements. If dry_run is true, the model is not modified.
+    #[pyo3(signature = (dry_run=true, element_types=None, roots=None))]
+    #[pyo3(
+        text_signature = "(self, dry_run: bool = True, element_types: Optional[List[ElementName]] = None, roots: Optional[List[Element]] = None)"
+    )]
+    fn remove_unreferenced(
+        &self,
+        dry_run: bool,
+        element_types: Option<Vec<String>>,
+        roots: Option<Vec<Element>>,
+    ) -> PyResult<Vec<String>> {
+        if !dry_run {
+            frozen::check_model(&self.0)?;
+        }
+        let element_types = element_name_set(element_types)?;
+        let removed = match roots {
+            Some(roots) => {
+                let roots: Vec<_> = roots.into_iter().map(|e| e.0).collect();
+                find_unreachable_elements(&self.0, element_types.as_ref(), &roots)
+            }
+            None => {
+                let mut removed = Vec::new();
+                let mut ignored = HashSet::new();
+                loop {
+                    let unreferenced =
+                        find_unreferenced_elements(&self.0, element_types.as_ref(), &ignored);
+                    if unreferenced.is_empty() {
+                        break;
+                    }
+                    ignored.extend(unreferenced.iter().map(|element| element.downgrade()));
+                    removed.extend(unreferenced);
+                }
+                removed
+            }
+        };
+
+        let mut removed: Vec<_> = removed
+            .into_iter()
+            .filter_map(|element| Some((element.path().ok()?, element)))
+            .collect();
+        removed.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+        if !dry_run {
+            for (_, element) in &removed {
+                if let Ok(Some(parent)) = element.parent() {
+                    parent
+                        .remove_sub_element(element.clone())
+                        .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+                }
+            }
+        }
+        Ok(removed.into_iter().map(|(path, _)| path).collect())
+    }
+
+    /// calculate a content hash for every identifiable element in the model
+    ///
+    /// Returns a dict which maps each Autosar path to the content hash of the element, see `Element.content_hash`
+    #[pyo3(signature = (algorithm="sha256", ignore=None))]
+    #[pyo3(
+        text_signature = "(self, algorithm: str = \"sha256\", ignore: Optional[List[AttributeName]] = None)"
+    )]
+    fn fingerprints(
+        &self,
+        algorithm: &str,
+        ignore: Option<Vec<String>>,
+    ) -> PyResult<HashMap<String, String>> {
+        let ignored_attributes = ignored_attribute_set(ignore)?;
+        self.0
+            .identifiable_elements()
+            .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+            .map(|(path, element)| {
+                Ok((
+                    path,
+                    element_content_hash(&element, algorithm, &ignored_attributes)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// get an element in the model by its UUID
+    ///
+    /// The lookup uses an index. Each result is checked against the model, and the index is rebuilt if it is
+    /// outdated or if the UUID is not found, so a lookup of a missing UUID scans the whole model.
+    /// If several elements share the same UUID, then any one of them is returned.
+    #[pyo3(signature = (uuid, /))]
+    #[pyo3(text_signature = "(self, uuid: str, /)")]
+    fn get_element_by_uuid(&self, uuid: &str) -> Option<Element> {
+        uuid_index::get_element_by_uuid(&self.0, uuid).map(Element)
+    }
+
+    /// find all UUIDs that are used by more than one element
+    ///
+    /// Returns a dict which maps each duplicated UUID to the list of elements that use it
+    fn find_duplicate_uuids(&self) -> HashMap<String, Vec<Element>> {
+        let mut uuids = HashMap::<String, Vec<Element>>::new();
+        for (_, element) in self.0.elements_dfs() {
+            if let Some(uuid) = element_uuid(&element) {
+                uuids.entry(uuid).or_default().push(Element(element));
+            }
+        }
+        uuids.retain(|_, elements| elements.len() > 1);
+        uuids
+    }
+
+    /// set a UUID on every identifiable element that does not have one yet
+    ///
+    /// The UUIDs are created by calling the generator, which defaults to `uuid.uuid4`.
+    /// Returns the list of elements that received a new UUID.
+    #[pyo3(signature = (generator=None))]
+    #[pyo3(text_signature = "(self, generator: Optional[Callable[[], Any]] = None)")]
+    fn assign_missing_uuids(
+        &self,
+        py: Python<'_>,
+        generator: Option<Py<PyAny>>,
+    ) -> PyResult<Vec<Element>> {
+        frozen::check_model(&self.0)?;
+        let generator = uuid_generator(py, generator)?;
+        let mut elements: Vec<_> = self
+            .0
+            .identifiable_elements()
+            .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+            .filter(|(_, element)| element_uuid(element).is_none())
+            .collect();
+        elements.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+        let mut result = Vec::with_capacity(elements.len());
+        for (_, element) in elements {
+            set_new_uuid(py, &element, &generator)?;
+            result.push(Element(element));
+        }
+        Ok(result)
+    }
+
+    /// replace the UUIDs of the given element and all of its sub elements with newly generated ones
+    ///
+    /// Only elements that already have a UUID are modified. This is useful after copying a subtree,
+    /// since the copy has the same UUIDs as the original.
+    /// The UUIDs are created by calling the generator, which defaults to `uuid.uuid4`.
+    #[pyo3(signature = (subtree, /, generator=None))]
+    #[pyo3(
+        text_signature = "(self, subtree: Element, /, generator: Optional[Callable[[], Any]] = None)"
+    )]
+    fn regenerate_uuids(
+        &self,
+        py: Python<'_>,
+        subtree: &Element,
+        generator: Option<Py<PyAny>>,
+    ) -> PyResult<()> {
+        frozen::check_element(&subtree.0)?;
+        let generator = uuid_generator(py, generator)?;
+        for (_, element) in subtree.0.elements_dfs() {
+            if element_uuid(&element).is_some() {
+                set_new_uuid(py, &element, &generator)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// merge elements in AR-PACKAGEs that only differ in their SHORT-NAME and UUID
+    ///
+    /// For each group of identical elements, the one with the lexicographically smallest path is kept.
+    /// All references to the other elements are redirected to it, and the other elements are removed.
+    /// Returns a dict which maps the paths of the removed elements to the paths of the kept elements.
+    #[pyo3(signature = (element_names, /))]
+    #[pyo3(text_signature = "(self, element_names: List[ElementName], /)")]
+    fn deduplicate(&self, element_names: Vec<String>) -> PyResult<HashMap<String, String>> {
+        frozen::check_model(&self.0)?;
+        let element_names = element_name_set(Some(element_names))?.unwrap_or_default();
+        let ignored_attributes = HashSet::from([autosar_data_rs::AttributeName::Uuid]);
+        let mut replacements = HashMap::<String, String>::new();
+
+        // merging elements can make further elements identical, if they only differed in their references
+        loop {
+            let mut candidates: Vec<_> = self
+                .0
+                .identifiable_elements()
+                .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+                .filter(|(_, element)| {
+                    element_names.contains(&element.element_name())
+                        && packaged_element(element).as_ref() == Some(element)
+                })
+                .collect();
+            candidates.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+            let mut groups =
+                BTreeMap::<(String, String), Vec<(String, autosar_data_rs::Element)>>::new();
+            for (path, element) in candidates {
+                let key = (
+                    element.element_name().to_string(),
+                    canonical_text(&element, &ignored_attributes, true),
+                );
+                groups.entry(key).or_default().push((path, element));
+            }
+
+            let mut changed = false;
+            for group in groups.into_values().filter(|group| group.len() > 1) {
+                let (keep_path, _) = &group[0];
+                for (dup_path, dup_element) in &group[1..] {
+                    redirect_references(&self.0, dup_path, keep_path)?;
+                    if let Ok(Some(parent)) = dup_element.parent() {
+                        parent
+                            .remove_sub_element(dup_element.clone())
+                            .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+                    }
+                    // earlier replacements might point to an element that was just removed
+                    for target in replacements.values_mut() {
+                        if target == dup_path {
+                            target.clone_from(keep_path);
+                        }
+                    }
+                    replacements.insert(dup_path.clone(), keep_path.clone());
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(replacements)
+    }
+
+    /// copy the elements with the given paths into a new or existing model, keeping their Autosar paths
+    ///
+    /// If include_dependencies is true, all elements that are referenced by the copied elements are copied as well,
+    /// until all references can be resolved. Elements that already exist in the destination model are not copied.
+    /// Returns a tuple of the destination model and a list of (element path, reference target) for all
+    /// references in the copied elements that could not be resolved in the destination model.
+    #[pyo3(signature = (paths, include_dependencies=true, into=None))]
+    #[pyo3(
+        text_signature = "(self, paths: List[str], include_dependencies: bool = True, into: Optional[AutosarModel] = None)"
+    )]
+    fn extract(
+        &self,
+        paths: Vec<String>,
+        include_dependencies: bool,
+        into: Option<&AutosarModel>,
+    ) -> PyResult<(AutosarModel, Vec<(String, String)>)> {
+        let destination = match into {
+            Some(model) if model.0 == self.0 => {
+                return Err(AutosarDataError::new_err(
+                    "Cannot extract elements into the model that contains them",
+                ));
+            }
+            Some(model) => {
+                frozen::check_model(&model.0)?;
+                model.0.clone()
+            }
+            None => {
+                let model = autosar_data_rs::AutosarModel::new();
+                let version = self
+                    .0
+                    .files()
+                    .next()
+                    .map_or(autosar_data_rs::AutosarVersion::LATEST, |file| {
+                        file.version()
+                    });
+                model
+                    .create_file("extract.arxml", version)
+                    .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+                model
+            }
+        };
+
+        // collect the packaged elements to copy. Requested packages are copied with all of their contents,
+        // while packages that are only referenced are created empty.
+        let mut packages = BTreeMap::new();
+        let mut selected = BTreeMap::new();
+        let mut worklist = Vec::new();
+        for path in &paths {
+            let Some(element) = self.0.get_element_by_path(path) else {
+                return Err(AutosarDataError::new_err(format!(
+                    "Element {path} was not found in the model"
+                )));
+            };
+            if element.element_name() == autosar_data_rs::ElementName::ArPackage {
+                for (_, sub_element) in element.elements_dfs() {
+                    if sub_element.element_name() == autosar_data_rs::ElementName::ArPackage {
+                        packages.insert(sub_element.path().unwrap_or_default(), sub_element);
+                    } else if packaged_element(&sub_element).as_ref() == Some(&sub_element) {
+                        worklist.push(sub_element);
+                    }
+                }
+            } else if let Some(packaged) = packaged_element(&element) {
+                worklist.push(packaged);
+            } else {
+                return Err(AutosarDataError::new_err(format!(
+                    "Element {path} is not located inside of an AR-PACKAGE"
+                )));
+            }
+        }
+        while let Some(element) = worklist.pop() {
+            let Ok(path) = element.path() else {
+                continue;
+            };
+            if selected.contains_key(&path) {
+                continue;
+            }
+            if include_dependencies {
+                for (_, sub_element) in element.elements_dfs() {
+                    if !sub_element.element_type().is_ref() {
+                        continue;
+                    }
+                    let Ok(target) = sub_element.get_reference_target() else {
+                        continue;
+                    };
+                    match packaged_element(&target) {
+                        Some(packaged) => worklist.push(packaged),
+                        None if target.element_name()
+                            == autosar_data_rs::ElementName::ArPackage =>
+                        {
+                            packages.insert(target.path().unwrap_or_default(), target);
+                        }
+                        None => {}
+                    }
+                }
+            }
+            selected.insert(path, element);
+        }
+
+        for package_path in packages.keys() {
+            get_or_create_package(&destination, package_path)
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        }
+        let mut copied = Vec::new();
+        for (path, element) in &selected {
+            if destination.get_element_by_path(path).is_some() {
+                continue;
+            }
+            let package_path = &path[..path.rfind('/').unwrap_or(0)];
+            let new_element = get_or_create_package(&destination, package_path)
+                .and_then(|package| {
+                    package.get_or_create_sub_element(autosar_data_rs::ElementName::Elements)
+                })
+                .and_then(|elements| elements.create_copied_sub_element(element))
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            copied.push((path.clone(), new_element));
+        }
+
+        // report all references in the copied elements that could not be resolved
+        let mut unresolved = Vec::new();
+        for (path, element) in &copied {
+            for (_, sub_element) in element.elements_dfs() {
+                if sub_element.element_type().is_ref()
+                    && sub_element.get_reference_target().is_err()
+                    && let Some(target) = sub_element
+                        .character_data()
+                        .and_then(|cdata| cdata.string_value())
+                {
+                    unresolved.push((path.clone(), target));
+                }
+            }
+        }
+
+        Ok((AutosarModel(destination), unresolved))
+    }
+
+    /// move an AR-PACKAGE to a new Autosar path
+    ///
+    /// Missing parent packages of the new path are created. All references to the package and its content are updated.
+    #[pyo3(signature = (old_path, new_path, /))]
+    #[pyo3(text_signature = "(self, old_path: str, new_path: str, /)")]
+    fn move_package(&self, old_path: &str, new_path: &str) -> PyResult<Element> {
+        frozen::check_model(&self.0)?;
+        let Some(package) = self.0.get_element_by_path(old_path) else {
+            return Err(AutosarDataError::new_err(format!(
+                "Element {old_path} was not found in the model"
+            )));
+        };
+        if package.element_name() != autosar_data_rs::ElementName::ArPackage {
+            return Err(AutosarDataError::new_err(format!(
+                "Element {old_path} is not an AR-PACKAGE"
+            )));
+        }
+        let mut moved = relocate_elements(&self.0, vec![(package, new_path.to_string())])?;
+        Ok(Element(moved.remove(0)))
+    }
+
+    /// move all AR-PACKAGEs and packaged elements whose Autosar path starts with old_prefix, so that their path starts with new_prefix
+    ///
+    /// The prefix only matches complete path segments, so the prefix "/A" matches "/A" and "/A/B", but not "/AB".
+    /// Missing parent packages are created, and all references are updated.
+    /// Returns a dict which maps the old paths of the moved elements to their new paths.
+    #[pyo3(signature = (old_prefix, new_prefix, /))]
+    #[pyo3(text_signature = "(self, old_prefix: str, new_prefix: str, /)")]
+    fn rename_path_prefix(
+        &self,
+        old_prefix: &str,
+        new_prefix: &str,
+    ) -> PyResult<HashMap<String, String>> {
+        frozen::check_model(&self.0)?;
+        let mut identifiables: Vec<_> = self
+            .0
+            .identifiable_elements()
+            .filter(|(path, _)| has_path_prefix(path, old_prefix))
+            .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+            .collect();
+        identifiables.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+        // only the outermost matching elements are moved, their content moves along with them
+        let mut moves: Vec<(String, autosar_data_rs::Element, String)> = Vec::new();
+        for (path, element) in identifiables {
+            if moves
+                .iter()
+                .any(|(moved_path, _, _)| path.starts_with(&format!("{moved_path}/")))
+            {
+                continue;
+            }
+            if element.element_name() != autosar_data_rs::ElementName::ArPackage
+                && packaged_element(&element).as_ref() != Some(&element)
+            {
+                return Err(AutosarDataError::new_err(format!(
+                    "Element {path} can not be moved, since it is neither an AR-PACKAGE nor an element in an AR-PACKAGE"
+                )));
+            }
+            let new_path = format!("{new_prefix}{}", &path[old_prefix.len()..]);
+            moves.push((path, element, new_path));
+        }
+
+        let replacements = moves
+            .iter()
+            .map(|(old_path, _, new_path)| (old_path.clone(), new_path.clone()))
+            .collect();
+        relocate_elements(
+            &self.0,
+            moves
+                .into_iter()
+                .map(|(_, element, new_path)| (element, new_path))
+                .collect(),
+        )?;
+        Ok(replacements)
+    }
+
+    /// find identifiable elements whose documentation is missing
+    ///
+    /// The fields LONG-NAME, DESC and INTRODUCTION can be checked; by default only DESC is checked.
+    /// If languages are given, then the documentation must exist in each of them, otherwise any language is sufficient.
+    /// Returns a list of (element, missing) sorted by path. The entries in missing are the field names, e.g. "DESC",
+    /// or the field names and languages, e.g. "DESC:DE", if languages are given.
+    #[pyo3(signature = (fields=None, languages=None, element_types=None))]
+    #[pyo3(
+        text_signature = "(self, fields: Optional[List[str]] = None, languages: Optional[List[str]] = None, element_types: Optional[List[ElementName]] = None)"
+    )]
+    fn find_missing_documentation(
+        &self,
+        fields: Option<Vec<String>>,
+        languages: Option<Vec<String>>,
+        element_types: Option<Vec<String>>,
+    ) -> PyResult<Vec<(Element, Vec<String>)>> {
+        let fields = match fields {
+            Some(fields) => fields
+                .iter()
+                .map(|field| field.parse())
+                .collect::<PyResult<Vec<DocField>>>()?,
+            None => vec![DocField::Desc],
+        };
+        let element_types = element_name_set(element_types)?;
+        let mut identifiables: Vec<_> = self
+            .0
+            .identifiable_elements()
+            .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+            .filter(|(_, element)| {
+                element_types
+                    .as_ref()
+                    .is_none_or(|types| types.contains(&element.element_name()))
+            })
+            .collect();
+        identifiables.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+        let mut result = Vec::new();
+        for (_, element) in identifiables {
+            let mut missing = Vec::new();
+            for field in &fields {
+                let present = documentation::languages(&element, *field);
+                match &languages {
+                    Some(languages) => missing.extend(
+                        languages
+                            .iter()
+                            .filter(|language| !present.contains(language))
+                            .map(|language| format!("{}:{language}", field.element_name())),
+                    ),
+                    None if present.is_empty() => missing.push(field.element_name().to_string()),
+                    None => {}
+                }
+            }
+            if !missing.is_empty() {
+                result.push((Element(element), missing));
+            }
+        }
+        Ok(result)
+    }
+
+    /// find all references whose target does not exist, and search for candidates that could be the intended target
+    ///
+    /// Candidates must have the element type given by the DEST attribute of the reference. Their score
+    /// is between 0 and 1: the similarity of the SHORT-NAME to the name in the missing path contributes 3/4,
+    /// and the fraction of matching trailing path elements contributes 1/4.
+    fn reference_report(&self) -> Vec<BrokenReference> {
+        reference_repair::broken_references(&self.0)
+            .into_iter()
+            .map(|info| BrokenReference {
+                element: Element(info.reference),
+                target: info.target,
+                dest: info.dest,
+                candidates: info
+                    .candidates
+                    .into_iter()
+                    .map(|(element, score)| (Element(element), score))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// repair broken references which have exactly one candidate with a score of at least threshold
+    ///
+    /// See `reference_report` for details about the candidates.
+    /// Returns a list of (reference element, old target path, new target path) for all repaired references.
+    #[pyo3(signature = (threshold=0.9))]
+    #[pyo3(text_signature = "(self, threshold: float = 0.9)")]
+    fn auto_repair(&self, threshold: f64) -> PyResult<Vec<(Element, String, String)>> {
+        frozen::check_model(&self.0)?;
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(PyValueError::new_err(format!(
+                "The threshold must be between 0 and 1, but it is {threshold}"
+            )));
+        }
+        let mut repaired = Vec::new();
+        for info in reference_repair::broken_references(&self.0) {
+            let mut matching = info
+                .candidates
+                .iter()
+                .filter(|(_, score)| *score >= threshold);
+            let (Some((target, _)), None) = (matching.next(), matching.next()) else {
+                continue;
+            };
+            info.reference
+                .set_reference_target(target)
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            let new_target = target.path().unwrap_or_default();
+            repaired.push((Element(info.reference), info.target, new_target));
+        }
+        Ok(repaired)
+    }
+
+    /// search for a regular expression in the character data of all elements
+    ///
+    /// If element_names is given, then only the content of elements with these names is searched.
+    /// If include_attributes is true, then the attribute values are searched as well.
+    #[pyo3(signature = (pattern, element_names=None, include_attributes=false))]
+    #[pyo3(
+        text_signature = "(self, pattern: str, element_names: Optional[List[ElementName]] = None, include_attributes: bool = False)"
+    )]
+    fn search_text(
+        &self,
+        pattern: &str,
+        element_names: Option<Vec<String>>,
+        include_attributes: bool,
+    ) -> PyResult<Vec<TextMatch>> {
+        let regex = compile_regex(pattern)?;
+        let element_names = element_name_set(element_names)?;
+        let mut matches = Vec::new();
+        for location in
+            text_search::text_locations(&self.0, element_names.as_ref(), include_attributes)
+        {
+            let text = location.text();
+            let spans = text_search::match_spans(&regex, &text);
+            if !spans.is_empty() {
+                matches.push(TextMatch {
+                    element: Element(location.element),
+                    attribute: location.attribute.map(|attribute| attribute.to_string()),
+                    content_index: location.content_index,
+                    text,
+                    spans,
+                });
+            }
+        }
+        Ok(matches)
+    }
+
+    /// replace all matches of a regular expression in the character data of all elements
+    ///
+    /// The replacement can refer to capture groups using $1 or ${name}. Only string values are modified.
+    /// Changing the text of a SHORT-NAME renames the parent element and updates all references to it.
+    /// Returns a list of (element, attribute name, old text, new text) for all changes.
+    /// If dry_run is true, the model is not modified.
+    #[pyo3(signature = (pattern, replacement, dry_run=true, element_names=None, include_attributes=false))]
+    #[pyo3(
+        text_signature = "(self, pattern: str, replacement: str, dry_run: bool = True, element_names: Optional[List[ElementName]] = None, include_attributes: bool = False)"
+    )]
+    fn replace_text(
+        &self,
+        pattern: &str,
+        replacement: &str,
+        dry_run: bool,
+        element_names: Option<Vec<String>>,
+        include_attributes: bool,
+    ) -> PyResult<Vec<TextChange>> {
+        if !dry_run {
+            frozen::check_model(&self.0)?;
+        }
+        let regex = compile_regex(pattern)?;
+        let element_names = element_name_set(element_names)?;
+        let mut changes = Vec::new();
+        for location in
+            text_search::text_locations(&self.0, element_names.as_ref(), include_attributes)
+        {
+            if !location.is_replaceable() {
+                continue;
+            }
+            let text = location.text();
+            let new_text = regex.replace_all(&text, replacement).into_owned();
+            if new_text != text {
+                changes.push((location, text, new_text));
+            }
+        }
+
+        if !dry_run {
+            for (location, _, new_text) in &changes {
+                location
+                    .replace(new_text)
+                    .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+            }
+        }
+        Ok(changes
+            .into_iter()
+            .map(|(location, text, new_text)| {
+                (
+                    Element(location.element),
+                    location.attribute.map(|attribute| attribute.to_string()),
+                    text,
+                    new_text,
+                )
+            })
+            .collect())
+    }
+}
+
+/// compile a regular expression, raising a ValueError if it is invalid
+fn compile_regex(pattern: &str) -> PyResult<regex::Regex> {
+    regex::Regex::new(pattern)
+        .map_err(|error| PyValueError::new_err(format!("Invalid regular expression: {error}")))
+}
+
+/// move AR-PACKAGEs or packaged elements to new Autosar paths and update all references to them
+///
+/// Missing parent packages are created. All moves are validated before the model is modified.
+fn relocate_elements(
+    model: &autosar_data_rs::AutosarModel,
+    moves: Vec<(autosar_data_rs::Element, String)>,
+) -> PyResult<Vec<autosar_data_rs::Element>> {
+    let mut references = Vec::new();
+    for (element, new_path) in &moves {
+        let old_path = element
+            .path()
+            .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        let Some((parent_path, name)) = new_path.rsplit_once('/') else {
+            return Err(AutosarDataError::new_err(format!(
+                "{new_path} is not a valid Autosar path"
+            )));
+        };
+        if !new_path.starts_with('/') || name.is_empty() {
+            return Err(AutosarDataError::new_err(format!(
+                "{new_path} is not a valid Autosar path"
+            )));
+        }
+        if parent_path.is_empty()
+            && element.element_name() != autosar_data_rs::ElementName::ArPackage
+        {
+            return Err(AutosarDataError::new_err(format!(
+                "Element {old_path} can not be moved to {new_path}, since it must be located inside of an AR-PACKAGE"
+            )));
+        }
+        if *new_path == old_path || new_path.starts_with(&format!("{old_path}/")) {
+            return Err(AutosarDataError::new_err(format!(
+                "Element {old_path} can not be moved to {new_path}"
+            )));
+        }
+        if model.get_element_by_path(new_path).is_some() {
+            return Err(AutosarDataError::new_err(format!(
+                "Element {new_path} already exists in the model"
+            )));
+        }
+
+        // remember all references to the element and its content, together with their new target paths
+        let old_prefix = format!("{old_path}/");
+        for (path, _) in model
+            .identifiable_elements()
+            .filter(|(path, _)| *path == old_path || path.starts_with(&old_prefix))
+        {
+            let target_path = format!("{new_path}{}", &path[old_path.len()..]);
+            for reference in model
+                .get_references_to(&path)
+                .iter()
+                .filter_map(|weak| weak.upgrade())
+            {
+                references.push((reference, target_path.clone()));
+            }
+        }
+    }
+
+    let mut moved_elements = Vec::with_capacity(moves.len());
+    for (element, new_path) in moves {
+        let (parent_path, name) = new_path.rsplit_once('/').unwrap_or_default();
+        let moved = get_or_create_package(model, parent_path)
+            .and_then(|parent| {
+                if element.element_name() == autosar_data_rs::ElementName::ArPackage {
+                    parent.get_or_create_sub_element(autosar_data_rs::ElementName::ArPackages)
+                } else {
+                    parent.get_or_create_sub_element(autosar_data_rs::ElementName::Elements)
+                }
+            })
+            .and_then(|container| {
+                // rename the element first if its current name is already taken in the new location
+                let name_taken = container
+                    .sub_elements()
+                    .any(|sub_element| sub_element.item_name() == element.item_name());
+                if name_taken {
+                    element.set_item_name(name)?;
+                }
+                let moved = container.move_element_here(&element)?;
+                if moved.item_name().as_deref() != Some(name) {
+                    moved.set_item_name(name)?;
+                }
+                Ok(moved)
+            })
+            .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        moved_elements.push(moved);
+    }
+
+    for (reference, target_path) in references {
+        let result = match model.get_element_by_path(&target_path) {
+            Some(target) => reference.set_reference_target(&target),
+            None => {
+                reference.set_character_data(autosar_data_rs::CharacterData::String(target_path))
+            }
+        };
+        result.map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+    }
+
+    Ok(moved_elements)
+}
+
+/// get the xml path segment of an element: its item name, or its element name in angle brackets
+fn xml_path_segment(element: &autosar_data_rs::Element) -> String {
+    match element.item_name() {
+        Some(item_name) => item_name,
+        None => format!("<{}>", element.element_name()),
+    }
+}
+
+/// split an xml path segment into the segment itself and an optional 1-based index, e.g. `<SUB-ELEMENT>[2]`
+fn parse_xml_path_segment(segment: &str) -> (&str, Option<usize>) {
+    if let Some(prefix) = segment.strip_suffix(']')
+        && let Some((name, index)) = prefix.rsplit_once('[')
+        && let Ok(index) = index.parse()
+    {
+        (name, Some(index))
+    } else {
+        (segment, None)
+    }
+}
+
+/// collect all descendants of the element that are reached by following the xml path segments
+fn find_by_xml_path(
+    element: &autosar_data_rs::Element,
+    segments: &[(&str, Option<usize>)],
+    matches: &mut Vec<autosar_data_rs::Element>,
+) {
+    let Some(((name, index), rest)) = segments.split_first() else {
+        matches.push(element.clone());
+        return;
+    };
+    let mut candidates = element
+        .sub_elements()
+        .filter(|sub_element| xml_path_segment(sub_element) == *name);
+    match index {
+        Some(index) => {
+            if let Some(sub_element) = index
+                .checked_sub(1)
+                .and_then(|position| candidates.nth(position))
+            {
+                find_by_xml_path(&sub_element, rest, matches);
+            }
+        }
+        None => {
+            for sub_element in candidates {
+                find_by_xml_path(&sub_element, rest, matches);
+            }
+        }
+    }
+}
+
+/// load a file into the model
+///
+/// The file is read only once, and the same text is used to record the source locations and the file header.
+pub(crate) fn load_file(
+    model: &autosar_data_rs::AutosarModel,
+    filename: &str,
+    strict: bool,
+) -> Result<
+    (
+        autosar_data_rs::ArxmlFile,
+        Vec<autosar_data_rs::AutosarDataError>,
+    ),
+    autosar_data_rs::AutosarDataError,
+> {
+    let buffer = std::fs::read(filename).map_err(|error| {
+        autosar_data_rs::AutosarDataError::IoErrorRead {
+            filename: filename.into(),
+            ioerror: error,
+        }
+    })?;
+    load_text(model, &buffer, filename, strict)
+}
+
+/// load a buffer into the model, and record the source locations and the file header
+fn load_text(
+    model: &autosar_data_rs::AutosarModel,
+    buffer: &[u8],
+    filename: &str,
+    strict: bool,
+) -> Result<
+    (
+        autosar_data_rs::ArxmlFile,
+        Vec<autosar_data_rs::AutosarDataError>,
+    ),
+    autosar_data_rs::AutosarDataError,
+> {
+    let (file, warnings) = model.load_buffer(buffer, filename, strict)?;
+    let text = String::from_utf8_lossy(buffer);
+    source_location::record_source_locations(model, &file, &text);
+    file_header::record_header(&file, &text);
+    Ok((file, warnings))
+}
+
+/// get the AR-PACKAGE with the given path, creating it and any missing parent packages if necessary
+pub(crate) fn get_or_create_package(
+    model: &autosar_data_rs::AutosarModel,
+    package_path: &str,
+) -> Result<autosar_data_rs::Element, autosar_data_rs::AutosarDataError> {
+    let mut parent = model.root_element();
+    for name in package_path.split('/').filter(|name| !name.is_empty()) {
+        let packages =
+            parent.get_or_create_sub_element(autosar_data_rs::ElementName::ArPackages)?;
+        let existing = packages.sub_elements().find(|package| {
+            package.element_name() == autosar_data_rs::ElementName::ArPackage
+                && package.item_name().as_deref() == Some(name)
+        });
+        parent = match existing {
+            Some(package) => package,
+            None => {
+                packages.create_named_sub_element(autosar_data_rs::ElementName::ArPackage, name)?
+            }
+        };
+    }
+    Ok(parent)
+}
+
+/// get the callable that generates new UUIDs: either the given one or `uuid.uuid4`
+fn uuid_generator(py: Python<'_>, generator: Option<Py<PyAny>>) -> PyResult<Py<PyAny>> {
+    match generator {
+        Some(generator) => Ok(generator),
+        None => Ok(py.import("uuid")?.getattr("uuid4")?.unbind()),
+    }
+}
+
+/// call the generator and set the result as the new UUID of the element
+fn set_new_uuid(
+    py: Python<'_>,
+    element: &autosar_data_rs::Element,
+    generator: &Py<PyAny>,
+) -> PyResult<()> {
+    let uuid = generator.call0(py)?.bind(py).str()?.to_string();
+    element
+        .set_attribute(
+            autosar_data_rs::AttributeName::Uuid,
+            autosar_data_rs::CharacterData::String(uuid),
+        )
+        .map_err(|error| AutosarDataError::new_err(error.to_string()))
+}
+
+/// redirect all references to the element at `old_path` or any of its sub elements to the corresponding element below `new_path`
+fn redirect_references(
+    model: &autosar_data_rs::AutosarModel,
+    old_path: &str,
+    new_path: &str,
+) -> PyResult<()> {
+    let old_prefix = format!("{old_path}/");
+    let affected_paths: Vec<String> = model
+        .identifiable_elements()
+        .map(|(path, _)| path)
+        .filter(|path| path == old_path || path.starts_with(&old_prefix))
+        .collect();
+    for path in affected_paths {
+        let target_path = format!("{new_path}{}", &path[old_path.len()..]);
+        let Some(target) = model.get_element_by_path(&target_path) else {
+            continue;
+        };
+        for reference in model
+            .get_references_to(&path)
+            .iter()
+            .filter_map(|weak| weak.upgrade())
+        {
+            reference
+                .set_reference_target(&target)
+                .map_err(|error| AutosarDataError::new_err(error.to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+/// write all files of the model to disk, applying the custom file header settings
+///
+/// All files are serialized before anything is written, so that an error does not leave a partially written model.
+pub(crate) fn write_model(model: &autosar_data_rs::AutosarModel) -> Result<(), String> {
+    let mut serialized = Vec::new();
+    for file in model.files() {
+        let text = file_header::serialize_file(&file).map_err(|error| error.to_string())?;
+        serialized.push((file.filename(), text));
+    }
+    for (filename, text) in serialized {
+        std::fs::write(&filename, text).map_err(|ioerror| {
+            format!(
+                "Failed to write file {}: {ioerror}",
+                filename.to_string_lossy()
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// convert an optional list of element name strings to a set of `ElementName`s
+fn element_name_set(
+    names: Option<Vec<String>>,
+) -> PyResult<Option<HashSet<autosar_data_rs::ElementName>>> {
+    names
+        .map(|names| {
+            names
+                .iter()
+                .map(|name| get_element_name(name))
+                .collect::<PyResult<HashSet<_>>>()
+        })
+        .transpose()
+}
+
+/// get the element inside an AR-PACKAGE which contains the given element
+///
+/// Returns None if the element is not located inside of an AR-PACKAGE/ELEMENTS
+pub(crate) fn packaged_element(
+    element: &autosar_data_rs::Element,
+) -> Option<autosar_data_rs::Element> {
+    let mut current = element.clone();
+    loop {
+        let parent = current.parent().ok()??;
+        if parent.element_name() == autosar_data_rs::ElementName::Elements
+            && parent
+                .parent()
+                .ok()
+                .flatten()
+                .is_some_and(|pkg| pkg.element_name() == autosar_data_rs::ElementName::ArPackage)
+        {
+            return Some(current);
+        }
+        current = parent;
+    }
+}
+
+/// check if the path starts with the prefix, which must end at a path segment boundary
+fn has_path_prefix(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/') || prefix.ends_with('/'))
+}
+
+/// find all packaged elements which are not referenced by any other element, sorted by path
+///
+/// References originating in any of the elements in `ignored` are not counted, and the
+/// elements in `ignored` are not returned again.
+fn find_unreferenced_elements(
+    model: &autosar_data_rs::AutosarModel,
+    element_types: Option<&HashSet<autosar_data_rs::ElementName>>,
+    ignored: &HashSet<autosar_data_rs::WeakElement>,
+) -> Vec<autosar_data_rs::Element> {
+    // collect all referenced paths, including the paths of all parents of each target
+    let mut referenced_paths = HashSet::new();
+    for (_, element) in model.elements_dfs() {
+        if !element.element_type().is_ref() {
+            continue;
+        }
+        let Some(target) = element
+            .character_data()
+            .and_then(|cdata| cdata.string_value())
+        else {
+            continue;
+        };
+        if let Some(owner) = packaged_element(&element) {
+            if ignored.contains(&owner.downgrade()) {
+                continue;
+            }
+            // references from an element to itself or to one of its sub elements don't count
+            if let Ok(owner_path) = owner.path()
+                && (target == owner_path || target.starts_with(&format!("{owner_path}/")))
+            {
+                continue;
+            }
+        }
+        let mut prefix_end = 0;
+        while let Some(pos) = target[prefix_end + 1..].find('/') {
+            prefix_end += pos + 1;
+            referenced_paths.insert(target[..prefix_end].to_string());
+        }
+        referenced_paths.insert(target);
+    }
+
+    model
+        .identifiable_elements()
+        .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+        .filter(|(_, element)| {
+            element_types.is_none_or(|types| types.contains(&element.element_name()))
+                && !ignored.contains(&element.downgrade())
+                && packaged_element(element).as_ref() == Some(element)
+        })
+        .filter(|(path, _)| !referenced_paths.contains(path))
+        .collect::<BTreeMap<_, _>>()
+        .into_values()
+        .collect()
+}
+
+/// find all packaged elements which can't be reached from any of the roots by following references, sorted by path
+///
+/// Packaged elements are always kept as a whole, so all references inside of a reachable element are
+/// followed. If an AR-PACKAGE is reachable, then all packaged elements inside of it are reachable as well. Packaged elements which are not among the `element_types` are kept as well.
+fn find_unreachable_elements(
+    model: &autosar_data_rs::AutosarModel,
+    element_types: Option<&HashSet<autosar_data_rs::ElementName>>,
+    roots: &[autosar_data_rs::Element],
+) -> Vec<autosar_data_rs::Element> {
+    let is_candidate = |element: &autosar_data_rs::Element| {
+        element_types.is_none_or(|types| types.contains(&element.element_name()))
+    };
+    let mut packaged: Vec<(String, autosar_data_rs::Element)> = model
+        .identifiable_elements()
+        .filter_map(|(path, weak)| Some((path, weak.upgrade()?)))
+        .filter(|(_, element)| packaged_element(element).as_ref() == Some(element))
+        .collect();
+    packaged.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+
+    // mark: follow all references, starting from the roots and the elements that are not collected
+    let mut worklist: Vec<autosar_data_rs::Element> = roots
+        .iter()
+        .map(|root| packaged_element(root).unwrap_or_else(|| root.clone()))
+        .chain(
+            packaged
+                .iter()
+                .filter(|(_, element)| !is_candidate(element))
+                .map(|(_, element)| element.clone()),
+        )
+        .collect();
+    let mut reachable: HashSet<autosar_data_rs::WeakElement> =
+        worklist.iter().map(|element| element.downgrade()).collect();
+    while let Some(current) = worklist.pop() {
+        for (_, element) in current.elements_dfs() {
+            // the packaged elements inside of a reachable AR-PACKAGE are reachable too. Their references are
+            // followed as part of the traversal of the package, so they don't need to be visited again
+            if packaged_element(&element).as_ref() == Some(&element) {
+                reachable.insert(element.downgrade());
+            }
+            if element.element_type().is_ref()
+                && let Ok(target) = element.get_reference_target()
+            {
+                let target = packaged_element(&target).unwrap_or(target);
+                if reachable.insert(target.downgrade()) {
+                    worklist.push(target);
+                }
+            }
+        }
+    }
+
+    // sweep: everything else is unreachable
+    packaged
+        .into_iter()
+        .map(|(_, element)| element)
+        .filter(|element| is_candidate(element) && !reachable.contains(&element.downgrade()))
+        .collect()
 }
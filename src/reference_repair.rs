@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{AttributeName, AutosarModel, Element};
+
+/// candidates with a lower score are not reported
+const MIN_CANDIDATE_SCORE: f64 = 0.5;
+/// maximum number of candidates that are reported for each broken reference
+const MAX_CANDIDATES: usize = 10;
+
+/// A reference whose target does not exist, together with possible replacement targets
+pub(crate) struct BrokenReferenceInfo {
+    pub(crate) reference: Element,
+    pub(crate) target: String,
+    pub(crate) dest: Option<String>,
+    /// candidate targets and their scores, sorted by descending score
+    pub(crate) candidates: Vec<(Element, f64)>,
+}
+
+/// an identifiable element that could be the target of a broken reference
+struct Candidate {
+    path: String,
+    segments: Vec<String>,
+    element: Element,
+}
+
+/// find all broken references in the model and search for candidate targets for each of them
+///
+/// Candidates must have the element type given by the DEST attribute of the reference. They are scored
+/// by the similarity of their SHORT-NAME to the last element of the missing path, and by the number of
+/// trailing path elements that match.
+pub(crate) fn broken_references(model: &AutosarModel) -> Vec<BrokenReferenceInfo> {
+    let broken: Vec<Element> = model
+        .check_references()
+        .iter()
+        .filter_map(|weak| weak.upgrade())
+        .collect();
+    if broken.is_empty() {
+        return Vec::new();
+    }
+
+    let mut identifiables = HashMap::<String, Vec<Candidate>>::new();
+    for (path, weak) in model.identifiable_elements() {
+        if let Some(element) = weak.upgrade() {
+            let segments = path_segments(&path).map(str::to_string).collect();
+            identifiables
+                .entry(element.element_name().to_string())
+                .or_default()
+                .push(Candidate {
+                    path,
+                    segments,
+                    element,
+                });
+        }
+    }
+
+    broken
+        .into_iter()
+        .filter_map(|reference| {
+            let target = reference.character_data()?.string_value()?;
+            let dest = reference
+                .attribute_value(AttributeName::Dest)
+                .map(|dest| dest.to_string());
+            let target_segments: Vec<&str> = path_segments(&target).collect();
+            let candidate_list: Vec<&Candidate> = match &dest {
+                Some(dest) => identifiables.get(dest).into_iter().flatten().collect(),
+                None => identifiables.values().flatten().collect(),
+            };
+            let mut scored: Vec<(&Candidate, f64)> = candidate_list
+                .into_iter()
+                .map(|candidate| (candidate, score(&target_segments, &candidate.segments)))
+                .filter(|(_, score)| *score >= MIN_CANDIDATE_SCORE)
+                .collect();
+            scored.sort_by(|(cand_a, score_a), (cand_b, score_b)| {
+                score_b
+                    .total_cmp(score_a)
+                    .then_with(|| cand_a.path.cmp(&cand_b.path))
+            });
+            scored.truncate(MAX_CANDIDATES);
+            let candidates = scored
+                .into_iter()
+                .map(|(candidate, score)| (candidate.element.clone(), score))
+                .collect();
+            Some(BrokenReferenceInfo {
+                reference,
+                target,
+                dest,
+                candidates,
+            })
+        })
+        .collect()
+}
+
+fn path_segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// calculate the similarity score between a missing target path and the path of a candidate
+///
+/// The score is between 0 and 1. The similarity of the names contributes 3/4 of the score, and
+/// the fraction of matching trailing path elements contributes the remaining 1/4.
+fn score(target: &[&str], candidate: &[String]) -> f64 {
+    let (Some(target_name), Some(candidate_name)) = (target.last(), candidate.last()) else {
+        return 0.0;
+    };
+    // if the names differ, then no path elements match, and the score depends only on the names. The edit
+    // distance is at least the difference in length, so names of very different length can be rejected quickly.
+    if *target_name != candidate_name.as_str() {
+        let len_target = target_name.chars().count();
+        let len_candidate = candidate_name.chars().count();
+        let max_similarity =
+            1.0 - len_target.abs_diff(len_candidate) as f64 / len_target.max(len_candidate) as f64;
+        if 0.75 * max_similarity < MIN_CANDIDATE_SCORE {
+            return 0.0;
+        }
+    }
+    let name_similarity = name_similarity(target_name, candidate_name);
+    let matching_suffix = target
+        .iter()
+        .rev()
+        .zip(candidate.iter().rev())
+        .take_while(|(target_segment, candidate_segment)| {
+            **target_segment == candidate_segment.as_str()
+        })
+        .count();
+    let suffix_fraction = matching_suffix as f64 / target.len().max(candidate.len()) as f64;
+    0.75 * name_similarity + 0.25 * suffix_fraction
+}
+
+/// similarity of two names, based on the Levenshtein distance: 1.0 means identical, 0.0 means completely different
+fn name_similarity(name_a: &str, name_b: &str) -> f64 {
+    let chars_a: Vec<char> = name_a.chars().collect();
+    let chars_b: Vec<char> = name_b.chars().collect();
+    let max_len = chars_a.len().max(chars_b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    let mut previous: Vec<usize> = (0..=chars_b.len()).collect();
+    let mut current = vec![0; chars_b.len() + 1];
+    for (idx_a, char_a) in chars_a.iter().enumerate() {
+        current[0] = idx_a + 1;
+        for (idx_b, char_b) in chars_b.iter().enumerate() {
+            let substitution = previous[idx_b] + usize::from(char_a != char_b);
+            current[idx_b + 1] = substitution
+                .min(previous[idx_b + 1] + 1)
+                .min(current[idx_b] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[chars_b.len()] as f64 / max_len as f64
+}
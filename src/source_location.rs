@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{ArxmlFile, AutosarModel, Element, WeakElement};
+
+use crate::{is_model_key, model_key};
+
+// The parser in the autosar_data crate does not keep track of source positions. Instead, the loaded
+// text is scanned a second time here, and the positions of the tags are matched up with the elements.
+// The table is keyed by the root element of each model (see `model_key`), and only holds weak
+// references, so that removed elements and dropped models can be freed.
+type LocationTable = HashMap<WeakElement, SourceLocation>;
+static SOURCE_LOCATIONS: Mutex<Vec<(WeakElement, LocationTable)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone)]
+struct SourceLocation {
+    filename: Arc<str>,
+    line: usize,
+    column: usize,
+}
+
+/// An element found by scanning the text of an arxml file
+#[derive(Debug)]
+struct ScannedElement {
+    name: String,
+    line: usize,
+    column: usize,
+    short_name: Option<String>,
+    children: Vec<usize>,
+}
+
+/// record the source locations of all elements that were loaded from the given text into the file
+///
+/// Only the elements that belong to the file are matched with the text, so that elements of other files
+/// which were loaded before do not shift the positions. Elements that already have a source location,
+/// because they were merged from several files, keep the location in the file that was loaded first.
+pub(crate) fn record_source_locations(model: &AutosarModel, file: &ArxmlFile, text: &str) {
+    let nodes = scan_elements(text);
+    if nodes.is_empty() {
+        return;
+    }
+    let filename: Arc<str> = Arc::from(file.filename().to_string_lossy().as_ref());
+
+    let mut locations = SOURCE_LOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    locations.retain(|(key, _)| key.upgrade().is_some_and(|root| root.model().is_ok()));
+    let idx = match locations
+        .iter()
+        .position(|(key, _)| is_model_key(key, model))
+    {
+        Some(idx) => idx,
+        None => {
+            locations.push((model_key(model), HashMap::new()));
+            locations.len() - 1
+        }
+    };
+    let model_locations = &mut locations[idx].1;
+    // entries of removed elements are dropped, since they will never be looked up again
+    model_locations.retain(|weak_element, _| {
+        weak_element
+            .upgrade()
+            .is_some_and(|element| element.model().is_ok())
+    });
+    match_elements(
+        &model.root_element(),
+        file,
+        &nodes,
+        0,
+        &filename,
+        model_locations,
+    );
+}
+
+/// get the source location of an element: (filename, line, column)
+pub(crate) fn source_location(element: &Element) -> Option<(String, usize, usize)> {
+    let model = element.model().ok()?;
+    let locations = SOURCE_LOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (_, model_locations) = locations
+        .iter()
+        .find(|(key, _)| is_model_key(key, &model))?;
+    model_locations.get(&element.downgrade()).map(|location| {
+        (
+            location.filename.to_string(),
+            location.line,
+            location.column,
+        )
+    })
+}
+
+/// walk the model and the scanned elements in parallel, and assign the positions to the model elements
+fn match_elements(
+    element: &Element,
+    file: &ArxmlFile,
+    nodes: &[ScannedElement],
+    node_idx: usize,
+    filename: &Arc<str>,
+    locations: &mut LocationTable,
+) {
+    let node = &nodes[node_idx];
+    if node.name != element.element_name().to_string() {
+        return;
+    }
+    locations
+        .entry(element.downgrade())
+        .or_insert_with(|| SourceLocation {
+            filename: filename.clone(),
+            line: node.line,
+            column: node.column,
+        });
+
+    let mut name_counts = HashMap::<String, usize>::new();
+    for sub_element in element
+        .sub_elements()
+        .filter(|sub_element| is_in_file(sub_element, file))
+    {
+        let sub_name = sub_element.element_name().to_string();
+        let count = name_counts.entry(sub_name.clone()).or_insert(0);
+        let mut candidates = node
+            .children
+            .iter()
+            .copied()
+            .filter(|&child_idx| nodes[child_idx].name == sub_name);
+        let child_idx = if let Some(item_name) = sub_element.item_name() {
+            candidates.find(|&child_idx| {
+                nodes[child_idx].short_name.as_deref() == Some(item_name.as_str())
+            })
+        } else {
+            candidates.nth(*count)
+        };
+        *count += 1;
+        if let Some(child_idx) = child_idx {
+            match_elements(&sub_element, file, nodes, child_idx, filename, locations);
+        }
+    }
+}
+
+/// check if an element is part of the file. Elements without a file membership are part of all files.
+fn is_in_file(element: &Element, file: &ArxmlFile) -> bool {
+    element.file_membership().is_ok_and(|(_, files)| {
+        files.is_empty()
+            || files
+                .iter()
+                .any(|weak| weak.upgrade().as_ref() == Some(file))
+    })
+}
+
+/// scan the text and return a tree of all elements. The root element is at index 0.
+fn scan_elements(text: &str) -> Vec<ScannedElement> {
+    let mut nodes: Vec<ScannedElement> = Vec::new();
+    let mut stack: Vec<usize> = Vec::new();
+    let mut short_name_text = String::new();
+    let mut position = LineCounter::new(text);
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find('<') {
+        let tag_start = pos + offset;
+        if stack
+            .last()
+            .is_some_and(|&idx| nodes[idx].name == "SHORT-NAME")
+        {
+            short_name_text.push_str(&text[pos..tag_start]);
+        }
+        let rest = &text[tag_start..];
+
+        let skip_until = |terminator: &str| {
+            rest.find(terminator)
+                .map(|end| tag_start + end + terminator.len())
+        };
+        let next_pos = if rest.starts_with("<!--") {
+            skip_until("-->")
+        } else if rest.starts_with("<![CDATA[") {
+            skip_until("]]>")
+        } else if rest.starts_with("<?") {
+            skip_until("?>")
+        } else if rest.starts_with("<!") {
+            skip_until(">")
+        } else if rest.starts_with("</") {
+            let end = skip_until(">");
+            if let Some(idx) = stack.pop()
+                && nodes[idx].name == "SHORT-NAME"
+            {
+                if let Some(&parent_idx) = stack.last() {
+                    nodes[parent_idx].short_name = Some(short_name_text.trim().to_string());
+                }
+                short_name_text.clear();
+            }
+            end
+        } else {
+            let name_len = rest[1..]
+                .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .unwrap_or(rest.len() - 1);
+            let name = rest[1..1 + name_len].to_string();
+            let tag_end = find_tag_end(rest);
+            let (line, column) = position.line_column(tag_start);
+            let idx = nodes.len();
+            nodes.push(ScannedElement {
+                name,
+                line,
+                column,
+                short_name: None,
+                children: Vec::new(),
+            });
+            if let Some(&parent_idx) = stack.last() {
+                nodes[parent_idx].children.push(idx);
+            }
+            if let Some(tag_end) = tag_end {
+                if !rest[..tag_end].ends_with('/') {
+                    stack.push(idx);
+                }
+                Some(tag_start + tag_end + 1)
+            } else {
+                None
+            }
+        };
+
+        match next_pos {
+            Some(next_pos) => pos = next_pos,
+            None => break,
+        }
+    }
+
+    nodes
+}
+
+/// find the position of the closing '>' of a start tag, skipping over quoted attribute values
+pub(crate) fn find_tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (idx, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Helper to convert byte offsets into line and column numbers. Offsets must be requested in increasing order.
+struct LineCounter<'a> {
+    text: &'a str,
+    counted_until: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> LineCounter<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            counted_until: 0,
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    /// get the 1-based line and column of the given byte offset
+    fn line_column(&mut self, offset: usize) -> (usize, usize) {
+        for (idx, byte) in self.text.as_bytes()[self.counted_until..offset]
+            .iter()
+            .enumerate()
+        {
+            if *byte == b'\n' {
+                self.line += 1;
+                self.line_start = self.counted_until + idx + 1;
+            }
+        }
+        self.counted_until = offset;
+        let column = self.text[self.line_start..offset].chars().count() + 1;
+        (self.line, column)
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{
+    AttributeName, AutosarDataError, AutosarModel, CharacterData, ContentType, Element,
+    ElementContent, ElementName,
+};
+use regex::Regex;
+
+/// A piece of text in the model: the character data of an element, a character content item
+/// of an element with mixed content, or the value of an attribute
+pub(crate) struct TextLocation {
+    pub(crate) element: Element,
+    pub(crate) attribute: Option<AttributeName>,
+    pub(crate) content_index: Option<usize>,
+    pub(crate) value: CharacterData,
+}
+
+impl TextLocation {
+    pub(crate) fn text(&self) -> String {
+        match &self.value {
+            CharacterData::String(text) => text.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    /// only string values can be replaced; enum and numeric values are never modified
+    pub(crate) fn is_replaceable(&self) -> bool {
+        matches!(self.value, CharacterData::String(_))
+    }
+
+    /// replace the text at this location with a new string
+    pub(crate) fn replace(&self, new_text: &str) -> Result<(), AutosarDataError> {
+        match (self.attribute, self.content_index) {
+            (Some(attribute), _) => self
+                .element
+                .set_attribute(attribute, CharacterData::String(new_text.to_string())),
+            (None, Some(index)) => {
+                self.element.remove_character_content_item(index)?;
+                self.element.insert_character_content_item(new_text, index)
+            }
+            (None, None) => {
+                // renaming the parent also updates all references to it
+                if self.element.element_name() == ElementName::ShortName
+                    && let Some(parent) = self.element.parent()?
+                {
+                    parent.set_item_name(new_text)
+                } else {
+                    self.element
+                        .set_character_data(CharacterData::String(new_text.to_string()))
+                }
+            }
+        }
+    }
+}
+
+/// collect all text in the model, optionally restricted to elements with the given names
+pub(crate) fn text_locations(
+    model: &AutosarModel,
+    element_names: Option<&HashSet<ElementName>>,
+    include_attributes: bool,
+) -> Vec<TextLocation> {
+    let mut locations = Vec::new();
+    for (_, element) in model.elements_dfs() {
+        if element_names.is_some_and(|names| !names.contains(&element.element_name())) {
+            continue;
+        }
+        match element.content_type() {
+            ContentType::CharacterData => {
+                if let Some(value) = element.character_data() {
+                    locations.push(TextLocation {
+                        element: element.clone(),
+                        attribute: None,
+                        content_index: None,
+                        value,
+                    });
+                }
+            }
+            ContentType::Mixed => {
+                for (index, item) in element.content().enumerate() {
+                    if let ElementContent::CharacterData(value) = item {
+                        locations.push(TextLocation {
+                            element: element.clone(),
+                            attribute: None,
+                            content_index: Some(index),
+                            value,
+                        });
+                    }
+                }
+            }
+            ContentType::Elements => {}
+        }
+        if include_attributes {
+            for attribute in element.attributes() {
+                locations.push(TextLocation {
+                    element: element.clone(),
+                    attribute: Some(attribute.attrname),
+                    content_index: None,
+                    value: attribute.content,
+                });
+            }
+        }
+    }
+    locations
+}
+
+/// find all matches of the regex in the text, and return their spans as character offsets
+pub(crate) fn match_spans(regex: &Regex, text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut byte_pos = 0;
+    let mut char_pos = 0;
+    for found in regex.find_iter(text) {
+        char_pos += text[byte_pos..found.start()].chars().count();
+        let start = char_pos;
+        char_pos += found.as_str().chars().count();
+        byte_pos = found.end();
+        spans.push((start, char_pos));
+    }
+    spans
+}
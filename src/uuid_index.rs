@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use ::autosar_data as autosar_data_rs;
+use autosar_data_rs::{AttributeName, AutosarModel, Element, WeakElement};
+
+use crate::{is_model_key, model_key};
+
+// The autosar_data crate only maintains an index of the Autosar paths. The UUID index is kept here
+// instead. Elements can be modified in ways that the bindings don't observe, so the index is never
+// trusted blindly: every hit is checked against the element, and a miss or a stale hit causes the
+// index to be rebuilt.
+static UUID_INDEX: Mutex<Vec<UuidIndex>> = Mutex::new(Vec::new());
+
+struct UuidIndex {
+    /// identifies the model, see `model_key`
+    model: WeakElement,
+    entries: HashMap<String, WeakElement>,
+}
+
+/// get the UUID of an element, if it has one
+pub(crate) fn element_uuid(element: &Element) -> Option<String> {
+    element
+        .attribute_value(AttributeName::Uuid)
+        .map(|cdata| cdata.to_string())
+}
+
+/// find the element with the given UUID in the model
+///
+/// If several elements share the UUID, then any one of them is returned.
+/// Looking up a UUID that does not exist rebuilds the index, so that lookup scans the whole model.
+pub(crate) fn get_element_by_uuid(model: &AutosarModel, uuid: &str) -> Option<Element> {
+    let mut index = UUID_INDEX
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // drop the entries of models that no longer exist
+    index.retain(|entry| {
+        entry
+            .model
+            .upgrade()
+            .is_some_and(|root| root.model().is_ok())
+    });
+
+    if let Some(position) = index
+        .iter()
+        .position(|entry| is_model_key(&entry.model, model))
+    {
+        if let Some(element) = index[position]
+            .entries
+            .get(uuid)
+            .and_then(WeakElement::upgrade)
+            && has_uuid(&element, model, uuid)
+        {
+            return Some(element);
+        }
+        // the index is outdated, or the UUID was added after the index was built
+        index.remove(position);
+    }
+
+    let entries = build_index(model);
+    let element = entries.get(uuid).and_then(WeakElement::upgrade);
+    index.push(UuidIndex {
+        model: model_key(model),
+        entries,
+    });
+    element
+}
+
+/// check that an element from the index is still part of the model and still has the UUID
+fn has_uuid(element: &Element, model: &AutosarModel, uuid: &str) -> bool {
+    element
+        .model()
+        .is_ok_and(|element_model| element_model == *model)
+        && element_uuid(element).as_deref() == Some(uuid)
+}
+
+fn build_index(model: &AutosarModel) -> HashMap<String, WeakElement> {
+    let mut entries = HashMap::new();
+    for (_, element) in model.elements_dfs() {
+        if let Some(uuid) = element_uuid(&element) {
+            entries.entry(uuid).or_insert_with(|| element.downgrade());
+        }
+    }
+    entries
+}
@@ -1,4 +1,5 @@
 import os
+import pytest
 from typing import *
 
 from autosar_data import *
@@ -57,6 +58,19 @@ def test_model_abstraction(tmp_path: str) -> None:
     assert "__repr__" in AutosarModelAbstraction.__dict__
     assert len(repr(model)) > 0
 
+    # a frozen model can't be modified through the abstraction layer either
+    model.model.freeze()
+    try:
+        package.create_system("system2", SystemCategory.EcuExtract)
+        assert False, "expected ModelFrozenError"
+    except ModelFrozenError:
+        pass
+    with pytest.raises(ModelFrozenError):
+        package.create_constant_specification("constant", NumericalValueSpecification(1.0))
+    with pytest.raises(ModelFrozenError):
+        system.create_isignal_ipdu_group("group", package, CommunicationDirection.In)
+    model.model.unfreeze()
+
 
 def test_package() -> None:
     model = AutosarModelAbstraction.create("file.arxml")
@@ -628,3 +642,342 @@ def test_ecu_instance() -> None:
     # quick check if a custom __repr__ method is implemented and returns a non-empty string
     assert "__repr__" in EcuInstance.__dict__
     assert len(repr(ecu_instance)) > 0
+
+
+def test_sdg() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system = package.create_system("system", SystemCategory.EcuExtract)
+    system_signal = package.create_system_signal("system_signal")
+    isignal = system.create_isignal("isignal", package, 8, system_signal)
+
+    assert isignal.sdgs() == []
+    assert isignal.get_sdg("OEM") is None
+
+    sdg = isignal.set_sdg("OEM", {"Owner": "Team A", "Cycle": "10", "Limits": {"Min": "0"}})
+    assert isinstance(sdg, Sdg)
+    assert sdg.gid == "OEM"
+    assert sdg.get_sd("Owner") == "Team A"
+    assert sdg.get_sd("Cycle") == "10"
+    assert len(sdg.get_sdg("Limits").sds) == 1
+    assert sdg.get_sdg("Limits").get_sd("Min") == "0"
+    assert isignal.get_sdg("OEM") == sdg
+    el_sdg = isignal.element.get_sub_element("ADMIN-DATA").get_sub_element("SDGS").get_sub_element("SDG")
+    assert el_sdg.attribute_value("GID") == "OEM"
+
+    # setting an SDG with an existing GID replaces it
+    package.set_sdg("Vendor", {"Tool": "x"})
+    isignal.set_sdg("Other", {})
+    isignal.set_sdg("OEM", {"Owner": "Team B"})
+    assert [sdg.gid for sdg in isignal.sdgs()] == ["OEM", "Other"]
+    assert isignal.get_sdg("OEM").get_sd("Owner") == "Team B"
+    assert isignal.get_sdg("OEM").get_sd("Cycle") is None
+    assert package.get_sdg("Vendor").sds[0].value == "x"
+
+    # invalid values don't modify the model
+    try:
+        isignal.set_sdg("OEM", {"Owner": [1, 2]})
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+    assert isignal.get_sdg("OEM").get_sd("Owner") == "Team B"
+    try:
+        isignal.set_sdg("OEM", {"Cycle": 10})
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+    assert isignal.get_sdg("OEM").get_sd("Cycle") is None
+
+    # a list of pairs can contain repeated GIDs, and the order of SDs and SDGs is kept
+    sdg = isignal.set_sdg("Order", [("Item", "a"), ("Nested", [("Min", "0")]), ("Item", "b")])
+    assert [(item.gid, isinstance(item, Sdg)) for item in sdg.content] == [
+        ("Item", False),
+        ("Nested", True),
+        ("Item", False),
+    ]
+    assert [sd.value for sd in sdg.sds] == ["a", "b"]
+    el_order = isignal.element.get_sub_element("ADMIN-DATA").get_sub_element("SDGS").sub_elements[2]
+    assert [sub.element_name for sub in el_order.sub_elements] == ["SD", "SDG", "SD"]
+    assert isignal.get_sdg("Order") == sdg
+
+    # an Sdg object can be copied to another element
+    package.set_sdg(sdg)
+    assert package.get_sdg("Order") == sdg
+    try:
+        package.set_sdg(sdg, [("Item", "c")])
+        assert False, "expected TypeError"
+    except TypeError:
+        pass
+
+
+def test_documentation() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system_signal = package.create_system_signal("system_signal")
+
+    assert system_signal.desc() == {}
+    system_signal.set_desc("vehicle speed")
+    system_signal.set_desc("Fahrzeuggeschwindigkeit", language="DE")
+    assert system_signal.desc() == {"EN": "vehicle speed", "DE": "Fahrzeuggeschwindigkeit"}
+    assert system_signal.desc(language="DE") == {"DE": "Fahrzeuggeschwindigkeit"}
+    el_desc = system_signal.element.get_sub_element("DESC")
+    assert len(list(el_desc.sub_elements)) == 2
+
+    system_signal.set_desc("speed of the vehicle", language="EN")
+    assert system_signal.desc("EN") == {"EN": "speed of the vehicle"}
+    system_signal.set_desc(None, language="DE")
+    system_signal.set_desc(None, language="EN")
+    assert system_signal.element.get_sub_element("DESC") is None
+
+    system_signal.set_long_name("Vehicle Speed")
+    assert system_signal.long_name() == {"EN": "Vehicle Speed"}
+    assert system_signal.element.get_sub_element("LONG-NAME").get_sub_element("L-4").attribute_value("L") == "EN"
+
+    # each line of an introduction is a separate paragraph
+    package.set_introduction("first paragraph\nsecond paragraph")
+    package.set_introduction("erster Absatz", language="DE")
+    assert package.introduction() == {
+        "EN": "first paragraph\nsecond paragraph",
+        "DE": "erster Absatz",
+    }
+    el_introduction = package.element.get_sub_element("INTRODUCTION")
+    assert len(list(el_introduction.sub_elements)) == 2
+
+    try:
+        system_signal.set_desc("text", language="not a language")
+        assert False, "expected ValueError"
+    except ValueError:
+        pass
+
+
+def test_wrap() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system = package.create_system("system", SystemCategory.EcuExtract)
+    system_signal = package.create_system_signal("system_signal")
+    isignal = system.create_isignal("isignal", package, 8, system_signal)
+    can_cluster = system.create_can_cluster("can_cluster", package)
+    can_frame = system.create_can_frame("can_frame", package, 8)
+    app_swc = package.create_application_sw_component_type("app_swc")
+
+    assert wrap(package.element) == package
+    assert wrap(isignal.element) == isignal
+    assert isinstance(wrap(system_signal.element), SystemSignal)
+    assert isinstance(wrap(can_cluster.element), CanCluster)
+    assert isinstance(wrap(can_frame.element), CanFrame)
+    assert isinstance(wrap(app_swc.element), ApplicationSwComponentType)
+    # the SHORT-NAME has no abstraction class
+    assert wrap(isignal.element.get_sub_element("SHORT-NAME")) is None
+
+    typed_elements = list(package.typed_elements())
+    assert len(typed_elements) == len(list(package.elements()))
+    assert system in typed_elements
+    assert isignal in typed_elements
+    assert app_swc in typed_elements
+
+    assert model.get_typed_element_by_path("/package/isignal") == isignal
+    assert model.get_typed_element_by_path("/package/can_frame") == can_frame
+    assert model.get_typed_element_by_path("/package/nonexistent") is None
+    assert isinstance(model.get_typed_element_by_path("/package"), ArPackage)
+
+
+def test_model_lookups() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    ecu_extract = package.create_system("ecu_extract", SystemCategory.EcuExtract)
+    system_description = package.create_system(
+        "system_description", SystemCategory.SystemDescription
+    )
+    system_signal = package.create_system_signal("system_signal")
+    isignal_b = system_description.create_isignal("isignal_b", package, 8, system_signal)
+    isignal_a = system_description.create_isignal("isignal_a", package, 8, system_signal)
+    ecu_instance = system_description.create_ecu_instance("ecu_instance", package)
+    compu_method = package.create_compu_method("compu_method", CompuMethodContent.Identical())
+    app_swc = package.create_application_sw_component_type("app_swc")
+    composition = package.create_composition_sw_component_type("composition")
+    can_frame = system_description.create_can_frame("can_frame", package, 8)
+    isignal_ipdu = system_description.create_isignal_ipdu("isignal_ipdu", package, 8)
+
+    assert model.systems() == [ecu_extract, system_description]
+    assert model.systems(category=SystemCategory.EcuExtract) == [ecu_extract]
+    assert model.systems(category=SystemCategory.SystemExtract) == []
+
+    assert model.get_isignal("/package/isignal_a") == isignal_a
+    assert model.get_isignal("/package/nonexistent") is None
+    with pytest.raises(Exception):
+        model.get_isignal("/package/system_signal")
+    assert model.get_ecu_instance("/package/ecu_instance") == ecu_instance
+    assert model.get_system("/package/ecu_extract") == ecu_extract
+    with pytest.raises(Exception):
+        model.get_system("/package/ecu_instance")
+    assert model.get_system_signal("/package/system_signal") == system_signal
+    assert model.get_compu_method("/package/compu_method") == compu_method
+    assert model.get_sw_component_type("/package/composition") == composition
+    assert model.get_sw_component_type("/package/nonexistent") is None
+    with pytest.raises(Exception):
+        model.get_sw_component_type("/package/compu_method")
+
+    assert model.all_isignals() == [isignal_a, isignal_b]
+    assert model.all_ecu_instances() == [ecu_instance]
+    assert model.all_compu_methods() == [compu_method]
+    assert model.all_sw_component_types() == [app_swc, composition]
+    assert model.all_system_signals() == [system_signal]
+    assert model.all_frames() == [can_frame]
+    assert model.all_pdus() == [isignal_ipdu]
+
+    # the results are updated after the model is modified
+    isignal_c = system_description.create_isignal("isignal_c", package, 8, system_signal)
+    assert model.all_isignals() == [isignal_a, isignal_b, isignal_c]
+    isignal_a.name = "isignal_d"
+    assert model.all_isignals() == [isignal_b, isignal_c, isignal_a]
+    # changes made through the plain Element API are seen as well
+    package.element.get_sub_element("ELEMENTS").remove_sub_element(isignal_b.element)
+    assert model.all_isignals() == [isignal_c, isignal_a]
+    assert model.get_isignal("/package/isignal_b") is None
+
+
+def test_hash() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system = package.create_system("system", SystemCategory.EcuExtract)
+    system_signal = package.create_system_signal("system_signal")
+    isignal = system.create_isignal("isignal", package, 8, system_signal)
+    ecu_instance = system.create_ecu_instance("ecu_instance", package)
+
+    # wrappers of the same element are equal and have the same hash
+    assert hash(isignal) == hash(ISignal(isignal.element))
+    assert len({isignal, ISignal(isignal.element), system_signal}) == 2
+    # the hash is consistent with the hash of the element
+    assert hash(isignal) == hash(isignal.element)
+    assert hash(ecu_instance) == hash(ecu_instance.element)
+
+    senders = {ecu_instance: {isignal}}
+    assert senders[EcuInstance(ecu_instance.element)] == {isignal}
+    assert hash(model) == hash(AutosarModelAbstraction(model.model))
+
+    # wrappers without any other methods are hashable too
+    sporadic_frame = system.create_lin_sporadic_frame("sporadic_frame", package, 8)
+    unconditional_frame = system.create_lin_unconditional_frame("unconditional_frame", package, 8)
+    assert hash(sporadic_frame) == hash(LinSporadicFrame(sporadic_frame.element))
+    assert hash(unconditional_frame) == hash(unconditional_frame.element)
+    assert len({sporadic_frame, unconditional_frame, LinSporadicFrame(sporadic_frame.element)}) == 2
+    el_mapping = (
+        system.get_or_create_mapping("mapping")
+        .element.create_sub_element("DATA-MAPPINGS")
+        .create_sub_element("SENDER-RECEIVER-TO-SIGNAL-MAPPING")
+    )
+    signal_mapping = SenderReceiverToSignalMapping(el_mapping)
+    assert hash(signal_mapping) == hash(el_mapping)
+    assert signal_mapping in {SenderReceiverToSignalMapping(el_mapping)}
+
+
+def test_get_or_create() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system = package.create_system("system", SystemCategory.EcuExtract)
+
+    system_signal = package.get_or_create_system_signal("system_signal")
+    assert isinstance(system_signal, SystemSignal)
+    assert package.get_or_create_system_signal("system_signal") == system_signal
+    # an element with the same name, but a different type
+    with pytest.raises(Exception):
+        package.get_or_create_system_signal_group("system_signal")
+
+    isignal = system.get_or_create_isignal("isignal", package, 8, system_signal)
+    assert system.get_or_create_isignal("isignal", package, 8, system_signal) == isignal
+    with pytest.raises(Exception):
+        system.get_or_create_isignal("isignal", package, 16, system_signal)
+    other_signal = package.create_system_signal("other_signal")
+    with pytest.raises(Exception):
+        system.get_or_create_isignal("isignal", package, 8, other_signal)
+
+    ecu_instance = system.get_or_create_ecu_instance("ecu_instance", package)
+    assert system.get_or_create_ecu_instance("ecu_instance", package) == ecu_instance
+
+    composition = package.get_or_create_composition_sw_component_type("composition")
+    assert package.get_or_create_composition_sw_component_type("composition") == composition
+    interface = package.get_or_create_sender_receiver_interface("interface")
+    other_interface = package.create_sender_receiver_interface("other_interface")
+    r_port = composition.get_or_create_r_port("port", interface)
+    assert isinstance(r_port, RPortPrototype)
+    assert composition.get_or_create_r_port("port", interface) == r_port
+    assert len(list(composition.ports())) == 1
+    # the existing port has a different interface
+    with pytest.raises(Exception):
+        composition.get_or_create_r_port("port", other_interface)
+    # the existing port is not a provided port
+    with pytest.raises(Exception):
+        composition.get_or_create_p_port("port", interface)
+
+    assert package.get_or_create_system("system", SystemCategory.EcuExtract) == system
+    with pytest.raises(Exception):
+        package.get_or_create_system("system", SystemCategory.SystemDescription)
+
+    encoding = BaseTypeEncoding.NoEncoding
+    base_type = package.get_or_create_sw_base_type("uint8", 8, encoding)
+    assert package.get_or_create_sw_base_type("uint8", 8, encoding) == base_type
+    with pytest.raises(Exception):
+        package.get_or_create_sw_base_type("uint8", 16, encoding)
+
+    identical = CompuMethodContent.Identical()
+    compu_method = package.get_or_create_compu_method("compu_method", identical)
+    assert package.get_or_create_compu_method("compu_method", identical) == compu_method
+    linear = CompuMethodContent.Linear(
+        direction=CompuScaleDirection.IntToPhys,
+        offset=0.0,
+        factor=2.0,
+        divisor=1.0,
+        lower_limit=0.0,
+        upper_limit=100.0,
+    )
+    with pytest.raises(Exception):
+        package.get_or_create_compu_method("compu_method", linear)
+
+    can_frame = system.get_or_create_can_frame("can_frame", package, 8)
+    assert system.get_or_create_can_frame("can_frame", package, 8) == can_frame
+    with pytest.raises(Exception):
+        system.get_or_create_can_frame("can_frame", package, 16)
+    flexray_frame = system.get_or_create_flexray_frame("flexray_frame", package, 8)
+    assert system.get_or_create_flexray_frame("flexray_frame", package, 8) == flexray_frame
+    isignal_ipdu = system.get_or_create_isignal_ipdu("isignal_ipdu", package, 8)
+    assert system.get_or_create_isignal_ipdu("isignal_ipdu", package, 8) == isignal_ipdu
+    with pytest.raises(Exception):
+        system.get_or_create_isignal_ipdu("isignal_ipdu", package, 4)
+    nm_pdu = system.get_or_create_nm_pdu("nm_pdu", package, 8)
+    assert system.get_or_create_nm_pdu("nm_pdu", package, 8) == nm_pdu
+    n_pdu = system.get_or_create_n_pdu("n_pdu", package, 8)
+    assert system.get_or_create_n_pdu("n_pdu", package, 8) == n_pdu
+    with pytest.raises(Exception):
+        system.get_or_create_n_pdu("nm_pdu", package, 8)
+
+
+def test_preview_remove() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/package")
+    system = package.create_system("system", SystemCategory.EcuExtract)
+    system_signal = package.create_system_signal("system_signal")
+    isignal = system.create_isignal("isignal", package, 8, system_signal)
+    pdu = system.create_isignal_ipdu("pdu", package, 8)
+    pdu.map_signal(isignal, 0, ByteOrder.MostSignificantByteLast)
+
+    preview = pdu.preview_remove()
+    assert pdu.element in preview.elements
+    # elements inside of removed elements are not listed separately
+    assert all(
+        other.parent not in preview.elements
+        for other in preview.elements
+        if other.parent is not None
+    )
+    assert [file.filename for file in preview.files] == ["file.arxml"]
+    for reference in preview.dangling_references:
+        assert reference.reference_target is not None
+    assert "RemovePreview" in repr(preview)
+
+    # the model is not modified
+    assert model.get_element_by_path("/package/pdu") == pdu.element
+    assert model.get_element_by_path("/package/isignal") == isignal.element
+    assert len(list(pdu.mapped_signals())) == 1
+
+    shallow_preview = isignal.preview_remove(deep=False)
+    assert isignal.element in shallow_preview.elements
+    assert system_signal.element not in shallow_preview.elements
@@ -0,0 +1,97 @@
+from autosar_data import *
+from autosar_data.abstraction import *
+import pytest
+
+
+def add_condition(element: Element, formula_start: str, formula_end: str) -> None:
+    el_syscond = element.create_sub_element("VARIATION-POINT").create_sub_element(
+        "SW-SYSCOND"
+    )
+    if formula_start:
+        el_syscond.insert_character_content_item(formula_start, 0)
+    el_sysc_ref = el_syscond.create_sub_element("SYSC-REF")
+    el_sysc_ref.set_attribute("DEST", "SW-SYSTEMCONST")
+    el_sysc_ref.character_data = "/Pkg/Line"
+    el_syscond.insert_character_content_item(formula_end, el_syscond.content_item_count)
+
+
+def create_variant(elements: Element, name: str, value: str) -> Element:
+    el_value_set = elements.create_named_sub_element(
+        "SW-SYSTEMCONSTANT-VALUE-SET", f"{name}Values"
+    )
+    el_value = el_value_set.create_sub_element(
+        "SW-SYSTEMCONSTANT-VALUES"
+    ).create_sub_element("SW-SYSTEMCONST-VALUE")
+    el_const_ref = el_value.create_sub_element("SW-SYSTEMCONST-REF")
+    el_const_ref.set_attribute("DEST", "SW-SYSTEMCONST")
+    el_const_ref.character_data = "/Pkg/Line"
+    el_value.create_sub_element("VALUE").insert_character_content_item(value, 0)
+
+    el_variant = elements.create_named_sub_element("PREDEFINED-VARIANT", name)
+    el_set_ref = el_variant.create_sub_element(
+        "SW-SYSTEMCONSTANT-VALUE-SET-REFS"
+    ).create_sub_element("SW-SYSTEMCONSTANT-VALUE-SET-REF")
+    el_set_ref.set_attribute("DEST", "SW-SYSTEMCONSTANT-VALUE-SET")
+    el_set_ref.character_data = el_value_set.path
+    return el_variant
+
+
+def test_predefined_variant() -> None:
+    model = AutosarModelAbstraction.create("file.arxml")
+    package = model.get_or_create_package("/Pkg")
+    system = package.create_system("System", SystemCategory.SystemDescription)
+    elements = package.element.get_sub_element("ELEMENTS")
+    elements.create_named_sub_element("SW-SYSTEMCONST", "Line")
+    create_variant(elements, "Line1", "1")
+    create_variant(elements, "Line2", "0x2")
+
+    el_fibex_elements = system.element.get_or_create_sub_element("FIBEX-ELEMENTS")
+    el_cond1 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+    add_condition(el_cond1, "", " == 1")
+    el_cond2 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+    add_condition(el_cond2, "(", " >= 2) && !(1 > 2 ? 1 : 0)")
+    el_cond3 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+
+    variants = model.predefined_variants()
+    assert [variant.name for variant in variants] == ["Line1", "Line2"]
+    line1, line2 = variants
+    assert line1 == PredefinedVariant(line1.element)
+    with pytest.raises(Exception):
+        PredefinedVariant(elements)
+    assert line1.system_constant_values() == {"/Pkg/Line": 1.0}
+    assert line2.system_constant_values() == {"/Pkg/Line": 2.0}
+
+    assert line1.evaluate(el_cond1)
+    assert not line1.evaluate(el_cond2)
+    assert line1.evaluate(el_cond3)
+    assert not line2.evaluate(el_cond1.get_sub_element("VARIATION-POINT"))
+    assert line2.evaluate(el_cond2)
+    assert line1.variation_points() == [(el_cond1, True), (el_cond2, False)]
+
+    # the resolved model only contains the active elements, and no variation points
+    resolved = line2.resolve()
+    resolved_fibex_elements = resolved.get_element_by_path("/Pkg/System").get_sub_element(
+        "FIBEX-ELEMENTS"
+    )
+    resolved_conditionals = list(resolved_fibex_elements.sub_elements)
+    assert len(resolved_conditionals) == 2
+    assert all(
+        cond.get_sub_element("VARIATION-POINT") is None for cond in resolved_conditionals
+    )
+    # the original model is not modified
+    assert len(list(el_fibex_elements.sub_elements)) == 3
+    assert el_cond1.get_sub_element("VARIATION-POINT") is not None
+
+    # file headers and the frozen state are carried over to the resolved model
+    model.files()[0].header_comments = [" variant source "]
+    model.model.freeze()
+    resolved = line1.resolve()
+    assert resolved.files()[0].header_comments == [" variant source "]
+    assert resolved.model.is_frozen
+    model.model.unfreeze()
+
+    # formulas that can't be evaluated raise an error
+    el_cond4 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+    add_condition(el_cond4, "sin(", ")")
+    with pytest.raises(Exception):
+        line1.evaluate(el_cond4)
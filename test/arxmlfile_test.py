@@ -1,5 +1,6 @@
 from autosar_data import *
 import pytest
+import os
 
 
 def test_arxlfile_basic() -> None:
@@ -147,3 +148,83 @@ def test_arxmlfile_elements_dfs_iterator() -> None:
 
     element_info = [x for x in arxmlfile.elements_dfs_with_max_depth(2)]
     assert len(element_info) == 3
+
+
+def test_arxmlfile_header(tmp_path: str) -> None:
+    buffer = """<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<!-- generated by vendor tool -->
+<AUTOSAR xmlns="http://autosar.org/schema/r4.0" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050_COMPACT.xsd" T="2024-01-01T00:00:00">
+  <AR-PACKAGES>
+    <AR-PACKAGE>
+      <SHORT-NAME>Pkg</SHORT-NAME>
+    </AR-PACKAGE>
+  </AR-PACKAGES>
+</AUTOSAR>"""
+    model = AutosarModel()
+    (file, _) = model.load_buffer(buffer, "test.arxml")
+    assert file.encoding == "UTF-8"
+    assert file.xml_standalone == False
+    assert file.header_comments == [" generated by vendor tool "]
+    assert (
+        file.schema_location
+        == "http://autosar.org/schema/r4.0 AUTOSAR_00050_COMPACT.xsd"
+    )
+    assert file.root_attributes == {"T": "2024-01-01T00:00:00"}
+
+    # the header survives a round trip
+    text = file.serialize()
+    assert text.startswith(
+        '<?xml version="1.0" encoding="UTF-8" standalone="no"?>\n<!-- generated by vendor tool -->\n<AUTOSAR'
+    )
+    model2 = AutosarModel()
+    (file2, _) = model2.load_buffer(text, "test.arxml")
+    assert file2.encoding == "UTF-8"
+    assert file2.header_comments == file.header_comments
+    assert file2.schema_location == file.schema_location
+    assert file2.root_attributes == file.root_attributes
+    # and it is carried over when the model is copied
+    assert model.duplicate().files[0].header_comments == file.header_comments
+
+    # modify the header
+    file.xml_standalone = None
+    file.header_comments = ["first", "second"]
+    file.schema_location = "http://autosar.org/schema/r4.0 AUTOSAR_00050_OEM.xsd"
+    file.root_attributes = {"S": "checksum", "xml:space": "default"}
+    text = file.serialize()
+    assert text.startswith(
+        '<?xml version="1.0" encoding="UTF-8"?>\n<!--first-->\n<!--second-->\n<AUTOSAR'
+    )
+    assert 'xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050_OEM.xsd"' in text
+    assert 'S="checksum"' in text
+    assert 'xml:space="default"' in text
+
+    # the custom header is also used when writing the files
+    filename = os.path.join(tmp_path, "test.arxml")
+    file.filename = filename
+    model.write()
+    with open(filename, "r", encoding="utf-8") as f:
+        assert f.read() == text
+    assert model.serialize_files()[filename] == text
+
+    # restore the defaults
+    file.schema_location = None
+    file.header_comments = []
+    file.root_attributes = {}
+    # the default schema location depends on the version of the file
+    default_file = AutosarModel().create_file("default.arxml", file.version)
+    assert file.schema_location == default_file.schema_location
+    assert file.serialize().startswith('<?xml version="1.0" encoding="UTF-8"?>\n<AUTOSAR')
+
+    # the encoding is read-only, since files are always written as utf-8
+    with pytest.raises(AttributeError):
+        file.encoding = "iso-8859-1"  # type: ignore
+    # files with a different encoding are rejected, so the declaration always matches the utf-8 output
+    with pytest.raises(AutosarDataError):
+        AutosarModel().load_buffer(
+            buffer.replace('encoding="UTF-8"', 'encoding="ISO-8859-1"'), "test3.arxml"
+        )
+    # invalid values are rejected
+    with pytest.raises(ValueError):
+        file.header_comments = ["a -- b"]
+    with pytest.raises(ValueError):
+        file.root_attributes = {"xmlns": "x"}
@@ -0,0 +1,153 @@
+from autosar_data import *
+from autosar_data.cli import main
+import json
+import os
+import pytest
+
+
+def create_test_file(filename: str, broken_reference: bool = False) -> None:
+    model = AutosarModel()
+    model.create_file(filename, AutosarVersion.AUTOSAR_00051)
+    packages = model.root_element.create_sub_element("AR-PACKAGES")
+    system = (
+        packages.create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+        .create_named_sub_element("SYSTEM", "System")
+    )
+    cluster = (
+        packages.create_named_sub_element("AR-PACKAGE", "Pkg2")
+        .create_sub_element("ELEMENTS")
+        .create_named_sub_element("CAN-CLUSTER", "CanCluster")
+    )
+    fibex_element_ref = (
+        system.create_sub_element("FIBEX-ELEMENTS")
+        .create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+        .create_sub_element("FIBEX-ELEMENT-REF")
+    )
+    fibex_element_ref.reference_target = cluster
+    if broken_reference:
+        fibex_element_ref.character_data = "/Pkg2/CanClustr"
+    model.write()
+
+
+def run_json(args: list, capsys: pytest.CaptureFixture) -> tuple:
+    exit_code = main(args + ["--json"])
+    return exit_code, json.loads(capsys.readouterr().out)
+
+
+def test_cli_validate(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    filename = os.path.join(tmp_path, "test.arxml")
+    create_test_file(filename)
+    exit_code, result = run_json(["validate", filename], capsys)
+    assert exit_code == 0
+    assert result == {"valid": True, "findings": []}
+
+    missing = os.path.join(tmp_path, "missing.arxml")
+    exit_code, result = run_json(["validate", missing], capsys)
+    assert exit_code == 1
+    assert result["findings"][0]["file"] == missing
+
+    # loading errors in other commands prevent the command from running
+    assert main(["stats", missing]) == 2
+
+
+def test_cli_check_refs(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    filename = os.path.join(tmp_path, "test.arxml")
+    create_test_file(filename)
+    exit_code, result = run_json(["check-refs", filename], capsys)
+    assert exit_code == 0
+    assert result == {"broken_references": []}
+
+    create_test_file(filename, broken_reference=True)
+    exit_code, result = run_json(["check-refs", filename], capsys)
+    assert exit_code == 1
+    assert len(result["broken_references"]) == 1
+    broken = result["broken_references"][0]
+    assert broken["target"] == "/Pkg2/CanClustr"
+    assert broken["candidates"][0]["path"] == "/Pkg2/CanCluster"
+
+
+def test_cli_diff(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    old = os.path.join(tmp_path, "old.arxml")
+    new = os.path.join(tmp_path, "new.arxml")
+    create_test_file(old)
+    create_test_file(new)
+    exit_code, result = run_json(["diff", old, new], capsys)
+    assert exit_code == 0
+    assert result == {"added": [], "removed": [], "changed": []}
+
+    create_test_file(new, broken_reference=True)
+    exit_code, result = run_json(["diff", old, new], capsys)
+    assert exit_code == 1
+    assert result == {"added": [], "removed": [], "changed": ["/Pkg/System"]}
+
+
+def test_cli_convert_and_sort(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    filename = os.path.join(tmp_path, "test.arxml")
+    create_test_file(filename)
+    output_dir = os.path.join(tmp_path, "out")
+    exit_code, result = run_json(
+        ["convert", filename, "--version", "4.3.0", "--output-dir", output_dir], capsys
+    )
+    assert exit_code == 0
+    converted = os.path.join(output_dir, "test.arxml")
+    assert result["written"] == [converted]
+    model = AutosarModel()
+    arxml_file, _ = model.load_file(converted)
+    assert arxml_file.version == AutosarVersion.AUTOSAR_4_3_0
+
+    assert main(["convert", filename, "--version", "9.9.9"]) == 2
+
+    exit_code, result = run_json(["sort", filename], capsys)
+    assert exit_code == 0
+    assert result["written"] == [filename]
+
+
+def test_cli_split_merge(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    filename = os.path.join(tmp_path, "test.arxml")
+    create_test_file(filename)
+    output_dir = os.path.join(tmp_path, "split")
+    exit_code, result = run_json(
+        ["split", filename, "--output-dir", output_dir], capsys
+    )
+    assert exit_code == 0
+    split_files = [
+        os.path.join(output_dir, "Pkg.arxml"),
+        os.path.join(output_dir, "Pkg2.arxml"),
+    ]
+    assert result["written"] == split_files
+
+    merged = os.path.join(tmp_path, "merged.arxml")
+    exit_code, result = run_json(["merge"] + split_files + ["-o", merged], capsys)
+    assert exit_code == 0
+    model = AutosarModel()
+    model.load_file(merged)
+    assert model.get_element_by_path("/Pkg/System") is not None
+    assert model.get_element_by_path("/Pkg2/CanCluster") is not None
+    assert model.check_references() == []
+
+
+def test_cli_stats_extract(tmp_path: str, capsys: pytest.CaptureFixture) -> None:
+    filename = os.path.join(tmp_path, "test.arxml")
+    create_test_file(filename)
+    exit_code, result = run_json(["stats", filename], capsys)
+    assert exit_code == 0
+    assert result["identifiable_elements"] == 4
+    assert result["broken_references"] == 0
+    assert result["element_types"]["AR-PACKAGE"] == 2
+    assert result["files"][0]["filename"] == filename
+
+    extracted = os.path.join(tmp_path, "extracted.arxml")
+    exit_code, result = run_json(
+        ["extract", filename, "-p", "/Pkg/System", "-o", extracted], capsys
+    )
+    assert exit_code == 0
+    assert result["unresolved_references"] == []
+    model = AutosarModel()
+    model.load_file(extracted)
+    # the referenced cluster is extracted as well
+    assert model.get_element_by_path("/Pkg2/CanCluster") is not None
+
+    # text output
+    assert main(["stats", filename]) == 0
+    assert "identifiable elements: 4" in capsys.readouterr().out
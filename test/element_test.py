@@ -643,3 +643,264 @@ def test_element_coment() -> None:
     assert model.root_element.comment is None
     model.root_element.comment = "text"
     assert model.root_element.comment == "text"
+
+
+def test_element_content_hash() -> None:
+    model = AutosarModel()
+    model.create_file("test")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_unit = el_elements.create_named_sub_element("UNIT", "Unit")
+    el_unit.set_attribute("UUID", "1234")
+    hash1 = el_unit.content_hash()
+    assert len(hash1) == 64
+    assert el_unit.content_hash("sha512") != hash1
+    with pytest.raises(ValueError):
+        el_unit.content_hash("md4")
+
+    # comments and the UUID do not affect the hash
+    el_unit.comment = "comment"
+    el_unit.set_attribute("UUID", "5678")
+    assert el_unit.content_hash() == hash1
+    # unless the UUID is not ignored
+    assert el_unit.content_hash(ignore=[]) != hash1
+
+    # an identical element in a different model has the same hash
+    model2 = AutosarModel()
+    model2.load_buffer(model.files[0].serialize(), "test2")
+    assert model2.get_element_by_path("/Pkg/Unit").content_hash() == hash1
+
+    # changing the content changes the hash
+    el_unit.create_sub_element("FACTOR-SI-TO-UNIT").character_data = 2.0
+    assert el_unit.content_hash() != hash1
+
+    fingerprints = model.fingerprints()
+    assert len(fingerprints) == 2
+    assert fingerprints["/Pkg/Unit"] == el_unit.content_hash()
+    assert model.fingerprints("sha384")["/Pkg"] != fingerprints["/Pkg"]
+
+
+def test_element_source_location() -> None:
+    buffer = """<?xml version="1.0" encoding="utf-8"?>
+<AUTOSAR xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050.xsd" xmlns="http://autosar.org/schema/r4.0" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <AR-PACKAGES>
+    <!-- comment > with a bracket -->
+    <AR-PACKAGE>
+      <SHORT-NAME>Pkg</SHORT-NAME>
+      <ELEMENTS>
+        <SYSTEM UUID="abc">
+          <SHORT-NAME>System</SHORT-NAME>
+        </SYSTEM>
+      </ELEMENTS>
+    </AR-PACKAGE>
+  </AR-PACKAGES>
+</AUTOSAR>"""
+    model = AutosarModel()
+    model.load_buffer(buffer, "test.arxml")
+    assert model.root_element.source_location == ("test.arxml", 2, 1)
+    assert model.get_element_by_path("/Pkg").source_location == ("test.arxml", 5, 5)
+    el_system = model.get_element_by_path("/Pkg/System")
+    assert el_system.source_location == ("test.arxml", 8, 9)
+    assert el_system.get_sub_element("SHORT-NAME").source_location == ("test.arxml", 9, 11)
+
+    # elements that were created programmatically have no source location
+    el_elements = model.get_element_by_path("/Pkg").get_sub_element("ELEMENTS")
+    el_unit = el_elements.create_named_sub_element("UNIT", "Unit")
+    assert el_unit.source_location is None
+
+    # a second file is merged into the existing elements
+    buffer2 = """<?xml version="1.0" encoding="utf-8"?>
+<AUTOSAR xsi:schemaLocation="http://autosar.org/schema/r4.0 AUTOSAR_00050.xsd" xmlns="http://autosar.org/schema/r4.0" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <AR-PACKAGES>
+    <AR-PACKAGE>
+      <SHORT-NAME>Pkg</SHORT-NAME>
+      <ELEMENTS>
+        <SYSTEM>
+          <SHORT-NAME>System2</SHORT-NAME>
+        </SYSTEM>
+      </ELEMENTS>
+    </AR-PACKAGE>
+  </AR-PACKAGES>
+</AUTOSAR>"""
+    model.load_buffer(buffer2, "test2.arxml")
+    # elements that are part of both files keep the location in the first file
+    assert model.get_element_by_path("/Pkg").source_location == ("test.arxml", 5, 5)
+    assert el_system.source_location == ("test.arxml", 8, 9)
+    el_system2 = model.get_element_by_path("/Pkg/System2")
+    assert el_system2.source_location == ("test2.arxml", 7, 9)
+    assert el_system2.get_sub_element("SHORT-NAME").source_location == ("test2.arxml", 8, 11)
+
+
+def test_element_import_from() -> None:
+    source = AutosarModel()
+    source.create_file("source")
+    el_supplier = source.root_element.create_sub_element("AR-PACKAGES").create_named_sub_element(
+        "AR-PACKAGE", "SupplierA"
+    )
+    el_src_elements = el_supplier.create_sub_element("ELEMENTS")
+    el_src_unit = el_src_elements.create_named_sub_element("UNIT", "Unit")
+    el_src_compu_method = el_src_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod")
+    el_src_compu_method.create_sub_element("UNIT-REF").reference_target = el_src_unit
+
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Vehicle")
+        .create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Imported")
+        .create_sub_element("ELEMENTS")
+    )
+    rename_map = {"/SupplierA/": "/Vehicle/Imported/"}
+    el_unit = el_elements.import_from(el_src_unit, rename_map=rename_map)
+    el_compu_method = el_elements.import_from(el_src_compu_method, rename_map=rename_map)
+    assert el_compu_method.path == "/Vehicle/Imported/CompuMethod"
+    el_unit_ref = el_compu_method.get_sub_element("UNIT-REF")
+    assert el_unit_ref.character_data == "/Vehicle/Imported/Unit"
+    assert el_unit_ref.reference_target == el_unit
+    assert model.check_references() == []
+
+    # handling of name conflicts
+    with pytest.raises(AutosarDataError):
+        el_elements.import_from(el_src_compu_method)
+    assert el_elements.import_from(el_src_compu_method, on_conflict="skip") == el_compu_method
+    el_renamed = el_elements.import_from(el_src_compu_method, rename_map=rename_map, on_conflict="rename")
+    assert el_renamed.item_name == "CompuMethod_1"
+    assert el_renamed.get_sub_element("UNIT-REF").reference_target == el_unit
+    el_replaced = el_elements.import_from(el_src_compu_method, on_conflict="replace")
+    assert el_replaced.path == "/Vehicle/Imported/CompuMethod"
+    assert el_replaced.position == 1
+    # without a rename_map, the reference still points to the source path
+    assert el_replaced.get_sub_element("UNIT-REF").character_data == "/SupplierA/Unit"
+    with pytest.raises(ValueError):
+        el_elements.import_from(el_src_unit, on_conflict="invalid")
+
+
+def test_element_reorder() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_ar_packages = model.root_element.create_sub_element("AR-PACKAGES")
+    el_pkg_c = el_ar_packages.create_named_sub_element("AR-PACKAGE", "C")
+    el_pkg_a = el_ar_packages.create_named_sub_element("AR-PACKAGE", "A")
+    el_pkg_b = el_ar_packages.create_named_sub_element("AR-PACKAGE", "B")
+
+    def names() -> list[str]:
+        return [element.item_name for element in el_ar_packages.sub_elements]
+
+    el_pkg_c.insert_after(el_pkg_b)
+    assert names() == ["A", "B", "C"]
+    el_pkg_c.insert_before(el_pkg_a)
+    assert names() == ["C", "A", "B"]
+    el_pkg_c.move_to(1)
+    assert names() == ["A", "C", "B"]
+    el_pkg_a.swap_with(el_pkg_b)
+    assert names() == ["B", "C", "A"]
+    el_ar_packages.sort_by(lambda element: element.item_name)
+    assert names() == ["A", "B", "C"]
+    el_ar_packages.sort_by(lambda element: element.item_name != "C")
+    assert names() == ["C", "A", "B"]
+    with pytest.raises(AutosarDataError):
+        el_pkg_a.move_to(3)
+
+    # the content of AR-PACKAGE is a Sequence: the order of differently named elements is fixed
+    el_short_name = el_pkg_a.get_sub_element("SHORT-NAME")
+    el_elements = el_pkg_a.create_sub_element("ELEMENTS")
+    with pytest.raises(AutosarDataError):
+        el_elements.insert_before(el_short_name)
+    with pytest.raises(AutosarDataError):
+        el_elements.swap_with(el_short_name)
+    with pytest.raises(AutosarDataError):
+        el_elements.move_to(0)
+    with pytest.raises(AutosarDataError):
+        el_pkg_a.sort_by(lambda element: element.element_name)
+    assert el_short_name.position == 0
+
+    # an element can be moved to the end of a run of same-named elements, even if a different element follows
+    el_list = el_pkg_a.create_sub_element("INTRODUCTION").create_sub_element("LIST")
+    el_item1 = el_list.create_sub_element("ITEM")
+    el_item2 = el_list.create_sub_element("ITEM")
+    el_variation_point = el_list.create_sub_element("VARIATION-POINT")
+    el_item1.move_to(1)
+    assert [item for item in el_list.sub_elements] == [el_item2, el_item1, el_variation_point]
+    with pytest.raises(AutosarDataError):
+        el_item1.move_to(2)
+
+    # elements can also be moved into another parent
+    el_system = el_elements.create_named_sub_element("SYSTEM", "System")
+    el_elements_b = el_pkg_b.create_sub_element("ELEMENTS")
+    el_unit = el_elements_b.create_named_sub_element("UNIT", "Unit")
+    el_system.insert_before(el_unit)
+    assert el_system.parent == el_elements_b
+    assert el_system.position == 0
+    assert el_system.path == "/B/System"
+
+    # an element can not be swapped with its own sub element; nothing is moved
+    with pytest.raises(AutosarDataError):
+        el_pkg_b.swap_with(el_system)
+    assert el_system.parent == el_elements_b
+    assert el_pkg_b.parent == el_ar_packages
+
+
+def test_element_iterators() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_ar_packages = model.root_element.create_sub_element("AR-PACKAGES")
+    for name in ["Pkg1", "Pkg2", "Pkg3"]:
+        el_ar_packages.create_named_sub_element("AR-PACKAGE", name)
+    el_pkg1 = model.get_element_by_path("/Pkg1")
+    el_elements = el_pkg1.create_sub_element("ELEMENTS")
+    el_elements.create_named_sub_element("SYSTEM-SIGNAL", "Signal")
+    el_pkg1.create_sub_element("DESC")
+
+    # the length hint does not buffer any items
+    sub_elements = el_ar_packages.sub_elements
+    assert sub_elements.__length_hint__() <= 3
+    assert [item.item_name for item in sub_elements] == ["Pkg1", "Pkg2", "Pkg3"]
+
+    # length and indexing
+    sub_elements = el_ar_packages.sub_elements
+    assert len(sub_elements) == 3
+    assert sub_elements[0].item_name == "Pkg1"
+    assert sub_elements[-1].item_name == "Pkg3"
+    assert [item.item_name for item in sub_elements[1:]] == ["Pkg2", "Pkg3"]
+    with pytest.raises(IndexError):
+        sub_elements[3]
+    # length and indexing don't consume any items
+    assert next(sub_elements).item_name == "Pkg1"
+    assert len(sub_elements) == 2
+    assert sub_elements.__length_hint__() == 2
+    assert [item.item_name for item in sub_elements] == ["Pkg2", "Pkg3"]
+    assert len(sub_elements) == 0
+
+    # lookup by name
+    sub_elements = el_ar_packages.sub_elements
+    assert sub_elements.by_name("Pkg2").path == "/Pkg2"
+    assert sub_elements.by_name("Pkg4") is None
+    assert len(sub_elements) == 3
+    assert model.identifiable_elements.by_name("Signal")[0] == "/Pkg1/Signal"
+    el_pkg1.set_attribute("UUID", "abc")
+    assert el_pkg1.attributes.by_name("UUID").content == "abc"
+
+    # filtering by element name
+    filtered = el_pkg1.sub_elements.filter(element_name="DESC")
+    assert [item.element_name for item in filtered] == ["DESC"]
+    filtered = el_pkg1.sub_elements.filter(element_name="DESC").filter(element_name="ELEMENTS")
+    assert list(filtered) == []
+    filtered = model.elements_dfs.filter(element_name="SYSTEM-SIGNAL")
+    assert [item[1].path for item in filtered] == ["/Pkg1/Signal"]
+    with pytest.raises(AutosarDataError):
+        el_pkg1.sub_elements.filter(element_name="not an element")
+
+    assert [item.element_name for item in el_pkg1.sub_elements_named("ELEMENTS")] == ["ELEMENTS"]
+    assert len(el_ar_packages.sub_elements_named("AR-PACKAGE")) == 3
+    named = el_ar_packages.elements_dfs_named(["AR-PACKAGE", "SYSTEM-SIGNAL"])
+    assert [(depth, item.item_name) for depth, item in named] == [
+        (1, "Pkg1"),
+        (3, "Signal"),
+        (1, "Pkg2"),
+        (1, "Pkg3"),
+    ]
+    assert len(model.elements_dfs_named(["SYSTEM-SIGNAL"])) == 1
@@ -0,0 +1,89 @@
+from autosar_data import *
+from autosar_data.lint import *
+import json
+import pytest
+import xml.etree.ElementTree as ET
+
+
+def test_lint() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_ar_packages = model.root_element.create_sub_element("AR-PACKAGES")
+    el_empty = el_ar_packages.create_named_sub_element("AR-PACKAGE", "Empty")
+    el_elements = el_ar_packages.create_named_sub_element(
+        "AR-PACKAGE", "Pkg"
+    ).create_sub_element("ELEMENTS")
+    el_isignal = el_elements.create_named_sub_element("I-SIGNAL", "bad_name")
+    el_isignal.create_sub_element("DESC")
+    el_isignal.set_attribute("UUID", "0123")
+    el_isignal2 = el_elements.create_named_sub_element("I-SIGNAL", "Sig_Speed")
+    el_isignal2.create_sub_element("DESC")
+    el_isignal2.set_attribute("UUID", "4567")
+    el_ref = el_isignal2.create_sub_element("SYSTEM-SIGNAL-REF")
+    el_ref.set_attribute("DEST", "SYSTEM-SIGNAL")
+    el_ref.character_data = "/Pkg/Missing"
+
+    linter = Linter()
+    assert "empty-package" in linter.rules
+    assert Linter.describe_rule("empty-package") is not None
+    assert linter.get_severity("missing-desc") == Severity.Info
+    with pytest.raises(ValueError):
+        linter.get_severity("no-such-rule")
+    linter.set_naming_convention("I-SIGNAL", r"Sig_\w+")
+    with pytest.raises(ValueError):
+        linter.set_naming_convention("I-SIGNAL", "(")
+
+    findings = linter.run(model)
+    found = {(finding.rule, finding.element) for finding in findings}
+    assert ("empty-package", el_empty) in found
+    assert ("short-name-convention", el_isignal) in found
+    assert ("isignal-without-system-signal", el_isignal) in found
+    assert ("broken-reference", el_ref) in found
+    assert ("short-name-convention", el_isignal2) not in found
+    assert ("isignal-without-system-signal", el_isignal2) not in found
+    assert all(finding.rule != "missing-desc" for finding in findings)
+
+    # rules can be disabled and their severity can be changed
+    linter.set_severity("broken-reference", None)
+    linter.set_severity("empty-package", Severity.Error)
+    findings = linter.run(model)
+    assert all(finding.rule != "broken-reference" for finding in findings)
+    assert [f.severity for f in findings if f.rule == "empty-package"] == [Severity.Error]
+
+    # python rules only receive the selected elements
+    checked = []
+
+    def check_isignal(element: Element):
+        checked.append(element)
+        if element.item_name == "bad_name":
+            return ["first problem", "second problem"]
+        return None
+
+    linter.add_rule("custom", check_isignal, element_names=["I-SIGNAL"], severity=Severity.Info)
+    with pytest.raises(ValueError):
+        linter.add_rule("custom", check_isignal)
+    findings = linter.run(model)
+    assert checked == [el_isignal, el_isignal2]
+    custom = [f for f in findings if f.rule == "custom"]
+    assert [f.message for f in custom] == ["first problem", "second problem"]
+    assert custom[0].severity == Severity.Info
+    assert "custom" in str(custom[0])
+
+    linter.add_rule("bad-return", lambda element: 42, element_names=["AR-PACKAGE"])
+    with pytest.raises(TypeError):
+        linter.run(model)
+    linter.set_severity("bad-return", None)
+
+    # output formats
+    findings = linter.run(model)
+    sarif = json.loads(to_sarif(findings))
+    assert sarif["version"] == "2.1.0"
+    results = sarif["runs"][0]["results"]
+    assert len(results) == len(findings)
+    assert results[0]["ruleId"] == findings[0].rule
+    assert results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"] == "file"
+
+    junit = ET.fromstring(to_junit(findings))
+    assert junit.tag == "testsuites"
+    assert int(junit.get("failures")) == len(findings)
+    assert len(junit.findall("testsuite/testcase/failure")) == len(findings)
@@ -298,3 +298,488 @@ def test_model_misc_4() -> None:
     subelements = [elem for elem in el_ar_packages.sub_elements]
     assert subelements[0] == el_pkg1
     assert subelements[1] == el_pkg2
+
+
+def test_model_unreferenced() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_system = el_elements.create_named_sub_element("SYSTEM", "System")
+    el_cluster = el_elements.create_named_sub_element("CAN-CLUSTER", "CanCluster")
+    el_unit = el_elements.create_named_sub_element("UNIT", "Unit")
+    el_compu_method = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod")
+    el_compu_method.create_sub_element("UNIT-REF").reference_target = el_unit
+    el_fibex_element_ref = (
+        el_system.create_sub_element("FIBEX-ELEMENTS")
+        .create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+        .create_sub_element("FIBEX-ELEMENT-REF")
+    )
+    el_fibex_element_ref.reference_target = el_cluster
+
+    # the system and the compu method are not referenced by anything
+    unreferenced = model.find_unreferenced()
+    assert unreferenced == [el_compu_method, el_system]
+
+    # filter by element type
+    unreferenced = model.find_unreferenced(element_types=["COMPU-METHOD"])
+    assert unreferenced == [el_compu_method]
+    with pytest.raises(AutosarDataError):
+        model.find_unreferenced(element_types=["NOT-AN-ELEMENT"])
+
+    # with roots, everything that can't be reached from them is reported
+    unreferenced = model.find_unreferenced(roots=[el_system])
+    assert unreferenced == [el_compu_method, el_unit]
+    # element types that are not collected are kept, so the unit is reachable from the compu method
+    unreferenced = model.find_unreferenced(element_types=["UNIT"], roots=[el_system])
+    assert unreferenced == []
+
+    # a dry run reports the unit, which becomes unreferenced once the compu method is removed
+    removed = model.remove_unreferenced(roots=[el_system])
+    assert removed == ["/Pkg/CompuMethod", "/Pkg/Unit"]
+    assert model.get_element_by_path("/Pkg/CompuMethod") is not None
+
+    removed = model.remove_unreferenced(dry_run=False, roots=[el_system])
+    assert removed == ["/Pkg/CompuMethod", "/Pkg/Unit"]
+    assert model.get_element_by_path("/Pkg/CompuMethod") is None
+    assert model.get_element_by_path("/Pkg/Unit") is None
+    assert model.get_element_by_path("/Pkg/CanCluster") is not None
+
+
+def test_model_unreferenced_cycle() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_system = el_elements.create_named_sub_element("SYSTEM", "System")
+    # two record types which only reference each other
+    el_record_a = el_elements.create_named_sub_element("APPLICATION-RECORD-DATA-TYPE", "RecordA")
+    el_record_b = el_elements.create_named_sub_element("APPLICATION-RECORD-DATA-TYPE", "RecordB")
+    el_record_a.create_sub_element("ELEMENTS").create_named_sub_element(
+        "APPLICATION-RECORD-ELEMENT", "Element"
+    ).create_sub_element("TYPE-TREF").reference_target = el_record_b
+    el_record_b.create_sub_element("ELEMENTS").create_named_sub_element(
+        "APPLICATION-RECORD-ELEMENT", "Element"
+    ).create_sub_element("TYPE-TREF").reference_target = el_record_a
+
+    # without roots, the cycle is not found because both records are referenced
+    assert model.find_unreferenced() == [el_system]
+
+    # the cycle can't be reached from the system
+    unreferenced = model.find_unreferenced(roots=[el_system])
+    assert unreferenced == [el_record_a, el_record_b]
+
+    removed = model.remove_unreferenced(dry_run=False, roots=[el_system])
+    assert removed == ["/Pkg/RecordA", "/Pkg/RecordB"]
+    assert model.get_element_by_path("/Pkg/RecordA") is None
+    assert model.get_element_by_path("/Pkg/RecordB") is None
+    assert model.get_element_by_path("/Pkg/System") is not None
+
+    # everything inside of an AR-PACKAGE root is kept
+    el_record_c = el_elements.create_named_sub_element("APPLICATION-RECORD-DATA-TYPE", "RecordC")
+    el_pkg = el_elements.parent
+    assert model.find_unreferenced(roots=[el_system]) == [el_record_c]
+    assert model.find_unreferenced(roots=[el_pkg]) == []
+
+
+def test_model_deduplicate() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_unit1 = el_elements.create_named_sub_element("UNIT", "Unit1")
+    el_unit1.set_attribute("UUID", "1111")
+    el_unit1.create_sub_element("FACTOR-SI-TO-UNIT").character_data = 1.0
+    el_unit2 = el_elements.create_named_sub_element("UNIT", "Unit2")
+    el_unit2.set_attribute("UUID", "2222")
+    el_unit2.create_sub_element("FACTOR-SI-TO-UNIT").character_data = 1.0
+    el_unit3 = el_elements.create_named_sub_element("UNIT", "Unit3")
+    el_unit3.create_sub_element("FACTOR-SI-TO-UNIT").character_data = 2.0
+    # two compu methods which only differ in the unit they reference
+    el_compu_method1 = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod1")
+    el_compu_method1.create_sub_element("UNIT-REF").reference_target = el_unit1
+    el_compu_method2 = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod2")
+    el_compu_method2.create_sub_element("UNIT-REF").reference_target = el_unit2
+    el_data_constr = el_elements.create_named_sub_element("DATA-CONSTR", "DataConstr")
+
+    replacements = model.deduplicate(["UNIT", "COMPU-METHOD"])
+    assert replacements == {
+        "/Pkg/Unit2": "/Pkg/Unit1",
+        "/Pkg/CompuMethod2": "/Pkg/CompuMethod1",
+    }
+    assert model.get_element_by_path("/Pkg/Unit2") is None
+    assert model.get_element_by_path("/Pkg/CompuMethod2") is None
+    assert model.get_element_by_path("/Pkg/Unit3") == el_unit3
+    assert el_compu_method1.get_sub_element("UNIT-REF").reference_target == el_unit1
+    assert len(model.check_references()) == 0
+
+    # element types that are not listed are not affected
+    el_elements.create_named_sub_element("DATA-CONSTR", "DataConstr2")
+    assert model.deduplicate(["UNIT"]) == {}
+    assert model.get_element_by_path("/Pkg/DataConstr2") is not None
+    assert model.deduplicate(["DATA-CONSTR"]) == {"/Pkg/DataConstr2": "/Pkg/DataConstr"}
+    assert model.get_element_by_path("/Pkg/DataConstr") == el_data_constr
+
+
+def test_model_uuids() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_unit1 = el_elements.create_named_sub_element("UNIT", "Unit1")
+    el_unit1.set_attribute("UUID", "1111")
+    assert model.get_element_by_uuid("1111") == el_unit1
+    assert model.get_element_by_uuid("2222") is None
+
+    # copying an element also copies its UUID
+    el_unit2 = el_elements.create_copied_sub_element(el_unit1)
+    assert el_unit2.attribute_value("UUID") == 1111
+    duplicates = model.find_duplicate_uuids()
+    assert len(duplicates) == 1
+    assert len(duplicates["1111"]) == 2
+
+    model.regenerate_uuids(el_unit2)
+    assert model.find_duplicate_uuids() == {}
+    uuid2 = el_unit2.attribute_value("UUID")
+    assert model.get_element_by_uuid(str(uuid2)) == el_unit2
+    assert model.get_element_by_uuid("1111") == el_unit1
+
+    # the index is updated when the UUIDs change
+    el_unit1.set_attribute("UUID", "3333")
+    assert model.get_element_by_uuid("1111") is None
+    assert model.get_element_by_uuid("3333") == el_unit1
+    # removed elements are not found, and elements that are added later are
+    el_elements.remove_sub_element(el_unit2)
+    assert model.get_element_by_uuid(str(uuid2)) is None
+    el_unit4 = el_elements.create_named_sub_element("UNIT", "Unit4")
+    el_unit4.set_attribute("UUID", "4444")
+    assert model.get_element_by_uuid("4444") == el_unit4
+
+    # the package is the only identifiable element without a UUID
+    counter = iter(range(100))
+    assigned = model.assign_missing_uuids(lambda: f"generated-{next(counter)}")
+    assert assigned == [model.get_element_by_path("/Pkg")]
+    assert assigned[0].attribute_value("UUID") == "generated-0"
+    assert model.assign_missing_uuids() == []
+
+
+def test_model_extract() -> None:
+    model = AutosarModel()
+    model.create_file("file", AutosarVersion.AUTOSAR_00050)
+    el_pkg = model.root_element.create_sub_element("AR-PACKAGES").create_named_sub_element(
+        "AR-PACKAGE", "Pkg"
+    )
+    el_elements = el_pkg.create_sub_element("ELEMENTS")
+    el_units = (
+        el_pkg.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Units")
+        .create_sub_element("ELEMENTS")
+    )
+    el_unit = el_units.create_named_sub_element("UNIT", "Unit")
+    el_compu_method = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod")
+    el_compu_method.create_sub_element("UNIT-REF").reference_target = el_unit
+    el_elements.create_named_sub_element("COMPU-METHOD", "Other")
+    el_broken = el_elements.create_named_sub_element("COMPU-METHOD", "Broken")
+    el_broken_ref = el_broken.create_sub_element("UNIT-REF")
+    el_broken_ref.set_attribute("DEST", "UNIT")
+    el_broken_ref.character_data = "/Pkg/Missing"
+
+    # the referenced unit is copied together with the compu method
+    extracted, unresolved = model.extract(["/Pkg/CompuMethod"])
+    assert unresolved == []
+    assert extracted != model
+    assert len(extracted.files) == 1
+    assert extracted.files[0].version == AutosarVersion.AUTOSAR_00050
+    assert extracted.get_element_by_path("/Pkg/CompuMethod") is not None
+    assert extracted.get_element_by_path("/Pkg/Units/Unit") is not None
+    assert extracted.get_element_by_path("/Pkg/Other") is None
+    assert extracted.check_references() == []
+
+    # without dependencies, the reference to the unit remains unresolved
+    extracted, unresolved = model.extract(["/Pkg/CompuMethod"], include_dependencies=False)
+    assert unresolved == [("/Pkg/CompuMethod", "/Pkg/Units/Unit")]
+    assert extracted.get_element_by_path("/Pkg/Units/Unit") is None
+
+    # extract into an existing model; elements that already exist there are not copied again
+    destination = AutosarModel()
+    destination.create_file("destination")
+    returned, unresolved = model.extract(["/Pkg/Broken", "/Pkg/Units"], into=destination)
+    assert returned == destination
+    assert unresolved == [("/Pkg/Broken", "/Pkg/Missing")]
+    assert destination.get_element_by_path("/Pkg/Units/Unit") is not None
+    _, unresolved = model.extract(["/Pkg/CompuMethod"], into=destination)
+    assert unresolved == []
+    assert destination.get_element_by_path("/Pkg/CompuMethod") is not None
+
+    with pytest.raises(AutosarDataError):
+        model.extract(["/Pkg/NotFound"])
+    with pytest.raises(AutosarDataError):
+        model.extract(["/Pkg/CompuMethod"], into=model)
+
+
+def test_model_move_package() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_ar_packages = model.root_element.create_sub_element("AR-PACKAGES")
+    el_pkg = el_ar_packages.create_named_sub_element("AR-PACKAGE", "Pkg")
+    el_units_pkg = el_pkg.create_sub_element("AR-PACKAGES").create_named_sub_element("AR-PACKAGE", "Units")
+    el_unit = el_units_pkg.create_sub_element("ELEMENTS").create_named_sub_element("UNIT", "Unit")
+    el_compu_method = el_pkg.create_sub_element("ELEMENTS").create_named_sub_element(
+        "COMPU-METHOD", "CompuMethod"
+    )
+    el_unit_ref = el_compu_method.create_sub_element("UNIT-REF")
+    el_unit_ref.reference_target = el_unit
+
+    # move the package to a new location; the intermediate packages are created
+    el_moved = model.move_package("/Pkg/Units", "/Common/Base/UnitDefinitions")
+    assert el_moved.path == "/Common/Base/UnitDefinitions"
+    assert el_unit.path == "/Common/Base/UnitDefinitions/Unit"
+    assert el_unit_ref.character_data == "/Common/Base/UnitDefinitions/Unit"
+    assert model.get_element_by_path("/Common/Base") is not None
+    assert model.check_references() == []
+
+    with pytest.raises(AutosarDataError):
+        model.move_package("/Pkg/CompuMethod", "/Other")
+    with pytest.raises(AutosarDataError):
+        model.move_package("/Common", "/Common/Base/Common")
+    with pytest.raises(AutosarDataError):
+        model.move_package("/Common", "/Pkg")
+
+    # move everything below /Common
+    replacements = model.rename_path_prefix("/Common/", "/Pkg/Common/")
+    assert replacements == {"/Common/Base": "/Pkg/Common/Base"}
+    assert el_unit.path == "/Pkg/Common/Base/UnitDefinitions/Unit"
+    assert el_unit_ref.reference_target == el_unit
+
+    # packaged elements are moved individually
+    replacements = model.rename_path_prefix("/Pkg/CompuMethod", "/Methods/CompuMethod")
+    assert replacements == {"/Pkg/CompuMethod": "/Methods/CompuMethod"}
+    assert el_compu_method.path == "/Methods/CompuMethod"
+    assert model.check_references() == []
+
+    # the prefix only matches complete path segments
+    el_pkg_a = el_ar_packages.create_named_sub_element("AR-PACKAGE", "A")
+    el_pkg_ab = el_ar_packages.create_named_sub_element("AR-PACKAGE", "AB")
+    replacements = model.rename_path_prefix("/A", "/C")
+    assert replacements == {"/A": "/C"}
+    assert el_pkg_a.path == "/C"
+    assert el_pkg_ab.path == "/AB"
+    assert model.rename_path_prefix("/Meth", "/Other") == {}
+
+
+def test_model_reference_report() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "New")
+        .create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Units")
+        .create_sub_element("ELEMENTS")
+    )
+    el_unit1 = el_elements.create_named_sub_element("UNIT", "Unit1")
+    el_unit2 = el_elements.create_named_sub_element("UNIT", "Unit2")
+    el_compu_method1 = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod1")
+    el_unit_ref1 = el_compu_method1.create_sub_element("UNIT-REF")
+    el_unit_ref1.set_attribute("DEST", "UNIT")
+    el_unit_ref1.character_data = "/Old/Units/Unit1"
+    el_compu_method2 = el_elements.create_named_sub_element("COMPU-METHOD", "CompuMethod2")
+    el_unit_ref2 = el_compu_method2.create_sub_element("UNIT-REF")
+    el_unit_ref2.set_attribute("DEST", "UNIT")
+    el_unit_ref2.character_data = "/Old/Units/Unit"
+
+    report = model.reference_report()
+    assert len(report) == 2
+    entry1 = next(entry for entry in report if entry.element == el_unit_ref1)
+    assert entry1.target == "/Old/Units/Unit1"
+    assert entry1.dest == "UNIT"
+    assert [element for element, _ in entry1.candidates] == [el_unit1, el_unit2]
+    assert entry1.candidates[0][1] > 0.9
+    assert entry1.candidates[1][1] < 0.9
+    # compu methods are never candidates for a reference to a unit
+    entry2 = next(entry for entry in report if entry.element == el_unit_ref2)
+    assert [element for element, _ in entry2.candidates] == [el_unit1, el_unit2]
+
+    # only the first reference has a single unambiguous candidate
+    repaired = model.auto_repair()
+    assert repaired == [(el_unit_ref1, "/Old/Units/Unit1", "/New/Units/Unit1")]
+    assert el_unit_ref1.reference_target == el_unit1
+    assert model.check_references() == [el_unit_ref2]
+    with pytest.raises(ValueError):
+        model.auto_repair(2.0)
+
+
+def test_model_search_replace_text() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_signal1 = el_elements.create_named_sub_element("I-SIGNAL", "Old_Speed")
+    el_signal1.set_attribute("UUID", "Old_uuid")
+    el_signal2 = el_elements.create_named_sub_element("I-SIGNAL", "Old_Rpm")
+    el_l2 = el_signal2.create_sub_element("DESC").create_sub_element("L-2")
+    el_l2.set_attribute("L", "EN")
+    el_l2.insert_character_content_item("Engine spede in Old_Rpm", 0)
+    el_system = el_elements.create_named_sub_element("SYSTEM", "System")
+    el_ref = (
+        el_system.create_sub_element("FIBEX-ELEMENTS")
+        .create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+        .create_sub_element("FIBEX-ELEMENT-REF")
+    )
+    el_ref.reference_target = el_signal1
+
+    matches = model.search_text(r"Old_\w+", element_names=["SHORT-NAME"])
+    assert len(matches) == 2
+    assert matches[0].element == el_signal1.get_sub_element("SHORT-NAME")
+    assert matches[0].text == "Old_Speed"
+    assert matches[0].spans == [(0, 9)]
+    assert matches[0].attribute is None
+
+    matches = model.search_text("spede", element_names=["L-2"])
+    assert len(matches) == 1
+    assert matches[0].element == el_l2
+    assert matches[0].content_index == 0
+    assert matches[0].spans == [(7, 12)]
+
+    matches = model.search_text("^Old_", element_names=["I-SIGNAL"], include_attributes=True)
+    assert len(matches) == 1
+    assert matches[0].attribute == "UUID"
+
+    with pytest.raises(ValueError):
+        model.search_text("(")
+
+    # a dry run reports the changes without applying them
+    changes = model.replace_text(r"^Old_(\w+)", "New_$1", element_names=["SHORT-NAME"])
+    assert len(changes) == 2
+    assert changes[0] == (el_signal1.get_sub_element("SHORT-NAME"), None, "Old_Speed", "New_Speed")
+    assert el_signal1.item_name == "Old_Speed"
+
+    # renaming through the SHORT-NAME also updates the references
+    model.replace_text(r"^Old_(\w+)", "New_$1", dry_run=False, element_names=["SHORT-NAME"])
+    assert el_signal1.item_name == "New_Speed"
+    assert el_signal2.item_name == "New_Rpm"
+    assert el_ref.character_data == "/Pkg/New_Speed"
+
+    changes = model.replace_text("spede", "speed", dry_run=False)
+    assert changes == [(el_l2, None, "Engine spede in Old_Rpm", "Engine speed in Old_Rpm")]
+    assert model.search_text("speed")[0].text == "Engine speed in Old_Rpm"
+
+
+def test_model_get_element_by_xml_path() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_system = el_elements.create_named_sub_element("SYSTEM", "System")
+    el_fibex_elements = el_system.create_sub_element("FIBEX-ELEMENTS")
+    el_conditional1 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+    el_conditional2 = el_fibex_elements.create_sub_element("FIBEX-ELEMENT-REF-CONDITIONAL")
+    el_ref = el_conditional2.create_sub_element("FIBEX-ELEMENT-REF")
+
+    assert model.get_element_by_xml_path(model.root_element.xml_path) == model.root_element
+    assert model.get_element_by_xml_path(el_system.xml_path) == el_system
+    assert model.get_element_by_xml_path(el_fibex_elements.xml_path) == el_fibex_elements
+    # the xml path of both conditionals is the same, so it is ambiguous
+    assert el_conditional1.xml_path == el_conditional2.xml_path
+    with pytest.raises(AutosarDataError):
+        model.get_element_by_xml_path(el_conditional2.xml_path)
+    # an index selects one of the siblings
+    assert model.get_element_by_xml_path(el_conditional2.xml_path + "[2]") == el_conditional2
+    assert model.get_element_by_xml_path(el_conditional1.xml_path + "[1]") == el_conditional1
+    assert model.get_element_by_xml_path(el_conditional1.xml_path + "[3]") is None
+    # only the second conditional contains a FIBEX-ELEMENT-REF, so its path is unique
+    assert model.get_element_by_xml_path(el_ref.xml_path) == el_ref
+
+    assert model.get_element_by_xml_path("/<AUTOSAR>/<AR-PACKAGES>/Missing") is None
+    assert model.get_element_by_xml_path("not a path") is None
+
+
+def test_model_freeze(tmp_path: str) -> None:
+    model = AutosarModel()
+    file = model.create_file("file")
+    el_pkg = model.root_element.create_sub_element("AR-PACKAGES").create_named_sub_element(
+        "AR-PACKAGE", "Pkg"
+    )
+    assert not model.is_frozen
+
+    model.freeze()
+    assert model.is_frozen
+    with pytest.raises(ModelFrozenError):
+        el_pkg.create_sub_element("ELEMENTS")
+    with pytest.raises(ModelFrozenError):
+        el_pkg.item_name = "Renamed"
+    with pytest.raises(ModelFrozenError):
+        file.filename = "other"
+    with pytest.raises(ModelFrozenError):
+        model.create_file("file2")
+    # ModelFrozenError is a subclass of AutosarDataError
+    with pytest.raises(AutosarDataError):
+        el_pkg.remove_sub_element_kind("ELEMENTS")
+    assert el_pkg.item_name == "Pkg"
+    assert file.filename == "file"
+    # read-only operations still work
+    assert model.remove_unreferenced(dry_run=True) is not None
+    assert model.get_element_by_path("/Pkg") == el_pkg
+
+    model.unfreeze()
+    assert not model.is_frozen
+    el_pkg.create_sub_element("ELEMENTS")
+
+    # a model can be loaded in read-only mode
+    filename = os.path.join(tmp_path, "test.arxml")
+    file.filename = filename
+    model.write()
+    model2 = AutosarModel()
+    model2.load_file(filename, read_only=True)
+    assert model2.is_frozen
+    with pytest.raises(ModelFrozenError):
+        model2.root_element.get_sub_element("AR-PACKAGES").create_named_sub_element(
+            "AR-PACKAGE", "Pkg2"
+        )
+
+
+def test_model_find_missing_documentation() -> None:
+    model = AutosarModel()
+    model.create_file("file")
+    el_elements = (
+        model.root_element.create_sub_element("AR-PACKAGES")
+        .create_named_sub_element("AR-PACKAGE", "Pkg")
+        .create_sub_element("ELEMENTS")
+    )
+    el_pkg = el_elements.parent
+    el_signal1 = el_elements.create_named_sub_element("SYSTEM-SIGNAL", "Signal1")
+    el_l2 = el_signal1.create_sub_element("DESC").create_sub_element("L-2")
+    el_l2.set_attribute("L", "EN")
+    el_l2.insert_character_content_item("documented", 0)
+    el_signal2 = el_elements.create_named_sub_element("SYSTEM-SIGNAL", "Signal2")
+
+    assert model.find_missing_documentation() == [(el_pkg, ["DESC"]), (el_signal2, ["DESC"])]
+    assert model.find_missing_documentation(element_types=["SYSTEM-SIGNAL"]) == [
+        (el_signal2, ["DESC"])
+    ]
+    assert model.find_missing_documentation(
+        fields=["LONG-NAME", "DESC"], languages=["EN", "DE"], element_types=["SYSTEM-SIGNAL"]
+    ) == [
+        (el_signal1, ["LONG-NAME:EN", "LONG-NAME:DE", "DESC:DE"]),
+        (el_signal2, ["LONG-NAME:EN", "LONG-NAME:DE", "DESC:EN", "DESC:DE"]),
+    ]
+    with pytest.raises(ValueError):
+        model.find_missing_documentation(fields=["SHORT-NAME"])